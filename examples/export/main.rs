@@ -71,30 +71,36 @@ fn export(output: Output) {
 
     let (min, max) = bounding_coords(&triangle_vertices);
 
+    let mut root = json::Root::default();
+
     let buffer_length = (triangle_vertices.len() * mem::size_of::<Vertex>()) as u32;
-    let buffer = json::Buffer {
+    let buffer = root.push(json::Buffer {
         byte_length: buffer_length,
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         name: None,
         uri: if output == Output::Standard {
             Some("buffer0.bin".into())
         } else {
             None
         },
-    };
-    let buffer_view = json::buffer::View {
-        buffer: json::Index::new(0),
-        byte_length: buffer.byte_length,
+    });
+    let buffer_view = root.push(json::buffer::View {
+        buffer,
+        byte_length: buffer_length,
         byte_offset: None,
         byte_stride: Some(mem::size_of::<Vertex>() as u32),
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         name: None,
         target: Some(Valid(json::buffer::Target::ArrayBuffer)),
-    };
-    let positions = json::Accessor {
-        buffer_view: Some(json::Index::new(0)),
+    });
+    let positions = root.push(json::Accessor {
+        buffer_view: Some(buffer_view),
         byte_offset: 0,
         count: triangle_vertices.len() as u32,
         component_type: Valid(json::accessor::GenericComponentType(
@@ -102,15 +108,17 @@ fn export(output: Output) {
         )),
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         type_: Valid(json::accessor::Type::Vec3),
         min: Some(json::Value::from(Vec::from(min))),
         max: Some(json::Value::from(Vec::from(max))),
         name: None,
         normalized: false,
         sparse: None,
-    };
-    let colors = json::Accessor {
-        buffer_view: Some(json::Index::new(0)),
+    });
+    let colors = root.push(json::Accessor {
+        buffer_view: Some(buffer_view),
         byte_offset: (3 * mem::size_of::<f32>()) as u32,
         count: triangle_vertices.len() as u32,
         component_type: Valid(json::accessor::GenericComponentType(
@@ -118,66 +126,68 @@ fn export(output: Output) {
         )),
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         type_: Valid(json::accessor::Type::Vec3),
         min: None,
         max: None,
         name: None,
         normalized: false,
         sparse: None,
-    };
+    });
 
     let primitive = json::mesh::Primitive {
         attributes: {
             let mut map = std::collections::BTreeMap::new();
-            map.insert(Valid(json::mesh::Semantic::Positions), json::Index::new(0));
-            map.insert(Valid(json::mesh::Semantic::Colors(0)), json::Index::new(1));
+            map.insert(Valid(json::mesh::Semantic::Positions), positions);
+            map.insert(Valid(json::mesh::Semantic::Colors(0)), colors);
             map
         },
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         indices: None,
         material: None,
         mode: Valid(json::mesh::Mode::Triangles),
         targets: None,
     };
 
-    let mesh = json::Mesh {
+    let mesh = root.push(json::Mesh {
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         name: None,
         primitives: vec![primitive],
         weights: None,
-    };
+    });
 
-    let node = json::Node {
+    let node = root.push(json::Node {
         camera: None,
         children: None,
         extensions: Default::default(),
         extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
         matrix: None,
-        mesh: Some(json::Index::new(0)),
+        mesh: Some(mesh),
         name: None,
         rotation: None,
         scale: None,
         translation: None,
         skin: None,
         weights: None,
-    };
+    });
 
-    let root = json::Root {
-        accessors: vec![positions, colors],
-        buffers: vec![buffer],
-        buffer_views: vec![buffer_view],
-        meshes: vec![mesh],
+    root.push(json::Scene {
+        extensions: Default::default(),
+        extras: Default::default(),
+        #[cfg(feature = "unrecognized")]
+        unrecognized: Default::default(),
+        name: None,
         nodes: vec![node],
-        scenes: vec![json::Scene {
-            extensions: Default::default(),
-            extras: Default::default(),
-            name: None,
-            nodes: vec![json::Index::new(0)],
-        }],
-        ..Default::default()
-    };
+    });
 
     match output {
         Output::Standard => {
@@ -202,6 +212,7 @@ fn export(output: Output) {
                 },
                 bin: Some(Cow::Owned(to_padded_byte_vector(triangle_vertices))),
                 json: Cow::Owned(json_string.into_bytes()),
+                chunks: Vec::new(),
             };
             let writer = std::fs::File::create("triangle.glb").expect("I/O error");
             glb.to_writer(writer).expect("glTF binary output error");
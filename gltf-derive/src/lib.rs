@@ -9,7 +9,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Validate)]
+#[proc_macro_derive(Validate, attributes(gltf))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
@@ -23,6 +23,24 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         _ => panic!("#[derive(Validate)] only works on `struct`s"),
     };
     let ident = &ast.ident;
+    // `#[gltf(validate_hook = "path::to::hook")]` names a function called
+    // after every field has been validated, for checks spanning fields.
+    let mut hook = None;
+    for attr in ast.attrs.iter().filter(|x| x.path().is_ident("gltf")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("validate_hook") {
+                let name: syn::LitStr = meta.value()?.parse()?;
+                hook = Some(name.parse::<syn::Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported gltf attribute"))
+            }
+        });
+        if let Err(err) = result {
+            return err.to_compile_error();
+        }
+    }
+    let hook = hook.map(|hook| quote!(#hook(self, _root, &_path, _report);));
     let validations: Vec<TokenStream> = fields
         .iter()
         .map(|f| f.ident.as_ref().unwrap())
@@ -55,6 +73,7 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 #(
                     #validations;
                 )*
+                #hook
             }
         }
     )
@@ -93,7 +93,11 @@ pub mod sparse {
         pub buffer_view: Index<buffer::View>,
 
         /// The offset relative to the start of the parent `BufferView` in bytes.
-        #[serde(default, rename = "byteOffset")]
+        #[serde(
+            default,
+            rename = "byteOffset",
+            skip_serializing_if = "is_byte_offset_default"
+        )]
         pub byte_offset: u32,
 
         /// The data type of each index.
@@ -109,8 +113,23 @@ pub mod sparse {
         #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
         #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
         pub extras: Extras,
+
+        /// Properties unknown to this crate, kept so that they are written back
+        /// when serializing.
+        #[cfg(feature = "unrecognized")]
+        #[cfg_attr(feature = "unrecognized", serde(flatten))]
+        pub unrecognized: crate::Unrecognized,
     }
 
+    impl_partial_eq!(Indices {
+        buffer_view,
+        byte_offset,
+        component_type,
+        extensions,
+        #[cfg(feature = "unrecognized")]
+        unrecognized,
+    } extras);
+
     /// Sparse storage of attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
     pub struct Sparse {
@@ -139,8 +158,23 @@ pub mod sparse {
         #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
         #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
         pub extras: Extras,
+
+        /// Properties unknown to this crate, kept so that they are written back
+        /// when serializing.
+        #[cfg(feature = "unrecognized")]
+        #[cfg_attr(feature = "unrecognized", serde(flatten))]
+        pub unrecognized: crate::Unrecognized,
     }
 
+    impl_partial_eq!(Sparse {
+        count,
+        indices,
+        values,
+        extensions,
+        #[cfg(feature = "unrecognized")]
+        unrecognized,
+    } extras);
+
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
@@ -153,7 +187,11 @@ pub mod sparse {
         pub buffer_view: Index<buffer::View>,
 
         /// The offset relative to the start of the parent buffer view in bytes.
-        #[serde(default, rename = "byteOffset")]
+        #[serde(
+            default,
+            rename = "byteOffset",
+            skip_serializing_if = "is_byte_offset_default"
+        )]
         pub byte_offset: u32,
 
         /// Extension specific data.
@@ -165,7 +203,21 @@ pub mod sparse {
         #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
         #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
         pub extras: Extras,
+
+        /// Properties unknown to this crate, kept so that they are written back
+        /// when serializing.
+        #[cfg(feature = "unrecognized")]
+        #[cfg_attr(feature = "unrecognized", serde(flatten))]
+        pub unrecognized: crate::Unrecognized,
     }
+
+    impl_partial_eq!(Values {
+        buffer_view,
+        byte_offset,
+        extensions,
+        #[cfg(feature = "unrecognized")]
+        unrecognized,
+    } extras);
 }
 
 /// A typed view into a buffer view.
@@ -179,7 +231,11 @@ pub struct Accessor {
     pub buffer_view: Option<Index<buffer::View>>,
 
     /// The offset relative to the start of the parent `BufferView` in bytes.
-    #[serde(default, rename = "byteOffset")]
+    #[serde(
+        default,
+        rename = "byteOffset",
+        skip_serializing_if = "is_byte_offset_default"
+    )]
     pub byte_offset: u32,
 
     /// The number of components within the buffer view - not to be confused
@@ -200,6 +256,12 @@ pub struct Accessor {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// Specifies if the attribute is a scalar, vector, or matrix.
     #[serde(rename = "type")]
     pub type_: Checked<Type>,
@@ -228,18 +290,210 @@ pub struct Accessor {
     pub sparse: Option<sparse::Sparse>,
 }
 
+impl_partial_eq!(Accessor {
+    buffer_view,
+    byte_offset,
+    count,
+    component_type,
+    extensions,
+    type_,
+    min,
+    max,
+    #[cfg(feature = "names")]
+    name,
+    normalized,
+    sparse,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+/// The per component values of an accessor's `min` or `max`, typed by its
+/// component type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    /// Values of type `i8`.
+    I8(Vec<i8>),
+    /// Values of type `u8`.
+    U8(Vec<u8>),
+    /// Values of type `i16`.
+    I16(Vec<i16>),
+    /// Values of type `u16`.
+    U16(Vec<u16>),
+    /// Values of type `u32`.
+    U32(Vec<u32>),
+    /// Values of type `f32`.
+    F32(Vec<f32>),
+}
+
+impl Values {
+    /// Reads `value` as the values of `n` components of type
+    /// `component_type`, or returns `None` if it is not an array of `n`
+    /// numbers representable by the component type.
+    fn from_value(value: &Value, component_type: ComponentType, n: usize) -> Option<Self> {
+        fn read<T: TryFrom<i64>>(numbers: &[f64]) -> Option<Vec<T>> {
+            numbers
+                .iter()
+                .map(|&x| {
+                    if x.fract() == 0.0 {
+                        T::try_from(x as i64).ok()
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        }
+
+        let array = value.as_array().filter(|array| array.len() == n)?;
+        let numbers = array
+            .iter()
+            .map(Value::as_f64)
+            .collect::<Option<Vec<f64>>>()?;
+        Some(match component_type {
+            ComponentType::I8 => Values::I8(read(&numbers)?),
+            ComponentType::U8 => Values::U8(read(&numbers)?),
+            ComponentType::I16 => Values::I16(read(&numbers)?),
+            ComponentType::U16 => Values::U16(read(&numbers)?),
+            ComponentType::U32 => Values::U32(read(&numbers)?),
+            ComponentType::F32 => Values::F32(numbers.iter().map(|&x| x as f32).collect()),
+        })
+    }
+
+    /// Returns the number of components.
+    pub fn len(&self) -> usize {
+        match self {
+            Values::I8(values) => values.len(),
+            Values::U8(values) => values.len(),
+            Values::I16(values) => values.len(),
+            Values::U16(values) => values.len(),
+            Values::U32(values) => values.len(),
+            Values::F32(values) => values.len(),
+        }
+    }
+
+    /// Returns `true` if there are no components.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Converts the values to `f32` as they are, without normalization.
+    pub fn to_f32(&self) -> Vec<f32> {
+        match self {
+            Values::I8(values) => values.iter().map(|&x| x as f32).collect(),
+            Values::U8(values) => values.iter().map(|&x| x as f32).collect(),
+            Values::I16(values) => values.iter().map(|&x| x as f32).collect(),
+            Values::U16(values) => values.iter().map(|&x| x as f32).collect(),
+            Values::U32(values) => values.iter().map(|&x| x as f32).collect(),
+            Values::F32(values) => values.clone(),
+        }
+    }
+}
+
+impl Accessor {
+    /// Returns the size of a single component in bytes, or `None` if the
+    /// component type is invalid.
+    pub fn component_size(&self) -> Option<usize> {
+        match self.component_type {
+            Checked::Valid(GenericComponentType(component_type)) => Some(component_type.size()),
+            Checked::Invalid => None,
+        }
+    }
+
+    /// Returns the number of components of each element, such as 3 for
+    /// `VEC3`, or `None` if the type is invalid.
+    pub fn num_components(&self) -> Option<usize> {
+        match self.type_ {
+            Checked::Valid(type_) => Some(type_.multiplicity()),
+            Checked::Invalid => None,
+        }
+    }
+
+    /// Returns `min` typed by the component type of the accessor.
+    ///
+    /// Returns `None` if `min` is missing, if the component type or type is
+    /// invalid, or if `min` does not hold one number of the component type
+    /// per component.
+    ///
+    /// ```
+    /// # use gltf_json::accessor::{Accessor, Values};
+    /// let json = r#"{"count": 1, "componentType": 5123, "type": "VEC2", "min": [0, 2], "max": [1.5, 3]}"#;
+    /// let accessor: Accessor = gltf_json::deserialize::from_str(json).unwrap();
+    /// assert_eq!(accessor.min_values(), Some(Values::U16(vec![0, 2])));
+    /// assert_eq!(accessor.max_values(), None);
+    /// ```
+    pub fn min_values(&self) -> Option<Values> {
+        self.values(self.min.as_ref()?)
+    }
+
+    /// Returns `max` typed by the component type of the accessor.
+    ///
+    /// See [`min_values`](Self::min_values).
+    pub fn max_values(&self) -> Option<Values> {
+        self.values(self.max.as_ref()?)
+    }
+
+    fn values(&self, value: &Value) -> Option<Values> {
+        match self.component_type {
+            Checked::Valid(GenericComponentType(component_type)) => {
+                Values::from_value(value, component_type, self.num_components()?)
+            }
+            Checked::Invalid => None,
+        }
+    }
+
+    /// Returns the size of a single element in bytes, or `None` if the
+    /// component type or type is invalid.
+    ///
+    /// Matrices with 1 or 2 byte components are larger than their number of
+    /// components suggests, since every column starts on a 4 byte boundary.
+    ///
+    /// ```
+    /// # use gltf_json::Accessor;
+    /// let json = r#"{"count": 1, "componentType": 5121, "type": "MAT3"}"#;
+    /// let accessor: Accessor = gltf_json::deserialize::from_str(json).unwrap();
+    /// assert_eq!(accessor.num_components(), Some(9));
+    /// assert_eq!(accessor.element_size(), Some(12));
+    /// ```
+    pub fn element_size(&self) -> Option<usize> {
+        match (self.component_type, self.type_) {
+            (Checked::Valid(GenericComponentType(component_type)), Checked::Valid(type_)) => {
+                Some(element_size(component_type, type_))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes from the start of the buffer view up to
+    /// the end of the last element, which is the least `byteLength` the
+    /// buffer view must have.
+    ///
+    /// `stride` is the `byteStride` of the buffer view, with elements tightly
+    /// packed if it is `None`. Returns `None` if the component type or type is
+    /// invalid.
+    ///
+    /// ```
+    /// # use gltf_json::Accessor;
+    /// let json = r#"{"byteOffset": 4, "count": 3, "componentType": 5126, "type": "VEC3"}"#;
+    /// let accessor: Accessor = gltf_json::deserialize::from_str(json).unwrap();
+    /// assert_eq!(accessor.required_byte_length(None), Some(40));
+    /// assert_eq!(accessor.required_byte_length(Some(32)), Some(80));
+    /// ```
+    pub fn required_byte_length(&self, stride: Option<usize>) -> Option<u64> {
+        let element_size = self.element_size()? as u64;
+        let stride = stride.map_or(element_size, |x| x as u64);
+        let offset = self.byte_offset as u64;
+        Some(match self.count {
+            0 => offset,
+            count => offset + stride * (count as u64 - 1) + element_size,
+        })
+    }
+}
+
 impl Validate for Accessor {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
         P: Fn() -> Path,
         R: FnMut(&dyn Fn() -> Path, Error),
     {
-        if self.sparse.is_none() && self.buffer_view.is_none() {
-            // If sparse is missing, then bufferView must be present. Report that bufferView is
-            // missing since it is the more common one to require.
-            report(&|| path().field("bufferView"), Error::Missing);
-        }
-
         self.buffer_view
             .validate(root, || path().field("bufferView"), report);
         self.byte_offset
@@ -258,6 +512,69 @@ impl Validate for Accessor {
             .validate(root, || path().field("normalized"), report);
         self.sparse
             .validate(root, || path().field("sparse"), report);
+
+        let component_type = match self.component_type {
+            Checked::Valid(GenericComponentType(component_type)) => component_type,
+            Checked::Invalid => return,
+        };
+
+        // spec: `normalized` **must not** be set to true for `FLOAT` or `UNSIGNED_INT`
+        // component types.
+        if self.normalized && matches!(component_type, ComponentType::F32 | ComponentType::U32) {
+            report(&|| path().field("normalized"), Error::Invalid);
+        }
+
+        // The accessed elements must lie within the parent buffer view.
+        if let Some(view) = self.buffer_view.and_then(|index| root.get(index)) {
+            let stride = view.byte_stride.map(|x| x as usize);
+            if let Some(end) = self.required_byte_length(stride) {
+                if self.count > 0 && end > view.byte_length as u64 {
+                    report(&|| path().field("count"), Error::Invalid);
+                }
+            }
+        }
+
+        // The sparse indices and values, which are tightly packed, must lie
+        // within their buffer views too.
+        if let Some(sparse) = self.sparse.as_ref() {
+            let count = sparse.count as u64;
+            let fits = |view: Index<buffer::View>, offset: u32, size: usize| {
+                root.get(view).map_or(true, |view| {
+                    offset as u64 + count * size as u64 <= view.byte_length as u64
+                })
+            };
+            if let Checked::Valid(IndexComponentType(index_type)) = sparse.indices.component_type {
+                let indices = &sparse.indices;
+                if !fits(indices.buffer_view, indices.byte_offset, index_type.size()) {
+                    report(
+                        &|| path().field("sparse").field("indices").field("byteOffset"),
+                        Error::Invalid,
+                    );
+                }
+            }
+            if let Some(element_size) = self.element_size() {
+                let values = &sparse.values;
+                if !fits(values.buffer_view, values.byte_offset, element_size) {
+                    report(
+                        &|| path().field("sparse").field("values").field("byteOffset"),
+                        Error::Invalid,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the size of a single element in bytes, accounting for the column
+/// padding of matrices with 1 or 2 byte components.
+fn element_size(component_type: ComponentType, type_: Type) -> usize {
+    let size = component_type.size();
+    let column = |rows: usize| (rows * size + 3) / 4 * 4;
+    match type_ {
+        Type::Mat2 => 2 * column(2),
+        Type::Mat3 => 3 * column(3),
+        Type::Mat4 => 4 * column(4),
+        _ => type_.multiplicity() * size,
     }
 }
 
@@ -266,12 +583,17 @@ fn is_normalized_default(b: &bool) -> bool {
     !*b
 }
 
+// Help serde avoid serializing this glTF 2.0 default value.
+fn is_byte_offset_default(offset: &u32) -> bool {
+    *offset == 0
+}
+
 /// The data type of an index.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct IndexComponentType(pub ComponentType);
 
 /// The data type of a generic vertex attribute.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GenericComponentType(pub ComponentType);
 
 impl<'de> de::Deserialize<'de> for Checked<GenericComponentType> {
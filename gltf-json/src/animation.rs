@@ -65,6 +65,12 @@ pub struct Animation {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// An array of channels, each of which targets an animation's sampler at a
     /// node's property.
     ///
@@ -83,6 +89,16 @@ pub struct Animation {
     pub samplers: Vec<Sampler>,
 }
 
+impl_partial_eq!(Animation {
+    extensions,
+    channels,
+    #[cfg(feature = "names")]
+    name,
+    samplers,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Channel {
@@ -102,8 +118,22 @@ pub struct Channel {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Channel {
+    sampler,
+    target,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Target {
@@ -117,6 +147,12 @@ pub struct Target {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// The index of the node to target.
     pub node: Index<scene::Node>,
 
@@ -125,6 +161,14 @@ pub struct Target {
     pub path: Checked<Property>,
 }
 
+impl_partial_eq!(Target {
+    extensions,
+    node,
+    path,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Sampler {
@@ -138,6 +182,12 @@ pub struct Sampler {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// The index of an accessor containing keyframe input values, e.g., time.
     pub input: Index<accessor::Accessor>,
 
@@ -149,6 +199,15 @@ pub struct Sampler {
     pub output: Index<accessor::Accessor>,
 }
 
+impl_partial_eq!(Sampler {
+    extensions,
+    input,
+    interpolation,
+    output,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 impl Validate for Animation {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
@@ -157,11 +216,83 @@ impl Validate for Animation {
     {
         self.samplers
             .validate(root, || path().field("samplers"), report);
+        for (index, sampler) in self.samplers.iter().enumerate() {
+            // spec: The input accessor **must** contain scalar floats.
+            if let Some(input) = root.get(sampler.input) {
+                let is_scalar_float = matches!(input.type_, Checked::Valid(accessor::Type::Scalar))
+                    && matches!(
+                        input.component_type,
+                        Checked::Valid(accessor::GenericComponentType(
+                            accessor::ComponentType::F32
+                        ))
+                    );
+                if !is_scalar_float {
+                    let path = || path().field("samplers").index(index).field("input");
+                    report(&path, Error::Invalid);
+                }
+            }
+        }
         for (index, channel) in self.channels.iter().enumerate() {
             if channel.sampler.value() >= self.samplers.len() {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            channel.target.validate(
+                root,
+                || path().field("channels").index(index).field("target"),
+                report,
+            );
+
+            // spec: When a node is targeted for animation, `matrix` will not be present.
+            if let Some(node) = root.get(channel.target.node) {
+                if node.matrix.is_some() {
+                    let path = || {
+                        path()
+                            .field("channels")
+                            .index(index)
+                            .field("target")
+                            .field("node")
+                    };
+                    report(&path, Error::Invalid);
+                }
+            }
+
+            // The output accessor must match the shape of the targeted property.
+            let sampler = match self.samplers.get(channel.sampler.value()) {
+                Some(sampler) => sampler,
+                None => continue,
+            };
+            let property = match channel.target.path {
+                Checked::Valid(property) => property,
+                Checked::Invalid => continue,
+            };
+            if let (Some(input), Some(output)) = (root.get(sampler.input), root.get(sampler.output))
+            {
+                let keyframes = match sampler.interpolation {
+                    Checked::Valid(Interpolation::CubicSpline) => 3 * input.count as u64,
+                    _ => input.count as u64,
+                };
+                let (type_, count_is_valid) = match property {
+                    Property::Translation | Property::Scale => {
+                        (accessor::Type::Vec3, output.count as u64 == keyframes)
+                    }
+                    Property::Rotation => (accessor::Type::Vec4, output.count as u64 == keyframes),
+                    // One weight per morph target for each keyframe.
+                    Property::MorphTargetWeights => (
+                        accessor::Type::Scalar,
+                        output.count as u64 % keyframes.max(1) == 0,
+                    ),
+                };
+                if output.type_ != Checked::Valid(type_) || !count_is_valid {
+                    let path = || {
+                        path()
+                            .field("samplers")
+                            .index(channel.sampler.value())
+                            .field("output")
+                    };
+                    report(&path, Error::Invalid);
+                }
+            }
         }
     }
 }
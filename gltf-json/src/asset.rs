@@ -1,6 +1,8 @@
 use crate::{extensions, Extras};
 use gltf_derive::Validate;
+use serde::de;
 use serde_derive::{Deserialize, Serialize};
+use std::fmt;
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
@@ -19,6 +21,12 @@ pub struct Asset {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// Tool that generated this glTF model.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub generator: Option<String>,
@@ -32,6 +40,26 @@ pub struct Asset {
     pub version: String,
 }
 
+impl_partial_eq!(Asset {
+    copyright,
+    extensions,
+    generator,
+    min_version,
+    version,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+impl Asset {
+    /// Constructs metadata for a glTF 2.0 asset written by `generator`.
+    pub fn new(generator: impl Into<String>) -> Self {
+        Self {
+            generator: Some(generator.into()),
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for Asset {
     fn default() -> Self {
         Self {
@@ -40,7 +68,273 @@ impl Default for Asset {
             extras: Default::default(),
             generator: None,
             min_version: None,
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
             version: "2.0".to_string(),
         }
     }
 }
+
+/// The major version of the glTF specification an asset was written against.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Version {
+    /// glTF 1.0.
+    V1,
+
+    /// glTF 2.0.
+    V2,
+}
+
+/// Top-level properties that are dictionaries in glTF 1.0 and arrays in glTF 2.0.
+const TOP_LEVEL_COLLECTIONS: &[&str] = &[
+    "accessors",
+    "animations",
+    "buffers",
+    "bufferViews",
+    "cameras",
+    "images",
+    "materials",
+    "meshes",
+    "nodes",
+    "samplers",
+    "scenes",
+    "skins",
+    "textures",
+];
+
+/// Detects the major glTF version of a JSON document.
+///
+/// The `asset.version` property is used when present. Since `asset` is
+/// optional in glTF 1.0, documents without one are identified by the shape
+/// of their top-level collections instead.
+///
+/// Returns `None` if the slice is not a JSON object or the version cannot be
+/// determined.
+pub fn detect_version(slice: &[u8]) -> Option<Version> {
+    // Scan the document without building a `serde_json::Value`, since it is
+    // parsed again once the version is known.
+    let mut deserializer = serde_json::Deserializer::from_slice(slice);
+    de::Deserializer::deserialize_map(&mut deserializer, VersionVisitor).ok()?
+}
+
+/// Parses the major version of a `version` string.
+fn parse_version(version: &str) -> Option<Version> {
+    match version.split('.').next() {
+        Some("1") => Some(Version::V1),
+        Some("2") => Some(Version::V2),
+        _ => None,
+    }
+}
+
+/// The object keys `detect_version` looks at.
+enum Key {
+    Asset,
+    Version,
+    Collection(usize),
+    Other,
+}
+
+impl<'de> de::Deserialize<'de> for Key {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Key;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an object key")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(match value {
+                    "asset" => Key::Asset,
+                    "version" => Key::Version,
+                    _ => match TOP_LEVEL_COLLECTIONS.iter().position(|x| *x == value) {
+                        Some(index) => Key::Collection(index),
+                        None => Key::Other,
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
+/// The parts of a JSON value that `detect_version` looks at.
+enum Shape {
+    /// An object, with the version given by its `version` string if it has
+    /// one.
+    Object(Option<Option<Version>>),
+    Array,
+    /// A string, parsed as a version.
+    String(Option<Version>),
+    Other,
+}
+
+impl<'de> de::Deserialize<'de> for Shape {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Shape;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "any JSON value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut version = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Key::Version => {
+                            version = match map.next_value()? {
+                                Shape::String(x) => Some(x),
+                                _ => None,
+                            }
+                        }
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Shape::Object(version))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+                Ok(Shape::Array)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::String(parse_version(value)))
+            }
+
+            fn visit_bool<E>(self, _value: bool) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::Other)
+            }
+
+            fn visit_i64<E>(self, _value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::Other)
+            }
+
+            fn visit_u64<E>(self, _value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::Other)
+            }
+
+            fn visit_f64<E>(self, _value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::Other)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Shape::Other)
+            }
+        }
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// Finds the version of a glTF document from its `asset.version` property,
+/// or else from the first top-level collection that is an object or an
+/// array.
+struct VersionVisitor;
+
+impl<'de> de::Visitor<'de> for VersionVisitor {
+    type Value = Option<Version>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a glTF object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut asset_version = None;
+        let mut shapes = [None; TOP_LEVEL_COLLECTIONS.len()];
+        while let Some(key) = map.next_key()? {
+            match key {
+                Key::Asset => {
+                    asset_version = match map.next_value()? {
+                        Shape::Object(version) => version,
+                        _ => None,
+                    }
+                }
+                Key::Collection(index) => {
+                    shapes[index] = match map.next_value()? {
+                        Shape::Object(_) => Some(Version::V1),
+                        Shape::Array => Some(Version::V2),
+                        _ => None,
+                    }
+                }
+                Key::Version | Key::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(match asset_version {
+            Some(version) => version,
+            None => shapes.iter().find_map(|x| *x),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_version, Version};
+
+    #[test]
+    fn detect_version_from_asset() {
+        let v1 = br#"{"asset": {"version": "1.0"}}"#;
+        let v2 = br#"{"asset": {"version": "2.0"}}"#;
+        let v3 = br#"{"asset": {"version": "3.0"}}"#;
+        assert_eq!(detect_version(v1), Some(Version::V1));
+        assert_eq!(detect_version(v2), Some(Version::V2));
+        assert_eq!(detect_version(v3), None);
+
+        let v1 = br#"{"nodes": [{}], "asset": {"version": "1.0.3", "extras": [1]}}"#;
+        let unknown = br#"{"nodes": [{}], "asset": {"version": 2}}"#;
+        assert_eq!(detect_version(v1), Some(Version::V1));
+        assert_eq!(detect_version(unknown), Some(Version::V2));
+    }
+
+    #[test]
+    fn detect_version_without_asset() {
+        let v1 = br#"{"nodes": {"node_0": {}}}"#;
+        let v2 = br#"{"nodes": [{}]}"#;
+        assert_eq!(detect_version(v1), Some(Version::V1));
+        assert_eq!(detect_version(v2), Some(Version::V2));
+        assert_eq!(detect_version(b"{}"), None);
+        assert_eq!(detect_version(b"[]"), None);
+    }
+}
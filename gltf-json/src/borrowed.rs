@@ -0,0 +1,338 @@
+use crate::{
+    buffer, texture, Accessor, Animation, Asset, Buffer, Camera, Error, Image, Index, Material,
+    Mesh, Node, Scene, Skin, Texture,
+};
+use serde::de::{self, DeserializeOwned, Deserializer, Visitor};
+use serde_derive::Deserialize;
+use serde_json::value::RawValue;
+use std::borrow::Cow;
+use std::{fmt, marker};
+
+/// The root object of a glTF 2.0 asset, borrowing from the JSON text it was
+/// deserialized from.
+///
+/// Deserializing a [`crate::Root`] copies every string of the document. This
+/// type instead keeps each top-level object as a slice of the input, along
+/// with its `name` and `uri`, which are only copied when they contain escape
+/// sequences. Objects are fully deserialized on demand with
+/// [`Object::parse`], or all at once with [`Root::to_owned`].
+///
+/// Top-level properties unknown to the specification are ignored, even when
+/// the `unrecognized` feature is enabled.
+///
+/// ```
+/// # use gltf_json as json;
+/// use std::borrow::Cow;
+///
+/// let text = r#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4,"uri":"data.bin"}]}"#;
+/// let root = json::borrowed::Root::from_str(text).unwrap();
+/// assert!(matches!(root.buffers[0].uri, Some(Cow::Borrowed("data.bin"))));
+/// assert_eq!(root.buffers[0].parse().unwrap().byte_length, 4);
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+pub struct Root<'a> {
+    /// An array of accessors.
+    #[serde(borrow, default)]
+    pub accessors: Vec<Object<'a, Accessor>>,
+
+    /// An array of keyframe animations.
+    #[serde(borrow, default)]
+    pub animations: Vec<Object<'a, Animation>>,
+
+    /// Metadata about the glTF asset.
+    #[serde(borrow)]
+    pub asset: Object<'a, Asset>,
+
+    /// An array of buffers.
+    #[serde(borrow, default)]
+    pub buffers: Vec<Object<'a, Buffer>>,
+
+    /// An array of buffer views.
+    #[serde(borrow, default, rename = "bufferViews")]
+    pub buffer_views: Vec<Object<'a, buffer::View>>,
+
+    /// The default scene.
+    #[serde(default)]
+    pub scene: Option<Index<Scene>>,
+
+    /// Extension specific data, left unparsed.
+    #[serde(borrow, default)]
+    pub extensions: Option<&'a RawValue>,
+
+    /// Optional application specific data, left unparsed.
+    #[serde(borrow, default)]
+    pub extras: Option<&'a RawValue>,
+
+    /// Names of glTF extensions used somewhere in this asset.
+    #[serde(borrow, default, rename = "extensionsUsed")]
+    #[serde(deserialize_with = "deserialize_names")]
+    pub extensions_used: Vec<Cow<'a, str>>,
+
+    /// Names of glTF extensions required to properly load this asset.
+    #[serde(borrow, default, rename = "extensionsRequired")]
+    #[serde(deserialize_with = "deserialize_names")]
+    pub extensions_required: Vec<Cow<'a, str>>,
+
+    /// An array of cameras.
+    #[serde(borrow, default)]
+    pub cameras: Vec<Object<'a, Camera>>,
+
+    /// An array of images.
+    #[serde(borrow, default)]
+    pub images: Vec<Object<'a, Image>>,
+
+    /// An array of materials.
+    #[serde(borrow, default)]
+    pub materials: Vec<Object<'a, Material>>,
+
+    /// An array of meshes.
+    #[serde(borrow, default)]
+    pub meshes: Vec<Object<'a, Mesh>>,
+
+    /// An array of nodes.
+    #[serde(borrow, default)]
+    pub nodes: Vec<Object<'a, Node>>,
+
+    /// An array of samplers.
+    #[serde(borrow, default)]
+    pub samplers: Vec<Object<'a, texture::Sampler>>,
+
+    /// An array of scenes.
+    #[serde(borrow, default)]
+    pub scenes: Vec<Object<'a, Scene>>,
+
+    /// An array of skins.
+    #[serde(borrow, default)]
+    pub skins: Vec<Object<'a, Skin>>,
+
+    /// An array of textures.
+    #[serde(borrow, default)]
+    pub textures: Vec<Object<'a, Texture>>,
+}
+
+/// A top-level glTF object of type `T`, left unparsed apart from its `name`
+/// and `uri`.
+pub struct Object<'a, T> {
+    /// The user-defined name of this object.
+    pub name: Option<Cow<'a, str>>,
+
+    /// The uri of this object, for buffers and images.
+    pub uri: Option<Cow<'a, str>>,
+
+    /// The JSON text of this object.
+    pub json: &'a RawValue,
+
+    /// The type of the object.
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+/// The properties of a top-level object that `Object` extracts eagerly.
+#[derive(Deserialize)]
+struct Strings<'a> {
+    #[serde(borrow, default)]
+    name: Option<Str<'a>>,
+
+    #[serde(borrow, default)]
+    uri: Option<Str<'a>>,
+}
+
+/// A string that borrows from the input unless it contains escape sequences.
+struct Str<'a>(Cow<'a, str>);
+
+impl<'a> Root<'a> {
+    /// Deserialize from a JSON string slice, borrowing from it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(str_: &'a str) -> Result<Self, Error> {
+        serde_json::from_str(str_)
+    }
+
+    /// Deserialize from a JSON byte slice, borrowing from it.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Self, Error> {
+        serde_json::from_slice(slice)
+    }
+
+    /// Fully deserializes every object into an owned [`crate::Root`].
+    pub fn to_owned(&self) -> Result<crate::Root, Error> {
+        Ok(crate::Root {
+            accessors: parse_all(&self.accessors)?,
+            animations: parse_all(&self.animations)?,
+            asset: self.asset.parse()?,
+            buffers: parse_all(&self.buffers)?,
+            buffer_views: parse_all(&self.buffer_views)?,
+            scene: self.scene,
+            extensions: self
+                .extensions
+                .map(|json| serde_json::from_str(json.get()))
+                .transpose()?,
+            #[cfg(feature = "extras")]
+            extras: self.extras.map(|json| json.to_owned()),
+            #[cfg(not(feature = "extras"))]
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            extensions_used: self.extensions_used.iter().map(|s| s.to_string()).collect(),
+            extensions_required: self
+                .extensions_required
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            cameras: parse_all(&self.cameras)?,
+            images: parse_all(&self.images)?,
+            materials: parse_all(&self.materials)?,
+            meshes: parse_all(&self.meshes)?,
+            nodes: parse_all(&self.nodes)?,
+            samplers: parse_all(&self.samplers)?,
+            scenes: parse_all(&self.scenes)?,
+            skins: parse_all(&self.skins)?,
+            textures: parse_all(&self.textures)?,
+        })
+    }
+}
+
+impl<'a, T: DeserializeOwned> Object<'a, T> {
+    /// Fully deserializes this object.
+    pub fn parse(&self) -> Result<T, Error> {
+        serde_json::from_str(self.json.get())
+    }
+}
+
+impl<'a, T> Clone for Object<'a, T> {
+    fn clone(&self) -> Self {
+        Object {
+            name: self.name.clone(),
+            uri: self.uri.clone(),
+            json: self.json,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for Object<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Object")
+            .field("name", &self.name)
+            .field("uri", &self.uri)
+            .field("json", &self.json)
+            .finish()
+    }
+}
+
+impl<'de: 'a, 'a, T> de::Deserialize<'de> for Object<'a, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = <&'a RawValue>::deserialize(deserializer)?;
+        let strings: Strings<'a> = serde_json::from_str(json.get()).map_err(de::Error::custom)?;
+        Ok(Object {
+            name: strings.name.map(|s| s.0),
+            uri: strings.uri.map(|s| s.0),
+            json,
+            _marker: marker::PhantomData,
+        })
+    }
+}
+
+impl<'de: 'a, 'a> de::Deserialize<'de> for Str<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StrVisitor;
+
+        impl<'de> Visitor<'de> for StrVisitor {
+            type Value = Str<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E> {
+                Ok(Str(Cow::Borrowed(value)))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(Str(Cow::Owned(value.to_owned())))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+                Ok(Str(Cow::Owned(value)))
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor)
+    }
+}
+
+fn deserialize_names<'de: 'a, 'a, D>(deserializer: D) -> Result<Vec<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let names = <Vec<Str<'a>> as de::Deserialize>::deserialize(deserializer)?;
+    Ok(names.into_iter().map(|s| s.0).collect())
+}
+
+fn parse_all<T: DeserializeOwned>(objects: &[Object<T>]) -> Result<Vec<T>, Error> {
+    objects.iter().map(Object::parse).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data.bin", "name": "escaped\"name"}],
+        "images": [{"uri": "image.png", "name": "image"}],
+        "nodes": [{"name": "root", "translation": [1.0, 2.0, 3.0]}],
+        "scenes": [{"nodes": [0]}],
+        "scene": 0,
+        "extensionsUsed": ["KHR_materials_unlit"],
+        "extras": {"tag": "borrowed"}
+    }"#;
+
+    #[test]
+    fn borrows_names_and_uris() {
+        let root = Root::from_str(TEXT).unwrap();
+        assert!(matches!(
+            root.buffers[0].uri,
+            Some(Cow::Borrowed("data.bin"))
+        ));
+        assert!(matches!(
+            root.buffers[0].name.as_deref(),
+            Some("escaped\"name")
+        ));
+        assert!(matches!(root.buffers[0].name, Some(Cow::Owned(_))));
+        assert!(matches!(
+            root.images[0].uri,
+            Some(Cow::Borrowed("image.png"))
+        ));
+        assert!(matches!(root.nodes[0].name, Some(Cow::Borrowed("root"))));
+        assert!(root.scenes[0].name.is_none());
+        assert!(matches!(
+            root.extensions_used[..],
+            [Cow::Borrowed("KHR_materials_unlit")]
+        ));
+
+        let root = Root::from_slice(TEXT.as_bytes()).unwrap();
+        assert!(matches!(root.nodes[0].name, Some(Cow::Borrowed("root"))));
+    }
+
+    #[test]
+    fn to_owned_matches_owned_deserialization() {
+        let borrowed = Root::from_str(TEXT).unwrap();
+        assert_eq!(
+            borrowed.to_owned().unwrap(),
+            crate::Root::from_str(TEXT).unwrap()
+        );
+        assert_eq!(
+            borrowed.nodes[0].parse().unwrap().translation,
+            Some([1.0, 2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn rejects_missing_asset() {
+        assert!(Root::from_str(r#"{"nodes": []}"#).is_err());
+    }
+}
@@ -1,5 +1,5 @@
-use crate::validation::Checked;
-use crate::{extensions, Extras, Index};
+use crate::validation::{Checked, Error, Validate};
+use crate::{extensions, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
@@ -68,13 +68,29 @@ pub struct Buffer {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Buffer {
+    byte_length,
+    #[cfg(feature = "names")]
+    name,
+    uri,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// A view into a buffer generally representing a subset of the buffer.
 ///
 /// <https://github.com/KhronosGroup/glTF/tree/master/specification/2.0#reference-bufferview>
 ///
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct View {
     /// The parent `Buffer`.
     pub buffer: Index<Buffer>,
@@ -116,6 +132,83 @@ pub struct View {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+}
+
+impl_partial_eq!(View {
+    buffer,
+    byte_length,
+    byte_offset,
+    byte_stride,
+    #[cfg(feature = "names")]
+    name,
+    target,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+impl Validate for View {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.buffer
+            .validate(root, || path().field("buffer"), report);
+        self.byte_length
+            .validate(root, || path().field("byteLength"), report);
+        self.byte_offset
+            .validate(root, || path().field("byteOffset"), report);
+        self.byte_stride
+            .validate(root, || path().field("byteStride"), report);
+        self.target
+            .validate(root, || path().field("target"), report);
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
+        self.extras
+            .validate(root, || path().field("extras"), report);
+
+        if let Some(stride) = self.byte_stride {
+            if !(MIN_BYTE_STRIDE..=MAX_BYTE_STRIDE).contains(&stride) || stride % 4 != 0 {
+                report(&|| path().field("byteStride"), Error::Invalid);
+            }
+        }
+
+        // The view must lie within the parent buffer.
+        if let Some(buffer) = root.get(self.buffer) {
+            let end = self.byte_offset.unwrap_or(0) as u64 + self.byte_length as u64;
+            if end > buffer.byte_length as u64 {
+                report(&|| path().field("byteLength"), Error::Invalid);
+            }
+        }
+
+        // Compressed data must decompress to within the view.
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if let Some(compression) = self
+            .extensions
+            .as_ref()
+            .and_then(|x| x.ext_meshopt_compression.as_ref())
+        {
+            let size = compression.count as u64 * compression.byte_stride as u64;
+            if size > self.byte_length as u64 {
+                report(
+                    &|| {
+                        path()
+                            .field("extensions")
+                            .field("EXT_meshopt_compression")
+                            .field("count")
+                    },
+                    Error::Invalid,
+                );
+            }
+        }
+    }
 }
 
 impl<'de> de::Deserialize<'de> for Checked<Target> {
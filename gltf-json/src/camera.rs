@@ -52,8 +52,25 @@ pub struct Camera {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Camera {
+    #[cfg(feature = "names")]
+    name,
+    orthographic,
+    perspective,
+    type_,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Orthographic {
@@ -78,8 +95,24 @@ pub struct Orthographic {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Orthographic {
+    xmag,
+    ymag,
+    zfar,
+    znear,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Values for a perspective camera.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Perspective {
@@ -107,8 +140,24 @@ pub struct Perspective {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Perspective {
+    aspect_ratio,
+    yfov,
+    zfar,
+    znear,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 impl Validate for Camera {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
@@ -0,0 +1,65 @@
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_json::Value;
+
+/// Serializes a JSON value with the keys of every object sorted by their
+/// UTF-16 code units, for [`ExportOptions::canonical`](crate::ExportOptions::canonical).
+pub(crate) struct Sorted<'a>(pub &'a Value);
+
+impl<'a> Serialize for Sorted<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Value::Array(array) => {
+                let mut seq = serializer.serialize_seq(Some(array.len()))?;
+                for item in array {
+                    seq.serialize_element(&Sorted(item))?;
+                }
+                seq.end()
+            }
+            Value::Object(object) => {
+                // Sorted explicitly, since the map keeps insertion order when
+                // `serde_json/preserve_order` is enabled elsewhere in the build.
+                let mut entries: Vec<_> = object.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, &Sorted(value))?;
+                }
+                map.end()
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sorted;
+    use crate::ExportOptions;
+    use serde_json::json;
+
+    fn canonical(value: serde_json::Value) -> String {
+        let options = ExportOptions {
+            canonical: true,
+            ..Default::default()
+        };
+        let mut serializer =
+            serde_json::Serializer::with_formatter(Vec::new(), options.formatter());
+        serde::Serialize::serialize(&Sorted(&value), &mut serializer).unwrap();
+        String::from_utf8(serializer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn canonical_json() {
+        let value = json!({
+            "b": [1, -2, 0.1f32 as f64, 0.1, 1.0, 1e-30],
+            "a": { "é": null, "z": true, "A": "\n" },
+        });
+        assert_eq!(
+            canonical(value),
+            r#"{"a":{"A":"\n","z":true,"é":null},"b":[1,-2,0.1,0.1,1.0,1e-30]}"#
+        );
+    }
+}
@@ -0,0 +1,705 @@
+use crate::v1;
+use crate::Root;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// Name of the glTF 1.0 extension describing common lighting models.
+const KHR_MATERIALS_COMMON: &str = "KHR_materials_common";
+
+/// Error encountered when converting a glTF 1.0 asset.
+#[derive(Debug)]
+pub enum Error {
+    /// An object refers to an id that does not exist in the asset.
+    UnresolvedId {
+        /// The kind of object that was referred to, for example `"accessor"`.
+        kind: &'static str,
+
+        /// The id that could not be resolved.
+        id: String,
+    },
+
+    /// The converted asset does not match the glTF 2.0 data model.
+    Deserialize(crate::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnresolvedId { kind, id } => write!(f, "unresolved {} id {:?}", kind, id),
+            Error::Deserialize(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Maps the string ids of one glTF 1.0 dictionary to glTF 2.0 indices.
+///
+/// Indices are assigned in the iteration order of the dictionary.
+struct Ids<'a> {
+    kind: &'static str,
+    map: HashMap<&'a str, u32>,
+}
+
+impl<'a> Ids<'a> {
+    fn new<T>(kind: &'static str, dictionary: &'a v1::Dictionary<T>) -> Self {
+        let map = dictionary
+            .keys()
+            .enumerate()
+            .map(|(index, id)| (id.as_str(), index as u32))
+            .collect();
+        Self { kind, map }
+    }
+
+    fn get(&self, id: &str) -> Result<u32, Error> {
+        self.map
+            .get(id)
+            .copied()
+            .ok_or_else(|| Error::UnresolvedId {
+                kind: self.kind,
+                id: id.to_owned(),
+            })
+    }
+
+    fn get_all(&self, ids: &[String]) -> Result<Vec<u32>, Error> {
+        ids.iter().map(|id| self.get(id)).collect()
+    }
+}
+
+/// Converts a glTF 1.0 asset to the glTF 2.0 data model.
+///
+/// String-keyed dictionaries become arrays, ordered by id, and references
+/// between objects are rewritten as indices. Accessor strides move to
+/// their buffer views, duplicating a view if its accessors disagree on the
+/// stride.
+///
+/// Materials are approximated with the metallic-roughness model. Their
+/// diffuse color or texture becomes the base color, emission becomes the
+/// emissive factor or texture, and shininess is mapped to roughness. These
+/// values are taken from the `KHR_materials_common` extension when present
+/// and from the material's technique parameters otherwise. Techniques,
+/// programs and shaders are dropped.
+///
+/// A node referring to several meshes is given a new mesh containing the
+/// primitives of all of them. The `bindShapeMatrix` of skins is ignored,
+/// as are extensions other than `KHR_materials_common`.
+pub fn v1_to_v2(root: &v1::Root) -> Result<Root, Error> {
+    let accessors = Ids::new("accessor", &root.accessors);
+    let cameras = Ids::new("camera", &root.cameras);
+    let images = Ids::new("image", &root.images);
+    let materials = Ids::new("material", &root.materials);
+    let meshes = Ids::new("mesh", &root.meshes);
+    let nodes = Ids::new("node", &root.nodes);
+    let samplers = Ids::new("sampler", &root.samplers);
+    let scenes = Ids::new("scene", &root.scenes);
+    let skins = Ids::new("skin", &root.skins);
+    let textures = Ids::new("texture", &root.textures);
+    let buffers = Ids::new("buffer", &root.buffers);
+    let buffer_views = Ids::new("bufferView", &root.buffer_views);
+
+    // Accessors of a view with differing strides are given copies of the view.
+    let mut view_strides = BTreeMap::<&str, Vec<u32>>::new();
+    for accessor in root.accessors.values() {
+        buffer_views.get(&accessor.buffer_view)?;
+        let strides = view_strides.entry(&accessor.buffer_view).or_default();
+        if !strides.contains(&accessor.byte_stride) {
+            strides.push(accessor.byte_stride);
+        }
+    }
+    let view_count = root.buffer_views.len() as u32;
+    let mut json_views = Vec::new();
+    let mut extra_views = Vec::new();
+    let mut view_indices = HashMap::new();
+    for (id, view) in &root.buffer_views {
+        let strides = view_strides.get(id.as_str()).map(Vec::as_slice);
+        for (i, &stride) in strides.unwrap_or(&[0]).iter().enumerate() {
+            let mut json = json!({
+                "buffer": buffers.get(&view.buffer)?,
+                "byteLength": view.byte_length,
+                "byteOffset": view.byte_offset,
+            });
+            if stride != 0 {
+                json["byteStride"] = json!(stride);
+            }
+            if let Some(target) = view.target {
+                json["target"] = json!(target);
+            }
+            common(&mut json, &view.name, &view.extras);
+            if i == 0 {
+                view_indices.insert((id.as_str(), stride), json_views.len() as u32);
+                json_views.push(json);
+            } else {
+                let index = view_count + extra_views.len() as u32;
+                view_indices.insert((id.as_str(), stride), index);
+                extra_views.push(json);
+            }
+        }
+    }
+    json_views.extend(extra_views);
+
+    let json_accessors = root
+        .accessors
+        .values()
+        .map(|accessor| {
+            let mut json = json!({
+                "bufferView": view_indices[&(accessor.buffer_view.as_str(), accessor.byte_stride)],
+                "byteOffset": accessor.byte_offset,
+                "componentType": accessor.component_type,
+                "count": accessor.count,
+                "type": accessor.type_,
+            });
+            if !accessor.min.is_empty() {
+                json["min"] = json!(accessor.min);
+            }
+            if !accessor.max.is_empty() {
+                json["max"] = json!(accessor.max);
+            }
+            common(&mut json, &accessor.name, &accessor.extras);
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let json_animations = root
+        .animations
+        .values()
+        .map(|animation| {
+            let animation_samplers = Ids::new("animation sampler", &animation.samplers);
+            let parameter = |name: &str| -> Result<u32, Error> {
+                let id = animation
+                    .parameters
+                    .get(name)
+                    .ok_or_else(|| Error::UnresolvedId {
+                        kind: "animation parameter",
+                        id: name.to_owned(),
+                    })?;
+                accessors.get(id)
+            };
+            let channels = animation
+                .channels
+                .iter()
+                .map(|channel| {
+                    let mut json = json!({
+                        "sampler": animation_samplers.get(&channel.sampler)?,
+                        "target": {
+                            "node": nodes.get(&channel.target.id)?,
+                            "path": channel.target.path,
+                        },
+                    });
+                    common(&mut json, &None, &channel.extras);
+                    Ok(json)
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let samplers = animation
+                .samplers
+                .values()
+                .map(|sampler| {
+                    let mut json = json!({
+                        "input": parameter(&sampler.input)?,
+                        "interpolation": sampler.interpolation,
+                        "output": parameter(&sampler.output)?,
+                    });
+                    common(&mut json, &None, &sampler.extras);
+                    Ok(json)
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let mut json = json!({ "channels": channels, "samplers": samplers });
+            common(&mut json, &animation.name, &animation.extras);
+            Ok(json)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut json_asset = json!({ "version": "2.0" });
+    if let Some(asset) = root.asset.as_ref() {
+        if let Some(copyright) = asset.copyright.as_ref() {
+            json_asset["copyright"] = json!(copyright);
+        }
+        if let Some(generator) = asset.generator.as_ref() {
+            json_asset["generator"] = json!(generator);
+        }
+        common(&mut json_asset, &None, &asset.extras);
+    }
+
+    let json_buffers = root
+        .buffers
+        .values()
+        .map(|buffer| {
+            let mut json = json!({ "byteLength": buffer.byte_length, "uri": buffer.uri });
+            common(&mut json, &buffer.name, &buffer.extras);
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let json_cameras = root
+        .cameras
+        .values()
+        .map(|camera| {
+            let mut json = json!({ "type": camera.type_ });
+            if let Some(orthographic) = camera.orthographic.as_ref() {
+                json["orthographic"] = json!(orthographic);
+            }
+            if let Some(perspective) = camera.perspective.as_ref() {
+                json["perspective"] = json!(perspective);
+            }
+            common(&mut json, &camera.name, &camera.extras);
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let json_images = root
+        .images
+        .values()
+        .map(|image| {
+            let mut json = json!({ "uri": image.uri });
+            common(&mut json, &image.name, &image.extras);
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let json_materials = root
+        .materials
+        .values()
+        .map(|material| convert_material(material, &textures))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut json_meshes = root
+        .meshes
+        .values()
+        .map(|mesh| {
+            let primitives = mesh
+                .primitives
+                .iter()
+                .map(|primitive| convert_primitive(primitive, &accessors, &materials))
+                .collect::<Result<Vec<_>, Error>>()?;
+            let mut json = json!({ "primitives": primitives });
+            common(&mut json, &mesh.name, &mesh.extras);
+            Ok(json)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut merged_meshes = HashMap::<&[String], u32>::new();
+    let mut json_nodes = Vec::new();
+    for node in root.nodes.values() {
+        let mut json = json!({});
+        if let Some(camera) = node.camera.as_ref() {
+            json["camera"] = json!(cameras.get(camera)?);
+        }
+        if !node.children.is_empty() {
+            json["children"] = json!(nodes.get_all(&node.children)?);
+        }
+        if let Some(skin) = node.skin.as_ref() {
+            json["skin"] = json!(skins.get(skin)?);
+        }
+        match node.meshes.as_slice() {
+            [] => {}
+            [mesh] => json["mesh"] = json!(meshes.get(mesh)?),
+            ids => {
+                let index = match merged_meshes.get(ids) {
+                    Some(index) => *index,
+                    None => {
+                        let mut primitives = Vec::new();
+                        for id in ids {
+                            let mesh = &json_meshes[meshes.get(id)? as usize];
+                            primitives
+                                .extend(mesh["primitives"].as_array().cloned().unwrap_or_default());
+                        }
+                        let index = json_meshes.len() as u32;
+                        json_meshes.push(json!({ "primitives": primitives }));
+                        merged_meshes.insert(ids, index);
+                        index
+                    }
+                };
+                json["mesh"] = json!(index);
+            }
+        }
+        if let Some(matrix) = node.matrix {
+            json["matrix"] = json!(matrix);
+        }
+        if let Some(rotation) = node.rotation {
+            json["rotation"] = json!(rotation);
+        }
+        if let Some(scale) = node.scale {
+            json["scale"] = json!(scale);
+        }
+        if let Some(translation) = node.translation {
+            json["translation"] = json!(translation);
+        }
+        common(&mut json, &node.name, &node.extras);
+        json_nodes.push(json);
+    }
+
+    let json_samplers = root
+        .samplers
+        .values()
+        .map(|sampler| {
+            let mut json = json!({
+                "magFilter": sampler.mag_filter,
+                "minFilter": sampler.min_filter,
+                "wrapS": sampler.wrap_s,
+                "wrapT": sampler.wrap_t,
+            });
+            common(&mut json, &sampler.name, &sampler.extras);
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let json_scenes = root
+        .scenes
+        .values()
+        .map(|scene| {
+            let mut json = json!({ "nodes": nodes.get_all(&scene.nodes)? });
+            common(&mut json, &scene.name, &scene.extras);
+            Ok(json)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let json_skins = root
+        .skins
+        .iter()
+        .map(|(id, skin)| convert_skin(root, id, skin, &accessors, &nodes))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let json_textures = root
+        .textures
+        .values()
+        .map(|texture| {
+            let mut json = json!({
+                "sampler": samplers.get(&texture.sampler)?,
+                "source": images.get(&texture.source)?,
+            });
+            common(&mut json, &texture.name, &texture.extras);
+            Ok(json)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut json = json!({
+        "accessors": json_accessors,
+        "animations": json_animations,
+        "asset": json_asset,
+        "buffers": json_buffers,
+        "bufferViews": json_views,
+        "cameras": json_cameras,
+        "images": json_images,
+        "materials": json_materials,
+        "meshes": json_meshes,
+        "nodes": json_nodes,
+        "samplers": json_samplers,
+        "scenes": json_scenes,
+        "skins": json_skins,
+        "textures": json_textures,
+    });
+    if let Some(scene) = root.scene.as_ref() {
+        json["scene"] = json!(scenes.get(scene)?);
+    }
+    common(&mut json, &None, &root.extras);
+    serde_json::from_value(json).map_err(Error::Deserialize)
+}
+
+/// Inserts the `name` and `extras` shared by most glTF objects.
+fn common(json: &mut Value, name: &Option<String>, extras: &crate::Extras) {
+    if let Some(name) = name.as_ref() {
+        json["name"] = json!(name);
+    }
+    match serde_json::to_value(extras) {
+        Ok(Value::Null) | Err(_) => {}
+        Ok(Value::Object(map)) if map.is_empty() => {}
+        Ok(extras) => json["extras"] = extras,
+    }
+}
+
+/// Converts a glTF 1.0 attribute semantic to its glTF 2.0 equivalent.
+///
+/// Semantics without a set index are given index 0, `JOINT` and `WEIGHT`
+/// are renamed to `JOINTS` and `WEIGHTS`, and application-specific semantics
+/// are prefixed with an underscore.
+fn convert_semantic(semantic: &str) -> String {
+    let (name, set) = match semantic.rfind('_') {
+        Some(i) if semantic[i + 1..].parse::<u32>().is_ok() => (&semantic[..i], &semantic[i + 1..]),
+        _ => (semantic, "0"),
+    };
+    match name {
+        "POSITION" | "NORMAL" | "TANGENT" => name.to_owned(),
+        "TEXCOORD" | "COLOR" => format!("{}_{}", name, set),
+        "JOINT" => format!("JOINTS_{}", set),
+        "WEIGHT" => format!("WEIGHTS_{}", set),
+        _ if semantic.starts_with('_') => semantic.to_owned(),
+        _ => format!("_{}", semantic),
+    }
+}
+
+fn convert_primitive(
+    primitive: &v1::Primitive,
+    accessors: &Ids,
+    materials: &Ids,
+) -> Result<Value, Error> {
+    let attributes = primitive
+        .attributes
+        .iter()
+        .map(|(semantic, id)| Ok((convert_semantic(semantic), json!(accessors.get(id)?))))
+        .collect::<Result<Map<_, _>, Error>>()?;
+    let mut json = json!({
+        "attributes": attributes,
+        "material": materials.get(&primitive.material)?,
+        "mode": primitive.mode,
+    });
+    if let Some(indices) = primitive.indices.as_ref() {
+        json["indices"] = json!(accessors.get(indices)?);
+    }
+    common(&mut json, &None, &primitive.extras);
+    Ok(json)
+}
+
+/// Approximates a glTF 1.0 material with the metallic-roughness model.
+fn convert_material(material: &v1::Material, textures: &Ids) -> Result<Value, Error> {
+    let common_material = material
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get(KHR_MATERIALS_COMMON));
+    let empty = Map::new();
+    let (values, technique) = match common_material {
+        Some(common_material) => (
+            common_material
+                .get("values")
+                .and_then(Value::as_object)
+                .unwrap_or(&empty),
+            common_material.get("technique").and_then(Value::as_str),
+        ),
+        None => (&empty, None),
+    };
+    let value = |name: &str| values.get(name).or_else(|| material.values.get(name));
+    let texture = |id: &str| -> Result<Value, Error> { Ok(json!({ "index": textures.get(id)? })) };
+
+    let mut pbr = json!({ "metallicFactor": 0.0 });
+    let mut base_color = [1.0, 1.0, 1.0, 1.0];
+    match value("diffuse") {
+        Some(Value::String(id)) => pbr["baseColorTexture"] = texture(id)?,
+        Some(Value::Array(color)) => {
+            for (channel, value) in base_color.iter_mut().zip(color) {
+                *channel = value.as_f64().unwrap_or(1.0);
+            }
+        }
+        _ => {}
+    }
+    let roughness = match technique {
+        Some("LAMBERT") | Some("CONSTANT") => 1.0,
+        _ => value("shininess")
+            .and_then(Value::as_f64)
+            .map_or(1.0, |shininess| (2.0 / (shininess.max(0.0) + 2.0)).sqrt()),
+    };
+    pbr["roughnessFactor"] = json!(roughness);
+
+    let mut json = json!({});
+    match value("emission") {
+        Some(Value::String(id)) => {
+            json["emissiveTexture"] = texture(id)?;
+            json["emissiveFactor"] = json!([1.0, 1.0, 1.0]);
+        }
+        Some(Value::Array(color)) => {
+            let color = color
+                .iter()
+                .take(3)
+                .map(|value| value.as_f64().unwrap_or(0.0))
+                .collect::<Vec<_>>();
+            if color.len() == 3 {
+                json["emissiveFactor"] = json!(color);
+            }
+        }
+        _ => {}
+    }
+    let transparency = value("transparency").and_then(Value::as_f64).unwrap_or(1.0);
+    let transparent = common_material
+        .and_then(|common_material| common_material.get("transparent"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    base_color[3] *= transparency;
+    if transparent || base_color[3] < 1.0 {
+        json["alphaMode"] = json!("BLEND");
+    }
+    let double_sided = common_material
+        .and_then(|common_material| common_material.get("doubleSided"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if double_sided {
+        json["doubleSided"] = json!(true);
+    }
+    pbr["baseColorFactor"] = json!(base_color);
+    json["pbrMetallicRoughness"] = pbr;
+    common(&mut json, &material.name, &material.extras);
+    Ok(json)
+}
+
+/// Resolves the `jointNames` of a glTF 1.0 skin to node indices.
+///
+/// Joints are looked up in the skeleton hierarchies of the first node using
+/// the skin, falling back to all nodes if the skin is unused.
+fn convert_skin(
+    root: &v1::Root,
+    id: &str,
+    skin: &v1::Skin,
+    accessors: &Ids,
+    nodes: &Ids,
+) -> Result<Value, Error> {
+    let skeletons = root
+        .nodes
+        .values()
+        .find(|node| node.skin.as_deref() == Some(id))
+        .map(|node| node.skeletons.as_slice())
+        .unwrap_or(&[]);
+    let mut candidates = Vec::new();
+    let mut stack = skeletons.iter().map(String::as_str).collect::<Vec<_>>();
+    // The hierarchy is not validated yet, so nodes reached twice, for example
+    // through a cycle, are skipped.
+    let mut visited = HashSet::new();
+    while let Some(node_id) = stack.pop() {
+        if !visited.insert(node_id) {
+            continue;
+        }
+        let node = root.nodes.get(node_id).ok_or_else(|| Error::UnresolvedId {
+            kind: "node",
+            id: node_id.to_owned(),
+        })?;
+        candidates.push((node_id, node));
+        stack.extend(node.children.iter().map(String::as_str));
+    }
+    if candidates.is_empty() {
+        candidates.extend(root.nodes.iter().map(|(id, node)| (id.as_str(), node)));
+    }
+    let joints = skin
+        .joint_names
+        .iter()
+        .map(|joint_name| {
+            candidates
+                .iter()
+                .find(|(_, node)| node.joint_name.as_ref() == Some(joint_name))
+                .ok_or_else(|| Error::UnresolvedId {
+                    kind: "joint",
+                    id: joint_name.clone(),
+                })
+                .and_then(|(id, _)| nodes.get(id))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let mut json = json!({ "joints": joints });
+    if let Some(skeleton) = skeletons.first() {
+        json["skeleton"] = json!(nodes.get(skeleton)?);
+    }
+    if let Some(matrices) = skin.inverse_bind_matrices.as_ref() {
+        json["inverseBindMatrices"] = json!(accessors.get(matrices)?);
+    }
+    common(&mut json, &skin.name, &skin.extras);
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert_semantic, v1_to_v2, Error};
+    use crate::{v1, Index};
+
+    const TRIANGLE: &str = r#"{
+        "asset": {"version": "1.0", "generator": "test"},
+        "scene": "scene",
+        "scenes": {"scene": {"nodes": ["root"]}},
+        "nodes": {
+            "root": {"children": ["child"], "meshes": ["a", "b"]},
+            "child": {"meshes": ["a"], "translation": [1, 2, 3]}
+        },
+        "meshes": {
+            "a": {"primitives": [{"attributes": {"POSITION": "positions"}, "material": "red"}]},
+            "b": {"primitives": [{"attributes": {"POSITION": "positions", "TEXCOORD": "uvs"}, "material": "red"}]}
+        },
+        "accessors": {
+            "positions": {
+                "bufferView": "vertices", "byteOffset": 0, "byteStride": 12,
+                "componentType": 5126, "count": 3, "type": "VEC3",
+                "min": [0, 0, 0], "max": [1, 1, 0]
+            },
+            "uvs": {
+                "bufferView": "vertices", "byteOffset": 36, "byteStride": 8,
+                "componentType": 5126, "count": 3, "type": "VEC2"
+            }
+        },
+        "bufferViews": {"vertices": {"buffer": "data", "byteOffset": 0, "byteLength": 60, "target": 34962}},
+        "buffers": {"data": {"uri": "data.bin", "byteLength": 60}},
+        "materials": {
+            "red": {
+                "extensions": {
+                    "KHR_materials_common": {
+                        "technique": "BLINN",
+                        "doubleSided": true,
+                        "values": {"diffuse": [1, 0, 0, 1], "shininess": 0, "transparency": 0.5}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn convert_triangle() {
+        let root = v1_to_v2(&v1::Root::from_str(TRIANGLE).unwrap()).unwrap();
+        assert_eq!(root.asset.version, "2.0");
+        assert_eq!(root.asset.generator.as_deref(), Some("test"));
+        assert_eq!(root.scene, Some(Index::new(0)));
+
+        // Ids are ordered, so "child" precedes "root".
+        assert_eq!(root.scenes[0].nodes, [Index::new(1)]);
+        assert_eq!(root.nodes[1].children, Some(vec![Index::new(0)]));
+        assert_eq!(root.nodes[0].mesh, Some(Index::new(0)));
+        assert_eq!(root.nodes[0].translation, Some([1.0, 2.0, 3.0]));
+
+        // Several meshes on one node are merged into a new mesh.
+        assert_eq!(root.meshes.len(), 3);
+        assert_eq!(root.nodes[1].mesh, Some(Index::new(2)));
+        assert_eq!(root.meshes[2].primitives.len(), 2);
+
+        // Accessors disagreeing on their stride get a copy of the view.
+        assert_eq!(root.buffer_views.len(), 2);
+        assert_eq!(root.buffer_views[0].byte_stride, Some(12));
+        assert_eq!(root.buffer_views[1].byte_stride, Some(8));
+        assert_eq!(root.accessors[0].buffer_view, Some(Index::new(0)));
+        assert_eq!(root.accessors[1].buffer_view, Some(Index::new(1)));
+
+        let material = &root.materials[0];
+        let pbr = &material.pbr_metallic_roughness;
+        assert_eq!(pbr.base_color_factor.0, [1.0, 0.0, 0.0, 0.5]);
+        assert_eq!(pbr.metallic_factor.0, 0.0);
+        assert_eq!(pbr.roughness_factor.0, 1.0);
+        assert!(material.double_sided);
+    }
+
+    #[test]
+    fn convert_unresolved_id() {
+        let json = r#"{"scenes": {"scene": {"nodes": ["missing"]}}}"#;
+        match v1_to_v2(&v1::Root::from_str(json).unwrap()) {
+            Err(Error::UnresolvedId { kind, id }) => {
+                assert_eq!(kind, "node");
+                assert_eq!(id, "missing");
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn convert_skin_with_cyclic_hierarchy() {
+        let json = r#"{
+            "nodes": {
+                "a": {"children": ["b"], "jointName": "joint_a"},
+                "b": {"children": ["a"], "jointName": "joint_b"},
+                "skinned": {"skin": "skin", "skeletons": ["a"]}
+            },
+            "skins": {"skin": {"jointNames": ["joint_a", "joint_b"]}}
+        }"#;
+        let root = v1_to_v2(&v1::Root::from_str(json).unwrap()).unwrap();
+        assert_eq!(root.skins[0].joints, [Index::new(0), Index::new(1)]);
+        assert_eq!(root.skins[0].skeleton, Some(Index::new(0)));
+    }
+
+    #[test]
+    fn convert_semantics() {
+        assert_eq!(convert_semantic("POSITION"), "POSITION");
+        assert_eq!(convert_semantic("TEXCOORD"), "TEXCOORD_0");
+        assert_eq!(convert_semantic("TEXCOORD_1"), "TEXCOORD_1");
+        assert_eq!(convert_semantic("COLOR"), "COLOR_0");
+        assert_eq!(convert_semantic("JOINT"), "JOINTS_0");
+        assert_eq!(convert_semantic("WEIGHT"), "WEIGHTS_0");
+        assert_eq!(convert_semantic("BATCHID"), "_BATCHID");
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Error, Path, Root, Value};
+use std::fmt;
+
+/// A difference between two documents, as reported by [`diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// A value present only in the new document.
+    Added {
+        /// The location of the value.
+        path: Path,
+        /// The added value.
+        value: Value,
+    },
+
+    /// A value present only in the old document.
+    Removed {
+        /// The location of the value.
+        path: Path,
+        /// The removed value.
+        value: Value,
+    },
+
+    /// A value present in both documents with different contents.
+    Changed {
+        /// The location of the value.
+        path: Path,
+        /// The value in the old document.
+        old: Value,
+        /// The value in the new document.
+        new: Value,
+    },
+}
+
+impl Change {
+    /// Returns the location of the changed value.
+    pub fn path(&self) -> &Path {
+        match self {
+            Change::Added { path, .. } => path,
+            Change::Removed { path, .. } => path,
+            Change::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {}: {}", path, value),
+            Change::Removed { path, value } => write!(f, "- {}: {}", path, value),
+            Change::Changed { path, old, new } => write!(f, "~ {}: {} -> {}", path, old, new),
+        }
+    }
+}
+
+/// Compares two documents structurally, returning every added, removed and
+/// changed value.
+///
+/// The documents are compared as JSON values, so the order of object keys
+/// and the formatting of the source files do not matter, and numbers compare
+/// equal if they have the same value, such as `1` and `1.0`. Objects are
+/// compared key by key and arrays of objects element by element, with
+/// changes reported at the deepest path that differs. Other arrays, such as
+/// matrices and bounds, are reported as a whole.
+///
+/// Objects are matched by index, so reordering the objects of a document
+/// shows up as changes to each of them.
+pub fn diff(old: &Root, new: &Root) -> Result<Vec<Change>, Error> {
+    let mut changes = Vec::new();
+    compare(
+        &Path::new(),
+        &old.to_value()?,
+        &new.to_value()?,
+        &mut changes,
+    );
+    Ok(changes)
+}
+
+fn compare(path: &Path, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old) in old {
+                match new.get(key) {
+                    Some(new) => compare(&path.field(key), old, new, changes),
+                    None => changes.push(Change::Removed {
+                        path: path.field(key),
+                        value: old.clone(),
+                    }),
+                }
+            }
+            for (key, new) in new {
+                if !old.contains_key(key) {
+                    changes.push(Change::Added {
+                        path: path.field(key),
+                        value: new.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) if old.iter().chain(new).all(Value::is_object) => {
+            for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                compare(&path.index(i), old, new, changes);
+            }
+            for (i, old) in old.iter().enumerate().skip(new.len()) {
+                changes.push(Change::Removed {
+                    path: path.index(i),
+                    value: old.clone(),
+                });
+            }
+            for (i, new) in new.iter().enumerate().skip(old.len()) {
+                changes.push(Change::Added {
+                    path: path.index(i),
+                    value: new.clone(),
+                });
+            }
+        }
+        _ if !equal(old, new) => changes.push(Change::Changed {
+            path: path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Compares two values, treating numbers with the same value as equal.
+pub(crate) fn equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equal(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).map_or(false, |b| equal(a, b)))
+        }
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Change};
+    use crate::Root;
+
+    #[test]
+    fn changes() {
+        let old = Root::from_str(
+            r#"{
+                "asset": {"version": "2.0"},
+                "nodes": [
+                    {"translation": [1, 0, 0], "mesh": 0},
+                    {"camera": 0}
+                ],
+                "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}]
+            }"#,
+        )
+        .unwrap();
+        let new = Root::from_str(
+            r#"{
+                "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+                "nodes": [{"mesh": 0, "translation": [1.0, 0.0, 2.0], "scale": [2, 2, 2]}],
+                "asset": {"version": "2.0", "generator": "test"}
+            }"#,
+        )
+        .unwrap();
+
+        let changes: Vec<String> = diff(&old, &new)
+            .unwrap()
+            .iter()
+            .map(Change::to_string)
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                r#"+ asset.generator: "test""#,
+                "~ nodes[0].translation: [1.0,0.0,0.0] -> [1.0,0.0,2.0]",
+                "+ nodes[0].scale: [2.0,2.0,2.0]",
+                r#"- nodes[1]: {"camera":0}"#,
+            ]
+        );
+        assert!(diff(&old, &old).unwrap().is_empty());
+    }
+}
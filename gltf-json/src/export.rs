@@ -0,0 +1,397 @@
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
+use std::io;
+
+/// Options controlling how a `Root` is written by
+/// [`Root::to_writer_with_options`](crate::Root::to_writer_with_options).
+///
+/// The default options write compact JSON with full float precision, the
+/// same as `Root::to_writer`, after updating `extensionsUsed` and
+/// `extensionsRequired` to match the extension data of the document.
+///
+/// ```
+/// # fn run() -> Result<(), gltf_json::Error> {
+/// use gltf_json::{ExportOptions, Root};
+///
+/// let root: Root = gltf_json::deserialize::from_str(
+///     r#"{ "asset": { "version": "2.0" }, "nodes": [{ "translation": [0.123456, -0.0001, 2] }] }"#,
+/// )?;
+/// let options = ExportOptions {
+///     float_precision: Some(3),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     root.to_string_with_options(&options)?,
+///     r#"{"asset":{"version":"2.0"},"nodes":[{"translation":[0.123,0.0,2.0]}]}"#,
+/// );
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Whether to write each value on its own line, indented by its depth.
+    pub pretty: bool,
+
+    /// The indentation of one level of pretty-printed output.
+    ///
+    /// Defaults to two spaces. Ignored unless `pretty` is set.
+    pub indent: String,
+
+    /// The maximum number of decimal places of floating point numbers.
+    ///
+    /// Numbers are rounded and trailing zeros are dropped. If `None`, numbers
+    /// are written in their shortest form that reads back as the same value.
+    /// Integers and `extras`, which are written as they were read, are never
+    /// rounded.
+    ///
+    /// Accessor bounds are rounded outwards, so `min` is rounded down and
+    /// `max` up, and keep enclosing the accessor data. Rotations are written
+    /// in full, so that unit quaternions stay normalized.
+    pub float_precision: Option<usize>,
+
+    /// Whether to write canonical JSON, whose output depends only on the
+    /// contents of the document, which keeps diffs of documents under
+    /// version control small.
+    ///
+    /// Object keys, including those of extensions and extras, are sorted by
+    /// their UTF-16 code units. Unless `float_precision` is set, numbers that
+    /// are exactly representable as `f32` are written in their shortest form
+    /// that reads back as the same `f32`, and other numbers in their shortest
+    /// `f64` form. Combined with the default compact output, no whitespace is
+    /// written between tokens.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf_json::Error> {
+    /// use gltf_json::{ExportOptions, Root};
+    ///
+    /// let root: Root = gltf_json::deserialize::from_str(
+    ///     r#"{ "nodes": [{ "translation": [0.1, 0, 0] }], "asset": { "version": "2.0" } }"#,
+    /// )?;
+    /// let options = ExportOptions {
+    ///     canonical: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     root.to_string_with_options(&options)?,
+    ///     r#"{"asset":{"version":"2.0"},"nodes":[{"translation":[0.1,0.0,0.0]}]}"#,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub canonical: bool,
+
+    /// Whether to write `extensionsUsed` and `extensionsRequired` as updated
+    /// by [`Root::update_extensions_used`](crate::Root::update_extensions_used),
+    /// leaving the document itself unchanged.
+    ///
+    /// Defaults to `true`. Finding the extension data walks the whole
+    /// document, which can be skipped for documents known to be up to date.
+    pub update_extensions_used: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            indent: "  ".to_owned(),
+            float_precision: None,
+            canonical: false,
+            update_extensions_used: true,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Returns the formatter implementing these options.
+    pub(crate) fn formatter(&self) -> ExportFormatter<'_> {
+        ExportFormatter {
+            pretty: if self.pretty {
+                Some(PrettyFormatter::with_indent(self.indent.as_bytes()))
+            } else {
+                None
+            },
+            float_precision: self.float_precision,
+            canonical: self.canonical,
+            keys: Vec::new(),
+            in_key: false,
+        }
+    }
+}
+
+/// A `serde_json` formatter that writes either compact or pretty-printed
+/// JSON with rounded floats.
+pub(crate) struct ExportFormatter<'a> {
+    /// The pretty-printing formatter, or `None` for compact output.
+    pretty: Option<PrettyFormatter<'a>>,
+
+    /// See [`ExportOptions::float_precision`].
+    float_precision: Option<usize>,
+
+    /// See [`ExportOptions::canonical`].
+    canonical: bool,
+
+    /// The key of the current member of each enclosing object.
+    keys: Vec<String>,
+
+    /// Whether an object key is being written.
+    in_key: bool,
+}
+
+impl<'a> ExportFormatter<'a> {
+    /// Writes a floating point number, rounded according to the innermost
+    /// object key.
+    fn write_float<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let rounding = match self.keys.last().map(String::as_str) {
+            Some("min") => Rounding::Down,
+            Some("max") => Rounding::Up,
+            _ => Rounding::Nearest,
+        };
+        write_rounded(writer, value, self.float_precision.unwrap(), rounding)
+    }
+}
+
+/// The direction in which to round a number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Rounding {
+    /// Round to the nearest number.
+    Nearest,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+}
+
+/// Forwards formatter methods to the pretty-printing formatter if there is
+/// one, or to the compact formatter otherwise.
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {$(
+        fn $method<W>(&mut self, writer: &mut W $(, $arg: $ty)*) -> io::Result<()>
+        where
+            W: ?Sized + io::Write,
+        {
+            match self.pretty.as_mut() {
+                Some(pretty) => pretty.$method(writer $(, $arg)*),
+                None => CompactFormatter.$method(writer $(, $arg)*),
+            }
+        }
+    )*};
+}
+
+impl<'a> Formatter for ExportFormatter<'a> {
+    forward! {
+        begin_array();
+        end_array();
+        begin_array_value(first: bool);
+        end_array_value();
+        end_object_value();
+    }
+
+    fn begin_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.keys.push(String::new());
+        match self.pretty.as_mut() {
+            Some(pretty) => pretty.begin_object(writer),
+            None => CompactFormatter.begin_object(writer),
+        }
+    }
+
+    fn end_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.keys.pop();
+        match self.pretty.as_mut() {
+            Some(pretty) => pretty.end_object(writer),
+            None => CompactFormatter.end_object(writer),
+        }
+    }
+
+    fn begin_object_key<W>(&mut self, writer: &mut W, first: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if let Some(key) = self.keys.last_mut() {
+            key.clear();
+        }
+        self.in_key = true;
+        match self.pretty.as_mut() {
+            Some(pretty) => pretty.begin_object_key(writer, first),
+            None => CompactFormatter.begin_object_key(writer, first),
+        }
+    }
+
+    fn begin_object_value<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.in_key = false;
+        match self.pretty.as_mut() {
+            Some(pretty) => pretty.begin_object_value(writer),
+            None => CompactFormatter.begin_object_value(writer),
+        }
+    }
+
+    fn write_string_fragment<W>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if self.in_key {
+            if let Some(key) = self.keys.last_mut() {
+                key.push_str(fragment);
+            }
+        }
+        writer.write_all(fragment.as_bytes())
+    }
+
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        match self.float_precision {
+            Some(_) if self.keys.last().map(String::as_str) != Some("rotation") => {
+                self.write_float(writer, value as f64)
+            }
+            _ => CompactFormatter.write_f32(writer, value),
+        }
+    }
+
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        match self.float_precision {
+            Some(_) if self.keys.last().map(String::as_str) != Some("rotation") => {
+                self.write_float(writer, value)
+            }
+            _ if self.canonical && (value as f32) as f64 == value => {
+                CompactFormatter.write_f32(writer, value as f32)
+            }
+            _ => CompactFormatter.write_f64(writer, value),
+        }
+    }
+}
+
+/// Writes `value` rounded to `precision` decimal places in the direction of
+/// `rounding`, without trailing zeros but with at least one decimal place.
+fn write_rounded<W>(
+    writer: &mut W,
+    value: f64,
+    precision: usize,
+    rounding: Rounding,
+) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+{
+    let mut text = format!("{:.*}", precision, value);
+    let step = 10f64.powi(-(precision as i32));
+    match text.parse::<f64>() {
+        Ok(nearest) if rounding == Rounding::Down && nearest > value => {
+            text = format!("{:.*}", precision, nearest - step);
+        }
+        Ok(nearest) if rounding == Rounding::Up && nearest < value => {
+            text = format!("{:.*}", precision, nearest + step);
+        }
+        _ => {}
+    }
+    if text.contains('.') {
+        text.truncate(text.trim_end_matches('0').len());
+    } else {
+        text.push('.');
+    }
+    if text.ends_with('.') {
+        text.push('0');
+    }
+    if text == "-0.0" {
+        text.remove(0);
+    }
+    writer.write_all(text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_rounded, ExportOptions, Rounding};
+    use crate::Root;
+
+    fn rounded(value: f64, precision: usize) -> String {
+        let mut bytes = Vec::new();
+        write_rounded(&mut bytes, value, precision, Rounding::Nearest).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn float_precision() {
+        assert_eq!(rounded(0.1f32 as f64, 4), "0.1");
+        assert_eq!(rounded(1.23456, 2), "1.23");
+        assert_eq!(rounded(-0.0001, 2), "0.0");
+        assert_eq!(rounded(2.5, 0), "2.0");
+        assert_eq!(rounded(1e20, 1), "100000000000000000000.0");
+    }
+
+    #[test]
+    fn float_precision_keeps_bounds_and_rotations() {
+        let root: Root = crate::deserialize::from_str(
+            r#"{
+                "asset": { "version": "2.0" },
+                "accessors": [{
+                    "componentType": 5126,
+                    "count": 2,
+                    "type": "VEC3",
+                    "min": [-0.12345, 0.12345, 1.0],
+                    "max": [-0.12345, 0.12345, 1.0]
+                }],
+                "nodes": [{
+                    "rotation": [0.0, 0.0, 0.38268343, 0.9238795],
+                    "translation": [0.12345, 0.0, 0.0]
+                }]
+            }"#,
+        )
+        .unwrap();
+        let options = ExportOptions {
+            float_precision: Some(2),
+            ..Default::default()
+        };
+        let text = root.to_string_with_options(&options).unwrap();
+        assert!(text.contains(r#""rotation":[0.0,0.0,0.38268343,0.9238795]"#));
+        let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            value["accessors"][0]["min"],
+            serde_json::json!([-0.13, 0.12, 1.0])
+        );
+        assert_eq!(
+            value["accessors"][0]["max"],
+            serde_json::json!([-0.12, 0.13, 1.0])
+        );
+        assert_eq!(
+            value["nodes"][0]["translation"],
+            serde_json::json!([0.12, 0.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let root: Root =
+            crate::deserialize::from_str(r#"{"asset":{"version":"2.0"},"nodes":[{}]}"#).unwrap();
+        let options = ExportOptions {
+            pretty: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            root.to_string_with_options(&options).unwrap(),
+            root.to_string_pretty().unwrap()
+        );
+        let options = ExportOptions {
+            indent: "\t".to_owned(),
+            ..options
+        };
+        assert_eq!(
+            root.to_string_with_options(&options).unwrap(),
+            "{\n\t\"asset\": {\n\t\t\"version\": \"2.0\"\n\t},\n\t\"nodes\": [\n\t\t{}\n\t]\n}"
+        );
+    }
+}
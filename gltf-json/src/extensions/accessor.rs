@@ -6,19 +6,35 @@ pub mod sparse {
     use super::*;
 
     /// Indices of those attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Indices {}
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+    pub struct Indices {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 
     /// Sparse storage of attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Sparse {}
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+    pub struct Sparse {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Values {}
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+    pub struct Values {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 }
 
 /// A typed view into a buffer view.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Accessor {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Accessor {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
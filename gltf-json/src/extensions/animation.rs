@@ -2,17 +2,33 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// A keyframe animation.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Animation {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Animation {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Targets an animation's sampler at a node's property.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Channel {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Channel {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Target {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Defines a keyframe graph but not its target.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Sampler {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
@@ -2,5 +2,9 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// Metadata about the glTF asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Asset {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Asset {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
@@ -2,9 +2,273 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Buffer {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Buffer {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::Buffer>,
+}
 
 /// A view into a buffer generally representing a subset of the buffer.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct View {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct View {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::View>,
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+pub mod ext_meshopt_compression {
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{buffer, Index, Path, Root};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid compression modes.
+    pub const VALID_MODES: &[&str] = &["ATTRIBUTES", "TRIANGLES", "INDICES"];
+
+    /// All valid filters.
+    pub const VALID_FILTERS: &[&str] = &["NONE", "OCTAHEDRAL", "QUATERNION", "EXPONENTIAL"];
+
+    /// Marks a buffer as a fallback for compressed buffer views.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+    pub struct Buffer {
+        /// Whether the buffer only exists to give compressed buffer views
+        /// somewhere to decompress to, in which case it has no data of its
+        /// own and may have no URI.
+        #[serde(default)]
+        pub fallback: bool,
+    }
+
+    /// The compressed data of a buffer view, which decompresses to the
+    /// contents of the buffer view.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct View {
+        /// The buffer containing the compressed data.
+        pub buffer: Index<buffer::Buffer>,
+
+        /// The offset of the compressed data in the buffer, in bytes.
+        #[serde(default)]
+        pub byte_offset: u32,
+
+        /// The length of the compressed data, in bytes.
+        pub byte_length: u32,
+
+        /// The size of each decompressed element, in bytes.
+        pub byte_stride: u32,
+
+        /// The number of decompressed elements.
+        pub count: u32,
+
+        /// The compression mode.
+        pub mode: Checked<Mode>,
+
+        /// The filter applied to the decompressed elements.
+        #[serde(default, skip_serializing_if = "is_default_filter")]
+        pub filter: Checked<Filter>,
+    }
+
+    fn is_default_filter(filter: &Checked<Filter>) -> bool {
+        *filter == Checked::Valid(Filter::None)
+    }
+
+    impl Validate for View {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            self.buffer
+                .validate(root, || path().field("buffer"), report);
+            self.mode.validate(root, || path().field("mode"), report);
+            self.filter
+                .validate(root, || path().field("filter"), report);
+
+            if let Some(buffer) = root.get(self.buffer) {
+                let end = self.byte_offset as u64 + self.byte_length as u64;
+                if end > buffer.byte_length as u64 {
+                    report(&|| path().field("byteLength"), Error::Invalid);
+                }
+            }
+
+            let stride = self.byte_stride;
+            let valid_stride = match self.mode {
+                Checked::Valid(Mode::Attributes) => stride % 4 == 0 && stride <= 256,
+                Checked::Valid(Mode::Triangles | Mode::Indices) => stride == 2 || stride == 4,
+                Checked::Invalid => true,
+            };
+            if !valid_stride {
+                report(&|| path().field("byteStride"), Error::Invalid);
+            }
+            if self.mode == Checked::Valid(Mode::Triangles) && self.count % 3 != 0 {
+                report(&|| path().field("count"), Error::Invalid);
+            }
+
+            let valid_filter = match (self.mode, self.filter) {
+                (_, Checked::Valid(Filter::None)) => true,
+                (Checked::Valid(Mode::Attributes), Checked::Valid(Filter::Octahedral)) => {
+                    stride == 4 || stride == 8
+                }
+                (Checked::Valid(Mode::Attributes), Checked::Valid(Filter::Quaternion)) => {
+                    stride == 8
+                }
+                (Checked::Valid(Mode::Attributes), Checked::Valid(Filter::Exponential)) => {
+                    stride % 4 == 0
+                }
+                (Checked::Invalid, _) | (_, Checked::Invalid) => true,
+                _ => false,
+            };
+            if !valid_filter {
+                report(&|| path().field("filter"), Error::Invalid);
+            }
+        }
+    }
+
+    /// Specifies how a buffer view is compressed.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Mode {
+        /// Vertex attribute data, or any other data made of elements whose
+        /// size is a multiple of four bytes.
+        Attributes = 1,
+
+        /// Triangle list indices.
+        Triangles,
+
+        /// Indices that need not form triangles.
+        Indices,
+    }
+
+    /// Specifies the filter applied to the decompressed data.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Filter {
+        /// The decompressed data is used as it is.
+        None = 1,
+
+        /// Unit vectors stored as octahedral coordinates in four 8 or 16 bit
+        /// components.
+        Octahedral,
+
+        /// Unit quaternions stored as three 16 bit components and the index of
+        /// the omitted one.
+        Quaternion,
+
+        /// Floating point values stored as 32 bit integers with a shared
+        /// exponent.
+        Exponential,
+    }
+
+    impl Default for Filter {
+        fn default() -> Self {
+            Filter::None
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Mode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Mode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::Mode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "ATTRIBUTES" => Valid(Attributes),
+                        "TRIANGLES" => Valid(Triangles),
+                        "INDICES" => Valid(Indices),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Mode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                Mode::Attributes => "ATTRIBUTES",
+                Mode::Triangles => "TRIANGLES",
+                Mode::Indices => "INDICES",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Filter> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Filter>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_FILTERS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::Filter::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "NONE" => Valid(None),
+                        "OCTAHEDRAL" => Valid(Octahedral),
+                        "QUATERNION" => Valid(Quaternion),
+                        "EXPONENTIAL" => Valid(Exponential),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Filter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                Filter::None => "NONE",
+                Filter::Octahedral => "OCTAHEDRAL",
+                Filter::Quaternion => "QUATERNION",
+                Filter::Exponential => "EXPONENTIAL",
+            })
+        }
+    }
+}
@@ -5,13 +5,25 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Camera {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Camera {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for an orthographic camera.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Orthographic {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Orthographic {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for a perspective camera.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Perspective {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Perspective {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
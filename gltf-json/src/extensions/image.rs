@@ -2,5 +2,9 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// Image data used to create a texture.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Image {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Image {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
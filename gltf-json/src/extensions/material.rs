@@ -1,11 +1,15 @@
 #[allow(unused_imports)] // different features use different imports
-use crate::{material::StrengthFactor, texture, validation::Validate, Extras};
+use crate::{material, material::StrengthFactor, texture, validation::Validate, Extras};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// The material appearance of a primitive.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct Material {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     #[serde(
         default,
@@ -53,12 +57,56 @@ pub struct Material {
         skip_serializing_if = "Option::is_none"
     )]
     pub ior: Option<Ior>,
+
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[serde(
+        default,
+        rename = "KHR_materials_emissive_strength",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub emissive_strength: Option<EmissiveStrength>,
+
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    #[serde(
+        default,
+        rename = "KHR_materials_clearcoat",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub clearcoat: Option<Clearcoat>,
+
+    #[cfg(feature = "KHR_materials_sheen")]
+    #[serde(
+        default,
+        rename = "KHR_materials_sheen",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sheen: Option<Sheen>,
+
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[serde(
+        default,
+        rename = "KHR_materials_anisotropy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub anisotropy: Option<Anisotropy>,
+
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[serde(
+        default,
+        rename = "KHR_materials_iridescence",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub iridescence: Option<Iridescence>,
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct PbrMetallicRoughness {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct PbrMetallicRoughness {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A set of parameter values that are used to define the specular-glossiness
 /// material model from Physically-Based Rendering (PBR) methodology.
@@ -112,17 +160,50 @@ pub struct PbrSpecularGlossiness {
     pub extras: Extras,
 }
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl_partial_eq!(PbrSpecularGlossiness {
+    diffuse_factor,
+    diffuse_texture,
+    specular_factor,
+    glossiness_factor,
+    specular_glossiness_texture,
+} extras);
+
 /// Defines the normal texture of a material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct NormalTexture {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct NormalTexture {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+}
 
 /// Defines the occlusion texture of a material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct OcclusionTexture {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct OcclusionTexture {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+}
 
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct PbrDiffuseFactor(pub [f32; 4]);
 
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -137,7 +218,7 @@ impl Validate for PbrDiffuseFactor {}
 
 /// The specular factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct PbrSpecularFactor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -152,12 +233,12 @@ impl Validate for PbrSpecularFactor {}
 
 /// Empty struct that should be present for primitives which should not be shaded with the PBR shading model.
 #[cfg(feature = "KHR_materials_unlit")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct Unlit {}
 
 /// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
 #[cfg(feature = "KHR_materials_transmission")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TransmissionFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_transmission")]
@@ -168,7 +249,17 @@ impl Default for TransmissionFactor {
 }
 
 #[cfg(feature = "KHR_materials_transmission")]
-impl Validate for TransmissionFactor {}
+impl Validate for TransmissionFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 #[cfg(feature = "KHR_materials_transmission")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -197,9 +288,15 @@ pub struct Transmission {
     pub extras: Extras,
 }
 
+#[cfg(feature = "KHR_materials_transmission")]
+impl_partial_eq!(Transmission {
+    transmission_factor,
+    transmission_texture,
+} extras);
+
 /// A positive number with default value of 1.5
 #[cfg(feature = "KHR_materials_ior")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IndexOfRefraction(pub f32);
 
 #[cfg(feature = "KHR_materials_ior")]
@@ -210,7 +307,17 @@ impl Default for IndexOfRefraction {
 }
 
 #[cfg(feature = "KHR_materials_ior")]
-impl Validate for IndexOfRefraction {}
+impl Validate for IndexOfRefraction {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 != 0.0 && self.0 < 1.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 #[cfg(feature = "KHR_materials_ior")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -229,9 +336,57 @@ pub struct Ior {
     pub extras: Extras,
 }
 
+#[cfg(feature = "KHR_materials_ior")]
+impl_partial_eq!(Ior {
+    ior,
+} extras);
+
+/// A non-negative number with a default value of 1.0.
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EmissiveStrengthFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Default for EmissiveStrengthFactor {
+    fn default() -> Self {
+        EmissiveStrengthFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Validate for EmissiveStrengthFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 < 0.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EmissiveStrength {
+    /// The strength adjustment to be multiplied with the material's emissive value.
+    pub emissive_strength: EmissiveStrengthFactor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl_partial_eq!(EmissiveStrength {
+    emissive_strength,
+} extras);
+
 /// A number in the inclusive range [0.0, +inf] with a default value of 0.0.
 #[cfg(feature = "KHR_materials_volume")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ThicknessFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -242,11 +397,21 @@ impl Default for ThicknessFactor {
 }
 
 #[cfg(feature = "KHR_materials_volume")]
-impl Validate for ThicknessFactor {}
+impl Validate for ThicknessFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 < 0.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 /// A number in the inclusive range [0.0, +inf] with a default value of +inf.
 #[cfg(feature = "KHR_materials_volume")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AttenuationDistance(pub f32);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -257,11 +422,26 @@ impl Default for AttenuationDistance {
 }
 
 #[cfg(feature = "KHR_materials_volume")]
-impl Validate for AttenuationDistance {}
+fn is_attenuation_distance_default(value: &AttenuationDistance) -> bool {
+    value.0 == f32::INFINITY
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for AttenuationDistance {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 <= 0.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 /// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_volume")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AttenuationColor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -272,7 +452,17 @@ impl Default for AttenuationColor {
 }
 
 #[cfg(feature = "KHR_materials_volume")]
-impl Validate for AttenuationColor {}
+impl Validate for AttenuationColor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !self.0.iter().all(|x| (0.0..=1.0).contains(x)) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -293,6 +483,7 @@ pub struct Volume {
     /// Density of the medium given as the average distance that light
     /// travels in the medium before interacting with a particle. The
     /// value is given in world space. Range is (0, +inf).
+    #[serde(skip_serializing_if = "is_attenuation_distance_default")]
     pub attenuation_distance: AttenuationDistance,
 
     /// The color that white light turns into due to absorption when
@@ -305,9 +496,345 @@ pub struct Volume {
     pub extras: Extras,
 }
 
-/// A number in the inclusive range [0.0, +inf] with a default value of 1.0.
+#[cfg(feature = "KHR_materials_volume")]
+impl_partial_eq!(Volume {
+    thickness_factor,
+    thickness_texture,
+    attenuation_distance,
+    attenuation_color,
+} extras);
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ClearcoatFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Validate for ClearcoatFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ClearcoatRoughnessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Validate for ClearcoatRoughnessFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Clearcoat {
+    /// The clearcoat layer intensity.
+    pub clearcoat_factor: ClearcoatFactor,
+
+    /// A texture that defines the clearcoat layer intensity, stored in the
+    /// `R` channel. This will be multiplied by `clearcoat_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_texture: Option<texture::Info>,
+
+    /// The clearcoat layer roughness.
+    pub clearcoat_roughness_factor: ClearcoatRoughnessFactor,
+
+    /// A texture that defines the clearcoat layer roughness, stored in the
+    /// `G` channel. This will be multiplied by `clearcoat_roughness_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_roughness_texture: Option<texture::Info>,
+
+    /// A tangent space normal map for the clearcoat layer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_normal_texture: Option<material::NormalTexture>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl_partial_eq!(Clearcoat {
+    clearcoat_factor,
+    clearcoat_texture,
+    clearcoat_roughness_factor,
+    clearcoat_roughness_texture,
+    clearcoat_normal_texture,
+} extras);
+
+/// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [0.0; 3].
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SheenColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenColorFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !self.0.iter().all(|x| (0.0..=1.0).contains(x)) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SheenRoughnessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenRoughnessFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Sheen {
+    /// The sheen color in linear space.
+    pub sheen_color_factor: SheenColorFactor,
+
+    /// A texture that defines the sheen color, stored in the `RGB` channels
+    /// and encoded in sRGB. This will be multiplied by `sheen_color_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_color_texture: Option<texture::Info>,
+
+    /// The sheen roughness.
+    pub sheen_roughness_factor: SheenRoughnessFactor,
+
+    /// A texture that defines the sheen roughness, stored in the alpha (`A`)
+    /// channel. This will be multiplied by `sheen_roughness_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_roughness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl_partial_eq!(Sheen {
+    sheen_color_factor,
+    sheen_color_texture,
+    sheen_roughness_factor,
+    sheen_roughness_texture,
+} extras);
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct AnisotropyStrength(pub f32);
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Validate for AnisotropyStrength {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Anisotropy {
+    /// The anisotropy strength.
+    pub anisotropy_strength: AnisotropyStrength,
+
+    /// The rotation of the anisotropy in tangent, bitangent space, measured in
+    /// radians counter-clockwise from the tangent.
+    pub anisotropy_rotation: f32,
+
+    /// A texture that defines the anisotropy direction in its `RG` channels and
+    /// strength in its `B` channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anisotropy_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl_partial_eq!(Anisotropy {
+    anisotropy_strength,
+    anisotropy_rotation,
+    anisotropy_texture,
+} extras);
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct IridescenceFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+/// A number in the inclusive range [1.0, +inf] with a default value of 1.3.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IridescenceIor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceIor {
+    fn default() -> Self {
+        IridescenceIor(1.3)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceIor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 < 1.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+/// A number in the inclusive range [0.0, +inf] with a default value of 100.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IridescenceThicknessMinimum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMinimum {
+    fn default() -> Self {
+        IridescenceThicknessMinimum(100.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMinimum {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 < 0.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+/// A number in the inclusive range [0.0, +inf] with a default value of 400.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IridescenceThicknessMaximum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMaximum {
+    fn default() -> Self {
+        IridescenceThicknessMaximum(400.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMaximum {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0 < 0.0 {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Iridescence {
+    /// The iridescence intensity.
+    pub iridescence_factor: IridescenceFactor,
+
+    /// A texture that defines the iridescence intensity, stored in the `R`
+    /// channel. This will be multiplied by `iridescence_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_texture: Option<texture::Info>,
+
+    /// The index of refraction of the thin-film layer.
+    pub iridescence_ior: IridescenceIor,
+
+    /// The minimum thickness of the thin-film layer in nanometres.
+    pub iridescence_thickness_minimum: IridescenceThicknessMinimum,
+
+    /// The maximum thickness of the thin-film layer in nanometres.
+    pub iridescence_thickness_maximum: IridescenceThicknessMaximum,
+
+    /// A texture that defines the thickness of the thin-film layer, stored in
+    /// the `G` channel. It interpolates between the minimum and maximum
+    /// thickness.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_thickness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl_partial_eq!(Iridescence {
+    iridescence_factor,
+    iridescence_texture,
+    iridescence_ior,
+    iridescence_thickness_minimum,
+    iridescence_thickness_maximum,
+    iridescence_thickness_texture,
+} extras);
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 1.0.
 #[cfg(feature = "KHR_materials_specular")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SpecularFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_specular")]
@@ -318,11 +845,21 @@ impl Default for SpecularFactor {
 }
 
 #[cfg(feature = "KHR_materials_specular")]
-impl Validate for SpecularFactor {}
+impl Validate for SpecularFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if !(0.0..=1.0).contains(&self.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
-/// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
+/// A colour in the inclusive range [[0.0; 3], [+inf; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_specular")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SpecularColorFactor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_specular")]
@@ -333,7 +870,17 @@ impl Default for SpecularColorFactor {
 }
 
 #[cfg(feature = "KHR_materials_specular")]
-impl Validate for SpecularColorFactor {}
+impl Validate for SpecularColorFactor {
+    fn validate<P, R>(&self, _root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        if self.0.iter().any(|x| *x < 0.0) {
+            report(&path, crate::validation::Error::Invalid);
+        }
+    }
+}
 
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -362,3 +909,11 @@ pub struct Specular {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 }
+
+#[cfg(feature = "KHR_materials_specular")]
+impl_partial_eq!(Specular {
+    specular_factor,
+    specular_texture,
+    specular_color_factor,
+    specular_color_texture,
+} extras);
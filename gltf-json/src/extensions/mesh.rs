@@ -5,12 +5,28 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Mesh {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Mesh {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Geometry to be rendered with the given material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct Primitive {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[serde(
+        default,
+        rename = "KHR_draco_mesh_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_draco_mesh_compression: Option<KhrDracoMeshCompression>,
+
     #[cfg(feature = "KHR_materials_variants")]
     #[serde(
         default,
@@ -20,15 +36,53 @@ pub struct Primitive {
     pub khr_materials_variants: Option<KhrMaterialsVariants>,
 }
 
+/// Draco compressed geometry of a primitive.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct KhrDracoMeshCompression {
+    /// The buffer view containing the compressed data.
+    pub buffer_view: crate::Index<crate::buffer::View>,
+
+    /// Maps each attribute semantic to its unique id in the compressed data.
+    pub attributes:
+        std::collections::BTreeMap<crate::validation::Checked<crate::mesh::Semantic>, u32>,
+}
+
 #[cfg(feature = "KHR_materials_variants")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct KhrMaterialsVariants {
     pub mappings: Vec<Mapping>,
 }
 
 #[cfg(feature = "KHR_materials_variants")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct Mapping {
     pub material: u32,
     pub variants: Vec<u32>,
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+impl crate::validation::Validate for Mapping {
+    fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        use crate::extensions::scene::khr_materials_variants::Variant;
+        use crate::validation::Error;
+        use crate::{Index, Material};
+
+        if root.get(Index::<Material>::new(self.material)).is_none() {
+            report(&|| path().field("material"), Error::IndexOutOfBounds);
+        }
+        for (index, variant) in self.variants.iter().enumerate() {
+            if root.get(Index::<Variant>::new(*variant)).is_none() {
+                report(
+                    &|| path().field("variants").index(index),
+                    Error::IndexOutOfBounds,
+                );
+            }
+        }
+    }
+}
@@ -38,26 +38,68 @@ pub use self::root::Root;
 
 /// Names of glTF 2.0 extensions enabled by the user.
 pub const ENABLED_EXTENSIONS: &[&str] = &[
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    #[cfg(feature = "EXT_texture_webp")]
+    "EXT_texture_webp",
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    "KHR_draco_mesh_compression",
     #[cfg(feature = "KHR_lights_punctual")]
     "KHR_lights_punctual",
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    "KHR_materials_anisotropy",
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    "KHR_materials_clearcoat",
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    "KHR_materials_emissive_strength",
+    #[cfg(feature = "KHR_materials_ior")]
+    "KHR_materials_ior",
+    #[cfg(feature = "KHR_materials_iridescence")]
+    "KHR_materials_iridescence",
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     "KHR_materials_pbrSpecularGlossiness",
+    #[cfg(feature = "KHR_materials_sheen")]
+    "KHR_materials_sheen",
+    #[cfg(feature = "KHR_materials_specular")]
+    "KHR_materials_specular",
+    #[cfg(feature = "KHR_materials_transmission")]
+    "KHR_materials_transmission",
     #[cfg(feature = "KHR_materials_unlit")]
     "KHR_materials_unlit",
+    #[cfg(feature = "KHR_materials_variants")]
+    "KHR_materials_variants",
+    #[cfg(feature = "KHR_materials_volume")]
+    "KHR_materials_volume",
+    #[cfg(feature = "KHR_mesh_quantization")]
+    "KHR_mesh_quantization",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "KHR_texture_basisu",
     #[cfg(feature = "KHR_texture_transform")]
     "KHR_texture_transform",
-    #[cfg(feature = "KHR_materials_transmission")]
-    "KHR_materials_transmission",
-    #[cfg(feature = "KHR_materials_ior")]
-    "KHR_materials_ior",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
 pub const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "EXT_mesh_gpu_instancing",
+    "EXT_meshopt_compression",
+    "EXT_texture_webp",
+    "KHR_draco_mesh_compression",
     "KHR_lights_punctual",
+    "KHR_materials_anisotropy",
+    "KHR_materials_clearcoat",
+    "KHR_materials_emissive_strength",
+    "KHR_materials_ior",
+    "KHR_materials_iridescence",
     "KHR_materials_pbrSpecularGlossiness",
+    "KHR_materials_sheen",
+    "KHR_materials_specular",
+    "KHR_materials_transmission",
     "KHR_materials_unlit",
+    "KHR_materials_variants",
+    "KHR_materials_volume",
+    "KHR_mesh_quantization",
+    "KHR_texture_basisu",
     "KHR_texture_transform",
-    "KHR_materials_transmission",
-    "KHR_materials_ior",
 ];
@@ -2,8 +2,12 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// The root object of a glTF 2.0 asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct Root {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
         default,
@@ -22,7 +26,7 @@ pub struct Root {
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct KhrLightsPunctual {
     /// Lights at this node.
     pub lights: Vec<crate::extensions::scene::khr_lights_punctual::Light>,
@@ -46,8 +50,23 @@ impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for
     }
 }
 
+#[cfg(feature = "KHR_lights_punctual")]
+impl crate::root::GetMut<crate::extensions::scene::khr_lights_punctual::Light> for crate::Root {
+    fn get_mut(
+        &mut self,
+        id: crate::Index<crate::extensions::scene::khr_lights_punctual::Light>,
+    ) -> Option<&mut crate::extensions::scene::khr_lights_punctual::Light> {
+        self.extensions
+            .as_mut()?
+            .khr_lights_punctual
+            .as_mut()?
+            .lights
+            .get_mut(id.value())
+    }
+}
+
 #[cfg(feature = "KHR_materials_variants")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct KhrMaterialsVariants {
     pub variants: Vec<crate::extensions::scene::khr_materials_variants::Variant>,
 }
@@ -66,3 +85,20 @@ impl crate::root::Get<crate::extensions::scene::khr_materials_variants::Variant>
             .get(id.value())
     }
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+impl crate::root::GetMut<crate::extensions::scene::khr_materials_variants::Variant>
+    for crate::Root
+{
+    fn get_mut(
+        &mut self,
+        id: crate::Index<crate::extensions::scene::khr_materials_variants::Variant>,
+    ) -> Option<&mut crate::extensions::scene::khr_materials_variants::Variant> {
+        self.extensions
+            .as_mut()?
+            .khr_materials_variants
+            .as_mut()?
+            .variants
+            .get_mut(id.value())
+    }
+}
@@ -11,8 +11,20 @@ use serde_derive::{Deserialize, Serialize};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct Node {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(
+        default,
+        rename = "EXT_mesh_gpu_instancing",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::ExtMeshGpuInstancing>,
+
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
         default,
@@ -22,6 +34,65 @@ pub struct Node {
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
 }
 
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::accessor::{ComponentType, GenericComponentType, Type};
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{Accessor, Index, Path, Root};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// Instances the mesh of a node once per set of instance attributes.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    pub struct ExtMeshGpuInstancing {
+        /// Maps each instance attribute, such as `TRANSLATION`, `ROTATION` or
+        /// `SCALE`, to the accessor containing its per-instance values.
+        pub attributes: BTreeMap<String, Index<Accessor>>,
+    }
+
+    impl Validate for ExtMeshGpuInstancing {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            self.attributes
+                .validate(root, || path().field("attributes"), report);
+
+            // Every attribute must hold one value per instance.
+            let mut count = None;
+            for (name, index) in &self.attributes {
+                let accessor = match root.get(*index) {
+                    Some(accessor) => accessor,
+                    None => continue,
+                };
+                let component_type = match accessor.component_type {
+                    Checked::Valid(GenericComponentType(component_type)) => component_type,
+                    Checked::Invalid => continue,
+                };
+                let valid_type = match name.as_str() {
+                    "TRANSLATION" | "SCALE" => {
+                        accessor.type_ == Checked::Valid(Type::Vec3)
+                            && component_type == ComponentType::F32
+                    }
+                    "ROTATION" => {
+                        accessor.type_ == Checked::Valid(Type::Vec4)
+                            && match component_type {
+                                ComponentType::F32 => true,
+                                ComponentType::I8 | ComponentType::I16 => accessor.normalized,
+                                _ => false,
+                            }
+                    }
+                    _ => true,
+                };
+                if !valid_type || *count.get_or_insert(accessor.count) != accessor.count {
+                    report(&|| path().field("attributes").key(name), Error::Invalid);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "KHR_lights_punctual")]
 pub mod khr_lights_punctual {
     use crate::validation::{Checked, Error, Validate};
@@ -34,8 +105,10 @@ pub mod khr_lights_punctual {
     /// All valid light types.
     pub const VALID_TYPES: &[&str] = &["directional", "point", "spot"];
 
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    /// Instantiates a light at a node.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
     pub struct KhrLightsPunctual {
+        /// The light referenced by this node.
         pub light: Index<Light>,
     }
 
@@ -70,6 +143,7 @@ pub mod khr_lights_punctual {
         Spot,
     }
 
+    /// A directional, point or spot light.
     #[derive(Clone, Debug, Deserialize, Serialize)]
     pub struct Light {
         /// Color of the light source.
@@ -110,6 +184,16 @@ pub mod khr_lights_punctual {
         pub type_: Checked<Type>,
     }
 
+    impl_partial_eq!(Light {
+        color,
+        intensity,
+        #[cfg(feature = "names")]
+        name,
+        range,
+        spot,
+        type_,
+    } extensions, extras);
+
     impl Validate for Light {
         fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where
@@ -122,6 +206,17 @@ pub mod khr_lights_punctual {
                 }
             }
 
+            if self.intensity < 0.0 {
+                report(&|| path().field("intensity"), Error::Invalid);
+            }
+
+            if let Some(range) = self.range {
+                if range <= 0.0 {
+                    report(&|| path().field("range"), Error::Invalid);
+                }
+            }
+
+            self.spot.validate(root, || path().field("spot"), report);
             self.type_.validate(root, || path().field("type"), report);
             self.extensions
                 .validate(root, || path().field("extensions"), report);
@@ -139,7 +234,7 @@ pub mod khr_lights_punctual {
     }
 
     /// Spot light parameters.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
     #[serde(rename_all = "camelCase")]
     pub struct Spot {
         /// Angle in radians from centre of spotlight where falloff begins.
@@ -151,6 +246,22 @@ pub mod khr_lights_punctual {
         pub outer_cone_angle: f32,
     }
 
+    impl Validate for Spot {
+        fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            let max_angle = std::f32::consts::FRAC_PI_2;
+            if !(0.0 < self.outer_cone_angle && self.outer_cone_angle <= max_angle) {
+                report(&|| path().field("outerConeAngle"), Error::Invalid);
+            }
+            if !(0.0 <= self.inner_cone_angle && self.inner_cone_angle < self.outer_cone_angle) {
+                report(&|| path().field("innerConeAngle"), Error::Invalid);
+            }
+        }
+    }
+
     fn outer_cone_angle_default() -> f32 {
         std::f32::consts::FRAC_PI_4
     }
@@ -206,7 +317,7 @@ pub mod khr_materials_variants {
     use crate::{Path, Root};
     use serde_derive::{Deserialize, Serialize};
 
-    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     pub struct Variant {
         pub name: String,
     }
@@ -223,5 +334,9 @@ pub mod khr_materials_variants {
 }
 
 /// The root `Node`s of a scene.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Scene {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Scene {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
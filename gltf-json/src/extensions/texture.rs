@@ -1,19 +1,94 @@
+#[cfg(any(
+    feature = "EXT_texture_webp",
+    feature = "KHR_texture_basisu",
+    feature = "KHR_texture_transform"
+))]
+use crate::extras::Extras;
 #[cfg(feature = "KHR_texture_transform")]
-use crate::{extras::Extras, validation::Validate};
+use crate::validation::Validate;
+#[cfg(any(feature = "EXT_texture_webp", feature = "KHR_texture_basisu"))]
+use crate::{image, Index};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// Texture sampler properties for filtering and wrapping modes.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Sampler {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A texture and its sampler.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Texture {}
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+pub struct Texture {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(
+        default,
+        rename = "KHR_texture_basisu",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_basisu: Option<TextureBasisu>,
+
+    #[cfg(feature = "EXT_texture_webp")]
+    #[serde(
+        default,
+        rename = "EXT_texture_webp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_webp: Option<TextureWebp>,
+}
+
+/// Refers to a WebP image to be used instead of the texture's `source`.
+#[cfg(feature = "EXT_texture_webp")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureWebp {
+    /// The index of the WebP image.
+    pub source: Index<image::Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "EXT_texture_webp")]
+impl_partial_eq!(TextureWebp {
+    source,
+} extras);
+
+/// Refers to a KTX2 image with Basis Universal supercompression to be used
+/// instead of the texture's `source`.
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image.
+    pub source: Index<image::Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+impl_partial_eq!(TextureBasisu {
+    source,
+} extras);
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+
     #[cfg(feature = "KHR_texture_transform")]
     #[serde(
         default,
@@ -35,7 +110,7 @@ pub struct Info {
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 #[serde(default, rename_all = "camelCase")]
 pub struct TextureTransform {
-    // The offset of the UV coordinate origin as a factor of the texture dimensions.
+    /// The offset of the UV coordinate origin as a factor of the texture dimensions.
     pub offset: TextureTransformOffset,
 
     /// Rotate the UVs by this many radians counter-clockwise around the origin.
@@ -46,6 +121,7 @@ pub struct TextureTransform {
     pub scale: TextureTransformScale,
 
     /// Overrides the textureInfo texCoord value if supplied, and if this extension is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tex_coord: Option<u32>,
 
     /// Optional application specific data.
@@ -54,9 +130,17 @@ pub struct TextureTransform {
     pub extras: Extras,
 }
 
+#[cfg(feature = "KHR_texture_transform")]
+impl_partial_eq!(TextureTransform {
+    offset,
+    rotation,
+    scale,
+    tex_coord,
+} extras);
+
 /// The offset of the UV coordinate origin as a factor of the texture dimensions.
 #[cfg(feature = "KHR_texture_transform")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TextureTransformOffset(pub [f32; 2]);
 
 #[cfg(feature = "KHR_texture_transform")]
@@ -72,7 +156,7 @@ impl Validate for TextureTransformOffset {}
 /// Rotate the UVs by this many radians counter-clockwise around the origin.
 /// This is equivalent to a similar rotation of the image clockwise.
 #[cfg(feature = "KHR_texture_transform")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TextureTransformRotation(pub f32);
 
 #[cfg(feature = "KHR_texture_transform")]
@@ -87,7 +171,7 @@ impl Validate for TextureTransformRotation {}
 
 /// The scale factor applied to the components of the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TextureTransformScale(pub [f32; 2]);
 
 #[cfg(feature = "KHR_texture_transform")]
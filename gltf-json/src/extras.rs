@@ -2,7 +2,6 @@ use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 
-#[cfg(feature = "extras")]
 pub use serde_json::value::RawValue;
 
 /// Data type of the `extras` attribute on all glTF objects.
@@ -13,8 +12,53 @@ pub type Extras = Option<::std::boxed::Box<RawValue>>;
 #[cfg(not(feature = "extras"))]
 pub type Extras = Void;
 
+/// Data type of the properties of a glTF object that are unknown to this
+/// crate, such as properties of newer versions of the specification.
+#[cfg(feature = "unrecognized")]
+pub type Unrecognized = std::collections::BTreeMap<String, serde_json::Value>;
+
+/// Deserializes the `extras` of a glTF object into an application-defined type.
+///
+/// Returns `None` if the object has no `extras`.
+///
+/// ```
+/// # use gltf_json as json;
+/// #[derive(serde_derive::Deserialize)]
+/// struct Tags {
+///     tags: Vec<String>,
+/// }
+///
+/// let node: json::Node = json::deserialize::from_str(r#"{"extras":{"tags":["door"]}}"#).unwrap();
+/// let tags: Tags = json::extras::deserialize(&node.extras).unwrap().unwrap();
+/// assert_eq!(tags.tags, ["door"]);
+/// ```
+#[cfg(feature = "extras")]
+pub fn deserialize<'a, T>(extras: &'a Extras) -> Option<Result<T, serde_json::Error>>
+where
+    T: serde::Deserialize<'a>,
+{
+    extras.as_ref().map(|raw| serde_json::from_str(raw.get()))
+}
+
+/// Compares unparsed JSON by its text, so formatting and key order matter.
+pub(crate) trait RawEq {
+    fn raw_eq(&self, other: &Self) -> bool;
+}
+
+impl RawEq for Option<Box<RawValue>> {
+    fn raw_eq(&self, other: &Self) -> bool {
+        self.as_ref().map(|x| x.get()) == other.as_ref().map(|x| x.get())
+    }
+}
+
+impl RawEq for Void {
+    fn raw_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 /// Type representing no user-defined data.
-#[derive(Clone, Default, Serialize, Deserialize, Validate)]
+#[derive(Clone, Default, Eq, PartialEq, Serialize, Deserialize, Validate)]
 pub struct Void {
     #[serde(default, skip_serializing)]
     _allow_unknown_fields: (),
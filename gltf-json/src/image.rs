@@ -1,13 +1,21 @@
-use crate::validation::Validate;
-use crate::{buffer, extensions, Extras, Index};
+use crate::validation::{Error, Validate};
+use crate::{buffer, extensions, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// All valid MIME types.
-pub const VALID_MIME_TYPES: &[&str] = &["image/jpeg", "image/png"];
+pub const VALID_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "image/ktx2",
+    "image/png",
+    #[cfg(feature = "EXT_texture_webp")]
+    "image/webp",
+];
 
 /// Image data used to create a texture.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[gltf(validate_hook = "image_validate_hook")]
 pub struct Image {
     /// The index of the buffer view that contains the image. Use this instead of
     /// the image's uri property.
@@ -40,10 +48,44 @@ pub struct Image {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+}
+
+impl_partial_eq!(Image {
+    buffer_view,
+    mime_type,
+    #[cfg(feature = "names")]
+    name,
+    uri,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+/// Checks that an image has exactly one of `uri` and `bufferView`, and a MIME
+/// type if it is stored in a buffer view.
+fn image_validate_hook<P, R>(image: &Image, _root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    match (&image.uri, &image.buffer_view) {
+        (None, None) => report(&|| path().field("uri"), Error::Missing),
+        (Some(_), Some(_)) => report(&|| path().field("bufferView"), Error::Invalid),
+        (None, Some(_)) if image.mime_type.is_none() => {
+            report(&|| path().field("mimeType"), Error::Missing)
+        }
+        _ => {}
+    }
 }
 
 /// An image MIME type.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct MimeType(pub String);
 
 impl Validate for MimeType {}
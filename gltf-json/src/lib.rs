@@ -1,3 +1,26 @@
+/// Implements `PartialEq` for a struct holding unparsed JSON, such as its
+/// `extras`, which `RawValue` does not implement `PartialEq` for.
+///
+/// The fields listed after the braces hold unparsed JSON and are compared by
+/// their text. Every other field must be listed inside the braces, which is
+/// checked by destructuring the struct.
+macro_rules! impl_partial_eq {
+    ($ty:ident { $($(#[$meta:meta])* $field:ident,)* } $($raw:ident),+) => {
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                let $ty { $($(#[$meta])* $field: _,)* $($raw: _),+ } = self;
+                $(
+                    $(#[$meta])*
+                    if self.$field != other.$field {
+                        return false;
+                    }
+                )*
+                $(crate::extras::RawEq::raw_eq(&self.$raw, &other.$raw))&&+
+            }
+        }
+    };
+}
+
 /// Contains `Accessor` and other related data structures.
 pub mod accessor;
 
@@ -7,17 +30,27 @@ pub mod animation;
 /// Contains `Asset` metadata.
 pub mod asset;
 
+/// Contains a `Root` that borrows names and uris from the JSON text.
+pub mod borrowed;
+
 /// Contains `Buffer`, `View`, and other related data structures.
 pub mod buffer;
 
 /// Contains `Camera` and other related data structures.
 pub mod camera;
 
+/// Contains the conversion of glTF 1.0 assets to glTF 2.0.
+#[cfg(feature = "v1")]
+pub mod convert;
+
+/// Contains functions that compare documents.
+pub mod diff;
+
 /// Contains extension specific data structures and the names of all
 /// 2.0 extensions supported by the library.
 pub mod extensions;
 
-/// Contains `Extras`.
+/// Contains `Extras` and `Unrecognized`.
 pub mod extras;
 
 /// Contains `Image` and other related data structures.
@@ -32,21 +65,50 @@ pub mod mesh;
 /// Contains `Path`.
 pub mod path;
 
+/// Contains `Pruned`.
+pub mod prune;
+
+/// Contains `RemoveError`.
+pub mod remove;
+
 /// Contains `Root`.
 pub mod root;
 
+/// Contains functions that validate glTF JSON data against JSON Schema files.
+#[cfg(feature = "schema")]
+pub mod schema;
+
 /// Contains `Scene`, `Node`, and other related data structures.
 pub mod scene;
 
 /// Contains `Skin` and other related data structures.
 pub mod skin;
 
+/// Contains `Stats`.
+pub mod stats;
+
 /// Contains `Texture`, `Sampler`, and other related data structures.
 pub mod texture;
 
+/// Contains the glTF 1.0 data model.
+#[cfg(feature = "v1")]
+pub mod v1;
+
 /// Contains functions that validate glTF JSON data against the specification.
 pub mod validation;
 
+/// Contains the key sorting of canonical JSON, see `ExportOptions::canonical`.
+mod canonical;
+
+/// Contains `ExportOptions`.
+mod export;
+
+/// Contains the implementation of `Root::merge`.
+mod merge;
+
+/// Contains functions that visit every index referring to a given type.
+mod visit;
+
 #[doc(inline)]
 pub use accessor::Accessor;
 #[doc(inline)]
@@ -54,26 +116,41 @@ pub use animation::Animation;
 #[doc(inline)]
 pub use asset::Asset;
 #[doc(inline)]
+pub use asset::{detect_version, Version};
+#[doc(inline)]
 pub use buffer::Buffer;
 #[doc(inline)]
 pub use camera::Camera;
 #[doc(inline)]
+pub use export::ExportOptions;
+#[doc(inline)]
 pub use image::Image;
 #[doc(inline)]
 pub use material::Material;
 #[doc(inline)]
+pub use merge::MergeError;
+#[doc(inline)]
 pub use mesh::Mesh;
 #[doc(inline)]
+pub use prune::Pruned;
+#[doc(inline)]
+pub use remove::RemoveError;
+#[doc(inline)]
 pub use scene::Node;
 #[doc(inline)]
 pub use scene::Scene;
 #[doc(inline)]
 pub use skin::Skin;
 #[doc(inline)]
+pub use stats::Stats;
+#[doc(inline)]
 pub use texture::Texture;
 
 #[doc(inline)]
 pub use self::extras::Extras;
+#[cfg(feature = "unrecognized")]
+#[doc(inline)]
+pub use self::extras::Unrecognized;
 #[doc(inline)]
 pub use self::path::Path;
 #[doc(inline)]
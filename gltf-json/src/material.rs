@@ -126,8 +126,30 @@ pub struct Material {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Material {
+    alpha_cutoff,
+    alpha_mode,
+    double_sided,
+    #[cfg(feature = "names")]
+    name,
+    pbr_metallic_roughness,
+    normal_texture,
+    occlusion_texture,
+    emissive_texture,
+    emissive_factor,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -173,8 +195,25 @@ pub struct PbrMetallicRoughness {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(PbrMetallicRoughness {
+    base_color_factor,
+    base_color_texture,
+    metallic_factor,
+    roughness_factor,
+    metallic_roughness_texture,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct NormalTexture {
@@ -200,8 +239,23 @@ pub struct NormalTexture {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(NormalTexture {
+    index,
+    scale,
+    tex_coord,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 fn material_normal_texture_scale_default() -> f32 {
     1.0
 }
@@ -229,22 +283,37 @@ pub struct OcclusionTexture {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(OcclusionTexture {
+    index,
+    strength,
+    tex_coord,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// The alpha cutoff value of a material.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AlphaCutoff(pub f32);
 
 /// The emissive color of a material.
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct EmissiveFactor(pub [f32; 3]);
 
 /// The base color factor of a material.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct PbrBaseColorFactor(pub [f32; 4]);
 
 /// A number in the inclusive range [0.0, 1.0] with a default value of 1.0.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StrengthFactor(pub f32);
 
 impl Default for AlphaCutoff {
@@ -0,0 +1,217 @@
+use crate::extensions::ENABLED_EXTENSIONS;
+use crate::visit::*;
+use crate::{Index, Node, Root};
+use std::fmt;
+
+/// The error returned when a document cannot be merged into another.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// The merged document uses extensions that are not enabled, whose
+    /// references to other objects cannot be offset.
+    UnknownExtensions,
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeError::UnknownExtensions => {
+                write!(f, "document uses extensions that are not enabled")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Offsets every index of type `T` in `root` by `offset`.
+fn offset<T>(root: &mut Root, visit: Visit<T>, offset: usize) {
+    visit(root, &mut |index| {
+        *index = Index::new((index.value() + offset) as u32);
+    });
+}
+
+/// Appends the names in `other` that are missing from `names`.
+fn union(names: &mut Vec<String>, other: Vec<String>) {
+    for name in other {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+}
+
+/// Merges `other` into `root`, see [`Root::merge`] and [`Root::merge_under`].
+pub(crate) fn merge(
+    root: &mut Root,
+    mut other: Root,
+    parent: Option<Index<Node>>,
+) -> Result<(), MergeError> {
+    if let Some(parent) = parent {
+        assert!(
+            parent.value() < root.nodes.len(),
+            "parent node {} does not exist",
+            parent
+        );
+    }
+    if other
+        .extensions_used
+        .iter()
+        .any(|name| !ENABLED_EXTENSIONS.contains(&name.as_str()))
+    {
+        return Err(MergeError::UnknownExtensions);
+    }
+
+    offset(&mut other, visit_accessors, root.accessors.len());
+    offset(&mut other, visit_buffers, root.buffers.len());
+    offset(&mut other, visit_buffer_views, root.buffer_views.len());
+    offset(&mut other, visit_cameras, root.cameras.len());
+    offset(&mut other, visit_images, root.images.len());
+    offset(&mut other, visit_materials, root.materials.len());
+    offset(&mut other, visit_meshes, root.meshes.len());
+    offset(&mut other, visit_nodes, root.nodes.len());
+    offset(&mut other, visit_samplers, root.samplers.len());
+    offset(&mut other, visit_skins, root.skins.len());
+    offset(&mut other, visit_textures, root.textures.len());
+    #[cfg(feature = "KHR_lights_punctual")]
+    if let Some(mut lights) = other
+        .extensions
+        .as_mut()
+        .and_then(|x| x.khr_lights_punctual.take())
+    {
+        let extensions = root.extensions.get_or_insert_with(Default::default);
+        let existing = extensions
+            .khr_lights_punctual
+            .get_or_insert_with(Default::default);
+        offset(&mut other, visit_lights, existing.lights.len());
+        existing.lights.append(&mut lights.lights);
+    }
+    #[cfg(feature = "KHR_materials_variants")]
+    if let Some(mut variants) = other
+        .extensions
+        .as_mut()
+        .and_then(|x| x.khr_materials_variants.take())
+    {
+        let extensions = root.extensions.get_or_insert_with(Default::default);
+        let existing = extensions
+            .khr_materials_variants
+            .get_or_insert_with(Default::default);
+        offset(&mut other, visit_variants, existing.variants.len());
+        existing.variants.append(&mut variants.variants);
+    }
+
+    match parent {
+        Some(parent) => {
+            let scene = other.scene.unwrap_or_else(|| Index::new(0));
+            if let Some(scene) = other.scenes.get(scene.value()) {
+                root.nodes[parent.value()]
+                    .children
+                    .get_or_insert_with(Vec::new)
+                    .extend_from_slice(&scene.nodes);
+            }
+        }
+        None => {
+            offset(&mut other, visit_scenes, root.scenes.len());
+            root.scenes.append(&mut other.scenes);
+        }
+    }
+
+    root.accessors.append(&mut other.accessors);
+    root.animations.append(&mut other.animations);
+    root.buffers.append(&mut other.buffers);
+    root.buffer_views.append(&mut other.buffer_views);
+    root.cameras.append(&mut other.cameras);
+    root.images.append(&mut other.images);
+    root.materials.append(&mut other.materials);
+    root.meshes.append(&mut other.meshes);
+    root.nodes.append(&mut other.nodes);
+    root.samplers.append(&mut other.samplers);
+    root.skins.append(&mut other.skins);
+    root.textures.append(&mut other.textures);
+    union(&mut root.extensions_used, other.extensions_used);
+    union(&mut root.extensions_required, other.extensions_required);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MergeError;
+    use crate::{Index, Root};
+
+    const BASE: &str = r#"{
+        "asset": {"version": "2.0"},
+        "scene": 0,
+        "scenes": [{"nodes": [0]}],
+        "nodes": [{"mesh": 0}],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]}],
+        "materials": [{}],
+        "accessors": [{"bufferView": 0, "count": 1, "componentType": 5126, "type": "VEC3"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 12}],
+        "buffers": [{"byteLength": 12}],
+        "extensionsUsed": ["KHR_materials_unlit"]
+    }"#;
+
+    const PROP: &str = r#"{
+        "asset": {"version": "2.0"},
+        "scenes": [{"nodes": [0]}],
+        "nodes": [{"children": [1]}, {"mesh": 0}],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]}],
+        "materials": [{}],
+        "accessors": [{"bufferView": 0, "count": 1, "componentType": 5126, "type": "VEC3"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 12}],
+        "buffers": [{"byteLength": 12}]
+    }"#;
+
+    #[test]
+    fn merge() {
+        let mut root = Root::from_str(BASE).unwrap();
+        root.merge(Root::from_str(PROP).unwrap()).unwrap();
+        assert_eq!(root.scene, Some(Index::new(0)));
+        assert_eq!(root.scenes.len(), 2);
+        assert_eq!(root.scenes[1].nodes, [Index::new(1)]);
+        assert_eq!(root.nodes[1].children, Some(vec![Index::new(2)]));
+        assert_eq!(root.nodes[2].mesh, Some(Index::new(1)));
+        let primitive = &root.meshes[1].primitives[0];
+        assert_eq!(primitive.material, Some(Index::new(1)));
+        assert_eq!(primitive.attributes.values().next(), Some(&Index::new(1)));
+        assert_eq!(root.accessors[1].buffer_view, Some(Index::new(1)));
+        assert_eq!(root.buffer_views[1].buffer, Index::new(1));
+    }
+
+    #[cfg(all(feature = "KHR_materials_unlit", feature = "KHR_texture_transform"))]
+    #[test]
+    fn merge_extensions_used() {
+        let mut root = Root::from_str(BASE).unwrap();
+        let mut prop = Root::from_str(PROP).unwrap();
+        prop.add_extension_used("KHR_materials_unlit");
+        prop.add_extension_used("KHR_texture_transform");
+        root.merge(prop).unwrap();
+        assert_eq!(
+            root.extensions_used,
+            ["KHR_materials_unlit", "KHR_texture_transform"]
+        );
+    }
+
+    #[test]
+    fn merge_under() {
+        let mut root = Root::from_str(BASE).unwrap();
+        root.merge_under(Root::from_str(PROP).unwrap(), Index::new(0))
+            .unwrap();
+        assert_eq!(root.scenes.len(), 1);
+        assert_eq!(root.nodes.len(), 3);
+        assert_eq!(root.nodes[0].children, Some(vec![Index::new(1)]));
+        assert_eq!(root.nodes[1].children, Some(vec![Index::new(2)]));
+    }
+
+    #[test]
+    fn merge_unknown_extensions() {
+        let mut root = Root::from_str(BASE).unwrap();
+        let before = root.to_string().unwrap();
+        let mut prop = Root::from_str(PROP).unwrap();
+        prop.add_extension_used("MSFT_lod");
+        assert_eq!(root.merge(prop), Err(MergeError::UnknownExtensions));
+        assert_eq!(root.to_string().unwrap(), before);
+
+        // Unknown extensions of the document merged into are left alone.
+        root.add_extension_used("MSFT_lod");
+        root.merge(Root::from_str(PROP).unwrap()).unwrap();
+    }
+}
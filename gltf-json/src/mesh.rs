@@ -71,7 +71,7 @@ pub enum Mode {
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Mesh {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -83,6 +83,12 @@ pub struct Mesh {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
@@ -96,6 +102,51 @@ pub struct Mesh {
     pub weights: Option<Vec<f32>>,
 }
 
+impl_partial_eq!(Mesh {
+    extensions,
+    #[cfg(feature = "names")]
+    name,
+    primitives,
+    weights,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+impl Validate for Mesh {
+    fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        // Generated part
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
+        self.extras
+            .validate(root, || path().field("extras"), report);
+        self.primitives
+            .validate(root, || path().field("primitives"), report);
+        self.weights
+            .validate(root, || path().field("weights"), report);
+
+        // Custom part
+        // spec: All primitives **must** have the same number of morph targets, and
+        // `weights` **must** have one entry per morph target.
+        let target_count = |primitive: &Primitive| primitive.targets.as_ref().map_or(0, Vec::len);
+        let expected = self.primitives.first().map_or(0, target_count);
+        for (index, primitive) in self.primitives.iter().enumerate().skip(1) {
+            if target_count(primitive) != expected {
+                let path = || path().field("primitives").index(index).field("targets");
+                report(&path, Error::Invalid);
+            }
+        }
+        if let Some(ref weights) = self.weights {
+            if weights.len() != expected {
+                report(&|| path().field("weights"), Error::Invalid);
+            }
+        }
+    }
+}
+
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Primitive {
@@ -113,6 +164,12 @@ pub struct Primitive {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// The index of the accessor that contains the indices.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indices: Option<Index<accessor::Accessor>>,
@@ -132,10 +189,46 @@ pub struct Primitive {
     pub targets: Option<Vec<MorphTarget>>,
 }
 
+impl_partial_eq!(Primitive {
+    attributes,
+    extensions,
+    indices,
+    material,
+    mode,
+    targets,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 fn is_primitive_mode_default(mode: &Checked<Mode>) -> bool {
     *mode == Checked::Valid(Mode::Triangles)
 }
 
+impl Primitive {
+    /// Returns the material used by this primitive when the given
+    /// `KHR_materials_variants` variant is active.
+    ///
+    /// Falls back to the default material of the primitive when the variant is
+    /// not mapped.
+    #[cfg(feature = "KHR_materials_variants")]
+    pub fn material_for_variant(
+        &self,
+        variant: Index<extensions::scene::khr_materials_variants::Variant>,
+    ) -> Option<Index<material::Material>> {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+            .and_then(|variants| {
+                variants
+                    .mappings
+                    .iter()
+                    .find(|mapping| mapping.variants.contains(&(variant.value() as u32)))
+            })
+            .map(|mapping| Index::new(mapping.material))
+            .or(self.material)
+    }
+}
+
 impl Validate for Primitive {
     fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
     where
@@ -158,28 +251,38 @@ impl Validate for Primitive {
             .validate(root, || path().field("targets"), report);
 
         // Custom part
+        for semantic in self.attributes.keys() {
+            // spec: application-specific attribute semantics **must** start with an underscore.
+            if let Checked::Valid(Semantic::Other(ref name)) = *semantic {
+                if !name.starts_with('_') {
+                    report(&|| path().field("attributes").key(name), Error::Invalid);
+                }
+            }
+        }
+
         let position_path = &|| path().field("attributes").key("POSITION");
         if let Some(pos_accessor_index) = self.attributes.get(&Checked::Valid(Semantic::Positions))
         {
             // spec: POSITION accessor **must** have `min` and `max` properties defined.
-            let pos_accessor = &root.accessors[pos_accessor_index.value()];
-
-            let min_path = &|| position_path().field("min");
-            if let Some(ref min) = pos_accessor.min {
-                if from_value::<[f32; 3]>(min.clone()).is_err() {
-                    report(min_path, Error::Invalid);
+            // An out of bounds index has already been reported by `attributes` above.
+            if let Some(pos_accessor) = root.get(*pos_accessor_index) {
+                let min_path = &|| position_path().field("min");
+                if let Some(ref min) = pos_accessor.min {
+                    if from_value::<[f32; 3]>(min.clone()).is_err() {
+                        report(min_path, Error::Invalid);
+                    }
+                } else {
+                    report(min_path, Error::Missing);
                 }
-            } else {
-                report(min_path, Error::Missing);
-            }
 
-            let max_path = &|| position_path().field("max");
-            if let Some(ref max) = pos_accessor.max {
-                if from_value::<[f32; 3]>(max.clone()).is_err() {
-                    report(max_path, Error::Invalid);
+                let max_path = &|| position_path().field("max");
+                if let Some(ref max) = pos_accessor.max {
+                    if from_value::<[f32; 3]>(max.clone()).is_err() {
+                        report(max_path, Error::Invalid);
+                    }
+                } else {
+                    report(max_path, Error::Missing);
                 }
-            } else {
-                report(max_path, Error::Missing);
             }
         } else {
             report(position_path, Error::Missing);
@@ -188,7 +291,7 @@ impl Validate for Primitive {
 }
 
 /// A dictionary mapping attributes to their deviations in the Morph Target.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
 pub struct MorphTarget {
     /// XYZ vertex position displacements of type `[f32; 3]`.
     #[serde(rename = "POSITION")]
@@ -234,6 +337,13 @@ pub enum Semantic {
 
     /// Joint weights.
     Weights(u32),
+
+    /// An attribute name unknown to this library, such as one introduced by a
+    /// newer version of the specification or an extension, or a malformed
+    /// name. It is kept as written so that it survives a round trip, but
+    /// fails validation unless it starts with an underscore, as the
+    /// specification requires of application-specific semantics.
+    Other(String),
 }
 
 impl Default for Mode {
@@ -255,6 +365,21 @@ impl Mode {
             Mode::TriangleFan => TRIANGLE_FAN,
         }
     }
+
+    /// Returns the corresponding `wgpu` primitive topology, or `None` for line
+    /// loops and triangle fans, which `wgpu` does not support.
+    #[cfg(feature = "wgpu")]
+    pub fn to_wgpu(self) -> Option<wgpu_types::PrimitiveTopology> {
+        use wgpu_types::PrimitiveTopology;
+        match self {
+            Mode::Points => Some(PrimitiveTopology::PointList),
+            Mode::Lines => Some(PrimitiveTopology::LineList),
+            Mode::LineStrip => Some(PrimitiveTopology::LineStrip),
+            Mode::Triangles => Some(PrimitiveTopology::TriangleList),
+            Mode::TriangleStrip => Some(PrimitiveTopology::TriangleStrip),
+            Mode::LineLoop | Mode::TriangleFan => None,
+        }
+    }
 }
 
 impl<'de> de::Deserialize<'de> for Checked<Mode> {
@@ -313,21 +438,21 @@ impl Semantic {
             _ if s.starts_with('_') => Valid(Extras(s[1..].to_string())),
             _ if s.starts_with("COLOR_") => match s["COLOR_".len()..].parse() {
                 Ok(set) => Valid(Colors(set)),
-                Err(_) => Invalid,
+                Err(_) => Valid(Other(s.to_string())),
             },
             _ if s.starts_with("TEXCOORD_") => match s["TEXCOORD_".len()..].parse() {
                 Ok(set) => Valid(TexCoords(set)),
-                Err(_) => Invalid,
+                Err(_) => Valid(Other(s.to_string())),
             },
             _ if s.starts_with("JOINTS_") => match s["JOINTS_".len()..].parse() {
                 Ok(set) => Valid(Joints(set)),
-                Err(_) => Invalid,
+                Err(_) => Valid(Other(s.to_string())),
             },
             _ if s.starts_with("WEIGHTS_") => match s["WEIGHTS_".len()..].parse() {
                 Ok(set) => Valid(Weights(set)),
-                Err(_) => Invalid,
+                Err(_) => Valid(Other(s.to_string())),
             },
-            _ => Invalid,
+            _ => Valid(Other(s.to_string())),
         }
     }
 }
@@ -341,28 +466,50 @@ impl ser::Serialize for Semantic {
     }
 }
 
-impl ToString for Semantic {
-    fn to_string(&self) -> String {
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Semantic::*;
         match *self {
-            Positions => "POSITION".into(),
-            Normals => "NORMAL".into(),
-            Tangents => "TANGENT".into(),
-            Colors(set) => format!("COLOR_{}", set),
-            TexCoords(set) => format!("TEXCOORD_{}", set),
-            Joints(set) => format!("JOINTS_{}", set),
-            Weights(set) => format!("WEIGHTS_{}", set),
+            Positions => write!(f, "POSITION"),
+            Normals => write!(f, "NORMAL"),
+            Tangents => write!(f, "TANGENT"),
+            Colors(set) => write!(f, "COLOR_{}", set),
+            TexCoords(set) => write!(f, "TEXCOORD_{}", set),
+            Joints(set) => write!(f, "JOINTS_{}", set),
+            Weights(set) => write!(f, "WEIGHTS_{}", set),
             #[cfg(feature = "extras")]
-            Extras(ref name) => format!("_{}", name),
+            Extras(ref name) => write!(f, "_{}", name),
+            Other(ref name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Parses an attribute name, the inverse of formatting with `Display`.
+///
+/// Every name parses, with unknown names becoming [`Semantic::Other`].
+///
+/// ```
+/// # use gltf_json::mesh::Semantic;
+/// let semantic: Semantic = "TEXCOORD_1".parse().unwrap();
+/// assert_eq!(semantic, Semantic::TexCoords(1));
+/// assert_eq!(semantic.to_string(), "TEXCOORD_1");
+/// ```
+impl std::str::FromStr for Semantic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Semantic::checked(s) {
+            Checked::Valid(semantic) => Ok(semantic),
+            Checked::Invalid => Ok(Semantic::Other(s.to_string())),
         }
     }
 }
 
-impl ToString for Checked<Semantic> {
-    fn to_string(&self) -> String {
+impl fmt::Display for Checked<Semantic> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Checked::Valid(ref semantic) => semantic.to_string(),
-            Checked::Invalid => "<invalid semantic name>".into(),
+            Checked::Valid(ref semantic) => semantic.fmt(f),
+            Checked::Invalid => write!(f, "<invalid semantic name>"),
         }
     }
 }
@@ -74,6 +74,81 @@ impl Path {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns the path of the innermost value enclosing a position in a JSON
+    /// text, such as the `line` and `column` reported by a deserialization
+    /// error.
+    ///
+    /// Lines and columns start at 1. The text is scanned only up to the
+    /// position, so it may be malformed after it. Object members are always
+    /// added as fields.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// # use gltf_json::Path;
+    /// let json = b"{\"meshes\": [{}, {\"primitives\": [{\"mode\": \"a\"}]}]}";
+    /// let path = Path::locate(json, 1, 43);
+    /// assert_eq!("meshes[1].primitives[0].mode", path.as_str());
+    /// ```
+    pub fn locate(json: &[u8], line: usize, column: usize) -> Self {
+        let mut start = 0;
+        for _ in 1..line {
+            match json[start..].iter().position(|&byte| byte == b'\n') {
+                Some(end) => start += end + 1,
+                None => break,
+            }
+        }
+        let end = (start + column).min(json.len());
+
+        enum Frame {
+            Array(usize),
+            Object(Option<String>),
+        }
+        let mut stack = Vec::new();
+        let mut bytes = json[..end].iter().copied();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'{' => stack.push(Frame::Object(None)),
+                b'[' => stack.push(Frame::Array(0)),
+                b'}' | b']' => {
+                    stack.pop();
+                }
+                b',' => match stack.last_mut() {
+                    Some(Frame::Array(index)) => *index += 1,
+                    Some(Frame::Object(key)) => *key = None,
+                    None => {}
+                },
+                b'"' => {
+                    let mut string = Vec::new();
+                    while let Some(byte) = bytes.next() {
+                        match byte {
+                            b'"' => break,
+                            b'\\' => string.extend(bytes.next()),
+                            _ => string.push(byte),
+                        }
+                    }
+                    // The first string of an object member is its key.
+                    if let Some(Frame::Object(key @ None)) = stack.last_mut() {
+                        *key = Some(String::from_utf8_lossy(&string).into_owned());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut path = Path::new();
+        for frame in &stack {
+            path = match frame {
+                Frame::Array(index) => path.index(*index),
+                Frame::Object(Some(key)) => path.field(key),
+                Frame::Object(None) => break,
+            };
+        }
+        path
+    }
 }
 
 impl fmt::Display for Path {
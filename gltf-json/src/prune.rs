@@ -0,0 +1,345 @@
+use crate::extensions::ENABLED_EXTENSIONS;
+use crate::visit::*;
+use crate::{
+    buffer, texture, Accessor, Animation, Buffer, Camera, Image, Index, Material, Mesh, Node, Root,
+    Skin, Texture,
+};
+
+/// The objects removed by [`Root::prune`], by their index before pruning.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pruned {
+    /// The removed accessors.
+    pub accessors: Vec<Index<Accessor>>,
+
+    /// The removed animations.
+    pub animations: Vec<Index<Animation>>,
+
+    /// The removed buffers.
+    pub buffers: Vec<Index<Buffer>>,
+
+    /// The removed buffer views.
+    pub buffer_views: Vec<Index<buffer::View>>,
+
+    /// The removed cameras.
+    pub cameras: Vec<Index<Camera>>,
+
+    /// The removed images.
+    pub images: Vec<Index<Image>>,
+
+    /// The removed materials.
+    pub materials: Vec<Index<Material>>,
+
+    /// The removed meshes.
+    pub meshes: Vec<Index<Mesh>>,
+
+    /// The removed nodes.
+    pub nodes: Vec<Index<Node>>,
+
+    /// The removed samplers.
+    pub samplers: Vec<Index<texture::Sampler>>,
+
+    /// The removed skins.
+    pub skins: Vec<Index<Skin>>,
+
+    /// The removed textures.
+    pub textures: Vec<Index<Texture>>,
+}
+
+impl Pruned {
+    /// Returns the total number of objects removed.
+    pub fn len(&self) -> usize {
+        self.accessors.len()
+            + self.animations.len()
+            + self.buffers.len()
+            + self.buffer_views.len()
+            + self.cameras.len()
+            + self.images.len()
+            + self.materials.len()
+            + self.meshes.len()
+            + self.nodes.len()
+            + self.samplers.len()
+            + self.skins.len()
+            + self.textures.len()
+    }
+
+    /// Returns `true` if no objects were removed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Removes unused objects, see [`Root::prune`].
+pub(crate) fn prune(root: &mut Root) -> Pruned {
+    // References made by extensions that are not enabled cannot be followed.
+    if root
+        .extensions_used
+        .iter()
+        .any(|name| !ENABLED_EXTENSIONS.contains(&name.as_str()))
+    {
+        return Pruned::default();
+    }
+
+    let mut pruned = Pruned::default();
+    // Objects are removed in order, such that the objects referring to each
+    // type have already been pruned. Without scenes nothing is reachable, so
+    // such documents keep their nodes and everything the nodes use.
+    if !root.scenes.is_empty() {
+        let reachable = reachable_nodes(root);
+        pruned.animations = prune_animations(root, &reachable);
+        pruned.nodes = retain(root, visit_nodes, reachable);
+        pruned.meshes = retain_used(root, visit_meshes);
+        pruned.cameras = retain_used(root, visit_cameras);
+        pruned.skins = retain_used(root, visit_skins);
+    }
+    pruned.materials = retain_used(root, visit_materials);
+    pruned.textures = retain_used(root, visit_textures);
+    pruned.samplers = retain_used(root, visit_samplers);
+    pruned.images = retain_used(root, visit_images);
+    pruned.accessors = retain_used(root, visit_accessors);
+    pruned.buffer_views = retain_used(root, visit_buffer_views);
+    pruned.buffers = retain_used(root, visit_buffers);
+    pruned
+}
+
+/// Returns whether each node is reachable from the root nodes of a scene,
+/// through the children of nodes and the joints and skeletons of their skins.
+fn reachable_nodes(root: &Root) -> Vec<bool> {
+    let mut reachable = vec![false; root.nodes.len()];
+    let mut pending: Vec<Index<Node>> = root
+        .scenes
+        .iter()
+        .flat_map(|scene| scene.nodes.iter().copied())
+        .collect();
+    while let Some(index) = pending.pop() {
+        match reachable.get_mut(index.value()) {
+            Some(seen @ false) => *seen = true,
+            _ => continue,
+        }
+        let node = &root.nodes[index.value()];
+        pending.extend(node.children.iter().flatten().copied());
+        if let Some(skin) = node.skin.and_then(|skin| root.skins.get(skin.value())) {
+            pending.extend(skin.joints.iter().copied());
+            pending.extend(skin.skeleton);
+        }
+    }
+    reachable
+}
+
+/// Removes the animation channels targeting nodes that are not reachable,
+/// the samplers only they used, and the animations left without channels.
+/// Returns the removed animation indices.
+fn prune_animations(root: &mut Root, reachable: &[bool]) -> Vec<Index<Animation>> {
+    let mut removed = Vec::new();
+    let mut index = 0;
+    root.animations.retain_mut(|animation| {
+        animation
+            .channels
+            .retain(|channel| reachable.get(channel.target.node.value()) == Some(&true));
+        let mut used = vec![false; animation.samplers.len()];
+        for channel in &animation.channels {
+            if let Some(used) = used.get_mut(channel.sampler.value()) {
+                *used = true;
+            }
+        }
+        let mut remap = Vec::with_capacity(used.len());
+        let mut next = 0;
+        for &used in &used {
+            remap.push(next);
+            next += used as u32;
+        }
+        let mut used = used.into_iter();
+        animation.samplers.retain(|_| used.next().unwrap());
+        for channel in &mut animation.channels {
+            if let Some(&new) = remap.get(channel.sampler.value()) {
+                channel.sampler = Index::new(new);
+            }
+        }
+
+        let keep = !animation.channels.is_empty();
+        if !keep {
+            removed.push(Index::new(index));
+        }
+        index += 1;
+        keep
+    });
+    removed
+}
+
+/// Removes the objects of type `T` that `visit` does not find a reference
+/// to, updating the remaining references. Returns the removed indices.
+fn retain_used<T>(root: &mut Root, visit: Visit<T>) -> Vec<Index<T>>
+where
+    Root: AsMut<Vec<T>>,
+{
+    let count = AsMut::<Vec<T>>::as_mut(root).len();
+    let mut used = vec![false; count];
+    visit(root, &mut |index| {
+        if let Some(used) = used.get_mut(index.value()) {
+            *used = true;
+        }
+    });
+    retain(root, visit, used)
+}
+
+/// Removes the objects of type `T` that are not `used`, updating the
+/// references that `visit` finds. Returns the removed indices.
+fn retain<T>(root: &mut Root, visit: Visit<T>, used: Vec<bool>) -> Vec<Index<T>>
+where
+    Root: AsMut<Vec<T>>,
+{
+    let count = used.len();
+    if used.iter().all(|&used| used) {
+        return Vec::new();
+    }
+
+    let mut removed = Vec::new();
+    let mut remap = Vec::with_capacity(count);
+    let mut next = 0;
+    for (i, &used) in used.iter().enumerate() {
+        remap.push(next);
+        if used {
+            next += 1;
+        } else {
+            removed.push(Index::new(i as u32));
+        }
+    }
+    let mut used = used.into_iter();
+    AsMut::<Vec<T>>::as_mut(root).retain(|_| used.next().unwrap());
+    visit(root, &mut |index| {
+        if let Some(&new) = remap.get(index.value()) {
+            *index = Index::new(new);
+        }
+    });
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Index, Root};
+
+    const JSON: &str = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"count": 3, "componentType": 5126, "type": "VEC3"},
+            {"bufferView": 1, "count": 3, "componentType": 5126, "type": "VEC3"},
+            {"bufferView": 2, "count": 3, "componentType": 5126, "type": "VEC3"}
+        ],
+        "buffers": [{"byteLength": 36}, {"byteLength": 108}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 36},
+            {"buffer": 1, "byteLength": 36},
+            {"buffer": 1, "byteOffset": 36, "byteLength": 36},
+            {"buffer": 1, "byteOffset": 72, "byteLength": 36}
+        ],
+        "images": [{"uri": "unused.png"}, {"bufferView": 3, "mimeType": "image/png"}],
+        "samplers": [{}, {}],
+        "textures": [{"source": 0, "sampler": 0}, {"source": 1, "sampler": 1}],
+        "materials": [
+            {"emissiveTexture": {"index": 0}},
+            {"normalTexture": {"index": 1}}
+        ],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 2}, "material": 1}]}]
+    }"#;
+
+    #[test]
+    fn prune() {
+        let mut root = Root::from_str(JSON).unwrap();
+        let pruned = root.prune();
+        assert_eq!(pruned.accessors, [Index::new(0), Index::new(1)]);
+        assert_eq!(pruned.buffer_views, [Index::new(0), Index::new(1)]);
+        assert_eq!(pruned.buffers, [Index::new(0)]);
+        assert_eq!(pruned.images, [Index::new(0)]);
+        assert_eq!(pruned.materials, [Index::new(0)]);
+        assert_eq!(pruned.samplers, [Index::new(0)]);
+        assert_eq!(pruned.textures, [Index::new(0)]);
+        assert_eq!(pruned.len(), 9);
+
+        let primitive = &root.meshes[0].primitives[0];
+        assert_eq!(primitive.material, Some(Index::new(0)));
+        let positions = primitive.attributes.values().next().unwrap();
+        assert_eq!(*positions, Index::new(0));
+        assert_eq!(root.accessors[0].buffer_view, Some(Index::new(0)));
+        assert_eq!(root.buffer_views.len(), 2);
+        assert_eq!(root.buffer_views[0].buffer, Index::new(0));
+        assert_eq!(root.buffer_views[0].byte_offset, Some(36));
+        assert_eq!(root.images[0].buffer_view, Some(Index::new(1)));
+        assert_eq!(root.textures[0].source, Some(Index::new(0)));
+        assert_eq!(root.textures[0].sampler, Some(Index::new(0)));
+        let normal_texture = root.materials[0].normal_texture.as_ref().unwrap();
+        assert_eq!(normal_texture.index, Index::new(0));
+
+        // A pruned document has nothing left to remove.
+        assert!(root.prune().is_empty());
+    }
+
+    #[test]
+    fn prune_unreachable() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0]}],
+            "nodes": [
+                {"children": [1]},
+                {"mesh": 0, "skin": 0},
+                {"mesh": 1, "camera": 0},
+                {},
+                {"skin": 1}
+            ],
+            "skins": [{"joints": [3]}, {"joints": [2]}],
+            "cameras": [{"type": "perspective", "perspective": {"yfov": 1.0, "znear": 0.1}}],
+            "meshes": [
+                {"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]},
+                {"primitives": [{"attributes": {"POSITION": 1}, "material": 1}]}
+            ],
+            "materials": [{}, {}],
+            "accessors": [
+                {"count": 3, "componentType": 5126, "type": "VEC3"},
+                {"count": 3, "componentType": 5126, "type": "VEC3"},
+                {"count": 2, "componentType": 5126, "type": "SCALAR"},
+                {"count": 2, "componentType": 5126, "type": "VEC3"}
+            ],
+            "animations": [
+                {
+                    "samplers": [{"input": 2, "output": 3}, {"input": 2, "output": 3}],
+                    "channels": [
+                        {"sampler": 1, "target": {"node": 2, "path": "translation"}},
+                        {"sampler": 0, "target": {"node": 3, "path": "translation"}}
+                    ]
+                },
+                {
+                    "samplers": [{"input": 2, "output": 3}],
+                    "channels": [{"sampler": 0, "target": {"node": 2, "path": "scale"}}]
+                }
+            ]
+        }"#;
+        let mut root = Root::from_str(json).unwrap();
+        let pruned = root.prune();
+        assert_eq!(pruned.nodes, [Index::new(2), Index::new(4)]);
+        assert_eq!(pruned.animations, [Index::new(1)]);
+        assert_eq!(pruned.meshes, [Index::new(1)]);
+        assert_eq!(pruned.cameras, [Index::new(0)]);
+        assert_eq!(pruned.skins, [Index::new(1)]);
+        assert_eq!(pruned.materials, [Index::new(1)]);
+        assert_eq!(pruned.accessors, [Index::new(1)]);
+        assert_eq!(pruned.len(), 8);
+
+        // The joint outside the node hierarchy is reachable through its skin.
+        assert_eq!(root.nodes.len(), 3);
+        assert_eq!(root.nodes[0].children, Some(vec![Index::new(1)]));
+        assert_eq!(root.skins[0].joints, [Index::new(2)]);
+        let animation = &root.animations[0];
+        assert_eq!(animation.samplers.len(), 1);
+        assert_eq!(animation.channels.len(), 1);
+        assert_eq!(animation.channels[0].sampler, Index::new(0));
+        assert_eq!(animation.channels[0].target.node, Index::new(2));
+        assert_eq!(animation.samplers[0].input, Index::new(1));
+        assert!(root.prune().is_empty());
+    }
+
+    #[test]
+    fn unknown_extensions() {
+        let mut root = Root::from_str(JSON).unwrap();
+        root.add_extension_used("EXT_unknown");
+        assert!(root.prune().is_empty());
+        assert_eq!(root.accessors.len(), 3);
+    }
+}
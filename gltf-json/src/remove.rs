@@ -0,0 +1,170 @@
+use crate::extensions::ENABLED_EXTENSIONS;
+use crate::visit::*;
+use crate::{
+    buffer, texture, Accessor, Animation, Buffer, Camera, Image, Index, Material, Mesh, Node, Root,
+    Scene, Skin, Texture,
+};
+use std::fmt;
+
+/// The error returned when an object cannot be removed from a document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RemoveError {
+    /// The object is still referred to, the given number of times, by other
+    /// objects of the document.
+    Referenced(usize),
+
+    /// The document uses extensions that are not enabled, whose references
+    /// to other objects cannot be followed.
+    UnknownExtensions,
+}
+
+impl fmt::Display for RemoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RemoveError::Referenced(count) => {
+                write!(f, "object is still referred to {} time(s)", count)
+            }
+            RemoveError::UnknownExtensions => {
+                write!(f, "document uses extensions that are not enabled")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RemoveError {}
+
+/// Removes the object of type `T` at `index`, decrementing the references
+/// that `visit` finds to the objects after it.
+fn remove<T>(root: &mut Root, index: Index<T>, visit: Visit<T>) -> Result<T, RemoveError>
+where
+    Root: AsMut<Vec<T>>,
+{
+    if root
+        .extensions_used
+        .iter()
+        .any(|name| !ENABLED_EXTENSIONS.contains(&name.as_str()))
+    {
+        return Err(RemoveError::UnknownExtensions);
+    }
+    let count = AsMut::<Vec<T>>::as_mut(root).len();
+    assert!(index.value() < count, "index out of bounds");
+
+    let mut references = 0;
+    visit(root, &mut |other| {
+        if *other == index {
+            references += 1;
+        }
+    });
+    if references > 0 {
+        return Err(RemoveError::Referenced(references));
+    }
+
+    let removed = AsMut::<Vec<T>>::as_mut(root).remove(index.value());
+    visit(root, &mut |other| {
+        if other.value() > index.value() {
+            *other = Index::new(other.value() as u32 - 1);
+        }
+    });
+    Ok(removed)
+}
+
+/// Adds `Root` methods that remove one object of a type.
+macro_rules! impl_remove {
+    ($($ty:ty => $method:ident, $visit:expr),*) => {
+        impl Root {
+            $(
+                #[doc = concat!("Removes the `", stringify!($ty), "` at `index` and returns it, decrementing every index to a later `", stringify!($ty), "` to match.")]
+                ///
+                /// Fails, leaving the document unchanged, if any other object
+                /// still refers to it, or if the document uses extensions that
+                /// are not enabled, because their references cannot be
+                /// followed.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `index` is out of bounds.
+                pub fn $method(&mut self, index: Index<$ty>) -> Result<$ty, RemoveError> {
+                    remove(self, index, $visit)
+                }
+            )*
+        }
+    };
+}
+
+impl_remove!(
+    Accessor => remove_accessor, visit_accessors,
+    Animation => remove_animation, |_, _| {},
+    Buffer => remove_buffer, visit_buffers,
+    buffer::View => remove_buffer_view, visit_buffer_views,
+    Camera => remove_camera, visit_cameras,
+    Image => remove_image, visit_images,
+    Material => remove_material, visit_materials,
+    Mesh => remove_mesh, visit_meshes,
+    Node => remove_node, visit_nodes,
+    texture::Sampler => remove_sampler, visit_samplers,
+    Scene => remove_scene, visit_scenes,
+    Skin => remove_skin, visit_skins,
+    Texture => remove_texture, visit_textures
+);
+
+#[cfg(test)]
+mod tests {
+    use super::RemoveError;
+    use crate::{Index, Root};
+
+    const JSON: &str = r#"{
+        "asset": {"version": "2.0"},
+        "scene": 1,
+        "scenes": [{"nodes": [0]}, {"nodes": [2]}],
+        "nodes": [{"children": [1]}, {"mesh": 1}, {"children": [3]}, {"mesh": 1}],
+        "meshes": [
+            {"primitives": [{"attributes": {}, "material": 0}]},
+            {"primitives": [{"attributes": {}, "material": 1}]}
+        ],
+        "materials": [{}, {}]
+    }"#;
+
+    #[test]
+    fn remove_updates_indices() {
+        let mut root = Root::from_str(JSON).unwrap();
+        root.remove_mesh(Index::new(0)).unwrap();
+        assert_eq!(root.meshes.len(), 1);
+        assert_eq!(root.nodes[1].mesh, Some(Index::new(0)));
+        assert_eq!(root.nodes[3].mesh, Some(Index::new(0)));
+
+        root.remove_material(Index::new(0)).unwrap();
+        let primitive = &root.meshes[0].primitives[0];
+        assert_eq!(primitive.material, Some(Index::new(0)));
+
+        root.scenes[0].nodes.clear();
+        root.nodes[0].children = None;
+        root.remove_node(Index::new(0)).unwrap();
+        root.remove_node(Index::new(0)).unwrap();
+        assert_eq!(root.scenes[1].nodes, [Index::new(0)]);
+        assert_eq!(root.nodes[0].children, Some(vec![Index::new(1)]));
+
+        root.remove_scene(Index::new(0)).unwrap();
+        assert_eq!(root.scene, Some(Index::new(0)));
+    }
+
+    #[test]
+    fn remove_referenced() {
+        let mut root = Root::from_str(JSON).unwrap();
+        let before = root.clone();
+        assert_eq!(
+            root.remove_mesh(Index::new(1)),
+            Err(RemoveError::Referenced(2))
+        );
+        assert_eq!(
+            root.remove_scene(Index::new(1)),
+            Err(RemoveError::Referenced(1))
+        );
+        assert_eq!(root, before);
+
+        root.add_extension_used("EXT_unknown");
+        assert_eq!(
+            root.remove_mesh(Index::new(0)),
+            Err(RemoveError::UnknownExtensions)
+        );
+    }
+}
@@ -2,8 +2,10 @@ use crate::buffer;
 use crate::extensions;
 use crate::texture;
 use crate::validation;
+use crate::ExportOptions;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::{self, fmt, io, marker};
 
 use crate::path::Path;
@@ -19,11 +21,18 @@ pub trait Get<T> {
     fn get(&self, id: Index<T>) -> Option<&T>;
 }
 
+/// Helper trait for retrieving mutable top-level objects by a universal identifier.
+pub trait GetMut<T> {
+    /// Retrieves a single mutable value at the given index.
+    fn get_mut(&mut self, id: Index<T>) -> Option<&mut T>;
+}
+
 /// Represents an offset into an array of type `T` owned by the root glTF object.
 pub struct Index<T>(u32, marker::PhantomData<fn() -> T>);
 
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[gltf(validate_hook = "root_validate_hook")]
 pub struct Root {
     /// An array of accessors.
     #[serde(default)]
@@ -62,6 +71,12 @@ pub struct Root {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// Names of glTF extensions used somewhere in this asset.
     #[serde(default, rename = "extensionsUsed")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -118,7 +133,41 @@ pub struct Root {
     pub textures: Vec<Texture>,
 }
 
+impl_partial_eq!(Root {
+    accessors,
+    animations,
+    asset,
+    buffers,
+    buffer_views,
+    scene,
+    extensions,
+    extensions_used,
+    extensions_required,
+    cameras,
+    images,
+    materials,
+    meshes,
+    nodes,
+    samplers,
+    scenes,
+    skins,
+    textures,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 impl Root {
+    /// Constructs an empty document with the given asset metadata.
+    ///
+    /// [`Root::default`] constructs an empty document with the default
+    /// [`Asset`], which targets glTF 2.0 without naming a generator.
+    pub fn new(asset: Asset) -> Self {
+        Self {
+            asset,
+            ..Default::default()
+        }
+    }
+
     /// Returns a single item from the root object.
     pub fn get<T>(&self, index: Index<T>) -> Option<&T>
     where
@@ -127,7 +176,296 @@ impl Root {
         (self as &dyn Get<T>).get(index)
     }
 
+    /// Returns a single mutable item from the root object.
+    pub fn get_mut<T>(&mut self, index: Index<T>) -> Option<&mut T>
+    where
+        Self: GetMut<T>,
+    {
+        (self as &mut dyn GetMut<T>).get_mut(index)
+    }
+
+    /// Returns the items of the corresponding array of the root object that
+    /// match `predicate`, together with their indices.
+    ///
+    /// ```
+    /// # use gltf_json as json;
+    /// let root: json::Root = json::deserialize::from_str(
+    ///     r#"{"asset": {"version": "2.0"}, "nodes": [{}, {"camera": 0}, {"camera": 1}]}"#,
+    /// )
+    /// .unwrap();
+    /// let cameras: Vec<json::Index<json::Node>> = root
+    ///     .find_all(|node: &json::Node| node.camera.is_some())
+    ///     .map(|(index, _)| index)
+    ///     .collect();
+    /// assert_eq!(cameras, [json::Index::new(1), json::Index::new(2)]);
+    /// ```
+    pub fn find_all<'a, T: 'a, P>(
+        &'a self,
+        mut predicate: P,
+    ) -> impl Iterator<Item = (Index<T>, &'a T)>
+    where
+        Self: AsRef<[T]>,
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().filter(move |(_, item)| predicate(item))
+    }
+
+    /// Returns the items of the corresponding array of the root object
+    /// together with their indices.
+    ///
+    /// ```
+    /// # use gltf_json as json;
+    /// let root: json::Root = json::deserialize::from_str(
+    ///     r#"{"asset": {"version": "2.0"}, "nodes": [{}, {"children": [0]}]}"#,
+    /// )
+    /// .unwrap();
+    /// let parents: Vec<(json::Index<json::Node>, json::Index<json::Node>)> = root
+    ///     .iter_nodes()
+    ///     .flat_map(|(parent, node)| node.children.iter().flatten().map(move |&child| (child, parent)))
+    ///     .collect();
+    /// assert_eq!(parents, [(json::Index::new(0), json::Index::new(1))]);
+    /// assert_eq!(root.iter::<json::Node>().count(), 2);
+    /// ```
+    pub fn iter<'a, T: 'a>(&'a self) -> impl Iterator<Item = (Index<T>, &'a T)>
+    where
+        Self: AsRef<[T]>,
+    {
+        self.as_ref()
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (Index::new(i as u32), item))
+    }
+
+    /// Appends an item to the corresponding array of the root object and returns
+    /// its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array already contains `u32::MAX` items.
+    pub fn push<T>(&mut self, value: T) -> Index<T>
+    where
+        Self: AsMut<Vec<T>>,
+    {
+        Index::push(self.as_mut(), value)
+    }
+
+    /// Returns the first item of the corresponding array of the root object
+    /// with the given name, together with its index.
+    ///
+    /// ```
+    /// # use gltf_json as json;
+    /// let root: json::Root = json::deserialize::from_str(
+    ///     r#"{"asset": {"version": "2.0"}, "nodes": [{"name": "Root"}, {"name": "Hips"}]}"#,
+    /// )
+    /// .unwrap();
+    /// let (index, _) = root.find_by_name::<json::Node>("Hips").unwrap();
+    /// assert_eq!(index, json::Index::new(1));
+    /// assert_eq!(root.node_named("Hips").map(|(index, _)| index), Some(index));
+    /// assert!(root.mesh_named("Hips").is_none());
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn find_by_name<T: Named>(&self, name: &str) -> Option<(Index<T>, &T)>
+    where
+        Self: AsRef<[T]>,
+    {
+        self.find_all(|item: &T| item.name() == Some(name)).next()
+    }
+
+    /// Adds an extension name to `extensionsUsed` unless it is already listed.
+    ///
+    /// Exporters attaching extension data, such as `KHR_materials_unlit`, should
+    /// call this so that readers know the extension is present.
+    pub fn add_extension_used(&mut self, name: &str) {
+        if !self.extensions_used.iter().any(|used| used == name) {
+            self.extensions_used.push(name.to_owned());
+        }
+    }
+
+    /// Adds an extension name to both `extensionsRequired` and
+    /// `extensionsUsed` unless it is already listed, for extensions that
+    /// readers must support to load the asset correctly.
+    pub fn add_extension_required(&mut self, name: &str) {
+        self.add_extension_used(name);
+        if !self
+            .extensions_required
+            .iter()
+            .any(|required| required == name)
+        {
+            self.extensions_required.push(name.to_owned());
+        }
+    }
+
+    /// Returns the names of the extensions with data attached to any object
+    /// of the document, in alphabetical order.
+    ///
+    /// Both typed extension data and, with the `extensions` feature, data of
+    /// other extensions are found. Extensions without data of their own,
+    /// which are only listed in `extensionsUsed`, are not.
+    pub fn extensions_present(&self) -> Result<BTreeSet<String>, Error> {
+        fn visit(value: &Value, names: &mut BTreeSet<String>) {
+            match value {
+                Value::Object(object) => {
+                    for (key, value) in object {
+                        match (key.as_str(), value) {
+                            // Application specific data is not part of the document.
+                            ("extras", _) => {}
+                            ("extensions", Value::Object(extensions)) => {
+                                names.extend(extensions.keys().cloned());
+                                extensions.values().for_each(|x| visit(x, names));
+                            }
+                            _ => visit(value, names),
+                        }
+                    }
+                }
+                Value::Array(array) => array.iter().for_each(|x| visit(x, names)),
+                _ => {}
+            }
+        }
+        let mut names = BTreeSet::new();
+        visit(&serde_json::to_value(self)?, &mut names);
+        Ok(names)
+    }
+
+    /// Brings `extensionsUsed` and `extensionsRequired` up to date with the
+    /// extension data attached to the document.
+    ///
+    /// Extensions with data present are added to `extensionsUsed`. Typed
+    /// extensions enabled by a feature with no data present are removed from
+    /// both lists. Other names are kept, since their data may have been
+    /// dropped when deserializing or they may have no data of their own.
+    ///
+    /// This is applied when writing with
+    /// [`ExportOptions::update_extensions_used`] set, as it is by default.
+    /// The plain `to_*` methods write the lists as they are, since finding
+    /// the extension data walks the whole document; call this before using
+    /// them to write a document whose extensions were attached or removed.
+    pub fn update_extensions_used(&mut self) -> Result<(), Error> {
+        let (used, required) = self.updated_extension_lists()?;
+        self.extensions_used = used;
+        self.extensions_required = required;
+        Ok(())
+    }
+
+    /// Returns `extensionsUsed` and `extensionsRequired` as updated by
+    /// [`Root::update_extensions_used`].
+    fn updated_extension_lists(&self) -> Result<(Vec<String>, Vec<String>), Error> {
+        let present = self.extensions_present()?;
+        // `KHR_mesh_quantization` only relaxes the attribute component types
+        // and has no data of its own to look for.
+        let stale = |name: &String| {
+            extensions::ENABLED_EXTENSIONS.contains(&name.as_str())
+                && name != "KHR_mesh_quantization"
+                && !present.contains(name)
+        };
+        let mut used = self.extensions_used.clone();
+        let mut required = self.extensions_required.clone();
+        used.retain(|name| !stale(name));
+        required.retain(|name| !stale(name));
+        for name in present {
+            if !used.contains(&name) {
+                used.push(name);
+            }
+        }
+        Ok((used, required))
+    }
+
+    /// Appends every object of another document to this one, offsetting the
+    /// indices of `other` to match, and adds its scenes as additional scenes.
+    ///
+    /// The indices of objects from `other` are increased by the number of
+    /// objects of the same type in `self` before merging. The asset, default
+    /// scene and extras of `self` are kept, and extensions used or required
+    /// by either document are combined. Buffers are appended as they are, so
+    /// a buffer without a URI from each document refers to a different GLB
+    /// binary chunk; use `gltf::edit::Model::merge` to merge buffer data too.
+    ///
+    /// Fails, leaving the document unchanged, if `other` uses extensions
+    /// that are not enabled, because their references to other objects
+    /// cannot be offset.
+    pub fn merge(&mut self, other: Root) -> Result<(), crate::MergeError> {
+        crate::merge::merge(self, other, None)
+    }
+
+    /// Appends every object of another document to this one like
+    /// [`Root::merge`], but instead of adding its scenes, makes the root
+    /// nodes of its default scene, or of its first scene if it has no
+    /// default, children of `parent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is not a node of this document.
+    pub fn merge_under(
+        &mut self,
+        other: Root,
+        parent: Index<Node>,
+    ) -> Result<(), crate::MergeError> {
+        crate::merge::merge(self, other, Some(parent))
+    }
+
+    /// Returns the parent of every node, indexed by node, or `None` for root
+    /// nodes.
+    ///
+    /// Building the map once makes looking up parents, for example to walk
+    /// from a joint to the root of its skeleton, a constant time operation.
+    /// Children out of range are ignored, and a node listed as the child of
+    /// several nodes, which is invalid, is given the first of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gltf_json::{Index, Root};
+    /// let json = r#"{"asset": {"version": "2.0"}, "nodes": [{"children": [2]}, {}, {"children": [1]}]}"#;
+    /// let root = Root::from_str(json).unwrap();
+    /// let parents = root.build_parent_map();
+    /// assert_eq!(parents, [None, Some(Index::new(2)), Some(Index::new(0))]);
+    /// ```
+    pub fn build_parent_map(&self) -> Vec<Option<Index<Node>>> {
+        let mut parents = vec![None; self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            for child in node.children.iter().flatten() {
+                if let Some(parent @ None) = parents.get_mut(child.value()) {
+                    *parent = Some(Index::new(i as u32));
+                }
+            }
+        }
+        parents
+    }
+
+    /// Removes the objects that no scene reaches, and updates every index to
+    /// match.
+    ///
+    /// Nodes are reached from the root nodes of each scene, through the
+    /// children of nodes and the joints and skeletons of their skins. Other
+    /// nodes are removed, along with the animation channels targeting them,
+    /// the animation samplers only those channels used, and animations left
+    /// without channels. Then the meshes, cameras, skins, materials, textures,
+    /// samplers, images, accessors, buffer views and buffers that nothing
+    /// remaining uses are removed.
+    ///
+    /// Scenes are always kept. Documents without scenes keep their nodes,
+    /// meshes, cameras, skins and animations, along with everything they use.
+    /// Documents using extensions that are not enabled are left unchanged,
+    /// because their references to other objects cannot be followed.
+    ///
+    /// Returns the indices, before pruning, of the removed objects. The
+    /// contents of removed buffers should be discarded by the caller.
+    pub fn prune(&mut self) -> crate::Pruned {
+        crate::prune::prune(self)
+    }
+
+    /// Returns summary statistics of the document, such as object counts,
+    /// vertex and triangle totals and buffer sizes.
+    pub fn stats(&self) -> crate::Stats {
+        crate::Stats::from_root(self)
+    }
+
     /// Deserialize from a JSON string slice.
+    ///
+    /// Like every deserializer in this crate, properties unknown to the
+    /// specification and to the enabled extensions are not rejected. They are
+    /// ignored, unless the `unrecognized` feature is enabled, in which case
+    /// they are kept in the `unrecognized` field of their object and written
+    /// back when serializing.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(str_: &str) -> Result<Self, Error> {
         serde_json::from_str(str_)
@@ -146,6 +484,30 @@ impl Root {
         serde_json::from_reader(reader)
     }
 
+    /// Deserialize from a stream of JSON, materializing only the top-level
+    /// properties accepted by `filter`.
+    ///
+    /// `filter` receives the JSON name of each top-level property, for example
+    /// `"animations"` or `"bufferViews"`. Rejected properties are parsed
+    /// without being stored and are left at their default values. References
+    /// into a skipped array are kept as-is, so the result may not pass
+    /// validation.
+    ///
+    /// Like [`Root::from_reader`], top-level arrays are read element by
+    /// element without buffering the whole document; wrap unbuffered readers
+    /// in an `io::BufReader`.
+    pub fn from_reader_filtered<R, F>(reader: R, filter: F) -> Result<Self, Error>
+    where
+        R: io::Read,
+        F: Fn(&str) -> bool,
+    {
+        use serde::de::DeserializeSeed;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let root = FilteredRoot(filter).deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(root)
+    }
+
     /// Serialize as a `String` of JSON.
     pub fn to_string(&self) -> Result<String, Error> {
         serde_json::to_string(self)
@@ -171,7 +533,7 @@ impl Root {
         serde_json::to_vec_pretty(self)
     }
 
-    /// Serialize as a JSON byte writertor.
+    /// Serialize as a JSON byte writer.
     pub fn to_writer<W>(&self, writer: W) -> Result<(), Error>
     where
         W: io::Write,
@@ -179,13 +541,325 @@ impl Root {
         serde_json::to_writer(writer, self)
     }
 
-    /// Serialize as a pretty-printed JSON byte writertor.
+    /// Serialize as a pretty-printed JSON byte writer.
     pub fn to_writer_pretty<W>(&self, writer: W) -> Result<(), Error>
     where
         W: io::Write,
     {
         serde_json::to_writer_pretty(writer, self)
     }
+
+    /// Serialize as JSON to a byte writer, formatted according to `options`.
+    pub fn to_writer_with_options<W>(&self, writer: W, options: &ExportOptions) -> Result<(), Error>
+    where
+        W: io::Write,
+    {
+        if options.update_extensions_used {
+            let (used, required) = self.updated_extension_lists()?;
+            if used != self.extensions_used || required != self.extensions_required {
+                let root = Root {
+                    extensions_used: used,
+                    extensions_required: required,
+                    ..self.clone()
+                };
+                return root.write_with_options(writer, options);
+            }
+        }
+        self.write_with_options(writer, options)
+    }
+
+    /// Serializes the document as it is, formatted according to `options`.
+    fn write_with_options<W>(&self, writer: W, options: &ExportOptions) -> Result<(), Error>
+    where
+        W: io::Write,
+    {
+        let mut serializer = serde_json::Serializer::with_formatter(writer, options.formatter());
+        if options.canonical {
+            let value = serde_json::to_value(self)?;
+            serde::Serialize::serialize(&crate::canonical::Sorted(&value), &mut serializer)
+        } else {
+            serde::Serialize::serialize(self, &mut serializer)
+        }
+    }
+
+    /// Serialize as a JSON byte vector, formatted according to `options`.
+    pub fn to_vec_with_options(&self, options: &ExportOptions) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        self.to_writer_with_options(&mut bytes, options)?;
+        Ok(bytes)
+    }
+
+    /// Serialize as a `String` of JSON, formatted according to `options`.
+    pub fn to_string_with_options(&self, options: &ExportOptions) -> Result<String, Error> {
+        let bytes = self.to_vec_with_options(options)?;
+        Ok(String::from_utf8(bytes).expect("serde_json writes UTF-8"))
+    }
+
+    /// Serialize as canonical JSON to a byte writer.
+    ///
+    /// The document is written as it is, with the formatting described by
+    /// [`ExportOptions::canonical`].
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf_json::Error> {
+    /// let root: gltf_json::Root = gltf_json::deserialize::from_str(
+    ///     r#"{ "nodes": [{ "translation": [0.1, 0, 0] }], "asset": { "version": "2.0" } }"#,
+    /// )?;
+    /// assert_eq!(
+    ///     root.to_string_canonical()?,
+    ///     r#"{"asset":{"version":"2.0"},"nodes":[{"translation":[0.1,0.0,0.0]}]}"#,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn to_writer_canonical<W>(&self, writer: W) -> Result<(), Error>
+    where
+        W: io::Write,
+    {
+        let options = ExportOptions {
+            canonical: true,
+            update_extensions_used: false,
+            ..Default::default()
+        };
+        self.to_writer_with_options(writer, &options)
+    }
+
+    /// Serialize as a canonical JSON byte vector.
+    ///
+    /// See [`to_writer_canonical`](Self::to_writer_canonical).
+    pub fn to_vec_canonical(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        self.to_writer_canonical(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Serialize as a canonical `String` of JSON.
+    ///
+    /// See [`to_writer_canonical`](Self::to_writer_canonical).
+    pub fn to_string_canonical(&self) -> Result<String, Error> {
+        let bytes = self.to_vec_canonical()?;
+        Ok(String::from_utf8(bytes).expect("serde_json writes UTF-8"))
+    }
+}
+
+/// Validates the constraints between top-level objects.
+fn root_validate_hook<P, R>(root: &Root, _also_root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, validation::Error),
+{
+    validate_accessor_views(root, &path, report);
+    validate_node_trees(root, &path, report);
+}
+
+/// Checks that every accessor has a buffer view, unless it is sparse or
+/// decoded from Draco compressed data.
+fn validate_accessor_views<P, R>(root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, validation::Error),
+{
+    #[cfg_attr(not(feature = "KHR_draco_mesh_compression"), allow(unused_mut))]
+    let mut decoded = vec![false; root.accessors.len()];
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        let compressed = primitive
+            .extensions
+            .as_ref()
+            .map_or(false, |x| x.khr_draco_mesh_compression.is_some());
+        if compressed {
+            for index in primitive
+                .indices
+                .iter()
+                .chain(primitive.attributes.values())
+            {
+                if let Some(decoded) = decoded.get_mut(index.value()) {
+                    *decoded = true;
+                }
+            }
+        }
+    }
+    for (i, accessor) in root.accessors.iter().enumerate() {
+        if accessor.sparse.is_none() && accessor.buffer_view.is_none() && !decoded[i] {
+            // If sparse is missing, then bufferView must be present. Report that bufferView is
+            // missing since it is the more common one to require.
+            report(
+                &|| path().field("accessors").index(i).field("bufferView"),
+                validation::Error::Missing,
+            );
+        }
+    }
+}
+
+/// Checks that the nodes form disjoint strict trees: no node may be the child
+/// of several nodes, nor its own ancestor.
+///
+/// The child index making a node the child of a second parent, or closing a
+/// cycle, is reported as invalid.
+fn validate_node_trees<P, R>(root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, validation::Error),
+{
+    let mut parents = vec![None; root.nodes.len()];
+    for (i, node) in root.nodes.iter().enumerate() {
+        for (j, child) in node.children.iter().flatten().enumerate() {
+            match parents.get_mut(child.value()) {
+                Some(parent @ None) => *parent = Some((i, j)),
+                Some(Some(_)) => report(
+                    &|| path().field("nodes").index(i).field("children").index(j),
+                    validation::Error::Invalid,
+                ),
+                None => {}
+            }
+        }
+    }
+
+    // Follow parents upwards from every node, marking the nodes on the
+    // current chain to find cycles and finished nodes to stop early.
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        OnChain,
+        Done,
+    }
+    let mut states = vec![State::New; root.nodes.len()];
+    let mut chain = Vec::new();
+    for start in 0..root.nodes.len() {
+        let mut node = Some(start);
+        while let Some(i) = node {
+            match states[i] {
+                State::New => {
+                    states[i] = State::OnChain;
+                    chain.push(i);
+                    node = parents[i].map(|(parent, _)| parent);
+                }
+                State::OnChain => {
+                    let (parent, j) = parents[i].unwrap();
+                    report(
+                        &|| {
+                            path()
+                                .field("nodes")
+                                .index(parent)
+                                .field("children")
+                                .index(j)
+                        },
+                        validation::Error::Invalid,
+                    );
+                    break;
+                }
+                State::Done => break,
+            }
+        }
+        for i in chain.drain(..) {
+            states[i] = State::Done;
+        }
+    }
+}
+
+/// Deserializes a `Root`, skipping the top-level properties rejected by the
+/// wrapped filter.
+struct FilteredRoot<F>(F);
+
+impl<'de, F> serde::de::DeserializeSeed<'de> for FilteredRoot<F>
+where
+    F: Fn(&str) -> bool,
+{
+    type Value = Root;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> serde::de::Visitor<'de> for FilteredRoot<F>
+where
+    F: Fn(&str) -> bool,
+{
+    type Value = Root;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "glTF root object")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        // Hand the filtered entries to the derived implementation, so that
+        // the fields, their defaults and the rejection of duplicate keys are
+        // the same as for an unfiltered `Root`.
+        use serde::Deserialize;
+        let default_asset = !(self.0)("asset");
+        let map = FilteredMap {
+            map,
+            filter: self.0,
+            default_asset,
+            in_default_asset: false,
+            ended: false,
+        };
+        Root::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+/// Yields the entries of a map whose keys are accepted by a filter, followed
+/// by a default `asset` if the filter rejects it.
+struct FilteredMap<A, F> {
+    map: A,
+    filter: F,
+    default_asset: bool,
+    in_default_asset: bool,
+    ended: bool,
+}
+
+impl<'de, A, F> serde::de::MapAccess<'de> for FilteredMap<A, F>
+where
+    A: serde::de::MapAccess<'de>,
+    F: Fn(&str) -> bool,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'de>,
+    {
+        use serde::de::IntoDeserializer;
+        while !self.ended {
+            match self.map.next_key::<String>()? {
+                Some(key) if (self.filter)(&key) => {
+                    return seed.deserialize(key.into_deserializer()).map(Some);
+                }
+                Some(_) => {
+                    self.map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                None => self.ended = true,
+            }
+        }
+        if self.default_asset {
+            self.default_asset = false;
+            self.in_default_asset = true;
+            let key: serde::de::value::StrDeserializer<A::Error> = "asset".into_deserializer();
+            return seed.deserialize(key).map(Some);
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::DeserializeSeed<'de>,
+    {
+        if self.in_default_asset {
+            self.in_default_asset = false;
+            let asset = std::iter::once(("version", "2.0"));
+            seed.deserialize(serde::de::value::MapDeserializer::new(asset))
+        } else {
+            self.map.next_value_seed(seed)
+        }
+    }
 }
 
 impl<T> Index<T> {
@@ -194,6 +868,17 @@ impl<T> Index<T> {
         Index(value, std::marker::PhantomData)
     }
 
+    /// Pushes a value onto a vector of glTF objects and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector already contains `u32::MAX` items.
+    pub fn push(vec: &mut Vec<T>, value: T) -> Index<T> {
+        let index = u32::try_from(vec.len()).expect("too many items for a glTF index");
+        vec.push(value);
+        Index::new(index)
+    }
+
     /// Returns the internal offset value.
     pub fn value(&self) -> usize {
         self.0 as usize
@@ -299,9 +984,106 @@ macro_rules! impl_get {
                 self.$field.get(index.value())
             }
         }
+
+        impl GetMut<$ty> for Root {
+            fn get_mut(&mut self, index: Index<$ty>) -> Option<&mut $ty> {
+                self.$field.get_mut(index.value())
+            }
+        }
+
+        impl AsRef<[$ty]> for Root {
+            fn as_ref(&self) -> &[$ty] {
+                &self.$field
+            }
+        }
+
+        impl AsMut<Vec<$ty>> for Root {
+            fn as_mut(&mut self) -> &mut Vec<$ty> {
+                &mut self.$field
+            }
+        }
+    };
+}
+
+/// Implemented by top-level objects that have an optional name.
+#[cfg(feature = "names")]
+pub trait Named {
+    /// Returns the user-defined name of the object, if any.
+    fn name(&self) -> Option<&str>;
+}
+
+/// Implements `Named` for a top-level object and adds a `Root` method that
+/// finds the object by name.
+#[cfg(feature = "names")]
+macro_rules! impl_named {
+    ($($ty:ty => $method:ident),*) => {
+        $(
+            impl Named for $ty {
+                fn name(&self) -> Option<&str> {
+                    self.name.as_deref()
+                }
+            }
+        )*
+
+        impl Root {
+            $(
+                #[doc = concat!("Returns the first `", stringify!($ty), "` with the given name, together with its index.")]
+                pub fn $method(&self, name: &str) -> Option<(Index<$ty>, &$ty)> {
+                    self.find_by_name(name)
+                }
+            )*
+        }
     };
 }
 
+#[cfg(feature = "names")]
+impl_named!(
+    Accessor => accessor_named,
+    Animation => animation_named,
+    Buffer => buffer_named,
+    buffer::View => buffer_view_named,
+    Camera => camera_named,
+    Image => image_named,
+    Material => material_named,
+    Mesh => mesh_named,
+    Node => node_named,
+    texture::Sampler => sampler_named,
+    Scene => scene_named,
+    Skin => skin_named,
+    Texture => texture_named
+);
+
+/// Adds `Root` methods that iterate over the objects of one type together
+/// with their indices.
+macro_rules! impl_iter {
+    ($($ty:ty => $method:ident),*) => {
+        impl Root {
+            $(
+                #[doc = concat!("Returns every `", stringify!($ty), "` together with its index.")]
+                pub fn $method(&self) -> impl Iterator<Item = (Index<$ty>, &$ty)> {
+                    self.iter()
+                }
+            )*
+        }
+    };
+}
+
+impl_iter!(
+    Accessor => iter_accessors,
+    Animation => iter_animations,
+    Buffer => iter_buffers,
+    buffer::View => iter_buffer_views,
+    Camera => iter_cameras,
+    Image => iter_images,
+    Material => iter_materials,
+    Mesh => iter_meshes,
+    Node => iter_nodes,
+    texture::Sampler => iter_samplers,
+    Scene => iter_scenes,
+    Skin => iter_skins,
+    Texture => iter_textures
+);
+
 impl_get!(Accessor, accessors);
 impl_get!(Animation, animations);
 impl_get!(Buffer, buffers);
@@ -340,9 +1122,424 @@ mod tests {
         assert!(Index::<Node>::new(1) < Index::new(1234));
     }
 
+    #[test]
+    fn index_is_display() {
+        assert_eq!(Index::<Node>::new(42).to_string(), "42");
+    }
+
+    #[test]
+    fn root_serialization_is_minimal() {
+        let json = r#"{"accessors":[{"bufferView":0,"count":3,"componentType":5126,"type":"VEC3"}],"asset":{"version":"2.0"}}"#;
+        let root = Root::from_str(json).unwrap();
+        assert_eq!(root.to_string().unwrap(), json);
+        assert_eq!(root.to_vec().unwrap(), json.as_bytes());
+        let mut writer = Vec::new();
+        root.to_writer(&mut writer).unwrap();
+        assert_eq!(writer, json.as_bytes());
+    }
+
+    #[cfg(feature = "KHR_texture_transform")]
+    #[test]
+    fn texture_transform_serialization_is_minimal() {
+        let json = r#"{"offset":[0.5,0.0],"rotation":0.0,"scale":[1.0,1.0]}"#;
+        let transform: crate::extensions::texture::TextureTransform =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&transform).unwrap(), json);
+
+        let json = r#"{"offset":[0.5,0.0],"rotation":0.0,"scale":[1.0,1.0],"texCoord":1}"#;
+        let transform: crate::extensions::texture::TextureTransform =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(transform.tex_coord, Some(1));
+        assert_eq!(serde_json::to_string(&transform).unwrap(), json);
+    }
+
+    #[test]
+    fn root_push_returns_index() {
+        let mut root = Root::default();
+        let buffer = root.push(Buffer {
+            byte_length: 4,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+        });
+        let view = root.push(buffer::View {
+            buffer,
+            byte_length: 4,
+            byte_offset: None,
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: None,
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+        });
+        assert_eq!(buffer.value(), 0);
+        assert_eq!(view.value(), 0);
+        assert_eq!(root.get(view).unwrap().buffer, buffer);
+
+        root.get_mut(buffer).unwrap().byte_length = 8;
+        assert_eq!(root.get(buffer).unwrap().byte_length, 8);
+        assert!(root.get_mut(Index::<Buffer>::new(1)).is_none());
+
+        let mut samplers = Vec::new();
+        assert_eq!(
+            Index::push(&mut samplers, texture::Sampler::default()).value(),
+            0
+        );
+        assert_eq!(
+            Index::push(&mut samplers, texture::Sampler::default()).value(),
+            1
+        );
+    }
+
+    #[test]
+    fn root_new_builds_valid_document() {
+        let mut root = Root::new(Asset::new("test"));
+        let node = root.push(Node {
+            translation: Some([1.0, 0.0, 0.0]),
+            ..Default::default()
+        });
+        root.push(Scene {
+            nodes: vec![node],
+            ..Default::default()
+        });
+        root.push(Image::default());
+        assert_eq!(
+            root.to_string().unwrap(),
+            r#"{"asset":{"generator":"test","version":"2.0"},"images":[{}],"nodes":[{"translation":[1.0,0.0,0.0]}],"scenes":[{"nodes":[0]}]}"#
+        );
+    }
+
+    #[test]
+    fn root_roundtrip_compares_equal() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "nodes": [{"mesh": 0, "rotation": [0.0, 0.0, 0.0, 1.0], "extras": {"id": 7}}],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+            "materials": [{"alphaMode": "MASK"}]
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        let reimported = Root::from_str(&root.to_string().unwrap()).unwrap();
+        assert_eq!(root, reimported);
+
+        let mut changed = reimported.clone();
+        changed.nodes[0].mesh = None;
+        assert_ne!(root, changed);
+
+        #[cfg(feature = "extras")]
+        {
+            let mut changed = reimported.clone();
+            let extras = crate::extras::RawValue::from_string(r#"{"id":8}"#.to_string());
+            changed.nodes[0].extras = Some(extras.unwrap());
+            assert_ne!(root, changed);
+        }
+    }
+
+    #[cfg(not(feature = "unrecognized"))]
+    #[test]
+    fn root_ignores_unknown_fields() {
+        let json = r#"{
+            "asset": {"version": "2.0", "vendorVersion": 3},
+            "nodes": [{"mesh": 0, "futureProperty": {"a": [1, 2]}}],
+            "futureArray": []
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        assert_eq!(root.nodes[0].mesh, Some(Index::new(0)));
+        assert_eq!(
+            root.to_string().unwrap(),
+            r#"{"asset":{"version":"2.0"},"nodes":[{"mesh":0}]}"#
+        );
+    }
+
+    #[test]
+    fn root_preserves_unknown_attributes() {
+        use crate::mesh::Semantic;
+        use crate::validation::Checked::Valid;
+
+        let json = r#"{"asset":{"version":"2.0"},"meshes":[{"primitives":[{"attributes":{"POSITION":0,"COLOR_A":1,"FEATURE_ID_0":2}}]}]}"#;
+        let root = Root::from_str(json).unwrap();
+        let attributes = &root.meshes[0].primitives[0].attributes;
+        assert_eq!(attributes.len(), 3);
+        let semantic = Valid(Semantic::Other("FEATURE_ID_0".to_string()));
+        assert_eq!(attributes[&semantic], Index::new(2));
+        assert_eq!(root.to_string().unwrap(), json);
+
+        // Names without the underscore of application-specific semantics
+        // are kept but reported.
+        let mut errors = Vec::new();
+        root.meshes[0].primitives[0].validate(&root, Path::new, &mut |path, error| {
+            errors.push((path().as_str().to_owned(), error))
+        });
+        let invalid = |name: &str| {
+            (
+                format!("attributes[\"{}\"]", name),
+                validation::Error::Invalid,
+            )
+        };
+        assert!(errors.contains(&invalid("COLOR_A")));
+        assert!(errors.contains(&invalid("FEATURE_ID_0")));
+    }
+
+    #[cfg(feature = "unrecognized")]
+    #[test]
+    fn root_keeps_unknown_fields() {
+        let json = r#"{
+            "asset": {"version": "2.0", "vendorVersion": 3},
+            "meshes": [{"primitives": [{"attributes": {}, "futureMode": "quads"}]}],
+            "nodes": [{"mesh": 0, "futureProperty": {"a": [1, 2]}}],
+            "futureArray": []
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        assert_eq!(root.nodes[0].mesh, Some(Index::new(0)));
+        assert_eq!(
+            root.nodes[0].unrecognized["futureProperty"],
+            serde_json::json!({"a": [1, 2]})
+        );
+        assert_eq!(
+            root.to_string().unwrap(),
+            concat!(
+                r#"{"asset":{"vendorVersion":3,"version":"2.0"},"futureArray":[],"#,
+                r#""meshes":[{"primitives":[{"attributes":{},"futureMode":"quads"}]}],"#,
+                r#""nodes":[{"futureProperty":{"a":[1,2]},"mesh":0}]}"#
+            )
+        );
+        assert_eq!(Root::from_str(&root.to_string().unwrap()).unwrap(), root);
+    }
+
+    #[test]
+    fn root_add_extension_used_is_idempotent() {
+        let mut root = Root::default();
+        root.add_extension_used("KHR_materials_unlit");
+        root.add_extension_used("KHR_materials_unlit");
+        assert_eq!(root.extensions_used, ["KHR_materials_unlit"]);
+    }
+
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[test]
+    fn root_accepts_draco_accessors_without_buffer_view() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 4}],
+            "bufferViews": [{"buffer": 0, "byteLength": 4}],
+            "accessors": [
+                {"componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+                {"componentType": 5126, "count": 3, "type": "VEC3"}
+            ],
+            "meshes": [{
+                "primitives": [{
+                    "attributes": {"POSITION": 0},
+                    "extensions": {
+                        "KHR_draco_mesh_compression": {
+                            "bufferView": 0,
+                            "attributes": {"POSITION": 0}
+                        }
+                    }
+                }]
+            }]
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        let mut errors = Vec::new();
+        root.validate(&root, Path::new, &mut |path, error| {
+            errors.push((path().0, error))
+        });
+        assert_eq!(
+            errors,
+            [(
+                "accessors[1].bufferView".to_string(),
+                validation::Error::Missing
+            )]
+        );
+    }
+
+    #[test]
+    fn root_rejects_invalid_node_hierarchies() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "nodes": [
+                {"children": [1, 2]},
+                {"children": [2]},
+                {"children": [3]},
+                {"children": [4]},
+                {"children": [2]},
+                {"children": [5]}
+            ]
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        let mut errors = Vec::new();
+        root.validate(&root, Path::new, &mut |path, error| {
+            errors.push((path().0, error))
+        });
+        assert_eq!(
+            errors,
+            [
+                (
+                    "nodes[1].children[0]".to_string(),
+                    validation::Error::Invalid
+                ),
+                (
+                    "nodes[4].children[0]".to_string(),
+                    validation::Error::Invalid
+                ),
+                (
+                    "nodes[5].children[0]".to_string(),
+                    validation::Error::Invalid
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_keeps_extensions_without_data() {
+        let mut root = Root::default();
+        root.add_extension_required("KHR_mesh_quantization");
+        root.add_extension_required("KHR_mesh_quantization");
+        assert_eq!(root.extensions_used, ["KHR_mesh_quantization"]);
+        assert_eq!(root.extensions_required, ["KHR_mesh_quantization"]);
+        assert!(root.extensions_present().unwrap().is_empty());
+        let json = root.to_string().unwrap();
+        assert_eq!(Root::from_str(&json).unwrap(), root);
+    }
+
+    #[cfg(feature = "KHR_materials_unlit")]
+    #[test]
+    fn root_updates_extensions_used() {
+        let json = r#"{"asset":{"version":"2.0"},"materials":[{"extensions":{"KHR_materials_unlit":{}},"extras":{"extensions":{"A":{}}}}]}"#;
+        let mut root = Root::from_str(json).unwrap();
+        // Serializing leaves the lists as they are.
+        let serialized = root.to_value().unwrap();
+        assert!(serialized.get("extensionsUsed").is_none());
+
+        root.update_extensions_used().unwrap();
+        root.add_extension_required("KHR_materials_unlit");
+        assert_eq!(root.extensions_used, ["KHR_materials_unlit"]);
+
+        root.materials[0].extensions = None;
+        root.update_extensions_used().unwrap();
+        assert!(root.extensions_used.is_empty());
+        assert!(root.extensions_required.is_empty());
+    }
+
+    #[cfg(all(feature = "KHR_materials_unlit", feature = "KHR_texture_transform"))]
+    #[test]
+    fn export_updates_extensions_used() {
+        let json = r#"{"asset":{"version":"2.0"},"extensionsRequired":["KHR_texture_transform"],"extensionsUsed":["KHR_texture_transform"],"materials":[{"extensions":{"KHR_materials_unlit":{}}}]}"#;
+        let root = Root::from_str(json).unwrap();
+        let options = ExportOptions::default();
+        let exported = Root::from_str(&root.to_string_with_options(&options).unwrap()).unwrap();
+        assert_eq!(exported.extensions_used, ["KHR_materials_unlit"]);
+        assert!(exported.extensions_required.is_empty());
+        // The document itself is left unchanged.
+        assert_eq!(root.extensions_used, ["KHR_texture_transform"]);
+
+        let options = ExportOptions {
+            update_extensions_used: false,
+            ..Default::default()
+        };
+        let exported = Root::from_str(&root.to_string_with_options(&options).unwrap()).unwrap();
+        assert_eq!(exported.extensions_used, ["KHR_texture_transform"]);
+    }
+
+    #[test]
+    fn export_canonical() {
+        let json = r#"{"nodes":[{"scale":[2,2,2]}],"extensionsUsed":["EXT_unknown"],"asset":{"version":"2.0"}}"#;
+        let root = Root::from_str(json).unwrap();
+        let canonical = root.to_string_canonical().unwrap();
+        assert_eq!(
+            canonical,
+            r#"{"asset":{"version":"2.0"},"extensionsUsed":["EXT_unknown"],"nodes":[{"scale":[2.0,2.0,2.0]}]}"#
+        );
+        assert_eq!(root.to_vec_canonical().unwrap(), canonical.as_bytes());
+        let options = ExportOptions {
+            canonical: true,
+            update_extensions_used: false,
+            ..Default::default()
+        };
+        assert_eq!(root.to_string_with_options(&options).unwrap(), canonical);
+    }
+
+    #[cfg(feature = "KHR_materials_unlit")]
+    #[test]
+    fn unlit_material_roundtrip() {
+        let json = r#"{"asset":{"version":"2.0"},"extensionsUsed":["KHR_materials_unlit"],"materials":[{"extensions":{"KHR_materials_unlit":{}}}]}"#;
+        let root = Root::from_str(json).unwrap();
+        let extensions = root.materials[0].extensions.as_ref().unwrap();
+        assert!(extensions.unlit.is_some());
+        let serialized = root.to_string().unwrap();
+        assert!(serialized.contains(r#""extensions":{"KHR_materials_unlit":{}}"#));
+        assert!(serialized.contains(r#""extensionsUsed":["KHR_materials_unlit"]"#));
+    }
+
+    #[cfg(feature = "extensions")]
+    #[test]
+    fn unknown_extensions_roundtrip() {
+        let json = r#"{"asset":{"version":"2.0"},"extensions":{"EXT_root":{"a":1}},"extensionsUsed":["EXT_node","EXT_root"],"nodes":[{"extensions":{"EXT_node":{"b":[2,3]}}}]}"#;
+        let root = Root::from_str(json).unwrap();
+        let extensions = root.nodes[0].extensions.as_ref().unwrap();
+        assert_eq!(extensions.others["EXT_node"]["b"][1], 3);
+        assert_eq!(root.to_string().unwrap(), json);
+    }
+
     fn _index_is_send_sync()
     where
         Index<Material>: Send + Sync,
     {
     }
+
+    #[test]
+    fn root_rejects_unaligned_byte_stride() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 24}],
+            "bufferViews": [
+                {"buffer": 0, "byteLength": 12, "byteStride": 6},
+                {"buffer": 0, "byteLength": 12, "byteStride": 8}
+            ]
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        let mut errors = Vec::new();
+        root.validate(&root, Path::new, &mut |path, error| {
+            errors.push((path().0, error))
+        });
+        assert_eq!(
+            errors,
+            [(
+                "bufferViews[0].byteStride".to_string(),
+                validation::Error::Invalid
+            )]
+        );
+    }
+
+    #[test]
+    fn from_reader_filtered_skips_sections() {
+        let json = br#"{
+            "asset": {"version": "2.0"},
+            "animations": [{"channels": [], "samplers": []}],
+            "nodes": [{"camera": 1}],
+            "unknown": {"ignored": [1, 2, 3]}
+        }"#;
+        let root = Root::from_reader_filtered(&json[..], |key| key != "animations").unwrap();
+        assert!(root.animations.is_empty());
+        assert_eq!(root.nodes[0].camera.map(|x| x.value()), Some(1));
+        let unfiltered = Root::from_reader_filtered(&json[..], |_| true).unwrap();
+        assert_eq!(
+            unfiltered.to_string().unwrap(),
+            Root::from_slice(json).unwrap().to_string().unwrap()
+        );
+
+        let error = Root::from_reader_filtered(&b"{}"[..], |_| true).unwrap_err();
+        assert!(error.to_string().contains("missing field `asset`"));
+        let root = Root::from_reader_filtered(&b"{}"[..], |key| key != "asset").unwrap();
+        assert_eq!(root.asset.version, "2.0");
+
+        let json = br#"{"asset": {"version": "2.0"}, "nodes": [], "nodes": []}"#;
+        let error = Root::from_reader_filtered(&json[..], |_| true).unwrap_err();
+        assert!(error.to_string().contains("duplicate field `nodes`"));
+        assert!(Root::from_reader_filtered(&json[..], |key| key != "nodes").is_ok());
+    }
 }
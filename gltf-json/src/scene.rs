@@ -1,5 +1,5 @@
-use crate::validation::Validate;
-use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
+use crate::validation::{Error, Validate};
+use crate::{camera, extensions, mesh, scene, skin, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
@@ -13,7 +13,7 @@ use serde_derive::{Deserialize, Serialize};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Node {
     /// The index of the camera referenced by this node.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +33,12 @@ pub struct Node {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// 4x4 column-major transformation matrix.
     ///
     /// glTF 2.0 specification:
@@ -78,8 +84,72 @@ pub struct Node {
     pub weights: Option<Vec<f32>>,
 }
 
+impl_partial_eq!(Node {
+    camera,
+    children,
+    extensions,
+    matrix,
+    mesh,
+    #[cfg(feature = "names")]
+    name,
+    rotation,
+    scale,
+    translation,
+    skin,
+    weights,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+impl Validate for Node {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.camera
+            .validate(root, || path().field("camera"), report);
+        self.children
+            .validate(root, || path().field("children"), report);
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
+        self.extras
+            .validate(root, || path().field("extras"), report);
+        self.matrix
+            .validate(root, || path().field("matrix"), report);
+        self.mesh.validate(root, || path().field("mesh"), report);
+        self.rotation
+            .validate(root, || path().field("rotation"), report);
+        self.scale.validate(root, || path().field("scale"), report);
+        self.translation
+            .validate(root, || path().field("translation"), report);
+        self.skin.validate(root, || path().field("skin"), report);
+        self.weights
+            .validate(root, || path().field("weights"), report);
+
+        // spec: A node can have either a `matrix` or any combination of TRS properties.
+        if self.matrix.is_some()
+            && (self.translation.is_some() || self.rotation.is_some() || self.scale.is_some())
+        {
+            report(&|| path().field("matrix"), Error::Invalid);
+        }
+
+        // spec: `weights` **must** have one entry per morph target of the mesh.
+        if let (Some(weights), Some(mesh)) = (&self.weights, self.mesh.and_then(|x| root.get(x))) {
+            let target_count = mesh
+                .primitives
+                .first()
+                .and_then(|primitive| primitive.targets.as_ref())
+                .map_or(0, Vec::len);
+            if weights.len() != target_count {
+                report(&|| path().field("weights"), Error::Invalid);
+            }
+        }
+    }
+}
+
 /// The root `Node`s of a scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Scene {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -91,6 +161,12 @@ pub struct Scene {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
@@ -101,8 +177,17 @@ pub struct Scene {
     pub nodes: Vec<Index<Node>>,
 }
 
+impl_partial_eq!(Scene {
+    extensions,
+    #[cfg(feature = "names")]
+    name,
+    nodes,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// Unit quaternion rotation in the order (x, y, z, w), where w is the scalar.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UnitQuaternion(pub [f32; 4]);
 
 impl Default for UnitQuaternion {
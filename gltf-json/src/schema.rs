@@ -0,0 +1,460 @@
+use crate::diff::equal;
+use crate::{Path, Value};
+use std::collections::BTreeMap;
+use std::{fmt, fs, io};
+
+/// A place where a JSON value does not match its schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// The location of the value.
+    pub path: Path,
+
+    /// A description of the mismatch.
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.as_str().is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// A set of JSON Schema files referring to each other by file name, such as
+/// the glTF 2.0 schema from `specification/2.0/schema` of the
+/// [glTF repository](https://github.com/KhronosGroup/glTF), optionally
+/// together with extension schemas.
+///
+/// Validating against the schema catches problems before typed
+/// deserialization, which stops at the first error, and reports them in the
+/// terms of the specification. The keywords used by the glTF schemas are
+/// supported, except for `pattern` and `format`, which are ignored together
+/// with any other unknown keyword.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf_json::schema::Schemas;
+/// # use gltf_json::Value;
+/// let mut schemas = Schemas::new();
+/// let schema = r#"{
+///     "type": "object",
+///     "properties": {"asset": {"type": "object", "required": ["version"]}},
+///     "required": ["asset"]
+/// }"#;
+/// schemas.insert(Schemas::ROOT, gltf_json::deserialize::from_str(schema).unwrap());
+///
+/// let json: Value = gltf_json::deserialize::from_str(r#"{"asset": {}}"#).unwrap();
+/// let violations = schemas.validate(&json);
+/// assert_eq!(violations[0].to_string(), "asset: property `version` is required");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Schemas {
+    /// Schemas by file name.
+    schemas: BTreeMap<String, Value>,
+}
+
+impl Schemas {
+    /// The file name of the schema of the glTF root object.
+    pub const ROOT: &'static str = "glTF.schema.json";
+
+    /// Creates an empty set of schemas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads every `.json` file in a directory, such as the `schema`
+    /// directory of the glTF specification.
+    pub fn from_dir<P: AsRef<std::path::Path>>(dir: P) -> io::Result<Self> {
+        let mut schemas = Self::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |x| x != "json") {
+                continue;
+            }
+            let schema = serde_json::from_slice(&fs::read(&path)?)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            schemas.insert(name, schema);
+        }
+        Ok(schemas)
+    }
+
+    /// Adds a schema, replacing any schema with the same file name.
+    pub fn insert<N: Into<String>>(&mut self, name: N, schema: Value) {
+        self.schemas.insert(name.into(), schema);
+    }
+
+    /// Returns the schema with the given file name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.schemas.get(name)
+    }
+
+    /// Validates a glTF document against the [root schema](Self::ROOT).
+    pub fn validate(&self, json: &Value) -> Vec<Violation> {
+        self.validate_with(Self::ROOT, json)
+    }
+
+    /// Validates a JSON value against the schema with the given file name.
+    pub fn validate_with(&self, name: &str, json: &Value) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        match self.schemas.get(name) {
+            Some(schema) => self.check(name, schema, json, &Path::new(), &mut violations),
+            None => violations.push(Violation {
+                path: Path::new(),
+                message: format!("schema `{}` not found", name),
+            }),
+        }
+        violations
+    }
+
+    /// Resolves a `$ref` found in the schema file `file`, returning the file
+    /// name and schema it refers to.
+    fn resolve<'a>(&'a self, file: &'a str, reference: &'a str) -> Option<(&'a str, &'a Value)> {
+        let (name, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        let name = if name.is_empty() { file } else { name };
+        let (name, schema) = self.schemas.get_key_value(name)?;
+        Some((name, schema.pointer(pointer)?))
+    }
+
+    fn check(
+        &self,
+        file: &str,
+        schema: &Value,
+        value: &Value,
+        path: &Path,
+        violations: &mut Vec<Violation>,
+    ) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Object(schema) => schema,
+            _ => {
+                violations.push(violation(path, "is not allowed"));
+                return;
+            }
+        };
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match self.resolve(file, reference) {
+                Some((file, schema)) => self.check(file, schema, value, path, violations),
+                None => violations.push(violation(
+                    path,
+                    format!("unresolved schema reference `{}`", reference),
+                )),
+            }
+        }
+        let mut fail_if = |failed: bool, message: &dyn Fn() -> String| {
+            if failed {
+                violations.push(violation(path, message()));
+            }
+        };
+
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::String(type_) => vec![type_],
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            fail_if(!types.iter().any(|x| has_type(value, x)), &|| {
+                format!(
+                    "expected {}, found {}",
+                    types.join(" or "),
+                    type_name(value)
+                )
+            });
+        }
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            fail_if(!values.iter().any(|x| equal(x, value)), &|| {
+                format!("{} is not one of {}", value, Value::Array(values.clone()))
+            });
+        }
+        if let Some(constant) = schema.get("const") {
+            fail_if(!equal(constant, value), &|| {
+                format!("{} is not {}", value, constant)
+            });
+        }
+
+        if let Some(number) = value.as_f64() {
+            let keyword = |name: &str| schema.get(name).and_then(Value::as_f64);
+            // Draft 4 uses boolean exclusive bounds modifying the inclusive ones.
+            let exclusive = |name: &str| schema.get(name).and_then(Value::as_bool) == Some(true);
+            if let Some(minimum) = keyword("minimum") {
+                let below = number < minimum || exclusive("exclusiveMinimum") && number == minimum;
+                fail_if(below, &|| format!("{} is less than {}", value, minimum));
+            }
+            if let Some(maximum) = keyword("maximum") {
+                let above = number > maximum || exclusive("exclusiveMaximum") && number == maximum;
+                fail_if(above, &|| format!("{} is greater than {}", value, maximum));
+            }
+            if let Some(minimum) = keyword("exclusiveMinimum") {
+                fail_if(number <= minimum, &|| {
+                    format!("{} is not greater than {}", value, minimum)
+                });
+            }
+            if let Some(maximum) = keyword("exclusiveMaximum") {
+                fail_if(number >= maximum, &|| {
+                    format!("{} is not less than {}", value, maximum)
+                });
+            }
+            if let Some(divisor) = keyword("multipleOf") {
+                fail_if((number / divisor).fract() != 0.0, &|| {
+                    format!("{} is not a multiple of {}", value, divisor)
+                });
+            }
+        }
+
+        if let Value::String(string) = value {
+            let length = string.chars().count() as u64;
+            if let Some(minimum) = schema.get("minLength").and_then(Value::as_u64) {
+                fail_if(length < minimum, &|| {
+                    format!("must be at least {} characters long", minimum)
+                });
+            }
+            if let Some(maximum) = schema.get("maxLength").and_then(Value::as_u64) {
+                fail_if(length > maximum, &|| {
+                    format!("must be at most {} characters long", maximum)
+                });
+            }
+        }
+
+        if let Value::Array(array) = value {
+            let length = array.len() as u64;
+            if let Some(minimum) = schema.get("minItems").and_then(Value::as_u64) {
+                fail_if(length < minimum, &|| {
+                    format!("must have at least {} items", minimum)
+                });
+            }
+            if let Some(maximum) = schema.get("maxItems").and_then(Value::as_u64) {
+                fail_if(length > maximum, &|| {
+                    format!("must have at most {} items", maximum)
+                });
+            }
+            if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+                let duplicate = array
+                    .iter()
+                    .enumerate()
+                    .any(|(i, x)| array[..i].iter().any(|y| equal(x, y)));
+                fail_if(duplicate, &|| "items must be unique".to_string());
+            }
+            match schema.get("items") {
+                Some(Value::Array(items)) => {
+                    for (i, (item, value)) in items.iter().zip(array).enumerate() {
+                        self.check(file, item, value, &path.index(i), violations);
+                    }
+                }
+                Some(items) => {
+                    for (i, value) in array.iter().enumerate() {
+                        self.check(file, items, value, &path.index(i), violations);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if let Value::Object(object) = value {
+            let length = object.len() as u64;
+            if let Some(minimum) = schema.get("minProperties").and_then(Value::as_u64) {
+                if length < minimum {
+                    violations.push(violation(
+                        path,
+                        format!("must have at least {} properties", minimum),
+                    ));
+                }
+            }
+            if let Some(maximum) = schema.get("maxProperties").and_then(Value::as_u64) {
+                if length > maximum {
+                    violations.push(violation(
+                        path,
+                        format!("must have at most {} properties", maximum),
+                    ));
+                }
+            }
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(name) {
+                        violations
+                            .push(violation(path, format!("property `{}` is required", name)));
+                    }
+                }
+            }
+            let dependencies = ["dependencies", "dependentRequired"];
+            for dependencies in dependencies.iter().filter_map(|x| schema.get(*x)) {
+                let dependencies = dependencies.as_object().into_iter().flatten();
+                for (name, required) in dependencies.filter(|(x, _)| object.contains_key(*x)) {
+                    match required {
+                        Value::Array(required) => {
+                            for other in required.iter().filter_map(Value::as_str) {
+                                if !object.contains_key(other) {
+                                    violations.push(violation(
+                                        path,
+                                        format!("property `{}` requires `{}`", name, other),
+                                    ));
+                                }
+                            }
+                        }
+                        schema => self.check(file, schema, value, path, violations),
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, value) in object {
+                let path = path.field(name);
+                match properties.and_then(|x| x.get(name)) {
+                    Some(property) => self.check(file, property, value, &path, violations),
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => {
+                            violations.push(violation(&path, "unexpected property"))
+                        }
+                        Some(additional) => self.check(file, additional, value, &path, violations),
+                        None => {}
+                    },
+                }
+            }
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.check(file, schema, value, path, violations);
+            }
+        }
+        let matches = |schemas: &Vec<Value>| {
+            schemas
+                .iter()
+                .filter(|schema| {
+                    let mut errors = Vec::new();
+                    self.check(file, schema, value, path, &mut errors);
+                    errors.is_empty()
+                })
+                .count()
+        };
+        if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+            if matches(schemas) == 0 {
+                violations.push(violation(path, "does not match any allowed schema"));
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            if matches(schemas) != 1 {
+                violations.push(violation(path, "must match exactly one schema"));
+            }
+        }
+        if let Some(not) = schema.get("not") {
+            let mut errors = Vec::new();
+            self.check(file, not, value, path, &mut errors);
+            if errors.is_empty() {
+                violations.push(violation(path, "matches a disallowed schema"));
+            }
+        }
+    }
+}
+
+fn violation<M: Into<String>>(path: &Path, message: M) -> Violation {
+    Violation {
+        path: path.clone(),
+        message: message.into(),
+    }
+}
+
+/// Returns whether `value` has the JSON Schema type `type_`.
+fn has_type(value: &Value, type_: &str) -> bool {
+    match (type_, value) {
+        ("null", Value::Null)
+        | ("boolean", Value::Bool(_))
+        | ("number", Value::Number(_))
+        | ("string", Value::String(_))
+        | ("array", Value::Array(_))
+        | ("object", Value::Object(_)) => true,
+        ("integer", Value::Number(number)) => number.as_f64().map_or(false, |x| x.fract() == 0.0),
+        _ => false,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Schemas;
+    use crate::Value;
+
+    fn value(json: &str) -> Value {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn validate() {
+        let mut schemas = Schemas::new();
+        schemas.insert(
+            Schemas::ROOT,
+            value(
+                r##"{
+                    "type": "object",
+                    "properties": {
+                        "asset": {"type": "object", "required": ["version"]},
+                        "accessors": {"type": "array", "items": {"$ref": "accessor.schema.json"}, "minItems": 1},
+                        "scene": {"allOf": [{"$ref": "#/definitions/id"}]}
+                    },
+                    "required": ["asset"],
+                    "dependencies": {"scene": ["scenes"]},
+                    "definitions": {"id": {"type": "integer", "minimum": 0}}
+                }"##,
+            ),
+        );
+        schemas.insert(
+            "accessor.schema.json",
+            value(
+                r#"{
+                    "properties": {
+                        "componentType": {"anyOf": [{"const": 5126}, {"const": 5123}]},
+                        "count": {"type": "integer", "exclusiveMinimum": 0},
+                        "type": {"enum": ["SCALAR", "VEC3"]}
+                    },
+                    "additionalProperties": false
+                }"#,
+            ),
+        );
+
+        let valid = value(
+            r#"{"asset": {"version": "2.0"}, "accessors": [{"componentType": 5126.0, "count": 3}]}"#,
+        );
+        assert_eq!(schemas.validate(&valid), []);
+
+        let invalid = value(
+            r#"{
+                "scene": -1,
+                "accessors": [{"componentType": 5121, "count": 0, "type": "VEC2", "min": []}]
+            }"#,
+        );
+        let messages: Vec<String> = schemas
+            .validate(&invalid)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "property `asset` is required",
+                "property `scene` requires `scenes`",
+                "accessors[0].componentType: does not match any allowed schema",
+                "accessors[0].count: 0 is not greater than 0",
+                "accessors[0].min: unexpected property",
+                r#"accessors[0].type: "VEC2" is not one of ["SCALAR","VEC3"]"#,
+                "scene: -1 is less than 0",
+            ]
+        );
+
+        assert_eq!(
+            schemas.validate_with("missing.schema.json", &valid)[0].message,
+            "schema `missing.schema.json` not found"
+        );
+    }
+}
@@ -15,6 +15,12 @@ pub struct Skin {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+
     /// The index of the accessor containing the 4x4 inverse-bind matrices.
     ///
     /// When `None`,each matrix is assumed to be the 4x4 identity matrix
@@ -41,3 +47,14 @@ pub struct Skin {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skeleton: Option<Index<scene::Node>>,
 }
+
+impl_partial_eq!(Skin {
+    extensions,
+    inverse_bind_matrices,
+    joints,
+    #[cfg(feature = "names")]
+    name,
+    skeleton,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
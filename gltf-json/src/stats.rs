@@ -0,0 +1,221 @@
+use crate::mesh::{Mode, Semantic};
+use crate::validation::Checked::Valid;
+use crate::{accessor, Index, Root};
+use std::collections::BTreeSet;
+
+/// Summary statistics of a glTF document, as returned by [`Root::stats`].
+///
+/// Vertex, triangle and byte counts are computed over mesh definitions, so a
+/// mesh instanced by several nodes is only counted once.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// The number of accessors.
+    pub accessors: usize,
+
+    /// The number of animations.
+    pub animations: usize,
+
+    /// The number of buffers.
+    pub buffers: usize,
+
+    /// The number of buffer views.
+    pub buffer_views: usize,
+
+    /// The number of cameras.
+    pub cameras: usize,
+
+    /// The number of images.
+    pub images: usize,
+
+    /// The number of materials.
+    pub materials: usize,
+
+    /// The number of meshes.
+    pub meshes: usize,
+
+    /// The number of nodes.
+    pub nodes: usize,
+
+    /// The number of samplers.
+    pub samplers: usize,
+
+    /// The number of scenes.
+    pub scenes: usize,
+
+    /// The number of skins.
+    pub skins: usize,
+
+    /// The number of textures.
+    pub textures: usize,
+
+    /// The number of mesh primitives.
+    pub primitives: usize,
+
+    /// The total number of vertices of all mesh primitives.
+    pub vertices: u64,
+
+    /// The total number of triangles of all triangle, triangle strip and
+    /// triangle fan mesh primitives.
+    pub triangles: u64,
+
+    /// The byte length of every buffer.
+    pub buffer_sizes: Vec<u64>,
+
+    /// The number of bytes of vertex, index and morph target data referenced
+    /// by mesh primitives, when tightly packed.
+    pub geometry_bytes: u64,
+
+    /// The width and height of every image, if known.
+    ///
+    /// The JSON alone does not contain image dimensions, so these are `None`
+    /// unless provided with [`Stats::with_image_sizes`].
+    pub image_sizes: Vec<Option<[u32; 2]>>,
+}
+
+impl Stats {
+    /// Computes the statistics of a document.
+    pub(crate) fn from_root(root: &Root) -> Self {
+        let mut stats = Stats {
+            accessors: root.accessors.len(),
+            animations: root.animations.len(),
+            buffers: root.buffers.len(),
+            buffer_views: root.buffer_views.len(),
+            cameras: root.cameras.len(),
+            images: root.images.len(),
+            materials: root.materials.len(),
+            meshes: root.meshes.len(),
+            nodes: root.nodes.len(),
+            samplers: root.samplers.len(),
+            scenes: root.scenes.len(),
+            skins: root.skins.len(),
+            textures: root.textures.len(),
+            buffer_sizes: root.buffers.iter().map(|x| x.byte_length as u64).collect(),
+            image_sizes: vec![None; root.images.len()],
+            ..Default::default()
+        };
+
+        let mut geometry = BTreeSet::new();
+        for primitive in root.meshes.iter().flat_map(|mesh| &mesh.primitives) {
+            stats.primitives += 1;
+            let positions = primitive
+                .attributes
+                .get(&Valid(Semantic::Positions))
+                .and_then(|index| root.get(*index));
+            let vertices = positions.map_or(0, |x| x.count as u64);
+            let elements = primitive
+                .indices
+                .and_then(|index| root.get(index))
+                .map_or(vertices, |x| x.count as u64);
+            stats.vertices += vertices;
+            stats.triangles += match primitive.mode {
+                Valid(Mode::Triangles) => elements / 3,
+                Valid(Mode::TriangleStrip) | Valid(Mode::TriangleFan) => elements.saturating_sub(2),
+                _ => 0,
+            };
+
+            geometry.extend(primitive.attributes.values().copied());
+            geometry.extend(primitive.indices);
+            for target in primitive.targets.iter().flatten() {
+                geometry.extend(target.positions);
+                geometry.extend(target.normals);
+                geometry.extend(target.tangents);
+            }
+        }
+        stats.geometry_bytes = geometry
+            .into_iter()
+            .filter_map(|index: Index<accessor::Accessor>| root.get(index))
+            .map(accessor_size)
+            .sum();
+        stats
+    }
+
+    /// Sets the dimensions of the document's images, in the same order as
+    /// `Root::images`, for example from the data returned by `gltf::import`.
+    pub fn with_image_sizes<I>(mut self, sizes: I) -> Self
+    where
+        I: IntoIterator<Item = [u32; 2]>,
+    {
+        for (slot, size) in self.image_sizes.iter_mut().zip(sizes) {
+            *slot = Some(size);
+        }
+        self
+    }
+
+    /// Returns an estimate of the GPU memory required by the document, in bytes.
+    ///
+    /// This is the geometry data plus every image of known size stored as
+    /// RGBA8 with a full mipmap chain.
+    pub fn estimated_gpu_memory(&self) -> u64 {
+        let textures: u64 = self
+            .image_sizes
+            .iter()
+            .flatten()
+            .map(|[width, height]| *width as u64 * *height as u64 * 4 * 4 / 3)
+            .sum();
+        self.geometry_bytes + textures
+    }
+}
+
+/// Returns the number of bytes of an accessor's elements when tightly packed.
+fn accessor_size(accessor: &accessor::Accessor) -> u64 {
+    match (&accessor.component_type, &accessor.type_) {
+        (Valid(component_type), Valid(type_)) => {
+            let element = component_type.0.size() * type_.multiplicity();
+            accessor.count as u64 * element as u64
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Root;
+
+    const DOCUMENT: &str = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"bufferView": 0, "count": 4, "componentType": 5126, "type": "VEC3"},
+            {"bufferView": 1, "count": 6, "componentType": 5123, "type": "SCALAR"},
+            {"bufferView": 0, "count": 4, "componentType": 5126, "type": "VEC2"}
+        ],
+        "buffers": [{"byteLength": 80}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 48},
+            {"buffer": 0, "byteOffset": 48, "byteLength": 12}
+        ],
+        "images": [{"uri": "a.png"}, {"uri": "b.png"}],
+        "meshes": [
+            {"primitives": [
+                {"attributes": {"POSITION": 0}, "indices": 1},
+                {"attributes": {"POSITION": 0}, "mode": 5}
+            ]}
+        ],
+        "nodes": [{"mesh": 0}, {"mesh": 0}],
+        "scenes": [{"nodes": [0, 1]}]
+    }"#;
+
+    #[test]
+    fn stats() {
+        let root = Root::from_str(DOCUMENT).unwrap();
+        let stats = root.stats();
+        assert_eq!(stats.accessors, 3);
+        assert_eq!(stats.meshes, 1);
+        assert_eq!(stats.nodes, 2);
+        assert_eq!(stats.primitives, 2);
+        assert_eq!(stats.vertices, 8);
+        assert_eq!(stats.triangles, 2 + 2);
+        assert_eq!(stats.buffer_sizes, vec![80]);
+        // The unused texture coordinates are not counted.
+        assert_eq!(stats.geometry_bytes, 48 + 12);
+        assert_eq!(stats.image_sizes, vec![None, None]);
+        assert_eq!(stats.estimated_gpu_memory(), 60);
+    }
+
+    #[test]
+    fn stats_with_image_sizes() {
+        let root = Root::from_str(DOCUMENT).unwrap();
+        let stats = root.stats().with_image_sizes([[2, 3]]);
+        assert_eq!(stats.image_sizes, vec![Some([2, 3]), None]);
+        assert_eq!(stats.estimated_gpu_memory(), 60 + 32);
+    }
+}
@@ -1,5 +1,5 @@
-use crate::validation::Checked;
-use crate::{extensions, image, Extras, Index};
+use crate::validation::{Checked, Error};
+use crate::{extensions, image, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
@@ -49,7 +49,7 @@ pub const VALID_MIN_FILTERS: &[u32] = &[
 pub const VALID_WRAPPING_MODES: &[u32] = &[CLAMP_TO_EDGE, MIRRORED_REPEAT, REPEAT];
 
 /// Magnification filter.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 pub enum MagFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -66,10 +66,19 @@ impl MagFilter {
             MagFilter::Linear => LINEAR,
         }
     }
+
+    /// Returns the corresponding `wgpu` filter mode.
+    #[cfg(feature = "wgpu")]
+    pub fn to_wgpu(&self) -> wgpu_types::FilterMode {
+        match *self {
+            MagFilter::Nearest => wgpu_types::FilterMode::Nearest,
+            MagFilter::Linear => wgpu_types::FilterMode::Linear,
+        }
+    }
 }
 
 /// Minification filter.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 pub enum MinFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -102,10 +111,26 @@ impl MinFilter {
             MinFilter::LinearMipmapLinear => LINEAR_MIPMAP_LINEAR,
         }
     }
+
+    /// Returns the corresponding `wgpu` minification filter mode, together
+    /// with the mipmap filter mode, which is `None` if the filter does not
+    /// use mipmaps.
+    #[cfg(feature = "wgpu")]
+    pub fn to_wgpu(&self) -> (wgpu_types::FilterMode, Option<wgpu_types::FilterMode>) {
+        use wgpu_types::FilterMode::{Linear, Nearest};
+        match *self {
+            MinFilter::Nearest => (Nearest, None),
+            MinFilter::Linear => (Linear, None),
+            MinFilter::NearestMipmapNearest => (Nearest, Some(Nearest)),
+            MinFilter::LinearMipmapNearest => (Linear, Some(Nearest)),
+            MinFilter::NearestMipmapLinear => (Nearest, Some(Linear)),
+            MinFilter::LinearMipmapLinear => (Linear, Some(Linear)),
+        }
+    }
 }
 
 /// Texture co-ordinate wrapping mode.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 pub enum WrappingMode {
     /// Corresponds to `GL_CLAMP_TO_EDGE`.
     ClampToEdge = 1,
@@ -126,6 +151,16 @@ impl WrappingMode {
             WrappingMode::Repeat => REPEAT,
         }
     }
+
+    /// Returns the corresponding `wgpu` address mode.
+    #[cfg(feature = "wgpu")]
+    pub fn to_wgpu(&self) -> wgpu_types::AddressMode {
+        match *self {
+            WrappingMode::ClampToEdge => wgpu_types::AddressMode::ClampToEdge,
+            WrappingMode::MirroredRepeat => wgpu_types::AddressMode::MirrorRepeat,
+            WrappingMode::Repeat => wgpu_types::AddressMode::Repeat,
+        }
+    }
 }
 
 /// Texture sampler properties for filtering and wrapping modes.
@@ -164,10 +199,29 @@ pub struct Sampler {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Sampler {
+    mag_filter,
+    min_filter,
+    #[cfg(feature = "names")]
+    name,
+    wrap_s,
+    wrap_t,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 /// A texture and its sampler.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[gltf(validate_hook = "texture_validate_hook")]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
@@ -179,7 +233,11 @@ pub struct Texture {
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    pub source: Index<image::Image>,
+    ///
+    /// This may be omitted when an extension, such as `KHR_texture_basisu`
+    /// or `EXT_texture_webp`, provides the image instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -190,6 +248,55 @@ pub struct Texture {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
+}
+
+impl_partial_eq!(Texture {
+    #[cfg(feature = "names")]
+    name,
+    sampler,
+    source,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
+impl Texture {
+    /// Returns the index of the image provided by an extension instead of
+    /// `source`, preferring `KHR_texture_basisu` over `EXT_texture_webp`.
+    pub fn extension_source(&self) -> Option<Index<image::Image>> {
+        #[cfg_attr(
+            not(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp")),
+            allow(unused_variables)
+        )]
+        let extensions = self.extensions.as_ref()?;
+        #[cfg(feature = "KHR_texture_basisu")]
+        if let Some(basisu) = &extensions.texture_basisu {
+            return Some(basisu.source);
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        if let Some(webp) = &extensions.texture_webp {
+            return Some(webp.source);
+        }
+        None
+    }
+}
+
+/// Checks that a texture has an image, either as `source` or provided by an
+/// extension.
+fn texture_validate_hook<P, R>(texture: &Texture, _root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    if texture.source.is_none() && texture.extension_source().is_none() {
+        report(&|| path().field("source"), Error::Missing);
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
@@ -211,8 +318,22 @@ pub struct Info {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
+
+    /// Properties unknown to this crate, kept so that they are written back
+    /// when serializing.
+    #[cfg(feature = "unrecognized")]
+    #[cfg_attr(feature = "unrecognized", serde(flatten))]
+    pub unrecognized: crate::Unrecognized,
 }
 
+impl_partial_eq!(Info {
+    index,
+    tex_coord,
+    extensions,
+    #[cfg(feature = "unrecognized")]
+    unrecognized,
+} extras);
+
 impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -340,3 +461,22 @@ impl ser::Serialize for WrappingMode {
         serializer.serialize_u32(self.as_gl_enum())
     }
 }
+
+#[cfg(all(test, feature = "wgpu"))]
+mod tests {
+    use super::{MinFilter, WrappingMode};
+    use wgpu_types::{AddressMode, FilterMode};
+
+    #[test]
+    fn to_wgpu() {
+        assert_eq!(
+            MinFilter::LinearMipmapNearest.to_wgpu(),
+            (FilterMode::Linear, Some(FilterMode::Nearest))
+        );
+        assert_eq!(MinFilter::Nearest.to_wgpu(), (FilterMode::Nearest, None));
+        assert_eq!(
+            WrappingMode::MirroredRepeat.to_wgpu(),
+            AddressMode::MirrorRepeat
+        );
+    }
+}
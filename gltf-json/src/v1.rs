@@ -0,0 +1,965 @@
+//! Data structures of the glTF 1.0 schema.
+//!
+//! These mirror the JSON of legacy assets closely. Top-level objects are
+//! stored in dictionaries keyed by string ids rather than in arrays, and
+//! references between objects are made by id.
+
+use crate::Extras;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Extension specific data, keyed by extension name.
+pub type Extensions = serde_json::Map<String, Value>;
+
+/// A dictionary of glTF objects keyed by id.
+pub type Dictionary<T> = BTreeMap<String, T>;
+
+/// Corresponds to `GL_BYTE`.
+pub const BYTE: u32 = 5120;
+
+/// Corresponds to `GL_UNSIGNED_BYTE`.
+pub const UNSIGNED_BYTE: u32 = 5121;
+
+/// Corresponds to `GL_SHORT`.
+pub const SHORT: u32 = 5122;
+
+/// Corresponds to `GL_UNSIGNED_SHORT`.
+pub const UNSIGNED_SHORT: u32 = 5123;
+
+/// Corresponds to `GL_FLOAT`.
+pub const FLOAT: u32 = 5126;
+
+/// Corresponds to `GL_FRAGMENT_SHADER`.
+pub const FRAGMENT_SHADER: u32 = 35632;
+
+/// Corresponds to `GL_VERTEX_SHADER`.
+pub const VERTEX_SHADER: u32 = 35633;
+
+/// The root object of a glTF 1.0 asset.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Root {
+    /// A dictionary of accessors.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub accessors: Dictionary<Accessor>,
+
+    /// A dictionary of keyframe animations.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub animations: Dictionary<Animation>,
+
+    /// Metadata about the glTF asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<Asset>,
+
+    /// A dictionary of buffers.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub buffers: Dictionary<Buffer>,
+
+    /// A dictionary of buffer views.
+    #[serde(
+        default,
+        rename = "bufferViews",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub buffer_views: Dictionary<BufferView>,
+
+    /// A dictionary of cameras.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub cameras: Dictionary<Camera>,
+
+    /// A dictionary of images.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub images: Dictionary<Image>,
+
+    /// A dictionary of materials.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub materials: Dictionary<Material>,
+
+    /// A dictionary of meshes.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub meshes: Dictionary<Mesh>,
+
+    /// A dictionary of nodes.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub nodes: Dictionary<Node>,
+
+    /// A dictionary of shader programs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub programs: Dictionary<Program>,
+
+    /// A dictionary of texture samplers.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub samplers: Dictionary<Sampler>,
+
+    /// The id of the default scene.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scene: Option<String>,
+
+    /// A dictionary of scenes.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scenes: Dictionary<Scene>,
+
+    /// A dictionary of shaders.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub shaders: Dictionary<Shader>,
+
+    /// A dictionary of skins.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub skins: Dictionary<Skin>,
+
+    /// A dictionary of techniques.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub techniques: Dictionary<Technique>,
+
+    /// A dictionary of textures.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub textures: Dictionary<Texture>,
+
+    /// Names of glTF extensions used somewhere in this asset.
+    #[serde(
+        default,
+        rename = "extensionsUsed",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub extensions_used: Vec<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+impl Root {
+    /// Deserialize from a JSON string slice.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(str_: &str) -> Result<Self, crate::Error> {
+        serde_json::from_str(str_)
+    }
+
+    /// Deserialize from a JSON byte slice.
+    pub fn from_slice(slice: &[u8]) -> Result<Self, crate::Error> {
+        serde_json::from_slice(slice)
+    }
+
+    /// Deserialize from a stream of JSON.
+    pub fn from_reader<R>(reader: R) -> Result<Self, crate::Error>
+    where
+        R: std::io::Read,
+    {
+        serde_json::from_reader(reader)
+    }
+}
+
+/// A typed view into a buffer view.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Accessor {
+    /// The id of the parent buffer view.
+    pub buffer_view: String,
+
+    /// The offset relative to the start of the buffer view in bytes.
+    pub byte_offset: u32,
+
+    /// The stride in bytes between attributes, or zero when tightly packed.
+    #[serde(default)]
+    pub byte_stride: u32,
+
+    /// The data type of each component, for example `FLOAT`.
+    pub component_type: u32,
+
+    /// The number of attributes referenced by this accessor.
+    pub count: u32,
+
+    /// Specifies whether the attributes are scalars, vectors or matrices.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// The maximum value of each component.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub max: Vec<f32>,
+
+    /// The minimum value of each component.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub min: Vec<f32>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A keyframe animation.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Animation {
+    /// Channels connecting samplers to the properties they animate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<Channel>,
+
+    /// Maps parameter names to the ids of the accessors holding their data.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: Dictionary<String>,
+
+    /// Samplers combining input and output parameters with an interpolation.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub samplers: Dictionary<AnimationSampler>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Connects an animation sampler to the node property it animates.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Channel {
+    /// The id of the animation sampler.
+    pub sampler: String,
+
+    /// The node property to animate.
+    pub target: Target,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// The node property targeted by an animation channel.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Target {
+    /// The id of the node to animate.
+    pub id: String,
+
+    /// The name of the property to animate, one of `"translation"`,
+    /// `"rotation"` or `"scale"`.
+    pub path: String,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Combines input and output parameters of an animation with an interpolation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnimationSampler {
+    /// The name of the parameter holding keyframe times.
+    pub input: String,
+
+    /// The interpolation algorithm, which is always `"LINEAR"` in glTF 1.0.
+    #[serde(default = "interpolation_default")]
+    pub interpolation: String,
+
+    /// The name of the parameter holding keyframe values.
+    pub output: String,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+fn interpolation_default() -> String {
+    "LINEAR".to_owned()
+}
+
+/// Metadata about the glTF asset.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Asset {
+    /// A copyright message suitable for display to credit the content creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+
+    /// Tool that generated this glTF model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<String>,
+
+    /// Specifies whether the colors in the rendered image are premultiplied
+    /// by alpha.
+    #[serde(default)]
+    pub premultiplied_alpha: bool,
+
+    /// The API and version that this asset targets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Profile>,
+
+    /// The glTF version of this asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// The API and version that an asset targets.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Profile {
+    /// The API, for example `"WebGL"`.
+    pub api: String,
+
+    /// The version of the API, for example `"1.0.2"`.
+    pub version: String,
+}
+
+/// A buffer points to binary data representing geometry, animations, or skins.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Buffer {
+    /// The uri of the buffer.
+    pub uri: String,
+
+    /// The length of the buffer in bytes.
+    #[serde(default)]
+    pub byte_length: u32,
+
+    /// How the buffer is loaded, either `"arraybuffer"` or `"text"`.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A view into a buffer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferView {
+    /// The id of the parent buffer.
+    pub buffer: String,
+
+    /// The offset into the parent buffer in bytes.
+    pub byte_offset: u32,
+
+    /// The length of the buffer view in bytes.
+    #[serde(default)]
+    pub byte_length: u32,
+
+    /// The target the buffer view should be bound to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<u32>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A camera's projection.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Camera {
+    /// An orthographic projection, present when `type_` is `"orthographic"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orthographic: Option<Orthographic>,
+
+    /// A perspective projection, present when `type_` is `"perspective"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub perspective: Option<Perspective>,
+
+    /// The projection type, either `"perspective"` or `"orthographic"`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Values for an orthographic camera projection.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Orthographic {
+    /// The horizontal magnification of the view.
+    pub xmag: f32,
+
+    /// The vertical magnification of the view.
+    pub ymag: f32,
+
+    /// The distance to the far clipping plane.
+    pub zfar: f32,
+
+    /// The distance to the near clipping plane.
+    pub znear: f32,
+}
+
+/// Values for a perspective camera projection.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Perspective {
+    /// The aspect ratio of the field of view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<f32>,
+
+    /// The vertical field of view in radians.
+    pub yfov: f32,
+
+    /// The distance to the far clipping plane.
+    pub zfar: f32,
+
+    /// The distance to the near clipping plane.
+    pub znear: f32,
+}
+
+/// Image data used to create a texture.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Image {
+    /// The uri of the image.
+    pub uri: String,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// The material appearance of a primitive, defined by a technique.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Material {
+    /// The id of the technique used to render the material.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub technique: Option<String>,
+
+    /// Values of the technique parameters, keyed by parameter name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub values: Dictionary<Value>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A set of primitives to be rendered.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Mesh {
+    /// The primitives of the mesh.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub primitives: Vec<Primitive>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Geometry to be rendered with the given material.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Primitive {
+    /// Maps attribute semantics, such as `"POSITION"`, to accessor ids.
+    #[serde(default)]
+    pub attributes: Dictionary<String>,
+
+    /// The id of the accessor containing the indices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indices: Option<String>,
+
+    /// The id of the material to apply.
+    pub material: String,
+
+    /// The type of primitives to render.
+    #[serde(default = "mode_default")]
+    pub mode: u32,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+fn mode_default() -> u32 {
+    4
+}
+
+/// A node in the node hierarchy.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Node {
+    /// The id of the camera referenced by this node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<String>,
+
+    /// The ids of this node's children.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<String>,
+
+    /// The ids of the skeleton root nodes of the skin.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skeletons: Vec<String>,
+
+    /// The id of the skin referenced by this node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skin: Option<String>,
+
+    /// The name of this node when it is used as a skin joint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joint_name: Option<String>,
+
+    /// 4x4 column-major transformation matrix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matrix: Option<[f32; 16]>,
+
+    /// The ids of the meshes referenced by this node.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub meshes: Vec<String>,
+
+    /// The node's unit quaternion rotation in the order (x, y, z, w).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<[f32; 4]>,
+
+    /// The node's non-uniform scale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<[f32; 3]>,
+
+    /// The node's translation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<[f32; 3]>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A shader program, including its vertex and fragment shaders.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Program {
+    /// Names of the GLSL vertex shader attributes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<String>,
+
+    /// The id of the fragment shader.
+    pub fragment_shader: String,
+
+    /// The id of the vertex shader.
+    pub vertex_shader: String,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Texture sampler properties for filtering and wrapping modes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sampler {
+    /// Magnification filter.
+    #[serde(default = "mag_filter_default")]
+    pub mag_filter: u32,
+
+    /// Minification filter.
+    #[serde(default = "min_filter_default")]
+    pub min_filter: u32,
+
+    /// `s` wrapping mode.
+    #[serde(default = "wrap_default")]
+    pub wrap_s: u32,
+
+    /// `t` wrapping mode.
+    #[serde(default = "wrap_default")]
+    pub wrap_t: u32,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+fn mag_filter_default() -> u32 {
+    9729
+}
+
+fn min_filter_default() -> u32 {
+    9986
+}
+
+fn wrap_default() -> u32 {
+    10497
+}
+
+/// The root nodes of a scene.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Scene {
+    /// The ids of the root nodes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nodes: Vec<String>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A GLSL vertex or fragment shader.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Shader {
+    /// The uri of the GLSL source.
+    pub uri: String,
+
+    /// The shader stage, either `FRAGMENT_SHADER` or `VERTEX_SHADER`.
+    #[serde(rename = "type")]
+    pub type_: u32,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// Joints and matrices defining a skin.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Skin {
+    /// Matrix pre-multiplied with each vertex before skinning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_shape_matrix: Option<[f32; 16]>,
+
+    /// The id of the accessor containing the inverse bind matrices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_bind_matrices: Option<String>,
+
+    /// The `jointName` of each node used as a joint.
+    pub joint_names: Vec<String>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// The shading technique of a material.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Technique {
+    /// Parameters of the technique, keyed by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: Dictionary<Parameter>,
+
+    /// Maps GLSL attribute names to technique parameter names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: Dictionary<String>,
+
+    /// The id of the shader program.
+    pub program: String,
+
+    /// Maps GLSL uniform names to technique parameter names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub uniforms: Dictionary<String>,
+
+    /// Fixed-function rendering states.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub states: Option<Value>,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// An attribute or uniform input of a technique.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Parameter {
+    /// The number of elements when the parameter is an array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The id of the node whose transform is used by the parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<String>,
+
+    /// The GL data type of the parameter.
+    #[serde(rename = "type")]
+    pub type_: u32,
+
+    /// The semantic of the parameter, for example `"MODELVIEW"` or `"POSITION"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semantic: Option<String>,
+
+    /// The default value of the parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+/// A texture and its sampler.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Texture {
+    /// The texture's format.
+    #[serde(default = "texture_format_default")]
+    pub format: u32,
+
+    /// The texture's internal format.
+    #[serde(default = "texture_format_default")]
+    pub internal_format: u32,
+
+    /// The id of the sampler used by this texture.
+    pub sampler: String,
+
+    /// The id of the image used by this texture.
+    pub source: String,
+
+    /// The target the texture should be bound to.
+    #[serde(default = "texture_target_default")]
+    pub target: u32,
+
+    /// The data type of the texels.
+    #[serde(default = "texture_type_default", rename = "type")]
+    pub type_: u32,
+
+    /// Optional user-defined name for this object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Extension specific data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Extensions>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+fn texture_format_default() -> u32 {
+    6408
+}
+
+fn texture_target_default() -> u32 {
+    3553
+}
+
+fn texture_type_default() -> u32 {
+    UNSIGNED_BYTE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Root;
+
+    #[test]
+    fn parse_v1_triangle() {
+        let json = r#"{
+            "asset": {"version": "1.0", "profile": {"api": "WebGL", "version": "1.0.2"}},
+            "scene": "defaultScene",
+            "scenes": {"defaultScene": {"nodes": ["node_0"]}},
+            "nodes": {"node_0": {"meshes": ["mesh_0"]}},
+            "meshes": {
+                "mesh_0": {
+                    "primitives": [{
+                        "attributes": {"POSITION": "accessor_0"},
+                        "material": "material_0"
+                    }]
+                }
+            },
+            "accessors": {
+                "accessor_0": {
+                    "bufferView": "bufferView_0",
+                    "byteOffset": 0,
+                    "componentType": 5126,
+                    "count": 3,
+                    "type": "VEC3"
+                }
+            },
+            "bufferViews": {
+                "bufferView_0": {"buffer": "buffer_0", "byteOffset": 0, "byteLength": 36}
+            },
+            "buffers": {"buffer_0": {"uri": "triangle.bin", "byteLength": 36}},
+            "materials": {
+                "material_0": {"technique": "technique_0", "values": {"diffuse": [1, 0, 0, 1]}}
+            },
+            "techniques": {
+                "technique_0": {
+                    "parameters": {"position": {"type": 35665, "semantic": "POSITION"}},
+                    "attributes": {"a_position": "position"},
+                    "program": "program_0"
+                }
+            },
+            "programs": {
+                "program_0": {"fragmentShader": "fs", "vertexShader": "vs"}
+            },
+            "shaders": {
+                "fs": {"uri": "fs.glsl", "type": 35632},
+                "vs": {"uri": "vs.glsl", "type": 35633}
+            }
+        }"#;
+        let root = Root::from_str(json).unwrap();
+        assert_eq!(root.scene.as_deref(), Some("defaultScene"));
+        let primitive = &root.meshes["mesh_0"].primitives[0];
+        assert_eq!(primitive.mode, 4);
+        assert_eq!(primitive.attributes["POSITION"], "accessor_0");
+        assert_eq!(root.accessors["accessor_0"].byte_stride, 0);
+        assert_eq!(root.techniques["technique_0"].program, "program_0");
+        assert_eq!(root.shaders["vs"].type_, super::VERTEX_SHADER);
+        assert_eq!(root.asset.unwrap().profile.unwrap().api, "WebGL".to_owned());
+    }
+}
@@ -177,3 +177,4 @@ impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
 impl Validate for serde_json::Value {}
+impl Validate for serde_json::Map<String, serde_json::Value> {}
@@ -0,0 +1,280 @@
+use crate::{
+    buffer, texture, Accessor, Buffer, Camera, Image, Index, Material, Mesh, Node, Root, Scene,
+    Skin, Texture,
+};
+
+/// Calls a function with every reference to an object of type `T`.
+pub(crate) type Visit<T> = fn(&mut Root, &mut dyn FnMut(&mut Index<T>));
+
+pub(crate) fn visit_materials(root: &mut Root, f: &mut dyn FnMut(&mut Index<Material>)) {
+    for primitive in root.meshes.iter_mut().flat_map(|mesh| &mut mesh.primitives) {
+        if let Some(index) = primitive.material.as_mut() {
+            f(index);
+        }
+        #[cfg(feature = "KHR_materials_variants")]
+        for mapping in primitive
+            .extensions
+            .iter_mut()
+            .flat_map(|extensions| &mut extensions.khr_materials_variants)
+            .flat_map(|variants| &mut variants.mappings)
+        {
+            let mut index = Index::new(mapping.material);
+            f(&mut index);
+            mapping.material = index.value() as u32;
+        }
+    }
+}
+
+pub(crate) fn visit_textures(root: &mut Root, f: &mut dyn FnMut(&mut Index<Texture>)) {
+    fn info(info: Option<&mut texture::Info>, f: &mut dyn FnMut(&mut Index<Texture>)) {
+        if let Some(info) = info {
+            f(&mut info.index);
+        }
+    }
+
+    for material in &mut root.materials {
+        let pbr = &mut material.pbr_metallic_roughness;
+        info(pbr.base_color_texture.as_mut(), f);
+        info(pbr.metallic_roughness_texture.as_mut(), f);
+        info(material.emissive_texture.as_mut(), f);
+        if let Some(texture) = material.normal_texture.as_mut() {
+            f(&mut texture.index);
+        }
+        if let Some(texture) = material.occlusion_texture.as_mut() {
+            f(&mut texture.index);
+        }
+
+        let extensions = match material.extensions.as_mut() {
+            Some(extensions) => extensions,
+            None => continue,
+        };
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        if let Some(x) = extensions.pbr_specular_glossiness.as_mut() {
+            info(x.diffuse_texture.as_mut(), f);
+            info(x.specular_glossiness_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        if let Some(x) = extensions.transmission.as_mut() {
+            info(x.transmission_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        if let Some(x) = extensions.volume.as_mut() {
+            info(x.thickness_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        if let Some(x) = extensions.specular.as_mut() {
+            info(x.specular_texture.as_mut(), f);
+            info(x.specular_color_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_clearcoat")]
+        if let Some(x) = extensions.clearcoat.as_mut() {
+            info(x.clearcoat_texture.as_mut(), f);
+            info(x.clearcoat_roughness_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_sheen")]
+        if let Some(x) = extensions.sheen.as_mut() {
+            info(x.sheen_color_texture.as_mut(), f);
+            info(x.sheen_roughness_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_anisotropy")]
+        if let Some(x) = extensions.anisotropy.as_mut() {
+            info(x.anisotropy_texture.as_mut(), f);
+        }
+        #[cfg(feature = "KHR_materials_iridescence")]
+        if let Some(x) = extensions.iridescence.as_mut() {
+            info(x.iridescence_texture.as_mut(), f);
+            info(x.iridescence_thickness_texture.as_mut(), f);
+        }
+        let _ = extensions;
+    }
+}
+
+pub(crate) fn visit_samplers(root: &mut Root, f: &mut dyn FnMut(&mut Index<texture::Sampler>)) {
+    for texture in &mut root.textures {
+        if let Some(index) = texture.sampler.as_mut() {
+            f(index);
+        }
+    }
+}
+
+pub(crate) fn visit_images(root: &mut Root, f: &mut dyn FnMut(&mut Index<Image>)) {
+    for texture in &mut root.textures {
+        if let Some(source) = texture.source.as_mut() {
+            f(source);
+        }
+        #[cfg(feature = "KHR_texture_basisu")]
+        if let Some(x) = texture
+            .extensions
+            .as_mut()
+            .and_then(|x| x.texture_basisu.as_mut())
+        {
+            f(&mut x.source);
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        if let Some(x) = texture
+            .extensions
+            .as_mut()
+            .and_then(|x| x.texture_webp.as_mut())
+        {
+            f(&mut x.source);
+        }
+    }
+}
+
+pub(crate) fn visit_accessors(root: &mut Root, f: &mut dyn FnMut(&mut Index<Accessor>)) {
+    for primitive in root.meshes.iter_mut().flat_map(|mesh| &mut mesh.primitives) {
+        primitive.attributes.values_mut().for_each(&mut *f);
+        if let Some(index) = primitive.indices.as_mut() {
+            f(index);
+        }
+        for target in primitive.targets.iter_mut().flatten() {
+            let accessors = [
+                &mut target.positions,
+                &mut target.normals,
+                &mut target.tangents,
+            ];
+            accessors.into_iter().flatten().for_each(&mut *f);
+        }
+    }
+    for skin in &mut root.skins {
+        if let Some(index) = skin.inverse_bind_matrices.as_mut() {
+            f(index);
+        }
+    }
+    for sampler in root.animations.iter_mut().flat_map(|x| &mut x.samplers) {
+        f(&mut sampler.input);
+        f(&mut sampler.output);
+    }
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    for instancing in root
+        .nodes
+        .iter_mut()
+        .filter_map(|node| node.extensions.as_mut())
+        .filter_map(|x| x.ext_mesh_gpu_instancing.as_mut())
+    {
+        instancing.attributes.values_mut().for_each(&mut *f);
+    }
+}
+
+pub(crate) fn visit_buffer_views(root: &mut Root, f: &mut dyn FnMut(&mut Index<buffer::View>)) {
+    for accessor in &mut root.accessors {
+        if let Some(index) = accessor.buffer_view.as_mut() {
+            f(index);
+        }
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            f(&mut sparse.indices.buffer_view);
+            f(&mut sparse.values.buffer_view);
+        }
+    }
+    for image in &mut root.images {
+        if let Some(index) = image.buffer_view.as_mut() {
+            f(index);
+        }
+    }
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    for draco in root
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| &mut mesh.primitives)
+        .filter_map(|primitive| primitive.extensions.as_mut())
+        .filter_map(|x| x.khr_draco_mesh_compression.as_mut())
+    {
+        f(&mut draco.buffer_view);
+    }
+}
+
+pub(crate) fn visit_buffers(root: &mut Root, f: &mut dyn FnMut(&mut Index<Buffer>)) {
+    for view in &mut root.buffer_views {
+        f(&mut view.buffer);
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if let Some(x) = view
+            .extensions
+            .as_mut()
+            .and_then(|x| x.ext_meshopt_compression.as_mut())
+        {
+            f(&mut x.buffer);
+        }
+    }
+}
+
+pub(crate) fn visit_cameras(root: &mut Root, f: &mut dyn FnMut(&mut Index<Camera>)) {
+    for node in &mut root.nodes {
+        if let Some(index) = node.camera.as_mut() {
+            f(index);
+        }
+    }
+}
+
+pub(crate) fn visit_meshes(root: &mut Root, f: &mut dyn FnMut(&mut Index<Mesh>)) {
+    for node in &mut root.nodes {
+        if let Some(index) = node.mesh.as_mut() {
+            f(index);
+        }
+    }
+}
+
+pub(crate) fn visit_nodes(root: &mut Root, f: &mut dyn FnMut(&mut Index<Node>)) {
+    for node in &mut root.nodes {
+        node.children.iter_mut().flatten().for_each(&mut *f);
+    }
+    for scene in &mut root.scenes {
+        scene.nodes.iter_mut().for_each(&mut *f);
+    }
+    for skin in &mut root.skins {
+        skin.joints.iter_mut().for_each(&mut *f);
+        skin.skeleton.iter_mut().for_each(&mut *f);
+    }
+    for channel in root.animations.iter_mut().flat_map(|x| &mut x.channels) {
+        f(&mut channel.target.node);
+    }
+}
+
+pub(crate) fn visit_scenes(root: &mut Root, f: &mut dyn FnMut(&mut Index<Scene>)) {
+    if let Some(index) = root.scene.as_mut() {
+        f(index);
+    }
+}
+
+pub(crate) fn visit_skins(root: &mut Root, f: &mut dyn FnMut(&mut Index<Skin>)) {
+    for node in &mut root.nodes {
+        if let Some(index) = node.skin.as_mut() {
+            f(index);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+pub(crate) fn visit_lights(
+    root: &mut Root,
+    f: &mut dyn FnMut(&mut Index<crate::extensions::scene::khr_lights_punctual::Light>),
+) {
+    for light in root
+        .nodes
+        .iter_mut()
+        .filter_map(|node| node.extensions.as_mut())
+        .filter_map(|x| x.khr_lights_punctual.as_mut())
+    {
+        f(&mut light.light);
+    }
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+pub(crate) fn visit_variants(
+    root: &mut Root,
+    f: &mut dyn FnMut(&mut Index<crate::extensions::scene::khr_materials_variants::Variant>),
+) {
+    for mapping in root
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| &mut mesh.primitives)
+        .filter_map(|primitive| primitive.extensions.as_mut())
+        .filter_map(|x| x.khr_materials_variants.as_mut())
+        .flat_map(|x| &mut x.mappings)
+    {
+        for variant in &mut mapping.variants {
+            let mut index = Index::new(*variant);
+            f(&mut index);
+            *variant = index.value() as u32;
+        }
+    }
+}
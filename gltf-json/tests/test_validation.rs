@@ -44,3 +44,227 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
         [(Path("accessors[0].bufferView".into()), Error::Missing)]
     );
 }
+
+#[test]
+fn test_invalid_indices_validate() {
+    let json = import_json("tests/invalid_indices.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("animations[0].channels[0].target.node".into()),
+                Error::IndexOutOfBounds
+            ),
+            (
+                Path("meshes[0].primitives[0].attributes[\"POSITION\"]".into()),
+                Error::IndexOutOfBounds
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_structure_validate() {
+    let json = import_json("tests/invalid_structure.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (Path("accessors[0].normalized".into()), Error::Invalid),
+            (Path("accessors[0].count".into()), Error::Invalid),
+            (
+                Path("animations[0].samplers[0].input".into()),
+                Error::Invalid
+            ),
+            (
+                Path("animations[0].channels[0].target.node".into()),
+                Error::Invalid
+            ),
+            (
+                Path("animations[0].samplers[0].output".into()),
+                Error::Invalid
+            ),
+            (Path("bufferViews[0].byteLength".into()), Error::Invalid),
+            (Path("nodes[0].matrix".into()), Error::Invalid),
+            (Path("samplers[0].magFilter".into()), Error::Invalid),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_morph_targets_validate() {
+    let json = import_json("tests/invalid_morph_targets.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("meshes[0].primitives[1].targets".into()),
+                Error::Invalid
+            ),
+            (Path("meshes[0].weights".into()), Error::Invalid),
+            (Path("nodes[0].weights".into()), Error::Invalid),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_invalid_lights_validate() {
+    let json = import_json("tests/invalid_lights.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("extensions.khrLightsPunctual.lights[0].intensity".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.khrLightsPunctual.lights[0].range".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.khrLightsPunctual.lights[1].spot.innerConeAngle".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.khrLightsPunctual.lights[2].spot.outerConeAngle".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.khrLightsPunctual.lights[3].spot".into()),
+                Error::Missing
+            ),
+            (
+                Path("nodes[1].extensions.khrLightsPunctual.light".into()),
+                Error::IndexOutOfBounds
+            ),
+        ]
+    );
+}
+
+#[cfg(all(
+    feature = "KHR_materials_ior",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume"
+))]
+#[test]
+fn test_invalid_material_extensions_validate() {
+    let json = import_json("tests/invalid_material_extensions.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("materials[0].extensions.transmission.transmissionFactor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.volume.thicknessFactor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.volume.attenuationDistance".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.volume.attenuationColor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.ior.ior".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+
+    // The default infinite attenuation distance cannot be written as JSON.
+    let json = gltf_json::Root::from_str(&json.to_string().unwrap()).unwrap();
+    let volume = json.materials[1]
+        .extensions
+        .as_ref()
+        .unwrap()
+        .volume
+        .as_ref();
+    assert_eq!(volume.unwrap().attenuation_distance.0, f32::INFINITY);
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[test]
+fn test_invalid_specular_validate() {
+    let json = import_json("tests/invalid_specular.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("materials[0].extensions.specular.specularFactor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.specular.specularColorFactor".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_images_validate() {
+    let json = import_json("tests/invalid_images.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (Path("images[2].uri".into()), Error::Missing),
+            (Path("images[3].bufferView".into()), Error::Invalid),
+            (Path("images[4].mimeType".into()), Error::Missing),
+            (Path("textures[1].source".into()), Error::Missing),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_sparse_validate() {
+    let json = import_json("tests/invalid_sparse.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("accessors[0].sparse.indices.byteOffset".into()),
+                Error::Invalid
+            ),
+            (
+                Path("accessors[1].sparse.values.byteOffset".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+}
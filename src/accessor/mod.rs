@@ -59,6 +59,7 @@ use crate::{buffer, Document};
 
 pub use json::accessor::ComponentType as DataType;
 pub use json::accessor::Type as Dimensions;
+pub use json::accessor::Values;
 
 /// Utility functions.
 #[cfg(feature = "utils")]
@@ -70,7 +71,7 @@ pub mod sparse;
 
 #[cfg(feature = "utils")]
 #[doc(inline)]
-pub use self::util::{Item, Iter};
+pub use self::util::{Interleaved, Item, Iter, Vertex};
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
@@ -154,6 +155,29 @@ impl<'a> Accessor<'a> {
         self.json.max.clone()
     }
 
+    /// Returns the minimum value of each component in this attribute, typed
+    /// by the component type, or `None` if it is missing or malformed.
+    pub fn min_values(&self) -> Option<Values> {
+        self.json.min_values()
+    }
+
+    /// Returns the maximum value of each component in this attribute, typed
+    /// by the component type, or `None` if it is missing or malformed.
+    pub fn max_values(&self) -> Option<Values> {
+        self.json.max_values()
+    }
+
+    /// Returns the `min` and `max` values of a three component accessor, such
+    /// as one containing `POSITION` data.
+    ///
+    /// Returns `None` if either value is missing or does not have exactly three
+    /// components.
+    pub fn bounds_vec3(&self) -> Option<crate::mesh::BoundingBox> {
+        let min = json::deserialize::from_value(self.json.min.clone()?).ok()?;
+        let max = json::deserialize::from_value(self.json.max.clone()?).ok()?;
+        Some(crate::mesh::Bounds { min, max })
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
@@ -4,6 +4,21 @@ use std::{iter, mem};
 
 use crate::{accessor, buffer};
 
+/// Returns the bytes spanned by `count` items of `size` bytes each, the first
+/// beginning at `offset` and each subsequent item `stride` bytes after the last.
+fn item_slice(
+    slice: &[u8],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    size: usize,
+) -> Option<&[u8]> {
+    match count.checked_sub(1) {
+        Some(last) => slice.get(offset..offset + stride * last + size),
+        None => Some(&[]),
+    }
+}
+
 fn buffer_view_slice<'a, 's>(
     view: buffer::View<'a>,
     get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
@@ -94,6 +109,9 @@ pub struct SparseIter<'a, T: Item> {
     /// This can be `None` if the base buffer view is not set. In this case the base values are all zero.
     base: Option<ItemIter<'a, T>>,
 
+    /// Number of elements in the accessor.
+    base_count: usize,
+
     /// Sparse indices iterator.
     indices: iter::Peekable<SparseIndicesIter<'a>>,
 
@@ -108,13 +126,34 @@ impl<'a, T: Item> SparseIter<'a, T> {
     /// Constructor.
     ///
     /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    /// The number of elements is taken from `base`. Without it, the number of elements is not
+    /// known, and the iterator ends after the last substituted element.
+    #[deprecated(note = "use `SparseIter::with_base_count` instead")]
     pub fn new(
         base: Option<ItemIter<'a, T>>,
         indices: SparseIndicesIter<'a>,
         values: ItemIter<'a, T>,
+    ) -> Self {
+        let base_count = match base {
+            Some(ref base) => base.len(),
+            None => indices.clone().last().map_or(0, |index| index as usize + 1),
+        };
+        Self::with_base_count(base, base_count, indices, values)
+    }
+
+    /// Constructs a `SparseIter` yielding `base_count` elements, the number of elements in the
+    /// accessor.
+    ///
+    /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    pub fn with_base_count(
+        base: Option<ItemIter<'a, T>>,
+        base_count: usize,
+        indices: SparseIndicesIter<'a>,
+        values: ItemIter<'a, T>,
     ) -> Self {
         SparseIter {
             base,
+            base_count,
             indices: indices.peekable(),
             values,
             counter: 0,
@@ -125,6 +164,10 @@ impl<'a, T: Item> SparseIter<'a, T> {
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
+        if self.counter as usize >= self.base_count {
+            return None;
+        }
+
         let mut next_value = self
             .base
             .as_mut()
@@ -145,7 +188,7 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.values.len() - self.counter as usize;
+        let hint = self.base_count.saturating_sub(self.counter as usize);
         (hint, Some(hint))
     }
 }
@@ -226,48 +269,88 @@ impl Item for f32 {
     }
 }
 
-impl<T: Item + Copy> Item for [T; 2] {
+impl<T: Item + Copy, const N: usize> Item for [T; N] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 2 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-        ]
+        let size = mem::size_of::<T>();
+        assert!(slice.len() >= N * size);
+        let mut array = [T::zero(); N];
+        for (i, item) in array.iter_mut().enumerate() {
+            *item = T::from_slice(&slice[i * size..]);
+        }
+        array
     }
     fn zero() -> Self {
-        [T::zero(); 2]
+        [T::zero(); N]
     }
 }
 
-impl<T: Item + Copy> Item for [T; 3] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 3 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-        ]
-    }
-    fn zero() -> Self {
-        [T::zero(); 3]
-    }
+/// Implements `Item` for `mint` types by reading the array they convert from.
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_item {
+    ($($ty:ident => $array:ty),*) => {$(
+        impl<T: Item + Copy> Item for mint::$ty<T> {
+            fn from_slice(slice: &[u8]) -> Self {
+                <$array>::from_slice(slice).into()
+            }
+            fn zero() -> Self {
+                <$array>::zero().into()
+            }
+        }
+    )*};
 }
 
-impl<T: Item + Copy> Item for [T; 4] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 4 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-            T::from_slice(&slice[3 * mem::size_of::<T>()..]),
-        ]
-    }
-    fn zero() -> Self {
-        [T::zero(); 4]
-    }
+#[cfg(feature = "mint")]
+impl_mint_item!(
+    Vector2 => [T; 2],
+    Vector3 => [T; 3],
+    Vector4 => [T; 4],
+    Quaternion => [T; 4],
+    ColumnMatrix2 => [[T; 2]; 2],
+    ColumnMatrix3 => [[T; 3]; 3],
+    ColumnMatrix4 => [[T; 4]; 4]
+);
+
+/// Implements `Item` for `cgmath` and `nalgebra` types by reading the `mint`
+/// type they convert from.
+#[cfg(any(feature = "cgmath", feature = "nalgebra"))]
+macro_rules! impl_item_via_mint {
+    ($($ty:ty => $mint:ident),*) => {$(
+        impl<T: Item + Copy> Item for $ty
+        where
+            mint::$mint<T>: Into<Self>,
+        {
+            fn from_slice(slice: &[u8]) -> Self {
+                <mint::$mint<T> as Item>::from_slice(slice).into()
+            }
+            fn zero() -> Self {
+                <mint::$mint<T> as Item>::zero().into()
+            }
+        }
+    )*};
 }
 
+#[cfg(feature = "cgmath")]
+impl_item_via_mint!(
+    cgmath::Vector2<T> => Vector2,
+    cgmath::Vector3<T> => Vector3,
+    cgmath::Vector4<T> => Vector4,
+    cgmath::Quaternion<T> => Quaternion,
+    cgmath::Matrix2<T> => ColumnMatrix2,
+    cgmath::Matrix3<T> => ColumnMatrix3,
+    cgmath::Matrix4<T> => ColumnMatrix4
+);
+
+#[cfg(feature = "nalgebra")]
+impl_item_via_mint!(
+    nalgebra::Vector2<T> => Vector2,
+    nalgebra::Vector3<T> => Vector3,
+    nalgebra::Vector4<T> => Vector4,
+    nalgebra::Quaternion<T> => Quaternion,
+    nalgebra::Matrix2<T> => ColumnMatrix2,
+    nalgebra::Matrix3<T> => ColumnMatrix3,
+    nalgebra::Matrix4<T> => ColumnMatrix4
+);
+
 impl<'a, T: Item> ItemIter<'a, T> {
     /// Constructor.
     pub fn new(slice: &'a [u8], stride: usize) -> Self {
@@ -281,20 +364,32 @@ impl<'a, T: Item> ItemIter<'a, T> {
 
 impl<'a, 's, T: Item> Iter<'s, T> {
     /// Constructor.
+    ///
+    /// Returns `None` if the size of `T` does not match the accessor's element
+    /// size or if the accessor data is out of the range of the buffer.
     pub fn new<F>(accessor: super::Accessor<'a>, get_buffer_data: F) -> Option<Iter<'s, T>>
     where
         F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
     {
+        if mem::size_of::<T>() != accessor.size() {
+            return None;
+        }
+
         match accessor.sparse() {
             Some(sparse) => {
                 // Using `if let` here instead of map to preserve the early return behavior.
                 let base_iter = if let Some(view) = accessor.view() {
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
 
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let subslice = buffer_view_slice(view, &get_buffer_data).and_then(|slice| {
+                        item_slice(
+                            slice,
+                            accessor.offset(),
+                            stride,
+                            accessor.count(),
+                            mem::size_of::<T>(),
+                        )
+                    })?;
 
                     Some(ItemIter::new(subslice, stride))
                 } else {
@@ -310,10 +405,15 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                     let index_size = indices.index_type().size();
                     let stride = view.stride().unwrap_or(index_size);
 
-                    let start = indices.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let subslice = buffer_view_slice(view, &get_buffer_data).and_then(|slice| {
+                        item_slice(
+                            slice,
+                            indices.offset() as usize,
+                            stride,
+                            sparse_count,
+                            index_size,
+                        )
+                    })?;
 
                     match indices.index_type() {
                         accessor::sparse::IndexType::U8 => {
@@ -332,20 +432,27 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                     let view = values.view();
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
 
-                    let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let subslice = buffer_view_slice(view, &get_buffer_data).and_then(|slice| {
+                        item_slice(
+                            slice,
+                            values.offset() as usize,
+                            stride,
+                            sparse_count,
+                            mem::size_of::<T>(),
+                        )
+                    })?;
 
                     ItemIter::new(subslice, stride)
                 };
 
-                Some(Iter::Sparse(SparseIter::new(
-                    base_iter, index_iter, value_iter,
+                Some(Iter::Sparse(SparseIter::with_base_count(
+                    base_iter,
+                    accessor.count(),
+                    index_iter,
+                    value_iter,
                 )))
             }
             None => {
-                debug_assert_eq!(mem::size_of::<T>(), accessor.size());
                 debug_assert!(mem::size_of::<T>() > 0);
 
                 accessor.view().and_then(|view| {
@@ -357,10 +464,15 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                         stride
                     );
 
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let subslice = buffer_view_slice(view, &get_buffer_data).and_then(|slice| {
+                        item_slice(
+                            slice,
+                            accessor.offset(),
+                            stride,
+                            accessor.count(),
+                            mem::size_of::<T>(),
+                        )
+                    })?;
 
                     Some(Iter::Standard(ItemIter {
                         stride,
@@ -429,3 +541,274 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+/// Visits the vertices of several attributes interleaved in one buffer view.
+///
+/// Each vertex is yielded as a [`Vertex`] holding the bytes of every
+/// attribute, in the order the accessors were given.
+#[derive(Clone, Debug)]
+pub struct Interleaved<'s, const N: usize> {
+    /// The buffer view data, from the start of the first vertex.
+    data: &'s [u8],
+
+    /// The distance in bytes between the start of consecutive vertices.
+    stride: usize,
+
+    /// The offset and size in bytes of each attribute within a vertex.
+    layout: [(usize, usize); N],
+
+    /// The index of the next vertex.
+    index: usize,
+
+    /// The number of vertices.
+    count: usize,
+}
+
+/// The attributes of one vertex read by [`Interleaved`].
+#[derive(Copy, Clone, Debug)]
+pub struct Vertex<'s, const N: usize>([&'s [u8]; N]);
+
+impl<'s, const N: usize> Vertex<'s, N> {
+    /// Returns the bytes of the attribute at `attribute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is out of range.
+    pub fn bytes(&self, attribute: usize) -> &'s [u8] {
+        self.0[attribute]
+    }
+
+    /// Reads the attribute at `attribute` as an item of type `T`.
+    ///
+    /// Returns `None` if the size of `T` does not match the attribute's
+    /// element size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is out of range.
+    pub fn read<T: Item>(&self, attribute: usize) -> Option<T> {
+        let bytes = self.0[attribute];
+        if bytes.len() == mem::size_of::<T>() {
+            Some(T::from_slice(bytes))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, 's, const N: usize> Interleaved<'s, N> {
+    /// Constructor.
+    ///
+    /// Returns `None` unless all accessors read the same number of elements
+    /// from the same buffer view without sparse storage, if the elements of
+    /// an accessor do not fit within the buffer view's byte stride, or if the
+    /// accessor data is out of the range of the buffer. A buffer view without
+    /// a byte stride is only accepted for a single accessor.
+    pub fn new<F>(accessors: [super::Accessor<'a>; N], get_buffer_data: F) -> Option<Self>
+    where
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let first = accessors.first()?;
+        let view = first.view()?;
+        let count = first.count();
+        let stride = match view.stride() {
+            Some(stride) => stride,
+            None if N == 1 => first.size(),
+            None => return None,
+        };
+        let data = buffer_view_slice(view.clone(), &get_buffer_data)?;
+
+        let mut layout = [(0, 0); N];
+        for (accessor, layout) in accessors.iter().zip(&mut layout) {
+            if accessor.sparse().is_some()
+                || accessor.view().map(|x| x.index()) != Some(view.index())
+                || accessor.count() != count
+                || accessor.offset() % stride + accessor.size() > stride
+            {
+                return None;
+            }
+            item_slice(data, accessor.offset(), stride, count, accessor.size())?;
+            *layout = (accessor.offset(), accessor.size());
+        }
+
+        Some(Interleaved {
+            data,
+            stride,
+            layout,
+            index: 0,
+            count,
+        })
+    }
+}
+
+impl<'s, const N: usize> Iterator for Interleaved<'s, N> {
+    type Item = Vertex<'s, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let start = self.index * self.stride;
+        let mut attributes: [&'s [u8]; N] = [&[]; N];
+        for (bytes, &(offset, size)) in attributes.iter_mut().zip(&self.layout) {
+            *bytes = &self.data[start + offset..start + offset + size];
+        }
+        self.index += 1;
+        Some(Vertex(attributes))
+    }
+
+    fn nth(&mut self, nth: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(nth).min(self.count);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.count - self.index;
+        (hint, Some(hint))
+    }
+}
+
+impl<'s, const N: usize> ExactSizeIterator for Interleaved<'s, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 12 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 12 }],
+        "accessors": [
+            { "bufferView": 0, "byteOffset": 4, "componentType": 5123, "count": 4, "type": "SCALAR" },
+            { "bufferView": 0, "componentType": 5123, "count": 0, "type": "SCALAR" },
+            {
+                "componentType": 5123,
+                "count": 5,
+                "type": "SCALAR",
+                "sparse": {
+                    "count": 2,
+                    "indices": { "bufferView": 0, "byteOffset": 4, "componentType": 5123 },
+                    "values": { "bufferView": 0, "byteOffset": 8 }
+                }
+            }
+        ]
+    }"#;
+
+    const DATA: [u8; 12] = [0, 0, 0, 0, 1, 0, 2, 0, 3, 0, 4, 0];
+
+    #[test]
+    fn iter_honours_offset_and_component_type() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let accessor = gltf.accessors().next().unwrap();
+
+        let iter = Iter::<u16>::new(accessor.clone(), |_| Some(&DATA[..])).unwrap();
+        assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        // Mismatched item sizes are rejected rather than misread.
+        assert!(Iter::<f32>::new(accessor, |_| Some(&DATA[..])).is_none());
+    }
+
+    #[test]
+    fn iter_with_zero_count_is_empty() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let accessor = gltf.accessors().nth(1).unwrap();
+        let iter = Iter::<u16>::new(accessor, |_| Some(&DATA[..])).unwrap();
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    fn sparse_iter_without_buffer_view() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let accessor = gltf.accessors().nth(2).unwrap();
+        let iter = Iter::<u16>::new(accessor, |_| Some(&DATA[..])).unwrap();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.collect::<Vec<_>>(), [0, 3, 4, 0, 0]);
+
+        // Without a count the iterator ends after the last substituted element.
+        let indices = SparseIndicesIter::U16(ItemIter::new(&DATA[4..8], 2));
+        let values = ItemIter::new(&DATA[8..12], 2);
+        #[allow(deprecated)]
+        let iter = SparseIter::<u16>::new(None, indices, values);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), [0, 3, 4]);
+    }
+
+    #[test]
+    fn interleaved_attributes() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 32 }],
+            "bufferViews": [{ "buffer": 0, "byteLength": 32, "byteStride": 16 }],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3" },
+                { "bufferView": 0, "byteOffset": 12, "componentType": 5123, "count": 2, "type": "VEC2" }
+            ]
+        }"#;
+        let mut data = Vec::new();
+        for (position, uv) in [([1.0f32, 2.0, 3.0], [4u16, 5]), ([6.0, 7.0, 8.0], [9, 10])] {
+            position.iter().for_each(|x| data.extend(x.to_le_bytes()));
+            uv.iter().for_each(|x| data.extend(x.to_le_bytes()));
+        }
+        let gltf = crate::Gltf::from_slice(json.as_bytes()).unwrap();
+        let mut accessors = gltf.accessors();
+        let positions = accessors.next().unwrap();
+        let uvs = accessors.next().unwrap();
+
+        let iter = Iter::<[u16; 2]>::new(uvs.clone(), |_| Some(&data[..])).unwrap();
+        assert_eq!(iter.collect::<Vec<_>>(), [[4, 5], [9, 10]]);
+
+        let vertices: Vec<_> = Interleaved::new([positions.clone(), uvs], |_| Some(&data[..]))
+            .unwrap()
+            .map(|x| {
+                (
+                    x.read::<[f32; 3]>(0).unwrap(),
+                    x.read::<[u16; 2]>(1).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vertices,
+            [([1.0, 2.0, 3.0], [4, 5]), ([6.0, 7.0, 8.0], [9, 10])]
+        );
+
+        // The same attribute twice is fine, a sparse accessor is not.
+        assert!(Interleaved::new([positions.clone(), positions], |_| Some(&data[..])).is_some());
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let sparse = gltf.accessors().nth(2).unwrap();
+        assert!(Interleaved::new([sparse], |_| Some(&DATA[..])).is_none());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn iter_mint_items() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let accessor = gltf.accessors().next().unwrap();
+        // Reinterpret the four scalars as two vectors.
+        let items = ItemIter::<mint::Vector2<u16>>::new(&DATA[4..], 4);
+        assert_eq!(
+            items.collect::<Vec<_>>(),
+            [mint::Vector2 { x: 1, y: 2 }, mint::Vector2 { x: 3, y: 4 }]
+        );
+        assert!(Iter::<mint::Vector2<u16>>::new(accessor, |_| Some(&DATA[..])).is_none());
+    }
+
+    #[cfg(feature = "cgmath")]
+    #[test]
+    fn iter_cgmath_items() {
+        let items = ItemIter::<cgmath::Vector2<u16>>::new(&DATA[4..], 4);
+        assert_eq!(
+            items.collect::<Vec<_>>(),
+            [cgmath::Vector2::new(1, 2), cgmath::Vector2::new(3, 4)]
+        );
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn iter_nalgebra_items() {
+        let items = ItemIter::<nalgebra::Vector2<u16>>::new(&DATA[4..], 4);
+        assert_eq!(
+            items.collect::<Vec<_>>(),
+            [nalgebra::Vector2::new(1, 2), nalgebra::Vector2::new(3, 4)]
+        );
+    }
+}
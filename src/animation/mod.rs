@@ -126,7 +126,16 @@ impl<'a> Channel<'a> {
     /// Returns the sampler in this animation used to compute the value for the
     /// target.
     pub fn sampler(&self) -> Sampler<'a> {
-        self.anim.samplers().nth(self.json.sampler.value()).unwrap()
+        self.try_sampler().unwrap()
+    }
+
+    /// Returns the sampler in this animation used to compute the value for the
+    /// target.
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_sampler(&self) -> Option<Sampler<'a>> {
+        self.anim.samplers().nth(self.json.sampler.value())
     }
 
     /// Returns the node and property to target.
@@ -171,11 +180,15 @@ impl<'a> Target<'a> {
 
     /// Returns the target node.
     pub fn node(&self) -> scene::Node<'a> {
-        self.anim
-            .document
-            .nodes()
-            .nth(self.json.node.value())
-            .unwrap()
+        self.try_node().unwrap()
+    }
+
+    /// Returns the target node.
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_node(&self) -> Option<scene::Node<'a>> {
+        self.anim.document.nodes().nth(self.json.node.value())
     }
 
     /// Returns the node's property to modify or the 'weights' of the morph
@@ -203,11 +216,15 @@ impl<'a> Sampler<'a> {
 
     /// Returns the accessor containing the keyframe input values (e.g. time).
     pub fn input(&self) -> accessor::Accessor<'a> {
-        self.anim
-            .document
-            .accessors()
-            .nth(self.json.input.value())
-            .unwrap()
+        self.try_input().unwrap()
+    }
+
+    /// Returns the accessor containing the keyframe input values (e.g. time).
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_input(&self) -> Option<accessor::Accessor<'a>> {
+        self.anim.document.accessors().nth(self.json.input.value())
     }
 
     /// Returns the keyframe interpolation algorithm.
@@ -217,10 +234,14 @@ impl<'a> Sampler<'a> {
 
     /// Returns the accessor containing the keyframe output values.
     pub fn output(&self) -> accessor::Accessor<'a> {
-        self.anim
-            .document
-            .accessors()
-            .nth(self.json.output.value())
-            .unwrap()
+        self.try_output().unwrap()
+    }
+
+    /// Returns the accessor containing the keyframe output values.
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_output(&self) -> Option<accessor::Accessor<'a>> {
+        self.anim.document.accessors().nth(self.json.output.value())
     }
 }
@@ -58,6 +58,41 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// The value of an animated property at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sample {
+    /// XYZ translation.
+    Translation([f32; 3]),
+
+    /// XYZW rotation quaternion, where W is the scalar.
+    Rotation([f32; 4]),
+
+    /// XYZ scale.
+    Scale([f32; 3]),
+
+    /// Morph target weights.
+    MorphTargetWeights(Vec<f32>),
+}
+
+/// The keyframes of a channel, paired with their times in seconds.
+///
+/// For cubic spline samplers only the keyframe values are given; the tangents
+/// are available through [`Reader::read_outputs`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Keyframes {
+    /// XYZ translations.
+    Translations(Vec<(f32, [f32; 3])>),
+
+    /// XYZW rotation quaternions, where W is the scalar.
+    Rotations(Vec<(f32, [f32; 4])>),
+
+    /// XYZ scales.
+    Scales(Vec<(f32, [f32; 3])>),
+
+    /// Morph target weights, one per morph target.
+    MorphTargetWeights(Vec<(f32, Vec<f32>)>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -185,4 +220,401 @@ where
             },
         }
     }
+
+    /// Reads the keyframes of a channel, pairing each input time with its
+    /// output value.
+    ///
+    /// Rotations and morph target weights stored as normalized integers are
+    /// converted to `f32`. Returns `None` if the sampler data cannot be read,
+    /// if the type of the output accessor does not match the target path, or
+    /// if the number of outputs does not match the number of inputs.
+    pub fn read_keyframes(&self) -> Option<Keyframes> {
+        use crate::accessor::{DataType, Dimensions};
+        use crate::animation::{Interpolation, Property};
+
+        let output = self.channel.sampler().output();
+        let property = self.channel.target().property();
+        let valid = match property {
+            Property::Translation | Property::Scale => {
+                output.dimensions() == Dimensions::Vec3 && output.data_type() == DataType::F32
+            }
+            Property::Rotation => output.dimensions() == Dimensions::Vec4,
+            Property::MorphTargetWeights => output.dimensions() == Dimensions::Scalar,
+        };
+        if !valid {
+            return None;
+        }
+
+        let inputs: Vec<f32> = self.read_inputs()?.collect();
+        // Cubic spline keyframes store an in-tangent, a value, and an out-tangent.
+        let (stride, offset) = match self.channel.sampler().interpolation() {
+            Interpolation::CubicSpline => (3, 1),
+            _ => (1, 0),
+        };
+        let elements = stride * inputs.len();
+        if elements == 0 || output.count() % elements != 0 {
+            return None;
+        }
+        let width = output.count() / elements;
+        if property != Property::MorphTargetWeights && width != 1 {
+            return None;
+        }
+
+        fn pair<T>(
+            inputs: Vec<f32>,
+            outputs: impl Iterator<Item = T>,
+            stride: usize,
+            offset: usize,
+        ) -> Vec<(f32, T)> {
+            inputs
+                .into_iter()
+                .zip(outputs.skip(offset).step_by(stride))
+                .collect()
+        }
+
+        Some(match self.read_outputs()? {
+            ReadOutputs::Translations(iter) => {
+                Keyframes::Translations(pair(inputs, iter, stride, offset))
+            }
+            ReadOutputs::Rotations(iter) => {
+                Keyframes::Rotations(pair(inputs, iter.into_f32(), stride, offset))
+            }
+            ReadOutputs::Scales(iter) => Keyframes::Scales(pair(inputs, iter, stride, offset)),
+            ReadOutputs::MorphTargetWeights(iter) => {
+                let weights: Vec<f32> = iter.into_f32().collect();
+                let chunks = weights.chunks_exact(width).map(<[f32]>::to_vec);
+                Keyframes::MorphTargetWeights(pair(inputs, chunks, stride, offset))
+            }
+        })
+    }
+
+    /// Evaluates the channel at the given time in seconds.
+    ///
+    /// Times before the first keyframe or after the last keyframe are clamped to
+    /// the first and last keyframe respectively. Returns `None` if `time` is not
+    /// finite, or if the sampler data cannot be read or does not match the
+    /// target property.
+    ///
+    /// ### Note
+    ///
+    /// The sampler data is read on every call.
+    pub fn sample(&self, time: f32) -> Option<Sample> {
+        use crate::animation::{Interpolation, Property};
+
+        if !time.is_finite() {
+            return None;
+        }
+        let inputs: Vec<f32> = self.read_inputs()?.collect();
+        let outputs: Vec<f32> = match self.read_outputs()? {
+            ReadOutputs::Translations(iter) | ReadOutputs::Scales(iter) => iter.flatten().collect(),
+            ReadOutputs::Rotations(iter) => iter.into_f32().flatten().collect(),
+            ReadOutputs::MorphTargetWeights(iter) => iter.into_f32().collect(),
+        };
+        let interpolation = self.channel.sampler().interpolation();
+        // Cubic spline keyframes store an in-tangent, a value, and an out-tangent.
+        let elements = match interpolation {
+            Interpolation::CubicSpline => 3 * inputs.len(),
+            _ => inputs.len(),
+        };
+        if elements == 0 || outputs.len() % elements != 0 {
+            return None;
+        }
+        let width = outputs.len() / elements;
+        let property = self.channel.target().property();
+        let expected_width = match property {
+            Property::Translation | Property::Scale => 3,
+            Property::Rotation => 4,
+            Property::MorphTargetWeights => width,
+        };
+        if width != expected_width {
+            return None;
+        }
+        let element = |index: usize| &outputs[index * width..(index + 1) * width];
+        let keyframe = |index: usize| match interpolation {
+            Interpolation::CubicSpline => element(3 * index + 1),
+            _ => element(index),
+        };
+
+        let last = inputs.len() - 1;
+        let value = if time <= inputs[0] {
+            keyframe(0).to_vec()
+        } else if time >= inputs[last] {
+            keyframe(last).to_vec()
+        } else {
+            // Unsorted inputs of unvalidated documents may place the partition
+            // point at the first keyframe.
+            let next = inputs.partition_point(|&input| input <= time).max(1);
+            let previous = next - 1;
+            let delta = inputs[next] - inputs[previous];
+            let factor = (time - inputs[previous]) / delta;
+            match interpolation {
+                Interpolation::Step => keyframe(previous).to_vec(),
+                Interpolation::Linear if property == Property::Rotation => {
+                    slerp(keyframe(previous), keyframe(next), factor)
+                }
+                Interpolation::Linear => lerp(keyframe(previous), keyframe(next), factor),
+                Interpolation::CubicSpline => {
+                    let out_tangent = element(3 * previous + 2);
+                    let in_tangent = element(3 * next);
+                    let value = cubic_spline(
+                        keyframe(previous),
+                        out_tangent,
+                        keyframe(next),
+                        in_tangent,
+                        delta,
+                        factor,
+                    );
+                    if property == Property::Rotation {
+                        normalize(value)
+                    } else {
+                        value
+                    }
+                }
+            }
+        };
+
+        Some(match property {
+            Property::Translation => Sample::Translation([value[0], value[1], value[2]]),
+            Property::Rotation => Sample::Rotation([value[0], value[1], value[2], value[3]]),
+            Property::Scale => Sample::Scale([value[0], value[1], value[2]]),
+            Property::MorphTargetWeights => Sample::MorphTargetWeights(value),
+        })
+    }
+}
+
+/// Linearly interpolates between `a` and `b`.
+fn lerp(a: &[f32], b: &[f32], factor: f32) -> Vec<f32> {
+    a.iter().zip(b).map(|(a, b)| a + (b - a) * factor).collect()
+}
+
+/// Spherically interpolates between the unit quaternions `a` and `b`.
+fn slerp(a: &[f32], b: &[f32], factor: f32) -> Vec<f32> {
+    let dot: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+    // Take the shortest path around the hypersphere.
+    let (dot, sign) = if dot < 0.0 { (-dot, -1.0) } else { (dot, 1.0) };
+    let (wa, wb) = if dot > 0.9995 {
+        // The quaternions are nearly parallel, so fall back to linear interpolation.
+        (1.0 - factor, factor)
+    } else {
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        (
+            ((1.0 - factor) * theta).sin() / sin_theta,
+            (factor * theta).sin() / sin_theta,
+        )
+    };
+    normalize(
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| wa * a + sign * wb * b)
+            .collect(),
+    )
+}
+
+/// Evaluates the cubic Hermite spline between the values `a` and `b` with the
+/// tangents `a_out` and `b_in`, where `delta` is the time between the keyframes.
+fn cubic_spline(
+    a: &[f32],
+    a_out: &[f32],
+    b: &[f32],
+    b_in: &[f32],
+    delta: f32,
+    factor: f32,
+) -> Vec<f32> {
+    let s2 = factor * factor;
+    let s3 = s2 * factor;
+    let pa = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let ma = s3 - 2.0 * s2 + factor;
+    let pb = -2.0 * s3 + 3.0 * s2;
+    let mb = s3 - s2;
+    (0..a.len())
+        .map(|i| pa * a[i] + ma * delta * a_out[i] + pb * b[i] + mb * delta * b_in[i])
+        .collect()
+}
+
+/// Scales `v` to unit length.
+fn normalize(v: Vec<f32>) -> Vec<f32> {
+    let magnitude = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    v.into_iter().map(|x| x / magnitude).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [{}],
+        "buffers": [{ "byteLength": 136 }],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 8 },
+            { "buffer": 0, "byteOffset": 8, "byteLength": 24 },
+            { "buffer": 0, "byteOffset": 32, "byteLength": 32 },
+            { "buffer": 0, "byteOffset": 64, "byteLength": 72 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR" },
+            { "bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3" },
+            { "bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC4" },
+            { "bufferView": 3, "componentType": 5126, "count": 6, "type": "VEC3" },
+            { "bufferView": 1, "componentType": 5126, "count": 4, "type": "SCALAR" }
+        ],
+        "animations": [{
+            "samplers": [
+                { "input": 0, "output": 1 },
+                { "input": 0, "output": 1, "interpolation": "STEP" },
+                { "input": 0, "output": 2 },
+                { "input": 0, "output": 3, "interpolation": "CUBICSPLINE" },
+                { "input": 0, "output": 4, "interpolation": "STEP" }
+            ],
+            "channels": [
+                { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                { "sampler": 1, "target": { "node": 0, "path": "scale" } },
+                { "sampler": 2, "target": { "node": 0, "path": "rotation" } },
+                { "sampler": 3, "target": { "node": 0, "path": "translation" } },
+                { "sampler": 4, "target": { "node": 0, "path": "weights" } }
+            ]
+        }]
+    }"#;
+
+    fn data() -> Vec<u8> {
+        let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+        #[rustfmt::skip]
+        let values = [
+            // Inputs.
+            0.0, 1.0,
+            // Translations and scales.
+            0.0, 0.0, 0.0,
+            2.0, 4.0, 6.0,
+            // Rotations.
+            0.0, 0.0, 0.0, 1.0,
+            0.0, 0.0, half_sqrt_2, half_sqrt_2,
+            // Cubic spline in-tangents, values and out-tangents.
+            0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0,
+            2.0, 4.0, 6.0,
+            0.0, 0.0, 0.0,
+        ];
+        values.iter().flat_map(|x: &f32| x.to_le_bytes()).collect()
+    }
+
+    fn sample(channel: usize, time: f32) -> Sample {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let data = data();
+        let animation = gltf.animations().next().unwrap();
+        let channel = animation.channels().nth(channel).unwrap();
+        let reader = channel.reader(|_| Some(&data));
+        reader.sample(time).unwrap()
+    }
+
+    fn keyframes(channel: usize) -> Option<Keyframes> {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let data = data();
+        let animation = gltf.animations().next().unwrap();
+        let channel = animation.channels().nth(channel).unwrap();
+        let reader = channel.reader(|_| Some(&data));
+        reader.read_keyframes()
+    }
+
+    #[test]
+    fn read_keyframes() {
+        let translations = vec![(0.0, [0.0, 0.0, 0.0]), (1.0, [2.0, 4.0, 6.0])];
+        assert_eq!(
+            keyframes(0),
+            Some(Keyframes::Translations(translations.clone()))
+        );
+        assert_eq!(keyframes(1), Some(Keyframes::Scales(translations.clone())));
+        assert_eq!(keyframes(3), Some(Keyframes::Translations(translations)));
+        let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+        assert_eq!(
+            keyframes(2),
+            Some(Keyframes::Rotations(vec![
+                (0.0, [0.0, 0.0, 0.0, 1.0]),
+                (1.0, [0.0, 0.0, half_sqrt_2, half_sqrt_2]),
+            ]))
+        );
+        assert_eq!(
+            keyframes(4),
+            Some(Keyframes::MorphTargetWeights(vec![
+                (0.0, vec![0.0, 0.0]),
+                (1.0, vec![0.0, 2.0]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn read_keyframes_rejects_mismatched_outputs() {
+        // Translations of type VEC4, which validation would otherwise reject.
+        let mut json: crate::json::Root = crate::json::deserialize::from_str(JSON).unwrap();
+        json.animations[0].samplers[0].output = crate::json::Index::new(2);
+        let document = crate::Document::from_json_without_validation(json);
+        let data = data();
+        let animation = document.animations().next().unwrap();
+        let channel = animation.channels().next().unwrap();
+        assert_eq!(channel.reader(|_| Some(&data)).read_keyframes(), None);
+    }
+
+    #[test]
+    fn sample_linear() {
+        assert_eq!(sample(0, 0.5), Sample::Translation([1.0, 2.0, 3.0]));
+        assert_eq!(sample(0, -1.0), Sample::Translation([0.0, 0.0, 0.0]));
+        assert_eq!(sample(0, 2.0), Sample::Translation([2.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn sample_rejects_non_finite_time() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let data = data();
+        let animation = gltf.animations().next().unwrap();
+        let channel = animation.channels().next().unwrap();
+        let reader = channel.reader(|_| Some(&data));
+        assert_eq!(reader.sample(f32::NAN), None);
+        assert_eq!(reader.sample(f32::INFINITY), None);
+    }
+
+    #[test]
+    fn sample_rejects_mismatched_outputs() {
+        // Three inputs with two VEC3 translations, which validation would
+        // otherwise reject.
+        let mut json: crate::json::Root = crate::json::deserialize::from_str(JSON).unwrap();
+        json.buffer_views[0].byte_length = 12;
+        json.accessors[0].count = 3;
+        let document = crate::Document::from_json_without_validation(json);
+        let data = data();
+        let animation = document.animations().next().unwrap();
+        let channel = animation.channels().next().unwrap();
+        assert_eq!(channel.reader(|_| Some(&data)).sample(0.5), None);
+    }
+
+    #[test]
+    fn sample_step() {
+        assert_eq!(sample(1, 0.5), Sample::Scale([0.0, 0.0, 0.0]));
+        assert_eq!(sample(1, 1.0), Sample::Scale([2.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn sample_rotation_is_slerped() {
+        let angle = std::f32::consts::FRAC_PI_8;
+        let expected = [0.0, 0.0, angle.sin(), angle.cos()];
+        match sample(2, 0.5) {
+            Sample::Rotation(rotation) => {
+                for (actual, expected) in rotation.iter().zip(expected) {
+                    assert!((actual - expected).abs() < 1e-6);
+                }
+            }
+            other => panic!("unexpected sample: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn sample_cubic_spline() {
+        assert_eq!(
+            sample(3, 0.25),
+            Sample::Translation([0.3125, 0.625, 0.9375])
+        );
+        assert_eq!(sample(3, 0.0), Sample::Translation([0.0, 0.0, 0.0]));
+        assert_eq!(sample(3, 1.0), Sample::Translation([2.0, 4.0, 6.0]));
+    }
 }
@@ -0,0 +1,464 @@
+use gltf::edit::Model;
+use gltf::json;
+use std::borrow::Cow;
+use std::error::Error as StdError;
+use std::path::{Component, Path, PathBuf};
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+usage:
+    gltf inspect <FILE>
+    gltf diff <OLD> <NEW>
+    gltf convert [--embed] <INPUT> <OUTPUT>
+    gltf optimize [--no-prune] [--no-merge] <INPUT> <OUTPUT>
+
+Whether OUTPUT is written as binary glTF is decided by its `.glb` extension.
+Buffers are written to `.bin` files next to a standard glTF OUTPUT unless
+`--embed` is given, in which case they are embedded as data URIs together
+with any external images. Relative URIs of other external files are
+rewritten to refer to the same files from the directory of OUTPUT.";
+
+type Result<T> = std::result::Result<T, Box<dyn StdError>>;
+
+/// Loads the document and buffers of the glTF or GLB file at `path`.
+fn load(path: &Path) -> Result<Model> {
+    let gltf::Gltf { document, blob } = gltf::Gltf::open(path)?;
+    let base = path.parent().unwrap_or_else(|| Path::new("./"));
+    let data = gltf::import_buffers(&document, Some(base), blob)?;
+    Ok(Model::from_import(document, data))
+}
+
+/// Writes a model loaded from `input` to `path`, as GLB if the path has a
+/// `.glb` extension.
+fn save(mut model: Model, input: &Path, path: &Path, embed: bool) -> Result<()> {
+    rebase_uris(&mut model.root, input, path)?;
+    let binary = path
+        .extension()
+        .map_or(false, |x| x.eq_ignore_ascii_case("glb"));
+    if binary {
+        model.merge_buffers();
+        // Any buffers after the merged one are meshopt fallbacks without data.
+        let bin = if model.buffers.is_empty() {
+            None
+        } else {
+            Some(Cow::Owned(model.buffers.remove(0)))
+        };
+        if let Some(buffer) = model.root.buffers.first_mut() {
+            buffer.uri = None;
+        }
+        let glb = gltf::binary::Glb {
+            header: gltf::binary::Header {
+                magic: *b"glTF",
+                version: 2,
+                // Computed when writing.
+                length: 0,
+            },
+            json: Cow::Owned(model.root.to_vec_with_options(&Default::default())?),
+            bin,
+            chunks: Vec::new(),
+        };
+        glb.to_writer(fs::File::create(path)?)?;
+    } else {
+        if embed {
+            model.embed_buffers();
+        } else {
+            let count = model.buffers.len();
+            let buffers = model.root.buffers.iter_mut().zip(&model.buffers);
+            for (i, (buffer, data)) in buffers.enumerate() {
+                let bin = bin_path(path, i, count);
+                fs::write(&bin, data)?;
+                let name = bin.file_name().unwrap().to_string_lossy();
+                buffer.uri = Some(urlencoding::encode(&name).into_owned());
+            }
+        }
+        let options = json::ExportOptions {
+            pretty: true,
+            ..Default::default()
+        };
+        fs::write(path, model.root.to_vec_with_options(&options)?)?;
+    }
+    Ok(())
+}
+
+/// Rewrites the relative URIs of images and buffers, which refer to files
+/// next to `input`, to refer to the same files from the directory of `output`.
+fn rebase_uris(root: &mut json::Root, input: &Path, output: &Path) -> Result<()> {
+    let directory = |path: &Path| match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => fs::canonicalize(parent),
+        _ => env::current_dir(),
+    };
+    let (from, to) = (directory(input)?, directory(output)?);
+    if from == to {
+        return Ok(());
+    }
+    let images = root.images.iter_mut().map(|image| &mut image.uri);
+    let buffers = root.buffers.iter_mut().map(|buffer| &mut buffer.uri);
+    for uri in images.chain(buffers).flatten() {
+        if is_relative(uri) {
+            let path = from.join(urlencoding::decode(uri)?.as_ref());
+            if let Some(rebased) = relative_uri(&path, &to) {
+                *uri = rebased;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether a URI is a relative reference to a path, rather than a
+/// data URI, another URI with a scheme or an absolute path.
+fn is_relative(uri: &str) -> bool {
+    let has_scheme = match uri.split_once(':') {
+        Some((scheme, _)) => !scheme.is_empty() && !scheme.contains('/'),
+        None => false,
+    };
+    !has_scheme && !uri.starts_with('/')
+}
+
+/// Returns the percent-encoded relative URI of the absolute `path` from the
+/// absolute directory `base`, or `None` if they have no common root.
+fn relative_uri(path: &Path, base: &Path) -> Option<String> {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    let base: Vec<Component> = base.components().collect();
+    let common = components
+        .iter()
+        .zip(&base)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return None;
+    }
+    let parents = std::iter::repeat("..".into()).take(base.len() - common);
+    let names = components[common..]
+        .iter()
+        .map(|x| urlencoding::encode(&x.as_os_str().to_string_lossy()).into_owned());
+    Some(parents.chain(names).collect::<Vec<String>>().join("/"))
+}
+
+/// Removes unused objects, unless the document uses extensions whose
+/// references cannot be followed.
+fn prune_unused(model: &mut Model) {
+    let unsupported: Vec<&str> = model
+        .root
+        .extensions_used
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !json::extensions::ENABLED_EXTENSIONS.contains(name))
+        .collect();
+    if !unsupported.is_empty() {
+        eprintln!(
+            "warning: {} may refer to other objects; skipping prune",
+            unsupported.join(", ")
+        );
+        return;
+    }
+    model.prune();
+}
+
+/// Returns the path of the `i`th of `count` buffers written alongside `path`.
+fn bin_path(path: &Path, i: usize, count: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    if count == 1 {
+        path.with_file_name(format!("{}.bin", stem))
+    } else {
+        path.with_file_name(format!("{}{}.bin", stem, i))
+    }
+}
+
+/// Formats an object index followed by its name, if any.
+fn label(index: usize, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{} ({})", index, name),
+        None => index.to_string(),
+    }
+}
+
+fn print_tree(node: &gltf::Node, depth: usize) {
+    #[cfg(feature = "names")]
+    let name = node.name();
+    #[cfg(not(feature = "names"))]
+    let name = None;
+    print!(
+        "{:indent$}- Node {}",
+        "",
+        label(node.index(), name),
+        indent = 2 * depth
+    );
+    if let Some(mesh) = node.mesh() {
+        print!(" [mesh {}]", mesh.index());
+    }
+    if let Some(camera) = node.camera() {
+        print!(" [camera {}]", camera.index());
+    }
+    if let Some(skin) = node.skin() {
+        print!(" [skin {}]", skin.index());
+    }
+    println!();
+    for child in node.children() {
+        print_tree(&child, depth + 1);
+    }
+}
+
+/// Returns the vertex and triangle counts of a primitive.
+fn primitive_counts(primitive: &gltf::Primitive) -> (usize, usize) {
+    use gltf::mesh::Mode;
+    let vertices = primitive
+        .get(&gltf::Semantic::Positions)
+        .map_or(0, |x| x.count());
+    let elements = primitive.indices().map_or(vertices, |x| x.count());
+    let triangles = match primitive.mode() {
+        Mode::Triangles => elements / 3,
+        Mode::TriangleStrip | Mode::TriangleFan => elements.saturating_sub(2),
+        _ => 0,
+    };
+    (vertices, triangles)
+}
+
+fn inspect(path: &Path) -> Result<()> {
+    let model = load(path)?;
+    let root = &model.root;
+    let document = gltf::Document::from_json_without_validation(root.clone());
+
+    println!("glTF {}", root.asset.version);
+    if let Some(generator) = root.asset.generator.as_ref() {
+        println!("generator: {}", generator);
+    }
+    let stats = root.stats();
+    let counts = [
+        ("accessors", stats.accessors),
+        ("animations", stats.animations),
+        ("buffers", stats.buffers),
+        ("buffer views", stats.buffer_views),
+        ("cameras", stats.cameras),
+        ("images", stats.images),
+        ("materials", stats.materials),
+        ("meshes", stats.meshes),
+        ("nodes", stats.nodes),
+        ("samplers", stats.samplers),
+        ("scenes", stats.scenes),
+        ("skins", stats.skins),
+        ("textures", stats.textures),
+    ];
+    for (name, count) in counts {
+        println!("{}: {}", name, count);
+    }
+    if !root.extensions_used.is_empty() {
+        println!("extensions used: {}", root.extensions_used.join(", "));
+    }
+
+    for scene in document.scenes() {
+        #[cfg(feature = "names")]
+        let name = scene.name();
+        #[cfg(not(feature = "names"))]
+        let name = None;
+        println!();
+        println!("Scene {}", label(scene.index(), name));
+        for node in scene.nodes() {
+            print_tree(&node, 1);
+        }
+    }
+
+    if document.meshes().len() > 0 {
+        println!();
+        println!("Meshes:");
+    }
+    for mesh in document.meshes() {
+        let (mut vertices, mut triangles) = (0, 0);
+        for primitive in mesh.primitives() {
+            let counts = primitive_counts(&primitive);
+            vertices += counts.0;
+            triangles += counts.1;
+        }
+        #[cfg(feature = "names")]
+        let name = mesh.name();
+        #[cfg(not(feature = "names"))]
+        let name = None;
+        println!(
+            "  Mesh {}: {} primitive(s), {} vertices, {} triangles",
+            label(mesh.index(), name),
+            mesh.primitives().len(),
+            vertices,
+            triangles,
+        );
+    }
+    if document.meshes().len() > 0 {
+        println!(
+            "  total: {} vertices, {} triangles, {} bytes of geometry",
+            stats.vertices, stats.triangles, stats.geometry_bytes
+        );
+    }
+
+    if document.materials().len() > 0 {
+        println!();
+        println!("Materials:");
+    }
+    for material in document.materials() {
+        let pbr = material.pbr_metallic_roughness();
+        #[cfg(feature = "names")]
+        let name = material.name();
+        #[cfg(not(feature = "names"))]
+        let name = None;
+        println!(
+            "  Material {}: base color {:?}, metallic {}, roughness {}, alpha mode {:?}{}",
+            label(material.index().unwrap_or_default(), name),
+            pbr.base_color_factor(),
+            pbr.metallic_factor(),
+            pbr.roughness_factor(),
+            material.alpha_mode(),
+            if material.double_sided() {
+                ", double sided"
+            } else {
+                ""
+            },
+        );
+    }
+
+    if !model.buffers.is_empty() {
+        println!();
+        println!("Buffers:");
+    }
+    for (i, buffer) in root.buffers.iter().enumerate() {
+        let source = match buffer.uri.as_deref() {
+            None => "binary chunk",
+            Some(uri) if uri.starts_with("data:") => "data URI",
+            Some(uri) => uri,
+        };
+        println!("  Buffer {}: {} bytes ({})", i, buffer.byte_length, source);
+    }
+    Ok(())
+}
+
+/// Prints the structural differences between the documents of two files.
+fn diff(old: &Path, new: &Path) -> Result<()> {
+    let old = gltf::Gltf::open(old)?.document.into_json();
+    let new = gltf::Gltf::open(new)?.document.into_json();
+    for change in json::diff::diff(&old, &new)? {
+        println!("{}", change);
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let command = args.first().map(String::as_str);
+    let mut embed = false;
+    let mut prune = true;
+    let mut merge = true;
+    let mut paths = Vec::new();
+    for arg in args.iter().skip(1) {
+        match arg.as_str() {
+            "--embed" if command == Some("convert") => embed = true,
+            "--no-prune" if command == Some("optimize") => prune = false,
+            "--no-merge" if command == Some("optimize") => merge = false,
+            _ if arg.starts_with("--") => usage(),
+            _ => paths.push(Path::new(arg)),
+        }
+    }
+    match (command, paths.as_slice()) {
+        (Some("inspect"), [path]) => inspect(path),
+        (Some("diff"), [old, new]) => diff(old, new),
+        (Some("convert"), [input, output]) => {
+            let mut model = load(input)?;
+            if embed {
+                let base = input.parent().unwrap_or_else(|| Path::new("./"));
+                model.embed_images(&gltf::FileResolver::new(base))?;
+            }
+            save(model, input, output, embed)
+        }
+        (Some("optimize"), [input, output]) => {
+            let mut model = load(input)?;
+            if prune {
+                prune_unused(&mut model);
+            }
+            if merge {
+                model.merge_buffers();
+            }
+            save(model, input, output, false)
+        }
+        _ => usage(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(error) = run(&args) {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_uris() {
+        assert!(is_relative("textures/base%20color.png"));
+        assert!(is_relative("../shared/buffer.bin"));
+        assert!(!is_relative("data:application/octet-stream;base64,AAAA"));
+        assert!(!is_relative("https://example.com/texture.png"));
+        assert!(!is_relative("/textures/texture.png"));
+
+        let base = env::temp_dir();
+        let path = base
+            .join("a b")
+            .join(".")
+            .join("c")
+            .join("..")
+            .join("d.png");
+        assert_eq!(
+            relative_uri(&path, &base.join("e")).unwrap(),
+            "../a%20b/d.png"
+        );
+    }
+
+    #[test]
+    fn convert_rebases_relative_uris() {
+        let directory = env::temp_dir().join(format!("gltf-convert-{}", process::id()));
+        let input = directory.join("in").join("model.gltf");
+        let output = directory.join("out").join("nested");
+        fs::create_dir_all(input.parent().unwrap()).unwrap();
+        fs::create_dir_all(&output).unwrap();
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 4, "uri": "data:application/octet-stream;base64,AAAAAA==" }],
+            "images": [
+                { "uri": "textures/base%20color.png" },
+                { "uri": "https://example.com/texture.png" }
+            ]
+        }"#;
+        fs::write(&input, json).unwrap();
+
+        for name in ["model.gltf", "model.glb"] {
+            let path = output.join(name);
+            let args = [
+                "convert".to_owned(),
+                input.display().to_string(),
+                path.display().to_string(),
+            ];
+            run(&args).unwrap();
+            let root = gltf::Gltf::open(&path).unwrap().document.into_json();
+            let uris: Vec<_> = root.images.iter().map(|x| x.uri.as_deref()).collect();
+            assert_eq!(
+                uris,
+                [
+                    Some("../../in/textures/base%20color.png"),
+                    Some("https://example.com/texture.png")
+                ]
+            );
+        }
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
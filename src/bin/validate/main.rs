@@ -0,0 +1,202 @@
+use gltf::json::validation::Validate;
+use gltf::json::{self, Value};
+use std::path::Path;
+use std::{env, fmt, fs, process};
+
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Severity {
+    /// The asset is invalid or cannot be loaded.
+    Error,
+
+    /// The asset is valid but may not be loaded as intended.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A problem found in an asset.
+struct Diagnostic {
+    severity: Severity,
+    path: String,
+    message: String,
+}
+
+impl Diagnostic {
+    fn new<P, M>(severity: Severity, path: P, message: M) -> Self
+    where
+        P: ToString,
+        M: ToString,
+    {
+        Self {
+            severity,
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut object = Value::Object(Default::default());
+        object["severity"] = Value::String(self.severity.to_string());
+        object["path"] = Value::String(self.path.clone());
+        object["message"] = Value::String(self.message.clone());
+        object
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.severity, self.message)
+        } else {
+            write!(f, "{}: {}: {}", self.severity, self.path, self.message)
+        }
+    }
+}
+
+/// Runs every check on the asset at `path`.
+fn validate(path: &Path) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            diagnostics.push(Diagnostic::new(Severity::Error, "", error));
+            return diagnostics;
+        }
+    };
+    let gltf::Gltf { document, blob } = match gltf::Gltf::from_slice_without_validation(&bytes) {
+        Ok(gltf) => gltf,
+        Err(error) => {
+            diagnostics.push(Diagnostic::new(Severity::Error, "", error));
+            return diagnostics;
+        }
+    };
+
+    let root = document.into_json();
+    root.validate(&root, json::Path::new, &mut |path, error| {
+        diagnostics.push(Diagnostic::new(Severity::Error, path(), error))
+    });
+    for (i, name) in root.extensions_required.iter().enumerate() {
+        if !json::extensions::ENABLED_EXTENSIONS.contains(&name.as_str()) {
+            let path = json::Path::new().field("extensionsRequired").index(i);
+            let message = format!("required extension {} is not supported", name);
+            diagnostics.push(Diagnostic::new(Severity::Error, path, message));
+        }
+    }
+    for (i, name) in root.extensions_used.iter().enumerate() {
+        if !json::extensions::ENABLED_EXTENSIONS.contains(&name.as_str()) {
+            let path = json::Path::new().field("extensionsUsed").index(i);
+            let message = format!("extension {} is not supported and will be ignored", name);
+            diagnostics.push(Diagnostic::new(Severity::Warning, path, message));
+        }
+    }
+
+    // External resources can only be checked once the document is valid.
+    if diagnostics.iter().all(|x| x.severity != Severity::Error) {
+        let document = gltf::Document::from_json_without_validation(root);
+        let base = path.parent().unwrap_or_else(|| Path::new("./"));
+        match gltf::import_buffers(&document, Some(base), blob) {
+            Ok(buffers) => {
+                if let Err(error) = gltf::import_images(&document, Some(base), &buffers) {
+                    diagnostics.push(Diagnostic::new(Severity::Error, "images", error));
+                }
+            }
+            Err(error) => diagnostics.push(Diagnostic::new(Severity::Error, "buffers", error)),
+        }
+    }
+    diagnostics
+}
+
+/// Checks the JSON of the asset at `path` against the JSON Schema files in
+/// `dir`.
+#[cfg(feature = "schema")]
+fn validate_schema(path: &Path, dir: &Path) -> Vec<Diagnostic> {
+    let fail = |error: &dyn fmt::Display| vec![Diagnostic::new(Severity::Error, "", error)];
+    let schemas = match json::schema::Schemas::from_dir(dir) {
+        Ok(schemas) => schemas,
+        Err(error) => return fail(&format!("{}: {}", dir.display(), error)),
+    };
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) => return fail(&error),
+    };
+    let glb;
+    let text = if bytes.starts_with(b"glTF") {
+        glb = match gltf::Glb::from_slice(&bytes) {
+            Ok(glb) => glb,
+            Err(error) => return fail(&error),
+        };
+        &glb.json[..]
+    } else {
+        &bytes[..]
+    };
+    let value: Value = match json::deserialize::from_slice(text) {
+        Ok(value) => value,
+        Err(error) => return fail(&error),
+    };
+    schemas
+        .validate(&value)
+        .into_iter()
+        .map(|x| Diagnostic::new(Severity::Error, x.path, x.message))
+        .collect()
+}
+
+#[cfg(feature = "schema")]
+const USAGE: &str = "usage: gltf-validate [--json] [--schema=<DIR>] <FILE>";
+#[cfg(not(feature = "schema"))]
+const USAGE: &str = "usage: gltf-validate [--json] <FILE>";
+
+fn main() {
+    let mut json_output = false;
+    #[cfg(feature = "schema")]
+    let mut schema_dir = None;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json_output = true,
+            #[cfg(feature = "schema")]
+            _ if arg.starts_with("--schema=") => {
+                schema_dir = Some(arg["--schema=".len()..].to_string());
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.len() != 1 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+    let path = paths.remove(0);
+
+    let mut diagnostics = Vec::new();
+    // Schema violations are reported before the errors of typed
+    // deserialization, which stops at the first of them.
+    #[cfg(feature = "schema")]
+    if let Some(dir) = schema_dir {
+        diagnostics.extend(validate_schema(Path::new(&path), Path::new(&dir)));
+    }
+    diagnostics.extend(validate(Path::new(&path)));
+    if json_output {
+        let array = Value::Array(diagnostics.iter().map(Diagnostic::to_json).collect());
+        println!("{}", json::serialize::to_string_pretty(&array).unwrap());
+    } else {
+        for diagnostic in &diagnostics {
+            println!("{}", diagnostic);
+        }
+        let errors = diagnostics
+            .iter()
+            .filter(|x| x.severity == Severity::Error)
+            .count();
+        let warnings = diagnostics.len() - errors;
+        println!("{}: {} error(s), {} warning(s)", path, errors, warnings);
+    }
+    if diagnostics.iter().any(|x| x.severity == Severity::Error) {
+        process::exit(1);
+    }
+}
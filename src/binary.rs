@@ -11,7 +11,8 @@ pub enum Error {
     Version(u32),
     /// Magic says that file is not glTF.
     Magic([u8; 4]),
-    /// Length specified in GLB header exceeeds that of slice.
+    /// Length specified in GLB header, or in the header of a chunk of
+    /// unknown type, exceeeds that of slice.
     Length {
         /// length specified in GLB header.
         length: u32,
@@ -42,6 +43,12 @@ pub struct Glb<'a> {
     pub json: Cow<'a, [u8]>,
     /// The optional BIN section of the `.glb` file.
     pub bin: Option<Cow<'a, [u8]>>,
+    /// Chunks of other types following the JSON and BIN chunks, in order, as
+    /// pairs of chunk type and data.
+    ///
+    /// The chunk type is the little endian value of its four bytes, so a
+    /// chunk tagged `b"XMPD"` has type `u32::from_le_bytes(*b"XMPD")`.
+    pub chunks: Vec<(u32, Cow<'a, [u8]>)>,
 }
 
 /// The header section of a .glb file.
@@ -71,8 +78,8 @@ pub enum ChunkType {
 struct ChunkHeader {
     /// The length of the chunk data in byte excluding the header.
     length: u32,
-    /// Chunk type.
-    ty: ChunkType,
+    /// Chunk type, as it appears in the file.
+    ty: [u8; 4],
 }
 
 impl Header {
@@ -105,71 +112,68 @@ impl ChunkHeader {
         let length = reader.read_u32::<LittleEndian>().map_err(Io)?;
         let mut ty = [0; 4];
         reader.read_exact(&mut ty).map_err(Io)?;
-        let ty = match &ty {
-            b"JSON" => Ok(ChunkType::Json),
-            b"BIN\0" => Ok(ChunkType::Bin),
-            _ => Err(Error::UnknownChunkType(ty)),
-        }?;
         Ok(Self { length, ty })
     }
+
+    /// Returns the type of a JSON or BIN chunk.
+    fn known_type(&self) -> Option<ChunkType> {
+        match &self.ty {
+            b"JSON" => Some(ChunkType::Json),
+            b"BIN\0" => Some(ChunkType::Bin),
+            _ => None,
+        }
+    }
 }
 
 fn align_to_multiple_of_four(n: &mut usize) {
     *n = (*n + 3) & !3;
 }
 
-fn split_binary_gltf(mut data: &[u8]) -> Result<(&[u8], Option<&[u8]>), Error> {
-    let (json, mut data) = ChunkHeader::from_reader(&mut data)
-        .and_then(|json_h| {
-            if let ChunkType::Json = json_h.ty {
-                Ok(json_h)
-            } else {
-                Err(Error::ChunkType(json_h.ty))
-            }
-        })
-        .and_then(|json_h| {
-            if json_h.length as usize <= data.len() {
-                Ok(json_h)
-            } else {
-                Err(Error::ChunkLength {
-                    ty: json_h.ty,
-                    length: json_h.length,
-                    length_read: data.len(),
-                })
-            }
-        })
-        // We have verified that json_h.length is no greater than that of
-        // data.len().
-        .map(|json_h| data.split_at(json_h.length as usize))?;
+/// Reads a chunk header and splits the chunk data off the front of `data`.
+fn read_chunk<'d>(data: &mut &'d [u8]) -> Result<(ChunkHeader, &'d [u8]), Error> {
+    let header = ChunkHeader::from_reader(&mut *data)?;
+    if header.length as usize > data.len() {
+        return Err(match header.known_type() {
+            Some(ty) => Error::ChunkLength {
+                ty,
+                length: header.length,
+                length_read: data.len(),
+            },
+            None => Error::Length {
+                length: header.length,
+                length_read: data.len(),
+            },
+        });
+    }
+    let (chunk, rest) = data.split_at(header.length as usize);
+    *data = rest;
+    Ok((header, chunk))
+}
 
-    let bin = if !data.is_empty() {
-        ChunkHeader::from_reader(&mut data)
-            .and_then(|bin_h| {
-                if let ChunkType::Bin = bin_h.ty {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkType(bin_h.ty))
-                }
-            })
-            .and_then(|bin_h| {
-                if bin_h.length as usize <= data.len() {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkLength {
-                        ty: bin_h.ty,
-                        length: bin_h.length,
-                        length_read: data.len(),
-                    })
-                }
-            })
-            // We have verified that bin_h.length is no greater than that
-            // of data.len().
-            .map(|bin_h| data.split_at(bin_h.length as usize))
-            .map(|(x, _)| Some(x))?
-    } else {
-        None
-    };
-    Ok((json, bin))
+/// The JSON chunk, optional BIN chunk and other chunks of a GLB file.
+type Chunks<'d> = (&'d [u8], Option<&'d [u8]>, Vec<(u32, &'d [u8])>);
+
+fn split_binary_gltf(mut data: &[u8]) -> Result<Chunks<'_>, Error> {
+    let (header, json) = read_chunk(&mut data)?;
+    match header.known_type() {
+        Some(ChunkType::Json) => {}
+        Some(ty) => return Err(Error::ChunkType(ty)),
+        None => return Err(Error::UnknownChunkType(header.ty)),
+    }
+
+    let mut bin = None;
+    let mut chunks = Vec::new();
+    // Anything too short to hold a chunk header is trailing padding.
+    while data.len() >= mem::size_of::<ChunkHeader>() {
+        let (header, chunk) = read_chunk(&mut data)?;
+        match header.known_type() {
+            // The BIN chunk, if any, must directly follow the JSON chunk.
+            Some(ChunkType::Bin) if bin.is_none() && chunks.is_empty() => bin = Some(chunk),
+            Some(ty) => return Err(Error::ChunkType(ty)),
+            None => chunks.push((u32::from_le_bytes(header.ty), chunk)),
+        }
+    }
+    Ok((json, bin, chunks))
 }
 
 impl<'a> Glb<'a> {
@@ -182,13 +186,7 @@ impl<'a> Glb<'a> {
         {
             let magic = b"glTF";
             let version = 2;
-            let mut length =
-                mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
-            align_to_multiple_of_four(&mut length);
-            if let Some(bin) = self.bin.as_ref() {
-                length += mem::size_of::<ChunkHeader>() + bin.len();
-                align_to_multiple_of_four(&mut length);
-            }
+            let length = self.length();
 
             writer.write_all(&magic[..])?;
             writer.write_u32::<LittleEndian>(version)?;
@@ -210,15 +208,21 @@ impl<'a> Glb<'a> {
             }
         }
 
-        if let Some(bin) = self.bin.as_ref() {
-            let magic = b"BIN\0";
-            let mut length = bin.len();
+        let bin = self
+            .bin
+            .as_ref()
+            .map(|bin| (u32::from_le_bytes(*b"BIN\0"), bin));
+        for (ty, data) in bin
+            .into_iter()
+            .chain(self.chunks.iter().map(|(ty, x)| (*ty, x)))
+        {
+            let mut length = data.len();
             align_to_multiple_of_four(&mut length);
-            let padding = length - bin.len();
+            let padding = length - data.len();
 
             writer.write_u32::<LittleEndian>(length as u32)?;
-            writer.write_all(&magic[..])?;
-            writer.write_all(bin)?;
+            writer.write_all(&ty.to_le_bytes())?;
+            writer.write_all(data)?;
             for _ in 0..padding {
                 writer.write_u8(0)?;
             }
@@ -227,25 +231,31 @@ impl<'a> Glb<'a> {
         Ok(())
     }
 
-    /// Writes binary glTF to a byte vector.
-    pub fn to_vec(&self) -> Result<Vec<u8>, crate::Error> {
+    /// Returns the length of the binary glTF in bytes, including padding.
+    fn length(&self) -> usize {
         let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
         align_to_multiple_of_four(&mut length);
-        if let Some(bin) = self.bin.as_ref() {
-            length += mem::size_of::<ChunkHeader>() + bin.len();
+        let chunks = self.chunks.iter().map(|(_, x)| x);
+        for chunk in self.bin.iter().chain(chunks) {
+            length += mem::size_of::<ChunkHeader>() + chunk.len();
             align_to_multiple_of_four(&mut length);
         }
+        length
+    }
 
-        let mut vec = Vec::with_capacity(length);
+    /// Writes binary glTF to a byte vector.
+    pub fn to_vec(&self) -> Result<Vec<u8>, crate::Error> {
+        let mut vec = Vec::with_capacity(self.length());
         self.to_writer(&mut vec as &mut dyn io::Write)?;
         Ok(vec)
     }
 
-    /// Splits loaded GLB into its three chunks.
+    /// Splits loaded GLB into its chunks.
     ///
     /// * Mandatory GLB header.
     /// * Mandatory JSON chunk.
     /// * Optional BIN chunk.
+    /// * Any number of chunks of other types.
     pub fn from_slice(mut data: &'a [u8]) -> Result<Self, crate::Error> {
         let header = Header::from_reader(&mut data)
             .and_then(|header| {
@@ -262,10 +272,11 @@ impl<'a> Glb<'a> {
             .map_err(crate::Error::Binary)?;
         match header.version {
             2 => split_binary_gltf(data)
-                .map(|(json, bin)| Glb {
+                .map(|(json, bin, chunks)| Glb {
                     header,
                     json: json.into(),
                     bin: bin.map(Into::into),
+                    chunks: chunks.into_iter().map(|(ty, x)| (ty, x.into())).collect(),
                 })
                 .map_err(crate::Error::Binary),
             x => Err(crate::Error::Binary(Error::Version(x))),
@@ -288,10 +299,14 @@ impl<'a> Glb<'a> {
                     Err(crate::Error::Binary(e))
                 } else {
                     split_binary_gltf(&buf)
-                        .map(|(json, bin)| Glb {
+                        .map(|(json, bin, chunks)| Glb {
                             header,
                             json: json.to_vec().into(),
                             bin: bin.map(<[u8]>::to_vec).map(Into::into),
+                            chunks: chunks
+                                .into_iter()
+                                .map(|(ty, x)| (ty, x.to_vec().into()))
+                                .collect(),
                         })
                         .map_err(crate::Error::Binary)
                 }
@@ -326,3 +341,40 @@ impl fmt::Display for Error {
 }
 
 impl ::std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::Glb;
+    use std::borrow::Cow;
+
+    #[test]
+    fn unknown_chunks_round_trip() {
+        let tag = u32::from_le_bytes(*b"XMPD");
+        let glb = Glb {
+            header: super::Header {
+                magic: *b"glTF",
+                version: 2,
+                length: 0,
+            },
+            json: Cow::Borrowed(br#"{"asset":{"version":"2.0"}}"#),
+            bin: None,
+            chunks: vec![(tag, Cow::Borrowed(&[1, 2, 3]))],
+        };
+        let bytes = glb.to_vec().unwrap();
+        assert_eq!(bytes.len(), 12 + 8 + 28 + 8 + 4);
+
+        let read = Glb::from_slice(&bytes).unwrap();
+        assert_eq!(read.header.length as usize, bytes.len());
+        assert!(read.bin.is_none());
+        // Chunk data is padded to a multiple of four bytes.
+        assert_eq!(read.chunks, [(tag, Cow::Borrowed(&[1, 2, 3, 0][..]))]);
+        let read = Glb::from_reader(&bytes[..]).unwrap();
+        assert_eq!(read.to_vec().unwrap(), bytes);
+
+        // Unknown chunks must not come before the BIN chunk.
+        let mut glb = read;
+        let bin = u32::from_le_bytes(*b"BIN\0");
+        glb.chunks.push((bin, Cow::Borrowed(&[0; 4])));
+        assert!(Glb::from_slice(&glb.to_vec().unwrap()).is_err());
+    }
+}
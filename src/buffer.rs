@@ -4,6 +4,11 @@ use std::ops;
 use crate::Document;
 
 pub use json::buffer::Target;
+#[cfg(feature = "EXT_meshopt_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+pub use json::extensions::buffer::ext_meshopt_compression::{
+    Filter as MeshoptFilter, Mode as MeshoptMode,
+};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug)]
@@ -36,6 +41,19 @@ pub struct View<'a> {
     parent: Buffer<'a>,
 }
 
+/// The compressed data of a buffer view, see
+/// [`View::meshopt_compression`].
+#[cfg(feature = "EXT_meshopt_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+#[derive(Clone, Debug)]
+pub struct MeshoptCompression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::buffer::ext_meshopt_compression::View,
+}
+
 /// Describes a buffer data source.
 #[derive(Clone, Debug)]
 pub enum Source<'a> {
@@ -61,6 +79,32 @@ impl ops::Deref for Data {
     }
 }
 
+/// Buffer data belonging to an imported glTF asset, memory-mapped from its
+/// file where possible.
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+#[derive(Debug)]
+pub enum MappedData {
+    /// Data read into memory, for example from a data URI or the `BIN`
+    /// section of binary glTF.
+    Owned(Data),
+
+    /// Data memory-mapped from an external file.
+    Mapped(memmap2::Mmap),
+}
+
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+impl ops::Deref for MappedData {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        match self {
+            MappedData::Owned(data) => data,
+            MappedData::Mapped(mmap) => mmap,
+        }
+    }
+}
+
 impl<'a> Buffer<'a> {
     /// Constructs a `Buffer`.
     pub(crate) fn new(
@@ -101,6 +145,19 @@ impl<'a> Buffer<'a> {
         self.json.name.as_deref()
     }
 
+    /// Returns `true` if the buffer only exists for buffer views compressed
+    /// with `EXT_meshopt_compression` to decompress into, in which case it
+    /// has no data of its own.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn is_meshopt_fallback(&self) -> bool {
+        self.json
+            .extensions
+            .as_ref()
+            .and_then(|x| x.ext_meshopt_compression.as_ref())
+            .map_or(false, |x| x.fallback)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -168,8 +225,67 @@ impl<'a> View<'a> {
         self.json.target.map(|target| target.unwrap())
     }
 
+    /// Returns the compressed data of this buffer view, if provided.
+    ///
+    /// The [`import`](crate::import) functions decompress it into the
+    /// buffer view's range of its buffer when that buffer is a fallback
+    /// buffer.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn meshopt_compression(&self) -> Option<MeshoptCompression<'a>> {
+        self.json
+            .extensions
+            .as_ref()
+            .and_then(|x| x.ext_meshopt_compression.as_ref())
+            .map(|json| MeshoptCompression {
+                document: self.document,
+                json,
+            })
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl<'a> MeshoptCompression<'a> {
+    /// Returns the buffer containing the compressed data.
+    pub fn buffer(&self) -> Buffer<'a> {
+        self.document
+            .buffers()
+            .nth(self.json.buffer.value())
+            .unwrap()
+    }
+
+    /// Returns the offset of the compressed data in its buffer in bytes.
+    pub fn offset(&self) -> usize {
+        self.json.byte_offset as usize
+    }
+
+    /// Returns the length of the compressed data in bytes.
+    pub fn length(&self) -> usize {
+        self.json.byte_length as usize
+    }
+
+    /// Returns the size of each decompressed element in bytes.
+    pub fn stride(&self) -> usize {
+        self.json.byte_stride as usize
+    }
+
+    /// Returns the number of decompressed elements.
+    pub fn count(&self) -> usize {
+        self.json.count as usize
+    }
+
+    /// Returns the compression mode.
+    pub fn mode(&self) -> MeshoptMode {
+        self.json.mode.unwrap()
+    }
+
+    /// Returns the filter applied to the decompressed elements.
+    pub fn filter(&self) -> MeshoptFilter {
+        self.json.filter.unwrap()
+    }
+}
@@ -73,7 +73,7 @@ impl<'a> Camera<'a> {
     }
 
     /// Returns the camera's projection.
-    pub fn projection(&self) -> Projection {
+    pub fn projection(&self) -> Projection<'_> {
         match self.json.type_.unwrap() {
             json::camera::Type::Orthographic => {
                 let json = self.json.orthographic.as_ref().unwrap();
@@ -118,6 +118,41 @@ impl<'a> Orthographic<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major projection matrix, as given by the glTF
+    /// specification.
+    ///
+    /// ```
+    /// # let gltf = gltf::Gltf::from_slice(br#"{
+    /// #     "asset": { "version": "2.0" },
+    /// #     "cameras": [{
+    /// #         "type": "orthographic",
+    /// #         "orthographic": { "xmag": 2.0, "ymag": 1.0, "znear": 1.0, "zfar": 3.0 }
+    /// #     }]
+    /// # }"#).unwrap();
+    /// # let camera = gltf.cameras().next().unwrap();
+    /// if let gltf::camera::Projection::Orthographic(orthographic) = camera.projection() {
+    ///     assert_eq!(
+    ///         orthographic.matrix(),
+    ///         [
+    ///             [0.5, 0.0, 0.0, 0.0],
+    ///             [0.0, 1.0, 0.0, 0.0],
+    ///             [0.0, 0.0, -1.0, 0.0],
+    ///             [0.0, 0.0, -2.0, 1.0],
+    ///         ]
+    ///     );
+    /// }
+    /// ```
+    pub fn matrix(&self) -> [[f32; 4]; 4] {
+        let (r, t) = (self.xmag(), self.ymag());
+        let (n, f) = (self.znear(), self.zfar());
+        [
+            [1.0 / r, 0.0, 0.0, 0.0],
+            [0.0, 1.0 / t, 0.0, 0.0],
+            [0.0, 0.0, 2.0 / (n - f), 0.0],
+            [0.0, 0.0, (f + n) / (n - f), 1.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -150,6 +185,46 @@ impl<'a> Perspective<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major projection matrix, as given by the glTF
+    /// specification.
+    ///
+    /// `aspect_fallback` is used when the camera does not specify an aspect
+    /// ratio, and is typically the aspect ratio of the viewport. An infinite
+    /// projection is returned when the camera has no far clipping plane.
+    ///
+    /// ```
+    /// # let gltf = gltf::Gltf::from_slice(br#"{
+    /// #     "asset": { "version": "2.0" },
+    /// #     "cameras": [{
+    /// #         "type": "perspective",
+    /// #         "perspective": { "yfov": 1.5707964, "znear": 1.0 }
+    /// #     }]
+    /// # }"#).unwrap();
+    /// # let camera = gltf.cameras().next().unwrap();
+    /// if let gltf::camera::Projection::Perspective(perspective) = camera.projection() {
+    ///     let matrix = perspective.matrix(2.0);
+    ///     assert!((matrix[0][0] - 0.5).abs() < 1e-6);
+    ///     assert!((matrix[1][1] - 1.0).abs() < 1e-6);
+    ///     assert_eq!(matrix[2], [0.0, 0.0, -1.0, -1.0]);
+    ///     assert_eq!(matrix[3], [0.0, 0.0, -2.0, 0.0]);
+    /// }
+    /// ```
+    pub fn matrix(&self, aspect_fallback: f32) -> [[f32; 4]; 4] {
+        let a = self.aspect_ratio().unwrap_or(aspect_fallback);
+        let t = (0.5 * self.yfov()).tan();
+        let n = self.znear();
+        let (z, w) = match self.zfar() {
+            Some(f) => ((f + n) / (n - f), 2.0 * f * n / (n - f)),
+            None => (-1.0, -2.0 * n),
+        };
+        [
+            [1.0 / (a * t), 0.0, 0.0, 0.0],
+            [0.0, 1.0 / t, 0.0, 0.0],
+            [0.0, 0.0, z, -1.0],
+            [0.0, 0.0, w, 0.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -0,0 +1,11 @@
+/// Conversion between glTF and Wavefront OBJ and MTL files.
+#[cfg(feature = "obj")]
+#[cfg_attr(docsrs, doc(cfg(feature = "obj")))]
+pub mod obj;
+
+/// Conversion between glTF and binary and ASCII STL files.
+#[cfg(feature = "stl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stl")))]
+pub mod stl;
+
+mod world;
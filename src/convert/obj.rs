@@ -0,0 +1,687 @@
+use super::world::instances;
+use crate::edit::{BufferWriter, Model};
+use crate::json::material::{AlphaMode, EmissiveFactor, PbrBaseColorFactor, StrengthFactor};
+use crate::json::mesh::{Mode, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::Path;
+use std::{fmt, fs, io};
+
+/// Error converting an OBJ file.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read.
+    Io(io::Error),
+
+    /// A statement is malformed.
+    Syntax {
+        /// The name of the material library containing the statement, or
+        /// `None` for the OBJ file itself.
+        library: Option<String>,
+
+        /// The line of the statement, starting at 1.
+        line: usize,
+    },
+
+    /// A face refers to a position, texture co-ordinate or normal that does
+    /// not exist.
+    Index {
+        /// The line of the face, starting at 1.
+        line: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => error.fmt(f),
+            Error::Syntax {
+                library: Some(library),
+                line,
+            } => write!(f, "{}:{}: malformed statement", library, line),
+            Error::Syntax {
+                library: None,
+                line,
+            } => write!(f, "line {}: malformed statement", line),
+            Error::Index { line } => write!(f, "line {}: vertex index out of range", line),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// Reads an OBJ file and the material libraries it refers to, which are
+/// looked up relative to its directory, and converts them with [`parse`].
+///
+/// Material libraries that cannot be read are skipped, leaving their
+/// materials with default values.
+pub fn import<P: AsRef<Path>>(path: P) -> Result<Model, Error> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path)?;
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    parse(&source, |name| fs::read_to_string(dir.join(name)).ok())
+}
+
+/// Converts the source of an OBJ file to a model with one buffer, calling
+/// `mtllib` with the name of every material library to get its source.
+///
+/// Every object or group becomes a mesh with its own node in a single
+/// scene, with a primitive per material used. Polygons are split into
+/// triangle fans, and texture co-ordinates are flipped vertically to match
+/// glTF. Points, lines, curves and surfaces are ignored.
+///
+/// Materials become metallic-roughness materials with the diffuse color and
+/// texture, dissolve and emissive color of the MTL material. The roughness
+/// is taken from `Pr` if given, or otherwise estimated from the specular
+/// exponent, and the metalness from `Pm`, or zero.
+pub fn parse<F>(source: &str, mut mtllib: F) -> Result<Model, Error>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut obj = Obj::default();
+    let mut libraries = HashMap::new();
+    for (number, text) in source.lines().enumerate() {
+        let line = number + 1;
+        let error = || Error::Syntax {
+            library: None,
+            line,
+        };
+        let mut words = strip_comment(text).split_whitespace();
+        match words.next() {
+            Some("v") => obj.positions.push(floats(words).ok_or_else(error)?),
+            Some("vt") => {
+                let [u, v] = floats(words.chain(["0"])).ok_or_else(error)?;
+                obj.tex_coords.push([u, 1.0 - v]);
+            }
+            Some("vn") => obj.normals.push(floats(words).ok_or_else(error)?),
+            Some("f") => {
+                let mut corners = Vec::new();
+                for word in words {
+                    corners.push(obj.corner(word, line)?);
+                }
+                if corners.len() < 3 {
+                    return Err(error());
+                }
+                let faces = obj.faces();
+                for i in 1..corners.len() - 1 {
+                    faces.extend([corners[0], corners[i], corners[i + 1]]);
+                }
+            }
+            Some("o" | "g") => obj.groups.push(Group {
+                #[cfg(feature = "names")]
+                name: words.next().map(str::to_owned),
+                primitives: Vec::new(),
+                material: obj.groups.last().and_then(|x| x.material.clone()),
+            }),
+            Some("usemtl") => {
+                let name = words.next().ok_or_else(error)?;
+                obj.group().material = Some(name.to_owned());
+            }
+            Some("mtllib") => {
+                for name in words {
+                    if let Some(source) = mtllib(name) {
+                        libraries.extend(parse_mtl(&source, name)?);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(obj.into_model(&libraries))
+}
+
+/// Writes the instances of `meshes` in a model to an OBJ file with
+/// [`write`].
+pub fn export<P: AsRef<Path>>(
+    model: &Model,
+    meshes: &[Index<json::Mesh>],
+    path: P,
+) -> io::Result<()> {
+    write(model, meshes, io::BufWriter::new(fs::File::create(path)?))
+}
+
+/// Writes every instance of `meshes` in the default scene of a model, or
+/// else its first scene, as an object of an OBJ file.
+///
+/// Positions and normals are transformed to world space, and objects are
+/// named after their node, or else their mesh, or otherwise `node` followed
+/// by the node index. Only triangles are written, without materials or
+/// texture co-ordinates. Skinned meshes are written in their bind pose.
+pub fn write<W: Write>(
+    model: &Model,
+    meshes: &[Index<json::Mesh>],
+    mut writer: W,
+) -> io::Result<()> {
+    writeln!(writer, "# gltf OBJ exporter")?;
+    // Positions and normals are numbered from 1 across the whole file.
+    let (mut positions, mut normals) = (1, 1);
+    for instance in instances(model, meshes) {
+        match instance.name {
+            Some(name) => writeln!(writer, "o {}", name.replace(char::is_whitespace, "_"))?,
+            None => writeln!(writer, "o node{}", instance.node)?,
+        }
+        for surface in instance.surfaces {
+            for v in surface.positions.chunks_exact(3) {
+                writeln!(writer, "v {} {} {}", v[0], v[1], v[2])?;
+            }
+            for n in surface.normals.iter().flat_map(|x| x.chunks_exact(3)) {
+                writeln!(writer, "vn {} {} {}", n[0], n[1], n[2])?;
+            }
+            for triangle in &surface.triangles {
+                let [a, b, c] = triangle.map(|i| i as usize);
+                if surface.normals.is_some() {
+                    writeln!(
+                        writer,
+                        "f {}//{} {}//{} {}//{}",
+                        a + positions,
+                        a + normals,
+                        b + positions,
+                        b + normals,
+                        c + positions,
+                        c + normals
+                    )?;
+                } else {
+                    writeln!(
+                        writer,
+                        "f {} {} {}",
+                        a + positions,
+                        b + positions,
+                        c + positions
+                    )?;
+                }
+            }
+            positions += surface.positions.len() / 3;
+            normals += surface.normals.map_or(0, |x| x.len() / 3);
+        }
+    }
+    writer.flush()
+}
+
+/// Returns a line without its comment.
+fn strip_comment(line: &str) -> &str {
+    line.split('#').next().unwrap_or("")
+}
+
+/// Parses the first `N` words as numbers, ignoring any further words.
+fn floats<'a, I, const N: usize>(mut words: I) -> Option<[f32; N]>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut values = [0.0; N];
+    for value in &mut values {
+        *value = words.next()?.parse().ok()?;
+    }
+    Some(values)
+}
+
+/// A corner of a face: the indices of its position, texture co-ordinate and
+/// normal.
+type Corner = (usize, Option<usize>, Option<usize>);
+
+/// An object or group of an OBJ file.
+#[derive(Default)]
+struct Group {
+    #[cfg(feature = "names")]
+    name: Option<String>,
+    primitives: Vec<(Option<String>, Vec<Corner>)>,
+    material: Option<String>,
+}
+
+#[derive(Default)]
+struct Obj {
+    positions: Vec<[f32; 3]>,
+    tex_coords: Vec<[f32; 2]>,
+    normals: Vec<[f32; 3]>,
+    groups: Vec<Group>,
+}
+
+impl Obj {
+    /// Returns the current group, creating an unnamed one if there is none.
+    fn group(&mut self) -> &mut Group {
+        if self.groups.is_empty() {
+            self.groups.push(Group::default());
+        }
+        self.groups.last_mut().unwrap()
+    }
+
+    /// Returns the triangle corners of the current group and material.
+    fn faces(&mut self) -> &mut Vec<Corner> {
+        let group = self.group();
+        let material = group.material.clone();
+        match group.primitives.iter().position(|(x, _)| *x == material) {
+            Some(i) => &mut group.primitives[i].1,
+            None => {
+                group.primitives.push((material, Vec::new()));
+                &mut group.primitives.last_mut().unwrap().1
+            }
+        }
+    }
+
+    /// Parses a face corner such as `1`, `1/2`, `1//3` or `-1/-1/-1`.
+    fn corner(&self, word: &str, line: usize) -> Result<Corner, Error> {
+        let resolve = |index: Option<&str>, len: usize| -> Result<Option<usize>, Error> {
+            let index = match index {
+                Some("") | None => return Ok(None),
+                Some(index) => index,
+            };
+            let index: i64 = index.parse().map_err(|_| Error::Syntax {
+                library: None,
+                line,
+            })?;
+            let resolved = if index < 0 {
+                len as i64 + index
+            } else {
+                index - 1
+            };
+            if (0..len as i64).contains(&resolved) {
+                Ok(Some(resolved as usize))
+            } else {
+                Err(Error::Index { line })
+            }
+        };
+        let mut parts = word.split('/');
+        let position = resolve(parts.next(), self.positions.len())?;
+        let tex_coord = resolve(parts.next(), self.tex_coords.len())?;
+        let normal = resolve(parts.next(), self.normals.len())?;
+        match position {
+            Some(position) => Ok((position, tex_coord, normal)),
+            None => Err(Error::Syntax {
+                library: None,
+                line,
+            }),
+        }
+    }
+
+    fn into_model(self, libraries: &HashMap<String, Mtl>) -> Model {
+        let mut root = json::Root::new(json::Asset::new("gltf OBJ converter"));
+        let mut materials = HashMap::new();
+        for (name, _) in self.groups.iter().flat_map(|x| &x.primitives) {
+            if let Some(name) = name {
+                if !materials.contains_key(name) {
+                    let mtl = libraries.get(name).cloned().unwrap_or_default();
+                    #[allow(unused_mut)]
+                    let mut material = mtl.into_material(&mut root);
+                    #[cfg(feature = "names")]
+                    {
+                        material.name = Some(name.clone());
+                    }
+                    materials.insert(name.clone(), root.push(material));
+                }
+            }
+        }
+
+        let groups: Vec<&Group> = self
+            .groups
+            .iter()
+            .filter(|x| !x.primitives.is_empty())
+            .collect();
+        let mut meshes = Vec::with_capacity(groups.len());
+        let mut buffers = Vec::new();
+        if !groups.is_empty() {
+            let mut writer = BufferWriter::new(&mut root);
+            for group in &groups {
+                let primitives = group
+                    .primitives
+                    .iter()
+                    .map(|(material, corners)| {
+                        let mut primitive = self.write_primitive(corners, &mut writer);
+                        primitive.material = material.as_ref().map(|x| materials[x]);
+                        primitive
+                    })
+                    .collect();
+                meshes.push(primitives);
+            }
+            buffers.push(writer.finish().1);
+        }
+
+        let mut nodes = Vec::with_capacity(groups.len());
+        for primitives in meshes {
+            let mesh = root.push(json::Mesh {
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+                #[cfg(feature = "names")]
+                name: None,
+                primitives,
+                weights: None,
+            });
+            nodes.push(root.push(json::Node {
+                mesh: Some(mesh),
+                ..Default::default()
+            }));
+        }
+        // The document is new, so meshes and nodes match groups by index.
+        #[cfg(feature = "names")]
+        for (i, group) in groups.iter().enumerate() {
+            root.meshes[i].name = group.name.clone();
+            root.nodes[i].name = group.name.clone();
+        }
+        let scene = root.push(json::Scene {
+            nodes,
+            ..Default::default()
+        });
+        root.scene = Some(scene);
+        Model::new(root, buffers)
+    }
+
+    /// Writes the vertices and indices of triangle corners, merging corners
+    /// that share all attributes.
+    fn write_primitive(
+        &self,
+        corners: &[Corner],
+        writer: &mut BufferWriter,
+    ) -> json::mesh::Primitive {
+        // Attributes missing from any corner are left out altogether.
+        let tex_coords = corners.iter().all(|x| x.1.is_some());
+        let normals = corners.iter().all(|x| x.2.is_some());
+        let mut vertices = HashMap::new();
+        let mut data = (Vec::new(), Vec::new(), Vec::new());
+        let mut indices = Vec::with_capacity(corners.len());
+        for &(position, tex_coord, normal) in corners {
+            let key = (
+                position,
+                tex_coord.filter(|_| tex_coords),
+                normal.filter(|_| normals),
+            );
+            let index = *vertices.entry(key).or_insert_with(|| {
+                data.0.push(self.positions[position]);
+                if let Some(tex_coord) = key.1 {
+                    data.1.push(self.tex_coords[tex_coord]);
+                }
+                if let Some(normal) = key.2 {
+                    data.2.push(self.normals[normal]);
+                }
+                data.0.len() as u32 - 1
+            });
+            indices.push(index);
+        }
+
+        let mut attributes = BTreeMap::new();
+        attributes.insert(Valid(Semantic::Positions), writer.write_vertices(&data.0));
+        if tex_coords {
+            let accessor = writer.write_vertices(&data.1);
+            attributes.insert(Valid(Semantic::TexCoords(0)), accessor);
+        }
+        if normals {
+            attributes.insert(Valid(Semantic::Normals), writer.write_vertices(&data.2));
+        }
+        let indices = if data.0.len() <= u16::MAX as usize {
+            let indices: Vec<u16> = indices.into_iter().map(|x| x as u16).collect();
+            writer.write_indices(&indices)
+        } else {
+            writer.write_indices(&indices)
+        };
+        json::mesh::Primitive {
+            attributes,
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            indices: Some(indices),
+            material: None,
+            mode: Valid(Mode::Triangles),
+            targets: None,
+        }
+    }
+}
+
+/// The properties of an MTL material that are converted.
+#[derive(Clone, Debug)]
+struct Mtl {
+    diffuse: [f32; 3],
+    diffuse_map: Option<String>,
+    dissolve: f32,
+    emissive: [f32; 3],
+    shininess: Option<f32>,
+    roughness: Option<f32>,
+    metalness: Option<f32>,
+}
+
+impl Default for Mtl {
+    fn default() -> Self {
+        Mtl {
+            diffuse: [1.0; 3],
+            diffuse_map: None,
+            dissolve: 1.0,
+            emissive: [0.0; 3],
+            shininess: None,
+            roughness: None,
+            metalness: None,
+        }
+    }
+}
+
+impl Mtl {
+    fn into_material(self, root: &mut json::Root) -> json::Material {
+        let texture = self.diffuse_map.map(|uri| {
+            let source = root.push(json::Image {
+                buffer_view: None,
+                mime_type: None,
+                #[cfg(feature = "names")]
+                name: None,
+                uri: Some(uri.replace('\\', "/")),
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+            });
+            let index = root.push(json::Texture {
+                #[cfg(feature = "names")]
+                name: None,
+                sampler: None,
+                source: Some(source),
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+            });
+            json::texture::Info {
+                index,
+                tex_coord: 0,
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+            }
+        });
+        // A common approximation of the Blinn-Phong exponent as roughness.
+        let roughness = self
+            .roughness
+            .or_else(|| self.shininess.map(|x| (2.0 / (x.max(0.0) + 2.0)).sqrt()))
+            .unwrap_or(1.0);
+        let [r, g, b] = self.diffuse;
+        let mut material = json::Material {
+            alpha_mode: Valid(if self.dissolve < 1.0 {
+                AlphaMode::Blend
+            } else {
+                AlphaMode::Opaque
+            }),
+            emissive_factor: EmissiveFactor(self.emissive),
+            ..Default::default()
+        };
+        let pbr = &mut material.pbr_metallic_roughness;
+        pbr.base_color_factor = PbrBaseColorFactor([r, g, b, self.dissolve]);
+        pbr.base_color_texture = texture;
+        pbr.metallic_factor = StrengthFactor(self.metalness.unwrap_or(0.0));
+        pbr.roughness_factor = StrengthFactor(roughness);
+        material
+    }
+}
+
+/// Parses the materials of an MTL file.
+fn parse_mtl(source: &str, library: &str) -> Result<HashMap<String, Mtl>, Error> {
+    let mut materials = HashMap::new();
+    let mut current: Option<(String, Mtl)> = None;
+    for (number, text) in source.lines().enumerate() {
+        let error = || Error::Syntax {
+            library: Some(library.to_owned()),
+            line: number + 1,
+        };
+        let mut words = strip_comment(text).split_whitespace();
+        let keyword = match words.next() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        if keyword == "newmtl" {
+            let name = words.next().ok_or_else(error)?;
+            materials.extend(current.take());
+            current = Some((name.to_owned(), Mtl::default()));
+            continue;
+        }
+        let mtl = match current.as_mut() {
+            Some((_, mtl)) => mtl,
+            None => continue,
+        };
+        match keyword {
+            "Kd" => mtl.diffuse = floats(words).ok_or_else(error)?,
+            "Ke" => mtl.emissive = floats(words).ok_or_else(error)?,
+            "d" => mtl.dissolve = floats::<_, 1>(words).ok_or_else(error)?[0],
+            "Tr" => mtl.dissolve = 1.0 - floats::<_, 1>(words).ok_or_else(error)?[0],
+            "Ns" => mtl.shininess = Some(floats::<_, 1>(words).ok_or_else(error)?[0]),
+            "Pr" => mtl.roughness = Some(floats::<_, 1>(words).ok_or_else(error)?[0]),
+            "Pm" => mtl.metalness = Some(floats::<_, 1>(words).ok_or_else(error)?[0]),
+            // Options such as `-s 1 1 1` precede the file name.
+            "map_Kd" => mtl.diffuse_map = Some(words.last().ok_or_else(error)?.to_owned()),
+            _ => {}
+        }
+    }
+    materials.extend(current);
+    Ok(materials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, write, Error};
+    use crate::edit::tests::triangle;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+    use crate::json::{self, accessor::Type, material::AlphaMode, Index};
+
+    const OBJ: &str = "
+        mtllib scene.mtl
+        o quad
+        v 0 0 0
+        v 1 0 0
+        v 1 1 0
+        v 0 1 0
+        vt 0 0
+        vt 1 1
+        vn 0 0 1
+        usemtl red
+        f 1/1/1 2/1/1 3/2/1 4/2/1 # a quad
+        o triangle
+        usemtl missing
+        f -4 -3 -2
+    ";
+
+    const MTL: &str = "
+        newmtl red
+        Kd 1 0 0
+        d 0.5
+        Ns 0
+    ";
+
+    #[test]
+    fn parse_obj() {
+        let model = parse(OBJ, |name| {
+            assert_eq!(name, "scene.mtl");
+            Some(MTL.to_owned())
+        })
+        .unwrap();
+        let root = &model.root;
+        assert!(crate::Document::from_json(root.clone()).is_ok());
+        assert_eq!(root.meshes.len(), 2);
+        assert_eq!(root.scenes[0].nodes.len(), 2);
+
+        let quad = &root.meshes[0].primitives[0];
+        let positions = quad.attributes[&Valid(Semantic::Positions)];
+        let tex_coords = quad.attributes[&Valid(Semantic::TexCoords(0))];
+        assert_eq!(root.accessors[positions.value()].count, 4);
+        assert_eq!(
+            model.read_f32(tex_coords).unwrap(),
+            [0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0]
+        );
+        assert_eq!(
+            model.read_u32(quad.indices.unwrap()).unwrap(),
+            [0, 1, 2, 0, 2, 3]
+        );
+        assert!(quad.attributes.contains_key(&Valid(Semantic::Normals)));
+
+        let red = &root.materials[quad.material.unwrap().value()];
+        let pbr = &red.pbr_metallic_roughness;
+        assert_eq!(pbr.base_color_factor.0, [1.0, 0.0, 0.0, 0.5]);
+        assert_eq!((pbr.metallic_factor.0, pbr.roughness_factor.0), (0.0, 1.0));
+        assert_eq!(red.alpha_mode, Valid(AlphaMode::Blend));
+
+        let triangle = &root.meshes[1].primitives[0];
+        assert_eq!(triangle.attributes.len(), 1);
+        let missing = &root.materials[triangle.material.unwrap().value()];
+        assert_eq!(missing.pbr_metallic_roughness.base_color_factor.0, [1.0; 4]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            parse("v 0 0 0\nf 1 2 3", |_| None),
+            Err(Error::Index { line: 2 })
+        ));
+        assert!(matches!(
+            parse("v 0 0", |_| None),
+            Err(Error::Syntax {
+                library: None,
+                line: 1
+            })
+        ));
+        assert!(matches!(
+            parse("mtllib a.mtl", |_| Some("newmtl a\nKd 1".to_owned())),
+            Err(Error::Syntax {
+                library: Some(_),
+                line: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn write_obj() {
+        // The triangle under a translated parent, with and without mirroring.
+        let mut model = triangle();
+        let normals = model.push_f32(&[0.0, 0.0, 1.0].repeat(3), Type::Vec3, None);
+        model.root.meshes[0].primitives[0]
+            .attributes
+            .insert(Valid(Semantic::Normals), normals);
+        let nodes = r#"[
+            {"children": [1, 2], "translation": [1.0, 0.0, 0.0]},
+            {"mesh": 0},
+            {"mesh": 0, "scale": [-1.0, 1.0, 1.0]}
+        ]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}]"#).unwrap();
+
+        let mut output = Vec::new();
+        write(&model, &[Index::new(0)], &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines[1..6],
+            ["o node1", "v 1 0 0", "v 2 0 0", "v 1 2 0", "vn 0 0 1"]
+        );
+        assert!(lines.contains(&"o node2"));
+        assert!(lines.contains(&"f 1//1 2//2 3//3"));
+        assert!(lines.contains(&"f 4//4 6//6 5//5"));
+
+        let model = parse(&output, |_| None).unwrap();
+        assert_eq!(model.root.meshes.len(), 2);
+        let mut output = Vec::new();
+        write(&model, &[], &mut output).unwrap();
+        assert_eq!(output, b"# gltf OBJ exporter\n");
+    }
+}
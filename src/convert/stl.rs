@@ -0,0 +1,453 @@
+use super::world::instances;
+use crate::edit::{BufferWriter, Model};
+use crate::json::mesh::{Mode, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::{fmt, fs, io};
+
+/// The size of the header of binary STL, including the triangle count.
+const HEADER_SIZE: usize = 84;
+
+/// The size of a triangle of binary STL.
+const TRIANGLE_SIZE: usize = 50;
+
+/// Error converting an STL file.
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io(io::Error),
+
+    /// A statement of ASCII STL is malformed.
+    Syntax {
+        /// The line of the statement, starting at 1.
+        line: usize,
+    },
+
+    /// Binary STL is shorter than its triangle count requires.
+    Truncated,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => error.fmt(f),
+            Error::Syntax { line } => write!(f, "line {}: malformed statement", line),
+            Error::Truncated => write!(f, "binary STL data is truncated"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// Reads an STL file and converts it with [`parse`].
+pub fn import<P: AsRef<Path>>(path: P) -> Result<Model, Error> {
+    parse(&fs::read(path)?)
+}
+
+/// Converts binary or ASCII STL data to a model with one buffer.
+///
+/// All triangles become a single triangle primitive of a single mesh, with
+/// a node in a single scene. Every triangle gets its own vertices, with a
+/// normal computed from its vertices rather than read from the file, since
+/// the normals of STL files are often missing or wrong. The normal given by
+/// the file is only used for triangles of zero area. Data without any
+/// triangles gives an empty scene.
+///
+/// Data starting with `solid` is read as ASCII STL, unless its size matches
+/// the triangle count of binary STL, since some binary files start with
+/// `solid` too.
+pub fn parse(data: &[u8]) -> Result<Model, Error> {
+    #[cfg_attr(not(feature = "names"), allow(unused_variables))]
+    let (name, triangles) = if is_ascii(data) {
+        parse_ascii(&String::from_utf8_lossy(data))?
+    } else {
+        (None, parse_binary(data)?)
+    };
+
+    let mut root = json::Root::new(json::Asset::new("gltf STL converter"));
+    let mut nodes = Vec::new();
+    let mut buffers = Vec::new();
+    if !triangles.is_empty() {
+        let mut positions = Vec::with_capacity(triangles.len() * 3);
+        let mut normals = Vec::with_capacity(triangles.len() * 3);
+        for triangle in &triangles {
+            let normal = face_normal(triangle.vertices)
+                .or_else(|| normalize(triangle.normal))
+                .unwrap_or([0.0, 0.0, 1.0]);
+            positions.extend(triangle.vertices);
+            normals.extend([normal; 3]);
+        }
+
+        let mut writer = BufferWriter::new(&mut root);
+        let mut attributes = BTreeMap::new();
+        attributes.insert(
+            Valid(Semantic::Positions),
+            writer.write_vertices(&positions),
+        );
+        attributes.insert(Valid(Semantic::Normals), writer.write_vertices(&normals));
+        buffers.push(writer.finish().1);
+
+        let mesh = root.push(json::Mesh {
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: vec![json::mesh::Primitive {
+                attributes,
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+                indices: None,
+                material: None,
+                mode: Valid(Mode::Triangles),
+                targets: None,
+            }],
+            weights: None,
+        });
+        nodes.push(root.push(json::Node {
+            mesh: Some(mesh),
+            ..Default::default()
+        }));
+        #[cfg(feature = "names")]
+        {
+            root.meshes[0].name = name.clone();
+            root.nodes[0].name = name;
+        }
+    }
+
+    let scene = root.push(json::Scene {
+        nodes,
+        ..Default::default()
+    });
+    root.scene = Some(scene);
+    Ok(Model::new(root, buffers))
+}
+
+/// Writes the instances of `meshes` in a model to a binary STL file with
+/// [`write`].
+pub fn export<P: AsRef<Path>>(
+    model: &Model,
+    meshes: &[Index<json::Mesh>],
+    path: P,
+) -> io::Result<()> {
+    write(model, meshes, io::BufWriter::new(fs::File::create(path)?))
+}
+
+/// Writes the triangles of every instance of `meshes` in the default scene of
+/// a model, or else its first scene, as binary STL.
+///
+/// Positions are transformed to world space, and every triangle gets the
+/// normal computed from its vertices, or zero if it has zero area. Skinned
+/// meshes are written in their bind pose.
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidInput`] if there are
+/// more triangles than binary STL can hold.
+pub fn write<W: Write>(
+    model: &Model,
+    meshes: &[Index<json::Mesh>],
+    mut writer: W,
+) -> io::Result<()> {
+    let surfaces: Vec<_> = instances(model, meshes)
+        .into_iter()
+        .flat_map(|x| x.surfaces)
+        .collect();
+    let count = surfaces.iter().map(|x| x.triangles.len()).sum::<usize>();
+    let count = u32::try_from(count)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many triangles for STL"))?;
+
+    // The header must not start with `solid`, which denotes ASCII STL.
+    let mut header = [0; 80];
+    let name = b"gltf STL exporter";
+    header[..name.len()].copy_from_slice(name);
+    writer.write_all(&header)?;
+    writer.write_all(&count.to_le_bytes())?;
+    for surface in &surfaces {
+        let position = |i: u32| {
+            let i = i as usize * 3;
+            [0, 1, 2].map(|j| surface.positions[i + j])
+        };
+        for triangle in &surface.triangles {
+            let vertices = triangle.map(position);
+            let normal = face_normal(vertices).unwrap_or([0.0; 3]);
+            for x in [normal].iter().chain(&vertices).flatten() {
+                writer.write_all(&x.to_le_bytes())?;
+            }
+            writer.write_all(&[0, 0])?;
+        }
+    }
+    writer.flush()
+}
+
+/// A triangle with the normal given by the file.
+struct Triangle {
+    normal: [f32; 3],
+    vertices: [[f32; 3]; 3],
+}
+
+/// Returns whether `data` is ASCII rather than binary STL.
+fn is_ascii(data: &[u8]) -> bool {
+    let start = data.iter().position(|x| !x.is_ascii_whitespace());
+    let solid = start.map_or(false, |start| data[start..].starts_with(b"solid"));
+    solid && binary_size(data) != Some(data.len())
+}
+
+/// Returns the size of binary STL data according to its triangle count.
+fn binary_size(data: &[u8]) -> Option<usize> {
+    let count = data.get(80..HEADER_SIZE)?;
+    let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]);
+    (count as usize)
+        .checked_mul(TRIANGLE_SIZE)?
+        .checked_add(HEADER_SIZE)
+}
+
+fn parse_binary(data: &[u8]) -> Result<Vec<Triangle>, Error> {
+    match binary_size(data) {
+        Some(size) if size <= data.len() => {}
+        _ => return Err(Error::Truncated),
+    }
+    let read = |bytes: &[u8]| {
+        let mut vector = [0.0; 3];
+        for (x, bytes) in vector.iter_mut().zip(bytes.chunks_exact(4)) {
+            *x = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        vector
+    };
+    let triangles = data[HEADER_SIZE..binary_size(data).unwrap()]
+        .chunks_exact(TRIANGLE_SIZE)
+        .map(|bytes| Triangle {
+            normal: read(&bytes[0..12]),
+            vertices: [
+                read(&bytes[12..24]),
+                read(&bytes[24..36]),
+                read(&bytes[36..48]),
+            ],
+        })
+        .collect();
+    Ok(triangles)
+}
+
+/// Parses ASCII STL, returning the name of the first solid and the triangles
+/// of every solid.
+fn parse_ascii(source: &str) -> Result<(Option<String>, Vec<Triangle>), Error> {
+    let mut name = None;
+    let mut triangles = Vec::new();
+    let mut normal = [0.0; 3];
+    let mut vertices = Vec::with_capacity(3);
+    for (number, text) in source.lines().enumerate() {
+        let error = || Error::Syntax { line: number + 1 };
+        let mut words = text.split_whitespace();
+        match words.next() {
+            Some("solid") if name.is_none() => {
+                name = Some(words.collect::<Vec<_>>().join(" ")).filter(|x| !x.is_empty());
+            }
+            Some("facet") => {
+                if words.next() != Some("normal") {
+                    return Err(error());
+                }
+                normal = floats(words).ok_or_else(error)?;
+                vertices.clear();
+            }
+            Some("vertex") => vertices.push(floats(words).ok_or_else(error)?),
+            Some("endfacet") => match vertices[..] {
+                [a, b, c] => triangles.push(Triangle {
+                    normal,
+                    vertices: [a, b, c],
+                }),
+                _ => return Err(error()),
+            },
+            _ => {}
+        }
+    }
+    Ok((name, triangles))
+}
+
+/// Parses the next three words as numbers.
+fn floats<'a, I: Iterator<Item = &'a str>>(mut words: I) -> Option<[f32; 3]> {
+    let mut values = [0.0; 3];
+    for value in &mut values {
+        *value = words.next()?.parse().ok()?;
+    }
+    Some(values)
+}
+
+/// Returns the unit normal of a counter-clockwise triangle, or `None` if it
+/// has zero area.
+fn face_normal([a, b, c]: [[f32; 3]; 3]) -> Option<[f32; 3]> {
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    normalize([
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ])
+}
+
+/// Returns a unit length vector, or `None` for vectors of zero length or
+/// with values that are not finite.
+fn normalize(v: [f32; 3]) -> Option<[f32; 3]> {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length > 0.0 && length.is_finite() {
+        Some([v[0] / length, v[1] / length, v[2] / length])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, write, Error};
+    use crate::edit::tests::triangle;
+    use crate::json::accessor::Values;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+    use crate::json::{self, Index};
+
+    const ASCII: &str = "
+        solid wedge
+          facet normal 0 0 0
+            outer loop
+              vertex 0 0 0
+              vertex 0 1 0
+              vertex 1 0 0
+            endloop
+          endfacet
+          facet normal 0 0 1
+            outer loop
+              vertex 0 0 1
+              vertex 1 0 1
+              vertex 2 0 1
+            endloop
+          endfacet
+        endsolid wedge
+    ";
+
+    fn binary(triangles: &[[f32; 12]]) -> Vec<u8> {
+        let mut data = b"solid binary".to_vec();
+        data.resize(80, 0);
+        data.extend((triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            triangle.iter().for_each(|x| data.extend(x.to_le_bytes()));
+            data.extend([0, 0]);
+        }
+        data
+    }
+
+    #[test]
+    fn parse_ascii() {
+        let model = parse(ASCII.as_bytes()).unwrap();
+        let root = &model.root;
+        assert!(crate::Document::from_json(root.clone()).is_ok());
+        #[cfg(feature = "names")]
+        assert_eq!(root.meshes[0].name.as_deref(), Some("wedge"));
+        assert_eq!(root.scenes[0].nodes.len(), 1);
+
+        let primitive = &root.meshes[0].primitives[0];
+        assert!(primitive.indices.is_none());
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        let accessor = &root.accessors[positions.value()];
+        assert_eq!(accessor.count, 6);
+        assert_eq!(accessor.min_values(), Some(Values::F32(vec![0.0; 3])));
+        assert_eq!(
+            accessor.max_values(),
+            Some(Values::F32(vec![2.0, 1.0, 1.0]))
+        );
+
+        // The first normal is computed, the second taken from the file since
+        // the triangle has zero area.
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        let normals = model.read_f32(normals).unwrap();
+        assert_eq!(normals[..9], [0.0, 0.0, -1.0].repeat(3));
+        assert_eq!(normals[9..], [0.0, 0.0, 1.0].repeat(3));
+    }
+
+    #[test]
+    fn parse_binary() {
+        // Normals of the file are ignored, even when wrong.
+        let triangle = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        let model = parse(&binary(&[triangle])).unwrap();
+        let primitive = &model.root.meshes[0].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(model.read_f32(positions).unwrap(), triangle[3..]);
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        assert_eq!(model.read_f32(normals).unwrap(), [0.0, 0.0, 1.0].repeat(3));
+
+        let model = parse(&binary(&[])).unwrap();
+        assert!(model.root.meshes.is_empty() && model.buffers.is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let mut data = binary(&[[0.0; 12]]);
+        data[0] = 0;
+        data.pop();
+        assert!(matches!(parse(&data), Err(Error::Truncated)));
+        assert!(matches!(parse(b"\0"), Err(Error::Truncated)));
+        assert!(matches!(
+            parse(b"solid\nfacet normal 0 0 1\nvertex 0 0\n"),
+            Err(Error::Syntax { line: 3 })
+        ));
+        assert!(matches!(
+            parse(b"solid\nfacet normal 0 0 1\nvertex 0 0 0\nendfacet\n"),
+            Err(Error::Syntax { line: 4 })
+        ));
+    }
+
+    #[test]
+    fn write_binary() {
+        // The triangle under a translated parent, with and without mirroring.
+        let mut model = triangle();
+        let nodes = r#"[
+            {"children": [1, 2], "translation": [1.0, 0.0, 0.0]},
+            {"mesh": 0},
+            {"mesh": 0, "scale": [-1.0, 1.0, 1.0]}
+        ]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}]"#).unwrap();
+
+        let mut output = Vec::new();
+        write(&model, &[Index::new(0)], &mut output).unwrap();
+        assert_eq!(output.len(), 84 + 2 * 50);
+        assert!(!output.starts_with(b"solid"));
+        let normal: Vec<u8> = [0.0f32, 0.0, 1.0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        assert_eq!(output[84..96], normal);
+
+        let model = parse(&output).unwrap();
+        let primitive = &model.root.meshes[0].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(
+            model.read_f32(positions).unwrap(),
+            [
+                1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 2.0, 0.0, //
+                1.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0,
+            ]
+        );
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        assert_eq!(model.read_f32(normals).unwrap(), [0.0, 0.0, 1.0].repeat(6));
+    }
+
+    #[test]
+    fn write_cyclic_nodes() {
+        let mut model = triangle();
+        let nodes = r#"[{"mesh": 0, "children": [0]}]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}]"#).unwrap();
+        let mut output = Vec::new();
+        write(&model, &[Index::new(0)], &mut output).unwrap();
+        assert_eq!(output.len(), 84 + 50);
+    }
+}
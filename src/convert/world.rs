@@ -0,0 +1,109 @@
+use crate::edit::{determinant, Kind, Model};
+use crate::json::mesh::Semantic;
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use crate::math::Matrix4;
+
+/// A mesh instanced by a node, transformed to world space.
+#[cfg_attr(not(feature = "obj"), allow(dead_code))]
+pub(super) struct Instance {
+    /// The index of the node.
+    pub node: usize,
+
+    /// The name of the node, or else of its mesh.
+    pub name: Option<String>,
+
+    /// The triangle primitives of the mesh that could be read.
+    pub surfaces: Vec<Surface>,
+}
+
+/// The triangles of a primitive.
+#[cfg_attr(not(feature = "obj"), allow(dead_code))]
+pub(super) struct Surface {
+    /// The `POSITION` values, three per vertex.
+    pub positions: Vec<f32>,
+
+    /// The `NORMAL` values, three per vertex, if the primitive has normals.
+    pub normals: Option<Vec<f32>>,
+
+    /// The vertices of every triangle, counter-clockwise in world space.
+    pub triangles: Vec<[u32; 3]>,
+}
+
+/// Returns every instance of `meshes` in the default scene of a model, or
+/// else its first scene, in depth first order.
+///
+/// Skinned meshes are left in their bind pose, since their nodes do not
+/// transform them. Primitives without triangles, and primitives whose
+/// positions, normals or indices cannot be read, are skipped.
+pub(super) fn instances(model: &Model, meshes: &[Index<json::Mesh>]) -> Vec<Instance> {
+    let document = crate::Document::from_json_without_validation(model.root.clone());
+    let scene = match model.root.scene {
+        Some(index) => document.scenes().nth(index.value()),
+        None => document.scenes().next(),
+    };
+    let scene = match scene {
+        Some(scene) => scene,
+        None => return Vec::new(),
+    };
+    let mut instances = Vec::new();
+    // The model is not validated, so nodes may form cycles.
+    scene.walk(|node, matrix, _| {
+        let mesh = match node.mesh() {
+            Some(mesh) if meshes.contains(&Index::new(mesh.index() as u32)) => mesh,
+            _ => return,
+        };
+        let matrix = if node.skin().is_some() {
+            Matrix4::identity()
+        } else {
+            Matrix4::from_array(matrix)
+        };
+        let surfaces = model.root.meshes[mesh.index()]
+            .primitives
+            .iter()
+            .filter_map(|primitive| surface(model, primitive, &matrix))
+            .collect();
+        #[cfg(feature = "names")]
+        let name = node.name().or_else(|| mesh.name()).map(String::from);
+        #[cfg(not(feature = "names"))]
+        let name = None;
+        instances.push(Instance {
+            node: node.index(),
+            name,
+            surfaces,
+        });
+    });
+    instances
+}
+
+/// Returns the triangles of a primitive transformed by `matrix`, or `None`
+/// if they cannot be read.
+fn surface(model: &Model, primitive: &json::mesh::Primitive, matrix: &Matrix4) -> Option<Surface> {
+    let positions = *primitive.attributes.get(&Valid(Semantic::Positions))?;
+    let (positions, _) = model.transformed(positions, Kind::Point, matrix)?;
+    let normals = match primitive.attributes.get(&Valid(Semantic::Normals)) {
+        Some(&normals) => Some(model.transformed(normals, Kind::Normal, matrix)?.0),
+        None => None,
+    };
+    let mut triangles = model.triangles(primitive)?;
+    let count = positions.len() / 3;
+    if triangles.iter().flatten().any(|&i| i as usize >= count) {
+        return None;
+    }
+    if normals
+        .as_ref()
+        .map_or(false, |x| x.len() != positions.len())
+    {
+        return None;
+    }
+    if determinant(matrix) < 0.0 {
+        triangles
+            .iter_mut()
+            .for_each(|triangle| triangle.swap(1, 2));
+    }
+    Some(Surface {
+        positions,
+        normals,
+        triangles,
+    })
+}
@@ -0,0 +1,503 @@
+use super::Model;
+use crate::json::accessor::{Accessor, Type};
+use crate::json::animation::Property;
+use crate::json::mesh::{Mode, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use std::collections::BTreeMap;
+use std::ops::Neg;
+
+/// A coordinate axis with a direction, as used by [`Model::convert_axes`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// The positive X axis.
+    X,
+    /// The negative X axis.
+    NegX,
+    /// The positive Y axis.
+    Y,
+    /// The negative Y axis.
+    NegY,
+    /// The positive Z axis.
+    Z,
+    /// The negative Z axis.
+    NegZ,
+}
+
+impl Axis {
+    /// Returns the component index of the axis and whether it is negative.
+    fn split(self) -> (usize, bool) {
+        match self {
+            Axis::X => (0, false),
+            Axis::NegX => (0, true),
+            Axis::Y => (1, false),
+            Axis::NegY => (1, true),
+            Axis::Z => (2, false),
+            Axis::NegZ => (2, true),
+        }
+    }
+}
+
+/// How an accessor is affected by a change of axes.
+#[derive(Clone, Copy)]
+enum Kind {
+    /// A `VEC3` position, direction or displacement.
+    Vector,
+    /// A `VEC4` tangent with the bitangent sign in `w`.
+    Tangent,
+    /// A `VEC4` rotation quaternion.
+    Rotation,
+    /// A `VEC3` scale factor.
+    Scale,
+    /// A `MAT4` transform.
+    Matrix,
+}
+
+impl Kind {
+    fn type_(self) -> Type {
+        match self {
+            Kind::Vector | Kind::Scale => Type::Vec3,
+            Kind::Tangent | Kind::Rotation => Type::Vec4,
+            Kind::Matrix => Type::Mat4,
+        }
+    }
+}
+
+/// A change of axes, mapping component `i` to component `axes[i].0`, negated
+/// if `axes[i].1` is `true`.
+struct Basis {
+    axes: [(usize, bool); 3],
+    mirrored: bool,
+}
+
+impl Basis {
+    fn vector<T: Copy + Neg<Output = T>>(&self, v: &mut [T]) {
+        let old = [v[0], v[1], v[2]];
+        for (&value, &(i, negated)) in old.iter().zip(&self.axes) {
+            v[i] = if negated { -value } else { value };
+        }
+    }
+
+    fn scale<T: Copy>(&self, v: &mut [T]) {
+        let old = [v[0], v[1], v[2]];
+        for (&value, &(i, _)) in old.iter().zip(&self.axes) {
+            v[i] = value;
+        }
+    }
+
+    /// The vector part of a quaternion is an axis of rotation, which also
+    /// flips when the basis is mirrored.
+    fn rotation<T: Copy + Neg<Output = T>>(&self, q: &mut [T]) {
+        self.vector(q);
+        if self.mirrored {
+            for value in &mut q[..3] {
+                *value = -*value;
+            }
+        }
+    }
+
+    fn tangent<T: Copy + Neg<Output = T>>(&self, t: &mut [T]) {
+        self.vector(t);
+        if self.mirrored {
+            t[3] = -t[3];
+        }
+    }
+
+    /// Conjugates a column-major matrix by the basis.
+    fn matrix<T: Copy + Neg<Output = T>>(&self, m: &mut [T]) {
+        let old = [
+            m[0], m[1], m[2], m[3], m[4], m[5], m[6], m[7], m[8], m[9], m[10], m[11], m[12], m[13],
+            m[14], m[15],
+        ];
+        let axes = [self.axes[0], self.axes[1], self.axes[2], (3, false)];
+        for (column, &(c, column_negated)) in axes.iter().enumerate() {
+            for (row, &(r, row_negated)) in axes.iter().enumerate() {
+                let value = old[column * 4 + row];
+                m[c * 4 + r] = if column_negated != row_negated {
+                    -value
+                } else {
+                    value
+                };
+            }
+        }
+    }
+
+    /// Returns the column-major matrix mapping vectors to the new basis.
+    fn to_matrix(&self) -> [f32; 16] {
+        let mut m = [0.0; 16];
+        for (column, &(row, negated)) in self.axes.iter().enumerate() {
+            m[column * 4 + row] = if negated { -1.0 } else { 1.0 };
+        }
+        m[15] = 1.0;
+        m
+    }
+
+    fn is_identity(&self) -> bool {
+        self.axes == [(0, false), (1, false), (2, false)]
+    }
+
+    fn apply(&self, kind: Kind, element: &mut [f64]) {
+        match kind {
+            Kind::Vector => self.vector(element),
+            Kind::Tangent => self.tangent(element),
+            Kind::Rotation => self.rotation(element),
+            Kind::Scale => self.scale(element),
+            Kind::Matrix => self.matrix(element),
+        }
+    }
+}
+
+impl Model {
+    /// Converts the document to another coordinate system, given the axes
+    /// that the `+X`, `+Y` and `+Z` axes of glTF become.
+    ///
+    /// glTF uses a right-handed coordinate system with `+Y` up. For example,
+    /// `[Axis::X, Axis::Z, Axis::NegY]` converts to a right-handed system
+    /// with `+Z` up, `[Axis::X, Axis::NegZ, Axis::Y]` converts back, and
+    /// `[Axis::X, Axis::Y, Axis::NegZ]` converts to a left-handed system with
+    /// `+Y` up.
+    ///
+    /// Node transforms, `POSITION`, `NORMAL` and `TANGENT` attributes, morph
+    /// targets, inverse bind matrices, animated translations, rotations and
+    /// scales, and `EXT_mesh_gpu_instancing` attributes are converted, and
+    /// their bounds updated. Triangles are rewound when the handedness
+    /// changes.
+    ///
+    /// Cameras and lights face along the `-Z` axis of their own node in every
+    /// coordinate system, so converting their node would turn them. They are
+    /// moved to a new child node whose matrix applies the change of axes, and
+    /// thus keep facing the same way in the converted scene.
+    ///
+    /// Converted data is appended to the first buffer as new buffer views.
+    /// Buffer views and accessors no longer referenced are left in place and
+    /// may be removed afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axes are not distinct.
+    pub fn convert_axes(&mut self, axes: [Axis; 3]) {
+        let axes = axes.map(Axis::split);
+        let rows = axes.map(|(i, _)| i);
+        assert!(
+            rows[0] != rows[1] && rows[1] != rows[2] && rows[0] != rows[2],
+            "{:?} are not distinct axes",
+            axes
+        );
+        // An odd permutation or an odd number of negated axes mirrors.
+        let odd = (rows[0] > rows[1]) ^ (rows[0] > rows[2]) ^ (rows[1] > rows[2]);
+        let negated = axes.iter().filter(|(_, negated)| *negated).count() % 2 == 1;
+        let basis = Basis {
+            axes,
+            mirrored: odd != negated,
+        };
+
+        for node in &mut self.root.nodes {
+            if let Some(matrix) = node.matrix.as_mut() {
+                basis.matrix(matrix);
+            }
+            if let Some(translation) = node.translation.as_mut() {
+                basis.vector(translation);
+            }
+            if let Some(rotation) = node.rotation.as_mut() {
+                basis.rotation(&mut rotation.0);
+            }
+            if let Some(scale) = node.scale.as_mut() {
+                basis.scale(scale);
+            }
+        }
+
+        if !basis.is_identity() {
+            self.reorient_cameras_and_lights(&basis);
+        }
+
+        for (accessor, kind) in self.accessor_kinds() {
+            self.convert_accessor(accessor, kind, &basis);
+        }
+
+        if basis.mirrored {
+            for mesh in 0..self.root.meshes.len() {
+                for index in 0..self.root.meshes[mesh].primitives.len() {
+                    let primitive = &self.root.meshes[mesh].primitives[index];
+                    if let Some(triangles) = self.triangles(primitive) {
+                        let indices = self.push_rewound(&triangles);
+                        let primitive = &mut self.root.meshes[mesh].primitives[index];
+                        primitive.indices = Some(indices);
+                        primitive.mode = Valid(Mode::Triangles);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the cameras and lights of every node to a new child node whose
+    /// matrix is the change of axes, which must be called after node
+    /// transforms are converted.
+    fn reorient_cameras_and_lights(&mut self, basis: &Basis) {
+        for index in 0..self.root.nodes.len() {
+            let node = &mut self.root.nodes[index];
+            let camera = node.camera.take();
+            #[cfg(feature = "KHR_lights_punctual")]
+            let light = node
+                .extensions
+                .as_mut()
+                .and_then(|x| x.khr_lights_punctual.take());
+            #[cfg(not(feature = "KHR_lights_punctual"))]
+            let light: Option<()> = None;
+            if camera.is_none() && light.is_none() {
+                continue;
+            }
+            if node.extensions == Some(Default::default()) {
+                node.extensions = None;
+            }
+
+            #[cfg_attr(not(feature = "KHR_lights_punctual"), allow(unused_mut))]
+            let mut child = json::Node {
+                camera,
+                matrix: Some(basis.to_matrix()),
+                ..Default::default()
+            };
+            #[cfg(feature = "KHR_lights_punctual")]
+            if light.is_some() {
+                child.extensions = Some(json::extensions::scene::Node {
+                    khr_lights_punctual: light,
+                    ..Default::default()
+                });
+            }
+            let child = self.root.push(child);
+            self.root.nodes[index]
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(child);
+        }
+    }
+
+    /// Returns every accessor affected by a change of axes. Accessors used in
+    /// several ways are converted as they are first encountered.
+    fn accessor_kinds(&self) -> BTreeMap<Index<Accessor>, Kind> {
+        let mut kinds = BTreeMap::new();
+        let semantics = [
+            (Semantic::Positions, Kind::Vector),
+            (Semantic::Normals, Kind::Vector),
+            (Semantic::Tangents, Kind::Tangent),
+        ];
+        for primitive in self.root.meshes.iter().flat_map(|x| &x.primitives) {
+            for (semantic, kind) in &semantics {
+                if let Some(&accessor) = primitive.attributes.get(&Valid(semantic.clone())) {
+                    kinds.entry(accessor).or_insert(*kind);
+                }
+            }
+            for target in primitive.targets.iter().flatten() {
+                let displacements = target
+                    .positions
+                    .iter()
+                    .chain(&target.normals)
+                    .chain(&target.tangents);
+                for &accessor in displacements {
+                    kinds.entry(accessor).or_insert(Kind::Vector);
+                }
+            }
+        }
+        for skin in &self.root.skins {
+            if let Some(accessor) = skin.inverse_bind_matrices {
+                kinds.entry(accessor).or_insert(Kind::Matrix);
+            }
+        }
+        for animation in &self.root.animations {
+            for channel in &animation.channels {
+                let kind = match channel.target.path {
+                    Valid(Property::Translation) => Kind::Vector,
+                    Valid(Property::Rotation) => Kind::Rotation,
+                    Valid(Property::Scale) => Kind::Scale,
+                    _ => continue,
+                };
+                if let Some(sampler) = animation.samplers.get(channel.sampler.value()) {
+                    kinds.entry(sampler.output).or_insert(kind);
+                }
+            }
+        }
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        for node in &self.root.nodes {
+            let instancing = node
+                .extensions
+                .as_ref()
+                .and_then(|x| x.ext_mesh_gpu_instancing.as_ref());
+            for (name, &accessor) in instancing.iter().flat_map(|x| &x.attributes) {
+                let kind = match name.as_str() {
+                    "TRANSLATION" => Kind::Vector,
+                    "ROTATION" => Kind::Rotation,
+                    "SCALE" => Kind::Scale,
+                    _ => continue,
+                };
+                kinds.entry(accessor).or_insert(kind);
+            }
+        }
+        kinds
+    }
+
+    /// Rewrites the data and bounds of an accessor in the new basis, leaving
+    /// accessors of an unexpected type or with unreadable data alone.
+    fn convert_accessor(&mut self, index: Index<Accessor>, kind: Kind, basis: &Basis) {
+        match self.root.get(index) {
+            Some(accessor) if accessor.type_ == Valid(kind.type_()) => {}
+            _ => return,
+        }
+        let mut values = match self.read_raw(index) {
+            Some(values) => values,
+            None => return,
+        };
+        let components = kind.type_().multiplicity();
+        for element in values.chunks_exact_mut(components) {
+            basis.apply(kind, element);
+        }
+
+        // Borrow the buffer view of a new accessor.
+        self.push_raw(index, &values);
+        let replacement = self.root.accessors.pop().unwrap();
+        let accessor = &mut self.root.accessors[index.value()];
+        accessor.buffer_view = replacement.buffer_view;
+        accessor.byte_offset = 0;
+        accessor.sparse = None;
+
+        // Negating a component swaps its bounds.
+        let bounds = |value: &Option<json::Value>| {
+            let value = value.clone()?;
+            let values: Vec<f64> = json::deserialize::from_value(value).ok()?;
+            Some(values).filter(|x| x.len() == components)
+        };
+        if let (Some(mut min), Some(mut max)) = (bounds(&accessor.min), bounds(&accessor.max)) {
+            basis.apply(kind, &mut min);
+            basis.apply(kind, &mut max);
+            for (min, max) in min.iter_mut().zip(&mut max) {
+                if min > max {
+                    std::mem::swap(min, max);
+                }
+            }
+            accessor.min = Some(json::Value::from(min));
+            accessor.max = Some(json::Value::from(max));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Axis;
+    use crate::edit::tests::triangle;
+    use crate::json::{self, Index};
+
+    const Z_UP: [Axis; 3] = [Axis::X, Axis::Z, Axis::NegY];
+    const Y_UP: [Axis; 3] = [Axis::X, Axis::NegZ, Axis::Y];
+
+    fn bounds(value: &Option<json::Value>) -> Vec<f32> {
+        json::deserialize::from_value(value.clone().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn convert_axes() {
+        let mut model = triangle();
+        let nodes = r#"[{
+            "mesh": 0,
+            "translation": [1.0, 2.0, 3.0],
+            "rotation": [0.0, 0.6, 0.0, 0.8],
+            "scale": [1.0, 2.0, 3.0]
+        }]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.update_bounds();
+
+        model.convert_axes(Z_UP);
+        let node = &model.root.nodes[0];
+        assert_eq!(node.translation, Some([1.0, -3.0, 2.0]));
+        assert_eq!(node.rotation.unwrap().0, [0.0, 0.0, 0.6, 0.8]);
+        assert_eq!(node.scale, Some([1.0, 3.0, 2.0]));
+        assert_eq!(
+            model.read_f32(Index::new(0)).unwrap(),
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0]
+        );
+        let accessor = &model.root.accessors[0];
+        assert_eq!(bounds(&accessor.min), [0.0, 0.0, 0.0]);
+        assert_eq!(bounds(&accessor.max), [1.0, 0.0, 2.0]);
+        assert_eq!(
+            model.root.meshes[0].primitives[0].indices,
+            Some(Index::new(1))
+        );
+
+        model.convert_axes(Y_UP);
+        let node = &model.root.nodes[0];
+        assert_eq!(node.translation, Some([1.0, 2.0, 3.0]));
+        assert_eq!(node.rotation.unwrap().0, [0.0, 0.6, 0.0, 0.8]);
+        assert_eq!(
+            model.read_f32(Index::new(0)).unwrap(),
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0]
+        );
+    }
+
+    /// Returns the world space position, forward and up vectors of the first
+    /// camera of the first scene, rounded to hide rounding errors.
+    fn camera_frame(model: &crate::edit::Model) -> [[f32; 3]; 3] {
+        let document = crate::Document::from_json(model.root.clone()).unwrap();
+        let scene = document.scenes().next().unwrap();
+        let (_, m) = scene
+            .world_transforms()
+            .find(|(node, _)| node.camera().is_some())
+            .unwrap();
+        let round = |v: [f32; 3]| v.map(|x| (x * 1e4).round() / 1e4 + 0.0);
+        [
+            round([m[3][0], m[3][1], m[3][2]]),
+            round([-m[2][0], -m[2][1], -m[2][2]]),
+            round([m[1][0], m[1][1], m[1][2]]),
+        ]
+    }
+
+    #[test]
+    fn convert_axes_cameras() {
+        let mut model = triangle();
+        let cameras = r#"[{"type": "perspective", "perspective": {"yfov": 1.0, "znear": 0.1}}]"#;
+        let nodes = r#"[{
+            "camera": 0,
+            "translation": [1.0, 2.0, 3.0],
+            "rotation": [0.70710677, 0.0, 0.0, 0.70710677]
+        }]"#;
+        model.root.cameras = json::deserialize::from_str(cameras).unwrap();
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}]"#).unwrap();
+        model.update_bounds();
+
+        // The camera looks along +Y with +Z up.
+        let before = [[1.0, 2.0, 3.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(camera_frame(&model), before);
+
+        // With +Z up, it looks along +Z with -Y up.
+        model.convert_axes(Z_UP);
+        assert_eq!(model.root.nodes[0].camera, None);
+        assert_eq!(model.root.nodes[0].children, Some(vec![Index::new(1)]));
+        let after = [[1.0, -3.0, 2.0], [0.0, 0.0, 1.0], [0.0, -1.0, 0.0]];
+        assert_eq!(camera_frame(&model), after);
+
+        model.convert_axes(Y_UP);
+        assert_eq!(camera_frame(&model), before);
+
+        // Mirroring Z flips the up vector only.
+        model.convert_axes([Axis::X, Axis::Y, Axis::NegZ]);
+        let mirrored = [[1.0, 2.0, -3.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        assert_eq!(camera_frame(&model), mirrored);
+    }
+
+    #[test]
+    fn convert_handedness() {
+        let mut model = triangle();
+        let nodes = r#"[{"mesh": 0, "rotation": [0.0, 0.6, 0.0, 0.8]}]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.convert_axes([Axis::X, Axis::Y, Axis::NegZ]);
+
+        // Mirroring reverses the direction of rotations and triangles.
+        let node = &model.root.nodes[0];
+        assert_eq!(node.rotation.unwrap().0, [0.0, -0.6, 0.0, 0.8]);
+        let indices = model.root.meshes[0].primitives[0].indices.unwrap();
+        assert_eq!(model.read_u32(indices).unwrap(), [0, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeated_axes() {
+        triangle().convert_axes([Axis::X, Axis::X, Axis::Z]);
+    }
+}
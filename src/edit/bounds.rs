@@ -0,0 +1,116 @@
+use super::Model;
+use crate::json::{self, accessor::Accessor, mesh::Semantic, validation::Checked::Valid, Index};
+use std::collections::BTreeSet;
+
+impl Model {
+    /// Recomputes the `min` and `max` values of every accessor used as a
+    /// `POSITION` attribute or morph target, which the specification requires.
+    ///
+    /// Values are rewritten only when they are missing or differ from the
+    /// accessor data. Returns the number of accessors that were updated.
+    pub fn update_bounds(&mut self) -> usize {
+        let mut positions = BTreeSet::new();
+        for primitive in self.root.meshes.iter().flat_map(|mesh| &mesh.primitives) {
+            positions.extend(primitive.attributes.get(&Valid(Semantic::Positions)));
+            for target in primitive.targets.iter().flatten() {
+                positions.extend(target.positions);
+            }
+        }
+
+        positions
+            .into_iter()
+            .filter(|&index| self.update_accessor_bounds(index))
+            .count()
+    }
+
+    /// Recomputes the `min` and `max` values of one accessor, returning
+    /// whether they were missing or differed from the accessor data.
+    pub(super) fn update_accessor_bounds(&mut self, index: Index<Accessor>) -> bool {
+        let (min, max) = match self.compute_bounds(index) {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+        let accessor = &mut self.root.accessors[index.value()];
+        // Compare in `f32`, since the bounds parsed from JSON are not the
+        // `f64` values the `f32` data serializes to.
+        if bounds(&accessor.min).as_ref() == Some(&min)
+            && bounds(&accessor.max).as_ref() == Some(&max)
+        {
+            return false;
+        }
+        accessor.min = Some(json::Value::from(min));
+        accessor.max = Some(json::Value::from(max));
+        true
+    }
+
+    /// Returns the per-component minimum and maximum of an accessor's data, or
+    /// `None` if the data cannot be read or the accessor is empty.
+    pub(super) fn compute_bounds(&self, index: Index<Accessor>) -> Option<(Vec<f32>, Vec<f32>)> {
+        let accessor = self.root.get(index)?;
+        let values = self.read_f32(index)?;
+        let components = match accessor.type_ {
+            Valid(type_) => type_.multiplicity(),
+            _ => return None,
+        };
+        let mut elements = values.chunks_exact(components);
+        let first = elements.next()?;
+        let (mut min, mut max) = (first.to_vec(), first.to_vec());
+        for element in elements {
+            for (i, &value) in element.iter().enumerate() {
+                min[i] = min[i].min(value);
+                max[i] = max[i].max(value);
+            }
+        }
+        Some((min, max))
+    }
+}
+
+/// Reads the `min` or `max` value of an accessor as `f32` components.
+fn bounds(value: &Option<json::Value>) -> Option<Vec<f32>> {
+    json::deserialize::from_value(value.clone()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json::Value;
+
+    #[test]
+    fn update_bounds() {
+        let mut model = triangle();
+        assert_eq!(model.update_bounds(), 1);
+        let accessor = &model.root.accessors[0];
+        let min: [f32; 3] =
+            crate::json::deserialize::from_value(accessor.min.clone().unwrap()).unwrap();
+        let max: [f32; 3] =
+            crate::json::deserialize::from_value(accessor.max.clone().unwrap()).unwrap();
+        assert_eq!(min, [0.0, 0.0, 0.0]);
+        assert_eq!(max, [1.0, 2.0, 0.0]);
+
+        // Up to date bounds are left alone.
+        assert_eq!(model.update_bounds(), 0);
+
+        // Stale bounds are replaced.
+        model.root.accessors[0].max = Some(Value::from(vec![5.0, 5.0, 5.0]));
+        assert_eq!(model.update_bounds(), 1);
+
+        // Bounds that are not exact in `f32` are up to date once parsed.
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [{
+                "bufferView": 0, "count": 1, "componentType": 5126, "type": "VEC3",
+                "min": [0.1, 0.2, 0.3], "max": [0.1, 0.2, 0.3]
+            }],
+            "buffers": [{"byteLength": 12}],
+            "bufferViews": [{"buffer": 0, "byteLength": 12}],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}]
+        }"#;
+        let root = crate::json::Root::from_str(json).unwrap();
+        let data = [0.1f32, 0.2, 0.3]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let mut model = crate::edit::Model::new(root, vec![data]);
+        assert_eq!(model.update_bounds(), 0);
+    }
+}
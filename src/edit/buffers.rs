@@ -0,0 +1,162 @@
+use super::Model;
+use crate::json::Index;
+
+impl Model {
+    /// Concatenates the contents of every buffer into a single buffer and
+    /// rewrites the byte offsets of buffer views to match.
+    ///
+    /// Every buffer is placed at a multiple of four bytes, so views keep the
+    /// alignment they had within their original buffer. The merged buffer
+    /// keeps the name and extras of the first buffer but has no URI, because
+    /// its contents are not stored anywhere yet; this is what a GLB binary
+    /// chunk expects. Does nothing if there is at most one buffer.
+    ///
+    /// Fallback buffers of `EXT_meshopt_compression` are kept as separate
+    /// buffers after the merged one, since they must keep their extension,
+    /// and the compressed data of buffer views is remapped like the views.
+    pub fn merge_buffers(&mut self) {
+        let fallback: Vec<bool> = self.root.buffers.iter().map(is_fallback).collect();
+        if fallback.iter().filter(|&&x| !x).count() < 2 {
+            return;
+        }
+        self.buffers.resize_with(self.root.buffers.len(), Vec::new);
+        let mut merged = Vec::new();
+        // The new buffer and offset of the contents of every buffer.
+        let mut remap = Vec::with_capacity(self.buffers.len());
+        let mut next = 1;
+        for (data, &fallback) in self.buffers.iter().zip(&fallback) {
+            if fallback {
+                remap.push((next, 0));
+                next += 1;
+                continue;
+            }
+            // Keep every view aligned for its largest possible component type.
+            while merged.len() % 4 != 0 {
+                merged.push(0);
+            }
+            remap.push((0, merged.len() as u32));
+            merged.extend_from_slice(data);
+        }
+        for view in &mut self.root.buffer_views {
+            if let Some(&(buffer, offset)) = remap.get(view.buffer.value()) {
+                if buffer == 0 {
+                    view.byte_offset = Some(view.byte_offset.unwrap_or(0) + offset);
+                }
+                view.buffer = Index::new(buffer);
+            }
+            #[cfg(feature = "EXT_meshopt_compression")]
+            if let Some(compression) = view
+                .extensions
+                .as_mut()
+                .and_then(|x| x.ext_meshopt_compression.as_mut())
+            {
+                if let Some(&(buffer, offset)) = remap.get(compression.buffer.value()) {
+                    compression.byte_offset += offset;
+                    compression.buffer = Index::new(buffer);
+                }
+            }
+        }
+
+        let first = fallback.iter().position(|&x| !x).unwrap();
+        let mut buffer = self.root.buffers[first].clone();
+        buffer.byte_length = merged.len() as u32;
+        buffer.uri = None;
+        let mut buffers = vec![buffer];
+        let mut data = vec![merged];
+        let old = self
+            .root
+            .buffers
+            .drain(..)
+            .zip(self.buffers.drain(..))
+            .zip(fallback);
+        for ((buffer, contents), fallback) in old {
+            if fallback {
+                buffers.push(buffer);
+                data.push(contents);
+            }
+        }
+        self.root.buffers = buffers;
+        self.buffers = data;
+    }
+}
+
+/// Returns whether a buffer is a fallback buffer of `EXT_meshopt_compression`.
+#[cfg_attr(not(feature = "EXT_meshopt_compression"), allow(unused_variables))]
+fn is_fallback(buffer: &crate::json::Buffer) -> bool {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    if let Some(extension) = buffer
+        .extensions
+        .as_ref()
+        .and_then(|x| x.ext_meshopt_compression.as_ref())
+    {
+        return extension.fallback;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json::{self, Index};
+
+    #[test]
+    fn merge_buffers() {
+        let mut model = triangle();
+        let mut buffer = model.root.buffers[0].clone();
+        buffer.byte_length = 2;
+        model.root.push(buffer);
+        model.buffers.push(vec![1, 2]);
+        model.root.push(json::buffer::View {
+            buffer: Index::new(1),
+            byte_length: 1,
+            byte_offset: Some(1),
+            ..model.root.buffer_views[1].clone()
+        });
+
+        model.merge_buffers();
+        assert_eq!(model.root.buffers.len(), 1);
+        assert_eq!(model.root.buffers[0].byte_length, 42);
+        assert_eq!(model.buffers[0].len(), 42);
+        let view = &model.root.buffer_views[2];
+        assert_eq!(view.buffer, Index::new(0));
+        assert_eq!(view.byte_offset, Some(41));
+        assert_eq!(model.buffers[0][41], 2);
+        assert_eq!(model.read_u32(Index::new(1)).unwrap(), [0, 1, 2]);
+    }
+
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[test]
+    fn merge_buffers_keeps_meshopt_fallback() {
+        let mut model = triangle();
+        let buffers = r#"[
+            {"byteLength": 2},
+            {"byteLength": 4, "extensions": {"EXT_meshopt_compression": {"fallback": true}}}
+        ]"#;
+        let buffers: Vec<json::Buffer> = json::deserialize::from_str(buffers).unwrap();
+        model.root.buffers.extend(buffers);
+        model.buffers.extend([vec![1, 2], vec![0; 4]]);
+        let view = r#"{
+            "buffer": 2, "byteLength": 4,
+            "extensions": {"EXT_meshopt_compression": {
+                "buffer": 1, "byteOffset": 1, "byteLength": 1, "byteStride": 4, "count": 1,
+                "mode": "ATTRIBUTES"
+            }}
+        }"#;
+        model
+            .root
+            .push(json::deserialize::from_str::<json::buffer::View>(view).unwrap());
+
+        model.merge_buffers();
+        assert_eq!(model.root.buffers.len(), 2);
+        assert_eq!(model.buffers.len(), 2);
+        assert_eq!(model.root.buffers[0].byte_length, 42);
+        let fallback = model.root.buffers[1].extensions.as_ref().unwrap();
+        assert!(fallback.ext_meshopt_compression.as_ref().unwrap().fallback);
+        let view = &model.root.buffer_views[2];
+        assert_eq!(view.buffer, Index::new(1));
+        let extensions = view.extensions.as_ref().unwrap();
+        let compression = extensions.ext_meshopt_compression.as_ref().unwrap();
+        assert_eq!(compression.buffer, Index::new(0));
+        assert_eq!(model.buffers[0][compression.byte_offset as usize], 2);
+    }
+}
@@ -0,0 +1,423 @@
+use super::{normalized_scale, Model};
+use crate::json::accessor::{Accessor, ComponentType, GenericComponentType};
+use crate::json::extensions::mesh::KhrDracoMeshCompression;
+use crate::json::mesh::{Mode, Primitive, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use crate::mesh::util::{DracoAttribute, DracoGeometry};
+use std::collections::BTreeMap;
+
+/// The result of encoding a primitive with Draco.
+#[derive(Clone, Debug)]
+pub struct DracoEncoded {
+    /// The compressed data.
+    pub data: Vec<u8>,
+
+    /// The unique id of every attribute in the compressed data, in the order
+    /// of [`DracoGeometry::attributes`].
+    pub ids: Vec<u32>,
+}
+
+impl Model {
+    /// Compresses primitives with `KHR_draco_mesh_compression`, returning the
+    /// number of primitives compressed.
+    ///
+    /// This library does not implement Draco itself; `encode` is called with
+    /// the mesh index, primitive index and geometry of every triangle
+    /// primitive without morph targets, and returns the encoded data, or
+    /// `None` to leave the primitive uncompressed. Triangle strips and fans
+    /// are converted to triangle lists.
+    ///
+    /// The compressed data is appended to the first buffer as a new buffer
+    /// view. Every compressed primitive gets new accessors without buffer
+    /// views, keeping the type and bounds of the ones they replace. The
+    /// replaced accessors and their data are left in place, in case other
+    /// primitives share them, and must be removed afterwards with
+    /// [`Model::prune`] for the output to shrink. The extension is marked as
+    /// used and required, since the uncompressed data is not kept as a
+    /// fallback.
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn compress_draco<F>(&mut self, mut encode: F) -> usize
+    where
+        F: FnMut(Index<json::Mesh>, usize, &DracoGeometry) -> Option<DracoEncoded>,
+    {
+        let mut compressed = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = &self.root.meshes[mesh].primitives[index];
+                let geometry = match self.draco_geometry(primitive) {
+                    Some(geometry) => geometry,
+                    None => continue,
+                };
+                let encoded = match encode(Index::new(mesh as u32), index, &geometry) {
+                    Some(encoded) if encoded.ids.len() == geometry.attributes.len() => encoded,
+                    _ => continue,
+                };
+                let primitive = self.root.meshes[mesh].primitives[index].clone();
+                let primitive = self.draco_primitive(primitive, &geometry, encoded);
+                self.root.meshes[mesh].primitives[index] = primitive;
+                compressed += 1;
+            }
+        }
+        if compressed > 0 {
+            self.root.add_extension_used("KHR_draco_mesh_compression");
+            self.root
+                .add_extension_required("KHR_draco_mesh_compression");
+        }
+        compressed
+    }
+
+    /// Decompresses primitives with `KHR_draco_mesh_compression`, returning
+    /// the number of primitives decompressed.
+    ///
+    /// This library does not implement Draco itself; `decode` is called with
+    /// the mesh index, primitive index, compressed data and the semantic and
+    /// unique id of every compressed attribute, and returns the decoded
+    /// geometry with its attributes in the same order, or `None` to leave the
+    /// primitive compressed. Primitives whose decoded attributes do not match
+    /// the accessors of the primitive in semantic or number of values are left
+    /// compressed as well.
+    ///
+    /// The decoded data is appended to the first buffer in the component type
+    /// of the accessors it replaces, which keep their type, normalization and
+    /// bounds. The replaced accessors and compressed data are left in place
+    /// and must be removed afterwards with [`Model::prune`], since accessors
+    /// without buffer views are invalid once no compressed primitive uses
+    /// them. The extension is no longer marked as used or required once no
+    /// primitive uses it.
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn decompress_draco<F>(&mut self, mut decode: F) -> usize
+    where
+        F: FnMut(Index<json::Mesh>, usize, &[u8], &[(Semantic, u32)]) -> Option<DracoGeometry>,
+    {
+        let mut decompressed = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = &self.root.meshes[mesh].primitives[index];
+                let draco = match draco_extension(primitive) {
+                    Some(draco) => draco,
+                    None => continue,
+                };
+                let ids: Option<Vec<_>> = draco
+                    .attributes
+                    .iter()
+                    .map(|(semantic, &id)| match semantic {
+                        Valid(semantic) => Some((semantic.clone(), id)),
+                        _ => None,
+                    })
+                    .collect();
+                let (ids, data) = match (ids, self.view_data(draco.buffer_view)) {
+                    (Some(ids), Some(data)) => (ids, data),
+                    _ => continue,
+                };
+                let geometry = match decode(Index::new(mesh as u32), index, data, &ids) {
+                    Some(geometry) => geometry,
+                    None => continue,
+                };
+                let primitive = self.root.meshes[mesh].primitives[index].clone();
+                if let Some(primitive) = self.decoded_primitive(primitive, &ids, &geometry) {
+                    self.root.meshes[mesh].primitives[index] = primitive;
+                    decompressed += 1;
+                }
+            }
+        }
+        let used = self
+            .root
+            .meshes
+            .iter()
+            .flat_map(|mesh| mesh.primitives.iter())
+            .any(|primitive| draco_extension(primitive).is_some());
+        if decompressed > 0 && !used {
+            let name = "KHR_draco_mesh_compression";
+            self.root.extensions_used.retain(|x| x != name);
+            self.root.extensions_required.retain(|x| x != name);
+        }
+        decompressed
+    }
+
+    /// Returns `primitive` rewritten to read the decoded geometry, or `None`
+    /// if the geometry does not match the accessors of the primitive.
+    fn decoded_primitive(
+        &mut self,
+        mut primitive: Primitive,
+        ids: &[(Semantic, u32)],
+        geometry: &DracoGeometry,
+    ) -> Option<Primitive> {
+        if geometry.attributes.len() != ids.len() {
+            return None;
+        }
+        let mut attributes = Vec::with_capacity(ids.len());
+        for ((semantic, _), attribute) in ids.iter().zip(&geometry.attributes) {
+            let accessor = *primitive.attributes.get(&Valid(semantic.clone()))?;
+            let json = self.root.get(accessor)?;
+            let (component_type, type_) = match (&json.component_type, &json.type_) {
+                (Valid(component_type), Valid(type_)) => (component_type.0, *type_),
+                _ => return None,
+            };
+            let count = json.count as usize * type_.multiplicity();
+            if attribute.semantic != *semantic || attribute.values.len() != count {
+                return None;
+            }
+            let normalized = json.normalized;
+            let values: Vec<f64> = attribute
+                .values
+                .iter()
+                .map(|&x| {
+                    if normalized {
+                        (x as f64 * normalized_scale(component_type)).round()
+                    } else {
+                        x as f64
+                    }
+                })
+                .collect();
+            attributes.push((semantic.clone(), accessor, values));
+        }
+
+        let vertices = ids
+            .first()
+            .and_then(|(semantic, _)| primitive.attributes.get(&Valid(semantic.clone())))
+            .map_or(0, |accessor| self.root.accessors[accessor.value()].count);
+        let indices: Vec<u32> = geometry.triangles.iter().flatten().copied().collect();
+        if indices.iter().any(|&x| x >= vertices) {
+            return None;
+        }
+        let component_type = primitive
+            .indices
+            .and_then(|indices| self.root.get(indices))
+            .and_then(|indices| match indices.component_type {
+                Valid(GenericComponentType(ComponentType::U8)) if vertices <= 1 << 8 => {
+                    Some(ComponentType::U8)
+                }
+                Valid(GenericComponentType(ComponentType::U16)) if vertices <= 1 << 16 => {
+                    Some(ComponentType::U16)
+                }
+                _ => None,
+            })
+            .unwrap_or(ComponentType::U32);
+
+        for (semantic, like, values) in attributes {
+            let accessor = self.push_raw(like, &values);
+            primitive.attributes.insert(Valid(semantic), accessor);
+        }
+        primitive.indices = Some(self.push_indices(&indices, component_type));
+        if let Some(extensions) = primitive.extensions.as_mut() {
+            extensions.khr_draco_mesh_compression = None;
+        }
+        if primitive.extensions == Some(Default::default()) {
+            primitive.extensions = None;
+        }
+        Some(primitive)
+    }
+
+    /// Reads the geometry of a primitive, or returns `None` if it cannot be
+    /// compressed.
+    fn draco_geometry(&self, primitive: &Primitive) -> Option<DracoGeometry> {
+        if draco_extension(primitive).is_some() || primitive.targets.is_some() {
+            return None;
+        }
+        let triangles = self.triangles(primitive)?;
+        let mut attributes = Vec::with_capacity(primitive.attributes.len());
+        for (semantic, &accessor) in &primitive.attributes {
+            let semantic = match semantic {
+                Valid(semantic) => semantic.clone(),
+                _ => return None,
+            };
+            let json = self.root.get(accessor)?;
+            let (component_type, type_) = match (&json.component_type, &json.type_) {
+                (Valid(component_type), Valid(type_)) => (component_type.0, *type_),
+                _ => return None,
+            };
+            attributes.push(DracoAttribute {
+                semantic,
+                component_type,
+                normalized: json.normalized,
+                components: type_.multiplicity(),
+                values: self.read_f32(accessor)?,
+            });
+        }
+        Some(DracoGeometry {
+            triangles,
+            attributes,
+        })
+    }
+
+    /// Returns `primitive` rewritten to decode from the compressed data.
+    fn draco_primitive(
+        &mut self,
+        mut primitive: Primitive,
+        geometry: &DracoGeometry,
+        encoded: DracoEncoded,
+    ) -> Primitive {
+        let mut ids = BTreeMap::new();
+        for ((semantic, accessor), id) in primitive.attributes.iter_mut().zip(encoded.ids) {
+            *accessor = self.push_without_view(&self.root.accessors[accessor.value()].clone());
+            ids.insert(semantic.clone(), id);
+        }
+
+        let vertices = primitive
+            .attributes
+            .values()
+            .next()
+            .map_or(0, |accessor| self.root.accessors[accessor.value()].count);
+        let component_type = if vertices <= u16::MAX as u32 {
+            ComponentType::U16
+        } else {
+            ComponentType::U32
+        };
+        let indices = Accessor {
+            buffer_view: None,
+            byte_offset: 0,
+            count: geometry.triangles.len() as u32 * 3,
+            component_type: Valid(GenericComponentType(component_type)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            type_: Valid(json::accessor::Type::Scalar),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        };
+        primitive.indices = Some(self.push_without_view(&indices));
+        primitive.mode = Valid(Mode::Triangles);
+
+        let buffer_view = self.push_view(&encoded.data, None);
+        primitive
+            .extensions
+            .get_or_insert_with(Default::default)
+            .khr_draco_mesh_compression = Some(KhrDracoMeshCompression {
+            buffer_view,
+            attributes: ids,
+        });
+        primitive
+    }
+
+    /// Appends a copy of `accessor` without a buffer view or sparse storage.
+    fn push_without_view(&mut self, accessor: &Accessor) -> Index<Accessor> {
+        self.root.push(Accessor {
+            buffer_view: None,
+            byte_offset: 0,
+            sparse: None,
+            ..accessor.clone()
+        })
+    }
+}
+
+/// Returns the `KHR_draco_mesh_compression` extension of a primitive.
+fn draco_extension(primitive: &Primitive) -> Option<&KhrDracoMeshCompression> {
+    primitive
+        .extensions
+        .as_ref()
+        .and_then(|x| x.khr_draco_mesh_compression.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DracoEncoded;
+    use crate::edit::tests::triangle;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+    use crate::json::Index;
+
+    #[test]
+    fn compress_draco() {
+        let mut model = triangle();
+        model.update_bounds();
+        let compressed = model.compress_draco(|mesh, primitive, geometry| {
+            assert_eq!((mesh, primitive), (Index::new(0), 0));
+            assert_eq!(geometry.triangles, [[0, 1, 2]]);
+            let positions = &geometry.attributes[0];
+            assert_eq!(positions.semantic, Semantic::Positions);
+            assert_eq!(positions.components, 3);
+            assert_eq!(positions.values.len(), 9);
+            Some(DracoEncoded {
+                data: vec![1, 2, 3],
+                ids: vec![7],
+            })
+        });
+        assert_eq!(compressed, 1);
+
+        let root = &model.root;
+        let primitive = &root.meshes[0].primitives[0];
+        let draco = primitive
+            .extensions
+            .as_ref()
+            .and_then(|x| x.khr_draco_mesh_compression.as_ref())
+            .unwrap();
+        assert_eq!(draco.attributes[&Valid(Semantic::Positions)], 7);
+        assert_eq!(root.buffer_views[draco.buffer_view.value()].byte_length, 3);
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(root.accessors[positions.value()].buffer_view, None);
+        assert_eq!(root.accessors[positions.value()].count, 3);
+        let indices = &root.accessors[primitive.indices.unwrap().value()];
+        assert_eq!((indices.buffer_view, indices.count), (None, 3));
+        assert_eq!(root.extensions_required, ["KHR_draco_mesh_compression"]);
+        assert!(crate::Document::from_json(root.clone()).is_ok());
+
+        // The bounds of compressed accessors are kept, not recomputed.
+        assert_eq!(model.update_bounds(), 0);
+
+        // Compressed primitives are skipped.
+        assert_eq!(model.compress_draco(|_, _, _| unreachable!()), 0);
+
+        // Pruning leaves only the compressed data.
+        model.prune();
+        assert_eq!(model.root.buffer_views.len(), 1);
+        assert_eq!(model.buffers[0], [1, 2, 3]);
+        assert!(crate::Document::from_json(model.root.clone()).is_ok());
+    }
+
+    #[test]
+    fn decompress_draco() {
+        let mut model = triangle();
+        model.update_bounds();
+        let positions = model.read_f32(Index::new(0)).unwrap();
+        let mut encoded = None;
+        model.compress_draco(|_, _, geometry| {
+            encoded = Some(geometry.clone());
+            Some(DracoEncoded {
+                data: vec![1, 2, 3],
+                ids: vec![7],
+            })
+        });
+        let geometry = encoded.unwrap();
+
+        // Geometry that does not match the primitive is rejected.
+        let decompressed = model.decompress_draco(|_, _, _, _| {
+            let mut geometry = geometry.clone();
+            geometry.attributes[0].values.pop();
+            Some(geometry)
+        });
+        assert_eq!(decompressed, 0);
+
+        let decompressed = model.decompress_draco(|mesh, primitive, data, ids| {
+            assert_eq!((mesh, primitive, data), (Index::new(0), 0, &[1, 2, 3][..]));
+            assert_eq!(ids, [(Semantic::Positions, 7)]);
+            Some(geometry.clone())
+        });
+        assert_eq!(decompressed, 1);
+
+        let root = &model.root;
+        let primitive = &root.meshes[0].primitives[0];
+        assert!(primitive.extensions.is_none());
+        let accessor = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(model.read_f32(accessor).unwrap(), positions);
+        assert_eq!(model.triangles(primitive).unwrap(), [[0, 1, 2]]);
+        assert!(root.extensions_used.is_empty());
+        assert!(root.extensions_required.is_empty());
+        assert!(crate::Document::from_json(root.clone()).is_err());
+        model.prune();
+        assert!(crate::Document::from_json(model.root.clone()).is_ok());
+    }
+
+    #[test]
+    fn compress_draco_declined() {
+        let mut model = triangle();
+        assert_eq!(model.compress_draco(|_, _, _| None), 0);
+        assert!(model.root.extensions_used.is_empty());
+        assert_eq!(model.root.accessors.len(), 3);
+    }
+}
@@ -0,0 +1,114 @@
+use super::Model;
+use crate::{Error, Resolver, Result};
+
+impl Model {
+    /// Stores the contents of every buffer in its URI as a base64 data URI,
+    /// so the document can be saved as a single `.gltf` file.
+    ///
+    /// This includes buffers without a URI, such as the binary chunk of a GLB
+    /// file.
+    pub fn embed_buffers(&mut self) {
+        self.buffers.resize_with(self.root.buffers.len(), Vec::new);
+        for (buffer, data) in self.root.buffers.iter_mut().zip(&self.buffers) {
+            buffer.uri = Some(data_uri("application/octet-stream", data));
+        }
+    }
+
+    /// Replaces every image URI that is not already a data URI with a base64
+    /// data URI holding the image, loaded with `resolver`.
+    ///
+    /// The media type of the data URI is taken from the image's MIME type,
+    /// then the extension of its URI, then its contents. Images stored in
+    /// buffer views are left alone, since they are embedded together with
+    /// their buffer.
+    ///
+    /// Returns an error, leaving the document unchanged, if an image cannot
+    /// be loaded or its format is not PNG, JPEG or WebP.
+    pub fn embed_images<R: Resolver>(&mut self, resolver: &R) -> Result<()> {
+        let mut uris = Vec::new();
+        for (i, image) in self.root.images.iter().enumerate() {
+            let uri = match image.uri.as_deref() {
+                Some(uri) if !uri.starts_with("data:") => uri,
+                _ => continue,
+            };
+            let data = resolver.resolve(uri)?;
+            let media_type = image
+                .mime_type
+                .as_ref()
+                .map(|x| x.0.as_str())
+                .or_else(|| media_type_from_extension(uri))
+                .or_else(|| media_type_from_contents(&data))
+                .ok_or(Error::UnsupportedImageEncoding)?;
+            uris.push((i, data_uri(media_type, &data)));
+        }
+        for (i, uri) in uris {
+            self.root.images[i].uri = Some(uri);
+        }
+        Ok(())
+    }
+}
+
+fn data_uri(media_type: &str, data: &[u8]) -> String {
+    format!("data:{};base64,{}", media_type, base64::encode(data))
+}
+
+fn media_type_from_extension(uri: &str) -> Option<&'static str> {
+    let extension = uri.rsplit('.').next()?;
+    match extension.to_ascii_lowercase().as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+fn media_type_from_contents(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json;
+
+    #[test]
+    fn embed() {
+        let mut model = triangle();
+        let images =
+            r#"[{"uri": "textures/a.PNG"}, {"uri": "b"}, {"uri": "data:image/png;base64,AA=="}]"#;
+        model.root.images = json::deserialize::from_str(images).unwrap();
+        let resolver = |uri: &str| match uri {
+            "textures/a.PNG" => Ok(vec![1, 2, 3]),
+            _ => Ok(b"\xff\xd8\xff".to_vec()),
+        };
+        model.embed_images(&resolver).unwrap();
+        let uris: Vec<_> = model
+            .root
+            .images
+            .iter()
+            .map(|x| x.uri.clone().unwrap())
+            .collect();
+        assert_eq!(
+            uris,
+            [
+                "data:image/png;base64,AQID",
+                "data:image/jpeg;base64,/9j/",
+                "data:image/png;base64,AA==",
+            ]
+        );
+
+        model.update_bounds();
+        model.embed_buffers();
+        let gltf = crate::Gltf::from_slice(&model.root.to_vec().unwrap()).unwrap();
+        let buffers = crate::import_buffers(&gltf, None, None).unwrap();
+        assert_eq!(buffers[0].0[..39], model.buffers[0][..]);
+    }
+}
@@ -0,0 +1,414 @@
+use super::Model;
+use crate::json::accessor::{Accessor, Type};
+use crate::json::buffer::Target;
+use crate::json::mesh::{Mode, Primitive, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, Index};
+use crate::math::Matrix4;
+use crate::scene::Transform;
+use std::collections::{HashMap, HashSet};
+
+/// How a vertex attribute is affected by a transform.
+#[derive(Clone, Copy)]
+pub(crate) enum Kind {
+    /// A position, affected by the whole transform.
+    Point,
+    /// A direction or displacement, unaffected by translation.
+    Vector,
+    /// A surface normal, transformed by the inverse transpose.
+    Normal,
+    /// A tangent with the bitangent sign in `w`.
+    Tangent,
+}
+
+impl Model {
+    /// Bakes the world transform of every node in a scene into the geometry
+    /// of its mesh and flattens the node hierarchy, returning the number of
+    /// nodes whose mesh was baked.
+    ///
+    /// `POSITION`, `NORMAL` and `TANGENT` attributes and morph targets are
+    /// transformed, and triangles are rewound for transforms that mirror the
+    /// geometry. A mesh instanced with several different transforms is
+    /// duplicated for each of them.
+    ///
+    /// Afterwards, every node that still matters becomes a root node of its
+    /// scenes with no children: nodes with a baked mesh get the identity
+    /// transform, while nodes with a camera, skin, extension or an
+    /// unreadable mesh, and nodes used by skins and animations, get their
+    /// former world transform. Other nodes in a scene are removed. Skinned
+    /// and instanced meshes are not baked, and animations are not updated,
+    /// so this is intended for static scenes.
+    ///
+    /// New data is appended to the first buffer. Accessors replaced in the
+    /// process are left in place and may be removed afterwards.
+    pub fn flatten(&mut self) -> usize {
+        let identity = Matrix4::identity().as_array();
+        let node_count = self.root.nodes.len();
+        let document = crate::Document::from_json_without_validation(self.root.clone());
+        let mut world = vec![None; node_count];
+        let mut scenes = vec![Vec::new(); self.root.scenes.len()];
+        for scene in document.scenes() {
+            for (node, matrix) in scene.world_transforms() {
+                world[node.index()] = Some(matrix);
+                scenes[scene.index()].push(node.index());
+            }
+        }
+
+        // Meshes keep their original data for nodes that are not baked.
+        let mut instances = HashMap::new();
+        let mut claimed = vec![false; self.root.meshes.len()];
+        for (index, node) in self.root.nodes.iter().enumerate() {
+            if let Some(mesh) = node.mesh {
+                if world[index].is_none() || !bakeable(node) {
+                    instances.insert((mesh, key(&identity)), mesh);
+                    claimed[mesh.value()] = true;
+                }
+            }
+        }
+
+        let meshes = self.root.meshes.clone();
+        let mut baked = vec![false; node_count];
+        for index in 0..node_count {
+            let node = &self.root.nodes[index];
+            let (mesh, matrix) = match (node.mesh, world[index]) {
+                (Some(mesh), Some(matrix)) if bakeable(node) => (mesh, matrix),
+                _ => continue,
+            };
+            let result = match instances.get(&(mesh, key(&matrix))) {
+                Some(&result) => Some(result),
+                None => self.bake_mesh(&meshes, mesh, matrix, &mut claimed),
+            };
+            if let Some(result) = result {
+                instances.insert((mesh, key(&matrix)), result);
+                self.root.nodes[index].mesh = Some(result);
+                baked[index] = true;
+            }
+        }
+
+        // Rebuild the scenes from the nodes that are kept.
+        let mut used = HashSet::new();
+        for skin in &self.root.skins {
+            used.extend(skin.joints.iter().chain(&skin.skeleton).copied());
+        }
+        for channel in self.root.animations.iter().flat_map(|x| &x.channels) {
+            used.insert(channel.target.node);
+        }
+        let mut keep = vec![true; node_count];
+        for (index, node) in self.root.nodes.iter_mut().enumerate() {
+            let matrix = match world[index] {
+                Some(matrix) => matrix,
+                None => continue,
+            };
+            keep[index] = used.contains(&Index::new(index as u32))
+                || node.mesh.is_some()
+                || node.camera.is_some()
+                || node.skin.is_some()
+                || node.extensions.is_some();
+            node.children = None;
+            node.matrix = None;
+            node.translation = None;
+            node.rotation = None;
+            node.scale = None;
+            if !baked[index] && matrix != identity {
+                let (translation, rotation, scale) = Transform::Matrix { matrix }.decomposed();
+                node.translation = Some(translation);
+                node.rotation = Some(json::scene::UnitQuaternion(rotation));
+                node.scale = Some(scale);
+            }
+        }
+        for (scene, nodes) in self.root.scenes.iter_mut().zip(scenes) {
+            scene.nodes = nodes
+                .into_iter()
+                .filter(|&index| keep[index])
+                .map(|index| Index::new(index as u32))
+                .collect();
+        }
+        self.remove_nodes(&keep);
+
+        self.update_bounds();
+        baked.into_iter().filter(|&baked| baked).count()
+    }
+
+    /// Returns a mesh with the data of `meshes[mesh]` transformed by
+    /// `matrix`, replacing the mesh itself unless it is already claimed by
+    /// another transform.
+    fn bake_mesh(
+        &mut self,
+        meshes: &[json::Mesh],
+        mesh: Index<json::Mesh>,
+        matrix: [[f32; 4]; 4],
+        claimed: &mut [bool],
+    ) -> Option<Index<json::Mesh>> {
+        if matrix == Matrix4::identity().as_array() && !claimed[mesh.value()] {
+            claimed[mesh.value()] = true;
+            return Some(mesh);
+        }
+        let matrix = Matrix4::from_array(matrix);
+        let mut result = meshes.get(mesh.value())?.clone();
+        for primitive in &result.primitives {
+            if !primitive
+                .attributes
+                .contains_key(&Valid(Semantic::Positions))
+            {
+                return None;
+            }
+        }
+        let mut primitives = Vec::with_capacity(result.primitives.len());
+        for primitive in &result.primitives {
+            primitives.push(self.transformed_primitive(primitive.clone(), &matrix)?);
+        }
+        result.primitives = primitives;
+        if claimed[mesh.value()] {
+            Some(self.root.push(result))
+        } else {
+            claimed[mesh.value()] = true;
+            self.root.meshes[mesh.value()] = result;
+            Some(mesh)
+        }
+    }
+
+    /// Returns `primitive` with its vertex data transformed by `matrix`, or
+    /// `None` if the data cannot be read.
+    fn transformed_primitive(
+        &mut self,
+        mut primitive: Primitive,
+        matrix: &Matrix4,
+    ) -> Option<Primitive> {
+        let semantics = [
+            (Semantic::Positions, Kind::Point),
+            (Semantic::Normals, Kind::Normal),
+            (Semantic::Tangents, Kind::Tangent),
+        ];
+        let mut attributes = Vec::new();
+        for (semantic, kind) in semantics {
+            if let Some(&accessor) = primitive.attributes.get(&Valid(semantic.clone())) {
+                attributes.push((semantic, self.transformed(accessor, kind, matrix)?));
+            }
+        }
+        let mut targets = Vec::new();
+        for target in primitive.targets.iter().flatten() {
+            let transform = |accessor: Option<Index<Accessor>>, kind| match accessor {
+                Some(accessor) => self.transformed(accessor, kind, matrix).map(Some),
+                None => Some(None),
+            };
+            targets.push([
+                transform(target.positions, Kind::Vector)?,
+                transform(target.normals, Kind::Normal)?,
+                transform(target.tangents, Kind::Vector)?,
+            ]);
+        }
+        let mirrored = determinant(matrix) < 0.0;
+        let triangles = if mirrored {
+            match primitive.mode {
+                Valid(Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan) => {
+                    Some(self.triangles(&primitive)?)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        for (semantic, (data, type_)) in attributes {
+            let accessor = self.push_f32(&data, type_, Some(Target::ArrayBuffer));
+            primitive.attributes.insert(Valid(semantic), accessor);
+        }
+        for (target, data) in primitive.targets.iter_mut().flatten().zip(targets) {
+            let slots = [
+                &mut target.positions,
+                &mut target.normals,
+                &mut target.tangents,
+            ];
+            for (slot, data) in slots.into_iter().zip(data) {
+                if let Some((data, type_)) = data {
+                    *slot = Some(self.push_f32(&data, type_, Some(Target::ArrayBuffer)));
+                }
+            }
+        }
+        if let Some(triangles) = triangles {
+            primitive.indices = Some(self.push_rewound(&triangles));
+            primitive.mode = Valid(Mode::Triangles);
+        }
+        Some(primitive)
+    }
+
+    /// Returns the data of a `VEC3` accessor, or a `VEC4` tangent accessor,
+    /// transformed by `matrix`.
+    pub(crate) fn transformed(
+        &self,
+        accessor: Index<Accessor>,
+        kind: Kind,
+        matrix: &Matrix4,
+    ) -> Option<(Vec<f32>, Type)> {
+        let type_ = match (self.root.get(accessor)?.type_, kind) {
+            (Valid(Type::Vec4), Kind::Tangent) => Type::Vec4,
+            (Valid(Type::Vec3), _) => Type::Vec3,
+            _ => return None,
+        };
+        let mut data = self.read_f32(accessor)?;
+        let sign = determinant(matrix).signum();
+        let [a, b, c] = [matrix.x, matrix.y, matrix.z].map(|x| [x.x, x.y, x.z]);
+        // The columns of the inverse transpose, scaled by the determinant.
+        let cofactors = [cross(b, c), cross(c, a), cross(a, b)];
+        for element in data.chunks_exact_mut(type_.multiplicity()) {
+            let v = [element[0], element[1], element[2]];
+            let v = match kind {
+                Kind::Point => matrix.transform_point(v),
+                Kind::Vector => matrix.transform_vector(v),
+                Kind::Normal => {
+                    let [x, y, z] = cofactors;
+                    let n = [0, 1, 2].map(|i| sign * (v[0] * x[i] + v[1] * y[i] + v[2] * z[i]));
+                    normalize(n)
+                }
+                Kind::Tangent => {
+                    if let Some(w) = element.get_mut(3) {
+                        *w *= sign;
+                    }
+                    normalize(matrix.transform_vector(v))
+                }
+            };
+            element[..3].copy_from_slice(&v);
+        }
+        Some((data, type_))
+    }
+
+    /// Removes the nodes for which `keep` is `false`, which nothing but scenes
+    /// and other nodes may refer to, and updates the remaining references.
+    fn remove_nodes(&mut self, keep: &[bool]) {
+        let mut remap = Vec::with_capacity(keep.len());
+        let mut next = 0;
+        for &keep in keep {
+            remap.push(next);
+            if keep {
+                next += 1;
+            }
+        }
+        let remap = |index: &mut Index<json::Node>| *index = Index::new(remap[index.value()]);
+        let mut keep = keep.iter();
+        self.root.nodes.retain(|_| *keep.next().unwrap());
+        for node in &mut self.root.nodes {
+            node.children.iter_mut().flatten().for_each(remap);
+        }
+        for scene in &mut self.root.scenes {
+            scene.nodes.iter_mut().for_each(remap);
+        }
+        for skin in &mut self.root.skins {
+            skin.joints.iter_mut().for_each(remap);
+            skin.skeleton.iter_mut().for_each(remap);
+        }
+        for channel in self
+            .root
+            .animations
+            .iter_mut()
+            .flat_map(|x| &mut x.channels)
+        {
+            remap(&mut channel.target.node);
+        }
+    }
+}
+
+/// Returns whether the mesh of a node may be transformed to world space.
+fn bakeable(node: &json::Node) -> bool {
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    {
+        let instanced = node
+            .extensions
+            .as_ref()
+            .map_or(false, |x| x.ext_mesh_gpu_instancing.is_some());
+        if instanced {
+            return false;
+        }
+    }
+    node.mesh.is_some() && node.skin.is_none()
+}
+
+/// Returns a hashable representation of a matrix.
+fn key(matrix: &[[f32; 4]; 4]) -> [u32; 16] {
+    let mut key = [0; 16];
+    for (key, value) in key.iter_mut().zip(matrix.iter().flatten()) {
+        *key = value.to_bits();
+    }
+    key
+}
+
+pub(crate) fn determinant(matrix: &Matrix4) -> f32 {
+    let [a, b, c] = [matrix.x, matrix.y, matrix.z].map(|x| [x.x, x.y, x.z]);
+    let n = cross(b, c);
+    a[0] * n[0] + a[1] * n[1] + a[2] * n[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Returns a unit length vector, or the vector itself if it has zero length.
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length > 0.0 {
+        v.map(|x| x / length)
+    } else {
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::{tests::triangle, Model};
+    use crate::json::{self, mesh::Semantic, validation::Checked::Valid};
+
+    /// The triangle instanced by two nodes under a translated parent, the
+    /// second of which is mirrored.
+    fn scene() -> Model {
+        let mut model = triangle();
+        let nodes = r#"[
+            {"children": [1, 2], "translation": [1.0, 0.0, 0.0]},
+            {"mesh": 0},
+            {"mesh": 0, "scale": [-1.0, 1.0, 1.0]}
+        ]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        model.root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}]"#).unwrap();
+        model
+    }
+
+    fn positions(model: &Model, node: usize) -> Vec<f32> {
+        let mesh = model.root.nodes[node].mesh.unwrap();
+        let primitive = &model.root.meshes[mesh.value()].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        model.read_f32(positions).unwrap()
+    }
+
+    #[test]
+    fn flatten() {
+        let mut model = scene();
+        assert_eq!(model.flatten(), 2);
+        assert_eq!(model.root.nodes.len(), 2);
+        assert_eq!(model.root.meshes.len(), 2);
+        let roots: Vec<usize> = model.root.scenes[0]
+            .nodes
+            .iter()
+            .map(|x| x.value())
+            .collect();
+        assert_eq!(roots, [0, 1]);
+        for node in &model.root.nodes {
+            assert!(node.children.is_none());
+            assert!(node.translation.is_none() && node.scale.is_none());
+        }
+
+        assert_eq!(
+            positions(&model, 0),
+            [1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 2.0, 0.0]
+        );
+        assert_eq!(
+            positions(&model, 1),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 0.0]
+        );
+        // The mirrored triangle is rewound.
+        let mesh = model.root.nodes[1].mesh.unwrap();
+        let indices = model.root.meshes[mesh.value()].primitives[0].indices;
+        assert_eq!(model.read_u32(indices.unwrap()).unwrap(), [0, 2, 1]);
+    }
+}
@@ -0,0 +1,123 @@
+use super::Model;
+use crate::json::accessor::{ComponentType, GenericComponentType};
+use crate::json::validation::Checked::Valid;
+use std::collections::BTreeSet;
+
+impl Model {
+    /// Converts the index accessors of every primitive to `component_type`,
+    /// returning the number of accessors rewritten.
+    ///
+    /// Accessors are widened instead when their largest index does not fit,
+    /// remembering that the maximum value of each type is reserved for
+    /// primitive restart. Passing `ComponentType::U8` therefore narrows every
+    /// index accessor as far as possible.
+    ///
+    /// Converted data is appended to the first buffer as a new buffer view.
+    /// Buffer views no longer referenced are left in place and may be removed
+    /// afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `component_type` is not an unsigned integer type.
+    pub fn convert_indices(&mut self, component_type: ComponentType) -> usize {
+        assert!(
+            matches!(
+                component_type,
+                ComponentType::U8 | ComponentType::U16 | ComponentType::U32
+            ),
+            "{:?} is not a valid index component type",
+            component_type
+        );
+        let accessors: BTreeSet<_> = self
+            .root
+            .meshes
+            .iter()
+            .flat_map(|mesh| &mesh.primitives)
+            .filter_map(|primitive| primitive.indices)
+            .collect();
+
+        let mut converted = 0;
+        for index in accessors {
+            let indices = match self.read_u32(index) {
+                Some(indices) => indices,
+                None => continue,
+            };
+            let max = indices.iter().copied().max().unwrap_or(0);
+            let needed = if max < u8::MAX as u32 {
+                ComponentType::U8
+            } else if max < u16::MAX as u32 {
+                ComponentType::U16
+            } else {
+                ComponentType::U32
+            };
+            let result = if needed.size() > component_type.size() {
+                needed
+            } else {
+                component_type
+            };
+            let accessor = &self.root.accessors[index.value()];
+            let current = match accessor.component_type {
+                Valid(GenericComponentType(current)) => Some(current),
+                _ => None,
+            };
+            if current == Some(result) && accessor.sparse.is_none() {
+                continue;
+            }
+
+            // Borrow the buffer view and component type of a new accessor.
+            self.push_indices(&indices, result);
+            let replacement = self.root.accessors.pop().unwrap();
+            let accessor = &mut self.root.accessors[index.value()];
+            accessor.buffer_view = replacement.buffer_view;
+            accessor.byte_offset = 0;
+            accessor.component_type = replacement.component_type;
+            accessor.sparse = None;
+            converted += 1;
+        }
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::{tests::triangle, Model};
+    use crate::json::accessor::{Accessor, ComponentType, GenericComponentType};
+    use crate::json::validation::Checked::Valid;
+    use crate::json::Index;
+
+    fn component_type(model: &Model, index: Index<Accessor>) -> Option<ComponentType> {
+        match model.root.accessors[index.value()].component_type {
+            Valid(GenericComponentType(component_type)) => Some(component_type),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn convert_indices() {
+        let mut model = triangle();
+        let indices = model.root.meshes[0].primitives[0].indices.unwrap();
+
+        assert_eq!(model.convert_indices(ComponentType::U32), 1);
+        assert_eq!(component_type(&model, indices), Some(ComponentType::U32));
+        assert_eq!(model.read_u32(indices).unwrap(), [0, 1, 2]);
+        assert_eq!(model.root.accessors.len(), 3);
+
+        // Already converted accessors are left alone.
+        assert_eq!(model.convert_indices(ComponentType::U32), 0);
+
+        assert_eq!(model.convert_indices(ComponentType::U8), 1);
+        assert_eq!(component_type(&model, indices), Some(ComponentType::U8));
+        assert_eq!(model.read_u32(indices).unwrap(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn widen_indices() {
+        let mut model = triangle();
+        let wide = model.push_indices(&[0, 1, 300], ComponentType::U32);
+        model.root.meshes[0].primitives[0].indices = Some(wide);
+
+        assert_eq!(model.convert_indices(ComponentType::U8), 1);
+        assert_eq!(component_type(&model, wide), Some(ComponentType::U16));
+        assert_eq!(model.read_u32(wide).unwrap(), [0, 1, 300]);
+    }
+}
@@ -0,0 +1,571 @@
+//! # Basic usage
+//!
+//! Recomputing the `POSITION` bounds of an imported asset.
+//!
+//! ```
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+//! let mut model = gltf::edit::Model::from_import(document, buffers);
+//! model.update_bounds();
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #    let _ = run().expect("runtime error");
+//! # }
+//! ```
+
+use crate::json::accessor::{ComponentType, GenericComponentType, IndexComponentType, Type};
+use crate::json::mesh::{Mode, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, accessor::Accessor, buffer, Index};
+use crate::mesh::util::topology::Triangles;
+
+mod axes;
+mod bounds;
+mod buffers;
+#[cfg(feature = "KHR_draco_mesh_compression")]
+mod draco;
+#[cfg(feature = "import")]
+mod embed;
+mod flatten;
+mod indices;
+mod normals;
+mod prune;
+mod sanity;
+mod scale;
+#[cfg(feature = "tangents")]
+mod tangents;
+mod weld;
+mod writer;
+
+pub use self::axes::Axis;
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+pub use self::draco::DracoEncoded;
+pub(crate) use self::flatten::determinant;
+#[cfg(any(feature = "obj", feature = "stl"))]
+pub(crate) use self::flatten::Kind;
+pub use self::normals::NormalMode;
+pub use self::writer::{BufferWriter, Component, Element};
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+pub use crate::mesh::util::{DracoAttribute, DracoGeometry};
+
+/// A glTF document together with the contents of its buffers.
+///
+/// Passes that read or rewrite vertex data are implemented as methods of this
+/// type. New data is appended to the first buffer, which is created if the
+/// document has no buffers.
+#[derive(Clone, Debug)]
+pub struct Model {
+    /// The JSON document.
+    pub root: json::Root,
+
+    /// The contents of every buffer, in the same order as `root.buffers`.
+    pub buffers: Vec<Vec<u8>>,
+}
+
+impl Model {
+    /// Constructs a `Model` from a document and the contents of its buffers.
+    pub fn new(root: json::Root, buffers: Vec<Vec<u8>>) -> Self {
+        Self { root, buffers }
+    }
+
+    /// Constructs a `Model` from the results of [`import`](crate::import) or
+    /// [`import_buffers`](crate::import_buffers).
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn from_import(document: crate::Document, buffers: Vec<crate::buffer::Data>) -> Self {
+        let root = document.into_json();
+        let buffers = buffers
+            .into_iter()
+            .zip(&root.buffers)
+            .map(|(mut data, buffer)| {
+                // Imported buffer data is padded to a multiple of four bytes.
+                data.0.truncate(buffer.byte_length as usize);
+                data.0
+            })
+            .collect();
+        Self { root, buffers }
+    }
+
+    /// Appends the document and buffers of another model with
+    /// [`Root::merge`](json::Root::merge), or with
+    /// [`Root::merge_under`](json::Root::merge_under) if `parent` is given.
+    ///
+    /// Fails, leaving the model unchanged, if `other` uses extensions that
+    /// are not enabled.
+    pub fn merge(
+        &mut self,
+        other: Model,
+        parent: Option<Index<json::Node>>,
+    ) -> Result<(), json::MergeError> {
+        let count = self.root.buffers.len();
+        match parent {
+            Some(parent) => self.root.merge_under(other.root, parent)?,
+            None => self.root.merge(other.root)?,
+        }
+        self.buffers.resize_with(count, Vec::new);
+        self.buffers.extend(other.buffers);
+        self.buffers.resize_with(self.root.buffers.len(), Vec::new);
+        Ok(())
+    }
+
+    /// Reads the components of every element of an accessor, applying sparse
+    /// substitution and integer normalization.
+    ///
+    /// The result contains `count * type.multiplicity()` values. Returns `None`
+    /// if the accessor is invalid, refers to data out of range, or has neither
+    /// a buffer view nor sparse values, such as when its data is compressed by
+    /// an extension.
+    pub fn read_f32(&self, index: Index<Accessor>) -> Option<Vec<f32>> {
+        let accessor = self.root.get(index)?;
+        let values = self.read_components(accessor, accessor.normalized)?;
+        Some(values.into_iter().map(|x| x as f32).collect())
+    }
+
+    /// Reads the components of every element of an integer accessor, such as
+    /// the indices of a primitive.
+    ///
+    /// Returns `None` if the accessor is invalid, refers to data out of range,
+    /// or contains floating point data.
+    pub fn read_u32(&self, index: Index<Accessor>) -> Option<Vec<u32>> {
+        let accessor = self.root.get(index)?;
+        match accessor.component_type {
+            Valid(GenericComponentType(ComponentType::F32)) => None,
+            _ => {
+                let values = self.read_components(accessor, false)?;
+                Some(values.into_iter().map(|x| x as u32).collect())
+            }
+        }
+    }
+
+    /// Reads the components of an accessor without normalization, such that
+    /// they can be written back exactly with `push_raw`.
+    pub(crate) fn read_raw(&self, index: Index<Accessor>) -> Option<Vec<f64>> {
+        let accessor = self.root.get(index)?;
+        self.read_components(accessor, false)
+    }
+
+    /// Returns the vertex indices of every triangle of a primitive, expanding
+    /// triangle strips and fans, or `None` if the primitive does not consist
+    /// of triangles or its indices cannot be read.
+    pub(crate) fn triangles(&self, primitive: &json::mesh::Primitive) -> Option<Vec<[u32; 3]>> {
+        let indices = match primitive.indices {
+            Some(index) => self.read_u32(index)?,
+            None => {
+                let positions = primitive.attributes.get(&Valid(Semantic::Positions))?;
+                (0..self.root.get(*positions)?.count).collect()
+            }
+        };
+        let mode = match primitive.mode {
+            Valid(mode @ (Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan)) => mode,
+            _ => return None,
+        };
+        let triangles = Triangles::new(indices.into_iter(), mode).collect();
+        Some(triangles)
+    }
+
+    fn read_components(&self, accessor: &Accessor, normalized: bool) -> Option<Vec<f64>> {
+        let (component_type, type_) = match (&accessor.component_type, &accessor.type_) {
+            (Valid(component_type), Valid(type_)) => (component_type.0, *type_),
+            _ => return None,
+        };
+        let size = component_type.size();
+        let components = type_.multiplicity();
+        let count = accessor.count as usize;
+        if accessor.buffer_view.is_none() && accessor.sparse.is_none() {
+            return None;
+        }
+        let mut values = vec![0.0; count * components];
+
+        if let Some(view) = accessor.buffer_view {
+            let view_json = self.root.get(view)?;
+            let stride = view_json
+                .byte_stride
+                .map_or(size * components, |x| x as usize);
+            let data = self.view_data(view)?;
+            for i in 0..count {
+                let start = accessor.byte_offset as usize + i * stride;
+                let element = data.get(start..start + size * components)?;
+                for (j, bytes) in element.chunks_exact(size).enumerate() {
+                    values[i * components + j] = read_component(bytes, component_type, normalized);
+                }
+            }
+        }
+
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            let index_type = match sparse.indices.component_type {
+                Valid(IndexComponentType(component_type)) => component_type,
+                _ => return None,
+            };
+            let indices = self.view_data(sparse.indices.buffer_view)?;
+            let indices = indices.get(sparse.indices.byte_offset as usize..)?;
+            let substitutes = self.view_data(sparse.values.buffer_view)?;
+            let substitutes = substitutes.get(sparse.values.byte_offset as usize..)?;
+            for i in 0..sparse.count as usize {
+                let start = i * index_type.size();
+                let bytes = indices.get(start..start + index_type.size())?;
+                let target = read_component(bytes, index_type, false) as usize;
+                if target >= count {
+                    return None;
+                }
+                let start = i * size * components;
+                let element = substitutes.get(start..start + size * components)?;
+                for (j, bytes) in element.chunks_exact(size).enumerate() {
+                    values[target * components + j] =
+                        read_component(bytes, component_type, normalized);
+                }
+            }
+        }
+
+        Some(values)
+    }
+
+    /// Replaces every vertex attribute and morph target accessor of a
+    /// primitive with a new accessor whose `i`th element is element
+    /// `sources[i]` of the original.
+    ///
+    /// Returns `None`, leaving the document unchanged, if any accessor cannot
+    /// be read or a source is out of range.
+    pub(crate) fn remap_vertices(
+        &mut self,
+        primitive: &mut json::mesh::Primitive,
+        sources: &[u32],
+    ) -> Option<()> {
+        let mut accessors: Vec<Index<Accessor>> = primitive.attributes.values().copied().collect();
+        for target in primitive.targets.iter().flatten() {
+            accessors.extend(target.positions);
+            accessors.extend(target.normals);
+            accessors.extend(target.tangents);
+        }
+        let mut data = Vec::with_capacity(accessors.len());
+        for &accessor in &accessors {
+            let values = self.read_raw(accessor)?;
+            let count = self.root.get(accessor)?.count as usize;
+            let components = values.len().checked_div(count)?;
+            let mut remapped = Vec::with_capacity(sources.len() * components);
+            for &source in sources {
+                let start = source as usize * components;
+                remapped.extend_from_slice(values.get(start..start + components)?);
+            }
+            data.push(remapped);
+        }
+
+        let mut replacements = accessors
+            .into_iter()
+            .zip(data)
+            .map(|(accessor, data)| self.push_raw(accessor, &data))
+            .collect::<Vec<_>>()
+            .into_iter();
+        for accessor in primitive.attributes.values_mut() {
+            *accessor = replacements.next().unwrap();
+        }
+        for target in primitive.targets.iter_mut().flatten() {
+            let accessors = [
+                &mut target.positions,
+                &mut target.normals,
+                &mut target.tangents,
+            ];
+            for accessor in accessors.into_iter().flatten() {
+                *accessor = replacements.next().unwrap();
+            }
+        }
+        Some(())
+    }
+
+    /// Returns the bytes of a buffer view.
+    fn view_data(&self, index: Index<buffer::View>) -> Option<&[u8]> {
+        let view = self.root.get(index)?;
+        let buffer = self.buffers.get(view.buffer.value())?;
+        let start = view.byte_offset.unwrap_or(0) as usize;
+        buffer.get(start..start + view.byte_length as usize)
+    }
+
+    /// Appends `bytes` to the first buffer as a new buffer view.
+    pub(crate) fn push_view(
+        &mut self,
+        bytes: &[u8],
+        target: Option<buffer::Target>,
+    ) -> Index<buffer::View> {
+        if self.root.buffers.is_empty() {
+            self.root.push(json::Buffer {
+                byte_length: 0,
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "unrecognized")]
+                unrecognized: Default::default(),
+            });
+        }
+        self.buffers.resize_with(self.root.buffers.len(), Vec::new);
+        let data = &mut self.buffers[0];
+        // Keep every view aligned for its largest possible component type.
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+        let offset = data.len() as u32;
+        data.extend_from_slice(bytes);
+        self.root.buffers[0].byte_length = data.len() as u32;
+        self.root.push(buffer::View {
+            buffer: Index::new(0),
+            byte_length: bytes.len() as u32,
+            byte_offset: Some(offset),
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Valid),
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+        })
+    }
+
+    /// Appends tightly packed `f32` data as a new accessor with its own
+    /// buffer view.
+    ///
+    /// `data` must contain a multiple of `type_.multiplicity()` values.
+    pub fn push_f32(
+        &mut self,
+        data: &[f32],
+        type_: Type,
+        target: Option<buffer::Target>,
+    ) -> Index<Accessor> {
+        let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_le_bytes()).collect();
+        let view = self.push_view(&bytes, target);
+        self.push_accessor(
+            view,
+            data.len() / type_.multiplicity(),
+            ComponentType::F32,
+            type_,
+        )
+    }
+
+    /// Appends index data as a new `SCALAR` accessor with its own buffer view,
+    /// stored with the given unsigned integer component type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `component_type` is not unsigned or too narrow for any index.
+    pub fn push_indices(&mut self, data: &[u32], component_type: ComponentType) -> Index<Accessor> {
+        let bytes: Vec<u8> = match component_type {
+            ComponentType::U8 => data.iter().map(|&x| u8::try_from(x).unwrap()).collect(),
+            ComponentType::U16 => data
+                .iter()
+                .flat_map(|&x| u16::try_from(x).unwrap().to_le_bytes())
+                .collect(),
+            ComponentType::U32 => data.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            _ => panic!("{:?} is not a valid index component type", component_type),
+        };
+        let target = Some(buffer::Target::ElementArrayBuffer);
+        let view = self.push_view(&bytes, target);
+        self.push_accessor(view, data.len(), component_type, Type::Scalar)
+    }
+
+    /// Appends values read with `read_raw` as a new accessor with the same
+    /// component type, type, normalization and bounds as the accessor `like`.
+    pub(crate) fn push_raw(&mut self, like: Index<Accessor>, values: &[f64]) -> Index<Accessor> {
+        let accessor = &self.root.accessors[like.value()];
+        let (component_type, type_) = match (&accessor.component_type, &accessor.type_) {
+            (Valid(component_type), Valid(type_)) => (component_type.0, *type_),
+            _ => panic!("accessor {} is invalid", like.value()),
+        };
+        let target = accessor
+            .buffer_view
+            .and_then(|view| self.root.get(view))
+            .and_then(|view| view.target)
+            .and_then(|target| match target {
+                Valid(target) => Some(target),
+                _ => None,
+            });
+        let (normalized, min, max) = (
+            accessor.normalized,
+            accessor.min.clone(),
+            accessor.max.clone(),
+        );
+        let mut bytes = Vec::with_capacity(values.len() * component_type.size());
+        for &value in values {
+            write_component(&mut bytes, value, component_type);
+        }
+        let view = self.push_view(&bytes, target);
+        let index = self.push_accessor(
+            view,
+            values.len() / type_.multiplicity(),
+            component_type,
+            type_,
+        );
+        let accessor = &mut self.root.accessors[index.value()];
+        accessor.normalized = normalized;
+        accessor.min = min;
+        accessor.max = max;
+        index
+    }
+
+    /// Appends the indices of `triangles` with their winding reversed, using
+    /// the narrowest index type with `U16` as a minimum.
+    pub(crate) fn push_rewound(&mut self, triangles: &[[u32; 3]]) -> Index<Accessor> {
+        let indices: Vec<u32> = triangles.iter().flat_map(|&[a, b, c]| [a, c, b]).collect();
+        let max = indices.iter().copied().max().unwrap_or(0);
+        let component_type = if max < u16::MAX as u32 {
+            ComponentType::U16
+        } else {
+            ComponentType::U32
+        };
+        self.push_indices(&indices, component_type)
+    }
+
+    fn push_accessor(
+        &mut self,
+        view: Index<buffer::View>,
+        count: usize,
+        component_type: ComponentType,
+        type_: Type,
+    ) -> Index<Accessor> {
+        self.root.push(Accessor {
+            buffer_view: Some(view),
+            byte_offset: 0,
+            count: count as u32,
+            component_type: Valid(GenericComponentType(component_type)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            type_: Valid(type_),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        })
+    }
+}
+
+/// Reads a single little endian component, normalizing integers to `[0, 1]` or
+/// `[-1, 1]` if requested.
+fn read_component(bytes: &[u8], component_type: ComponentType, normalized: bool) -> f64 {
+    let value = match component_type {
+        ComponentType::I8 => bytes[0] as i8 as f64,
+        ComponentType::U8 => bytes[0] as f64,
+        ComponentType::I16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+        ComponentType::U16 => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+        ComponentType::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+        ComponentType::F32 => {
+            let value = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            return value as f64;
+        }
+    };
+    if normalized {
+        (value / normalized_scale(component_type)).max(-1.0)
+    } else {
+        value
+    }
+}
+
+/// Returns the largest value of an integer component type, which normalized
+/// integers are divided by, or `1.0` for floats.
+fn normalized_scale(component_type: ComponentType) -> f64 {
+    match component_type {
+        ComponentType::I8 => 127.0,
+        ComponentType::U8 => 255.0,
+        ComponentType::I16 => 32767.0,
+        ComponentType::U16 => 65535.0,
+        ComponentType::U32 => u32::MAX as f64,
+        ComponentType::F32 => 1.0,
+    }
+}
+
+/// Appends a single little endian component, as read by `read_component`
+/// without normalization.
+fn write_component(bytes: &mut Vec<u8>, value: f64, component_type: ComponentType) {
+    match component_type {
+        ComponentType::I8 => bytes.push(value as i8 as u8),
+        ComponentType::U8 => bytes.push(value as u8),
+        ComponentType::I16 => bytes.extend_from_slice(&(value as i16).to_le_bytes()),
+        ComponentType::U16 => bytes.extend_from_slice(&(value as u16).to_le_bytes()),
+        ComponentType::U32 => bytes.extend_from_slice(&(value as u32).to_le_bytes()),
+        ComponentType::F32 => bytes.extend_from_slice(&(value as f32).to_le_bytes()),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A single triangle with a sparse accessor overriding its second vertex.
+    pub(crate) fn triangle() -> Model {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [
+                {"bufferView": 0, "count": 3, "componentType": 5126, "type": "VEC3"},
+                {"bufferView": 1, "count": 3, "componentType": 5121, "type": "SCALAR"},
+                {
+                    "bufferView": 0, "count": 3, "componentType": 5126, "type": "VEC3",
+                    "sparse": {
+                        "count": 1,
+                        "indices": {"bufferView": 1, "byteOffset": 1, "componentType": 5121},
+                        "values": {"bufferView": 0, "byteOffset": 24}
+                    }
+                }
+            ],
+            "buffers": [{"byteLength": 39}],
+            "bufferViews": [
+                {"buffer": 0, "byteLength": 36},
+                {"buffer": 0, "byteOffset": 36, "byteLength": 3}
+            ],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        let positions = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0];
+        let mut data: Vec<u8> = positions.iter().flat_map(|x| x.to_le_bytes()).collect();
+        data.extend_from_slice(&[0, 1, 2]);
+        Model::new(root, vec![data])
+    }
+
+    #[test]
+    fn read_f32() {
+        let model = triangle();
+        let positions = model.read_f32(Index::new(0)).unwrap();
+        assert_eq!(positions, [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+        let sparse = model.read_f32(Index::new(2)).unwrap();
+        assert_eq!(sparse, [0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 0.0]);
+        assert_eq!(model.read_u32(Index::new(0)), None);
+        assert_eq!(model.read_u32(Index::new(1)).unwrap(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn push() {
+        let mut model = triangle();
+        let normals = model.push_f32(&[0.0, 0.0, 1.0], Type::Vec3, None);
+        let indices = model.push_indices(&[2, 1, 0], ComponentType::U16);
+        assert_eq!(model.read_f32(normals).unwrap(), [0.0, 0.0, 1.0]);
+        assert_eq!(model.read_u32(indices).unwrap(), [2, 1, 0]);
+        assert_eq!(model.buffers[0].len(), 58);
+        assert_eq!(model.root.buffers[0].byte_length, 58);
+        assert_eq!(model.root.buffer_views[2].byte_offset, Some(40));
+        assert_eq!(model.root.buffer_views[3].byte_offset, Some(52));
+    }
+
+    #[test]
+    fn merge() {
+        let mut model = triangle();
+        model.merge(triangle(), None).unwrap();
+        assert_eq!(model.buffers.len(), 2);
+        assert_eq!(model.root.buffer_views[2].buffer, Index::new(1));
+        assert_eq!(model.read_u32(Index::new(4)).unwrap(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(read_component(&[255], ComponentType::U8, true), 1.0);
+        assert_eq!(read_component(&[128], ComponentType::I8, true), -1.0);
+        assert_eq!(read_component(&[255, 127], ComponentType::I16, true), 1.0);
+        assert_eq!(
+            read_component(&[255, 255], ComponentType::U16, false),
+            65535.0
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use super::Model;
+use crate::json::accessor::Type;
+use crate::json::buffer::Target;
+use crate::json::mesh::{Mode, Primitive, Semantic};
+use crate::json::validation::Checked::Valid;
+
+/// How [`Model::generate_normals`] computes vertex normals.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalMode {
+    /// Every triangle gets its own vertices, all with the triangle's normal.
+    ///
+    /// This is how the specification requires renderers to shade primitives
+    /// without normals. Vertices shared between triangles are duplicated, so
+    /// the primitive loses its indices.
+    Flat,
+
+    /// Every vertex gets the average of the normals of the triangles sharing
+    /// it, weighted by triangle area.
+    Smooth,
+}
+
+impl Model {
+    /// Generates a `NORMAL` attribute for every triangle primitive that lacks
+    /// one, returning the number of primitives updated.
+    ///
+    /// New data is appended to the first buffer. Accessors replaced by
+    /// [`NormalMode::Flat`] are left in place and may be removed afterwards.
+    pub fn generate_normals(&mut self, mode: NormalMode) -> usize {
+        let mut generated = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = self.root.meshes[mesh].primitives[index].clone();
+                if primitive.attributes.contains_key(&Valid(Semantic::Normals)) {
+                    continue;
+                }
+                if let Some(primitive) = self.with_normals(primitive, mode) {
+                    self.root.meshes[mesh].primitives[index] = primitive;
+                    generated += 1;
+                }
+            }
+        }
+        generated
+    }
+
+    /// Returns `primitive` with generated normals, or `None` if its triangles
+    /// or positions cannot be read.
+    fn with_normals(&mut self, mut primitive: Primitive, mode: NormalMode) -> Option<Primitive> {
+        let positions = *primitive.attributes.get(&Valid(Semantic::Positions))?;
+        if self.root.get(positions)?.type_ != Valid(Type::Vec3) {
+            return None;
+        }
+        let triangles = self.triangles(&primitive)?;
+        let positions = self.read_f32(positions)?;
+        let vertex_count = positions.len() / 3;
+        if triangles
+            .iter()
+            .flatten()
+            .any(|&i| i as usize >= vertex_count)
+        {
+            return None;
+        }
+        let position = |i: u32| {
+            let i = i as usize * 3;
+            [positions[i], positions[i + 1], positions[i + 2]]
+        };
+        let face_normals: Vec<[f32; 3]> = triangles
+            .iter()
+            .map(|&[a, b, c]| face_normal(position(a), position(b), position(c)))
+            .collect();
+
+        let normals = match mode {
+            NormalMode::Smooth => {
+                let mut normals = vec![[0.0; 3]; vertex_count];
+                for (triangle, face_normal) in triangles.iter().zip(&face_normals) {
+                    for &vertex in triangle {
+                        let normal = &mut normals[vertex as usize];
+                        for i in 0..3 {
+                            normal[i] += face_normal[i];
+                        }
+                    }
+                }
+                normals
+            }
+            NormalMode::Flat => {
+                let corners: Vec<u32> = triangles.iter().flatten().copied().collect();
+                self.remap_vertices(&mut primitive, &corners)?;
+                primitive.indices = None;
+                primitive.mode = Valid(Mode::Triangles);
+                face_normals
+                    .iter()
+                    .flat_map(|&normal| [normal; 3])
+                    .collect()
+            }
+        };
+
+        let normals: Vec<f32> = normals.into_iter().flat_map(normalize).collect();
+        let normals = self.push_f32(&normals, Type::Vec3, Some(Target::ArrayBuffer));
+        primitive
+            .attributes
+            .insert(Valid(Semantic::Normals), normals);
+        Some(primitive)
+    }
+}
+
+/// Returns the normal of a triangle scaled by twice its area.
+pub(super) fn face_normal(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+/// Returns a unit length vector, or `+Z` for vectors of zero length.
+pub(super) fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length > 0.0 {
+        [v[0] / length, v[1] / length, v[2] / length]
+    } else {
+        [0.0, 0.0, 1.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NormalMode;
+    use crate::edit::tests::triangle;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+
+    #[test]
+    fn smooth_normals() {
+        let mut model = triangle();
+        assert_eq!(model.generate_normals(NormalMode::Smooth), 1);
+        let primitive = &model.root.meshes[0].primitives[0];
+        assert!(primitive.indices.is_some());
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        let normals = model.read_f32(normals).unwrap();
+        assert_eq!(normals, [0.0, 0.0, 1.0].repeat(3));
+
+        // Primitives that have normals are left alone.
+        assert_eq!(model.generate_normals(NormalMode::Smooth), 0);
+    }
+
+    #[test]
+    fn flat_normals() {
+        let mut model = triangle();
+        assert_eq!(model.generate_normals(NormalMode::Flat), 1);
+        let primitive = &model.root.meshes[0].primitives[0];
+        assert!(primitive.indices.is_none());
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        assert_eq!(
+            model.read_f32(positions).unwrap(),
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0]
+        );
+        assert_eq!(model.read_f32(normals).unwrap(), [0.0, 0.0, 1.0].repeat(3));
+    }
+}
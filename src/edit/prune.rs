@@ -0,0 +1,147 @@
+use super::Model;
+use crate::json;
+
+impl Model {
+    /// Removes unused objects with [`Root::prune`](json::Root::prune), along
+    /// with the contents of removed buffers.
+    ///
+    /// This removes the accessors and buffer views left behind by passes that
+    /// replace accessors, and repacks every buffer to drop the bytes that no
+    /// remaining buffer view covers. Views keep their byte offset modulo four,
+    /// and thus the alignment of their components. Buffers whose contents are
+    /// not loaded, such as fallback buffers of `EXT_meshopt_compression`, are
+    /// left unchanged.
+    pub fn prune(&mut self) -> json::Pruned {
+        let pruned = self.root.prune();
+        for buffer in pruned.buffers.iter().rev() {
+            if buffer.value() < self.buffers.len() {
+                self.buffers.remove(buffer.value());
+            }
+        }
+        self.repack_buffers();
+        pruned
+    }
+
+    /// Drops the bytes of every buffer that no buffer view covers.
+    fn repack_buffers(&mut self) {
+        // The byte ranges of every buffer that views cover, as the view
+        // and whether the range is its compressed data.
+        let mut ranges = vec![Vec::new(); self.buffers.len()];
+        for (i, view) in self.root.buffer_views.iter().enumerate() {
+            let start = view.byte_offset.unwrap_or(0) as usize;
+            if let Some(ranges) = ranges.get_mut(view.buffer.value()) {
+                ranges.push((start, start + view.byte_length as usize, i, false));
+            }
+            #[cfg(feature = "EXT_meshopt_compression")]
+            if let Some(compression) = view
+                .extensions
+                .as_ref()
+                .and_then(|x| x.ext_meshopt_compression.as_ref())
+            {
+                let start = compression.byte_offset as usize;
+                let end = start + compression.byte_length as usize;
+                if let Some(ranges) = ranges.get_mut(compression.buffer.value()) {
+                    ranges.push((start, end, i, true));
+                }
+            }
+        }
+
+        for (buffer, mut ranges) in ranges.into_iter().enumerate() {
+            let data = &self.buffers[buffer];
+            let loaded = data.len() >= self.root.buffers[buffer].byte_length as usize;
+            if !loaded || ranges.iter().any(|&(_, end, _, _)| end > data.len()) {
+                continue;
+            }
+            ranges.sort_unstable();
+            let mut repacked = Vec::with_capacity(data.len());
+            // The current run of overlapping ranges, as its start before and
+            // after repacking and its end before repacking.
+            let mut run: Option<(usize, usize, usize)> = None;
+            for (start, end, view, compressed) in ranges {
+                let (old, new, run_end) = match run {
+                    Some((old, new, run_end)) if start <= run_end => (old, new, run_end.max(end)),
+                    _ => {
+                        if let Some((old, _, run_end)) = run {
+                            repacked.extend_from_slice(&data[old..run_end]);
+                        }
+                        while repacked.len() % 4 != start % 4 {
+                            repacked.push(0);
+                        }
+                        (start, repacked.len(), end)
+                    }
+                };
+                run = Some((old, new, run_end));
+                let offset = (new + start - old) as u32;
+                let view = &mut self.root.buffer_views[view];
+                if compressed {
+                    #[cfg(feature = "EXT_meshopt_compression")]
+                    if let Some(compression) = view
+                        .extensions
+                        .as_mut()
+                        .and_then(|x| x.ext_meshopt_compression.as_mut())
+                    {
+                        compression.byte_offset = offset;
+                    }
+                } else {
+                    view.byte_offset = Some(offset);
+                }
+            }
+            if let Some((old, _, run_end)) = run {
+                repacked.extend_from_slice(&data[old..run_end]);
+            }
+            self.root.buffers[buffer].byte_length = repacked.len() as u32;
+            self.buffers[buffer] = repacked;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json::accessor::Type;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+
+    #[test]
+    fn prune() {
+        let mut model = triangle();
+        model.push_f32(&[0.0, 0.0, 1.0], Type::Vec3, None);
+        let pruned = model.prune();
+        // The unused sparse accessor and the new accessor and its view.
+        assert_eq!(pruned.accessors.len(), 2);
+        assert_eq!(pruned.buffer_views.len(), 1);
+        assert_eq!(model.root.accessors.len(), 2);
+        assert_eq!(model.buffers.len(), 1);
+        assert_eq!(model.buffers[0].len(), 39);
+        assert_eq!(model.root.buffers[0].byte_length, 39);
+        assert_eq!(
+            model
+                .read_u32(model.root.meshes[0].primitives[0].indices.unwrap())
+                .unwrap(),
+            [0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn prune_repacks_buffers() {
+        let mut model = triangle();
+        let positions = model.push_f32(&[0.0, 0.0, 1.0], Type::Vec3, None);
+        model.root.meshes[0].primitives[0]
+            .attributes
+            .insert(Valid(Semantic::Positions), positions);
+        model.prune();
+        // The indices keep their offset modulo four, followed by the new
+        // positions.
+        assert_eq!(model.buffers[0].len(), 16);
+        assert_eq!(model.root.buffers[0].byte_length, 16);
+        assert_eq!(model.root.buffer_views[0].byte_offset, Some(0));
+        assert_eq!(model.root.buffer_views[1].byte_offset, Some(4));
+        let primitive = &model.root.meshes[0].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(model.read_f32(positions).unwrap(), [0.0, 0.0, 1.0]);
+        assert_eq!(
+            model.read_u32(primitive.indices.unwrap()).unwrap(),
+            [0, 1, 2]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use super::normals::{face_normal, normalize};
+use super::Model;
+use crate::json::accessor::{Accessor, ComponentType, GenericComponentType, Type};
+use crate::json::buffer::Target;
+use crate::json::mesh::{Mode, Primitive, Semantic};
+use crate::json::validation::{Checked::Valid, Error};
+use crate::json::{Index, Path};
+
+impl Model {
+    /// Checks the vertex data of every primitive for problems that many
+    /// consumers cannot cope with, reporting them like validation errors.
+    ///
+    /// [`Error::Invalid`] is reported at:
+    ///
+    /// * every attribute of a primitive holding `NaN` or infinite values;
+    /// * the `NORMAL` attribute of a primitive with normals not of unit length;
+    /// * the `indices` of a primitive with degenerate triangles, or at its
+    ///   `POSITION` attribute if it has no indices.
+    ///
+    /// A triangle is degenerate when it has zero area, such as when it
+    /// repeats a vertex, or when a vertex position is not finite. Data that
+    /// cannot be read, such as data compressed by an extension, is skipped.
+    pub fn check_geometry(&self) -> Vec<(Path, Error)> {
+        let mut errors = Vec::new();
+        for (m, mesh) in self.root.meshes.iter().enumerate() {
+            for (p, primitive) in mesh.primitives.iter().enumerate() {
+                let path = || {
+                    Path::new()
+                        .field("meshes")
+                        .index(m)
+                        .field("primitives")
+                        .index(p)
+                };
+                for (semantic, &accessor) in &primitive.attributes {
+                    let values = match (semantic, self.read_f32(accessor)) {
+                        (Valid(_), Some(values)) => values,
+                        _ => continue,
+                    };
+                    let semantic = semantic.as_ref().unwrap();
+                    let path = path().field("attributes").key(&semantic.to_string());
+                    let finite = values.iter().all(|x| x.is_finite());
+                    let normals = *semantic == Semantic::Normals;
+                    if !finite || normals && !self.unit_normals(accessor, &values) {
+                        errors.push((path, Error::Invalid));
+                    }
+                }
+                let degenerate = self
+                    .classify_triangles(primitive)
+                    .map_or(false, |triangles| triangles.iter().any(|&(_, x)| x));
+                if degenerate {
+                    let path = match primitive.indices {
+                        Some(_) => path().field("indices"),
+                        None => path().field("attributes").key("POSITION"),
+                    };
+                    errors.push((path, Error::Invalid));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Removes the degenerate triangles found by [`Model::check_geometry`]
+    /// from every primitive, returning the number of triangles removed.
+    ///
+    /// Primitives that lose triangles get a new index accessor, appended to
+    /// the first buffer, and triangle strips and fans become triangle lists.
+    /// Primitives without any other triangles are left unchanged, since an
+    /// accessor cannot be empty.
+    pub fn remove_degenerate_triangles(&mut self) -> usize {
+        let mut removed = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = &self.root.meshes[mesh].primitives[index];
+                let triangles = match self.classify_triangles(primitive) {
+                    Some(triangles) => triangles,
+                    None => continue,
+                };
+                let kept: Vec<u32> = triangles
+                    .iter()
+                    .filter(|&&(_, degenerate)| !degenerate)
+                    .flat_map(|&(triangle, _)| triangle)
+                    .collect();
+                if kept.is_empty() || kept.len() / 3 == triangles.len() {
+                    continue;
+                }
+                let max = kept.iter().copied().max().unwrap_or(0);
+                let component_type = if max < u16::MAX as u32 {
+                    ComponentType::U16
+                } else {
+                    ComponentType::U32
+                };
+                let indices = self.push_indices(&kept, component_type);
+                let primitive = &mut self.root.meshes[mesh].primitives[index];
+                primitive.indices = Some(indices);
+                primitive.mode = Valid(Mode::Triangles);
+                removed += triangles.len() - kept.len() / 3;
+            }
+        }
+        removed
+    }
+
+    /// Rescales the normals found by [`Model::check_geometry`] not to be of
+    /// unit length, returning the number of `NORMAL` accessors replaced.
+    ///
+    /// Normals of zero length or with values that are not finite are replaced
+    /// with `+Z`. Every affected accessor is replaced by a new `f32` accessor,
+    /// appended to the first buffer, in all primitives using it. Replaced
+    /// accessors are left in place and may be removed afterwards.
+    pub fn normalize_normals(&mut self) -> usize {
+        let mut accessors: Vec<Index<Accessor>> = self
+            .root
+            .meshes
+            .iter()
+            .flat_map(|mesh| &mesh.primitives)
+            .filter_map(|primitive| primitive.attributes.get(&Valid(Semantic::Normals)))
+            .copied()
+            .collect();
+        accessors.sort_unstable();
+        accessors.dedup();
+
+        let mut replaced = 0;
+        for accessor in accessors {
+            match self.root.get(accessor) {
+                Some(json) if json.type_ == Valid(Type::Vec3) => {}
+                _ => continue,
+            }
+            let values = match self.read_f32(accessor) {
+                Some(values) => values,
+                None => continue,
+            };
+            let finite = values.iter().all(|x| x.is_finite());
+            if finite && self.unit_normals(accessor, &values) {
+                continue;
+            }
+            let normals: Vec<f32> = values
+                .chunks_exact(3)
+                .flat_map(|v| {
+                    let v = [v[0], v[1], v[2]];
+                    if v.iter().all(|x| x.is_finite()) {
+                        normalize(v)
+                    } else {
+                        [0.0, 0.0, 1.0]
+                    }
+                })
+                .collect();
+            let normals = self.push_f32(&normals, Type::Vec3, Some(Target::ArrayBuffer));
+            let primitives = self
+                .root
+                .meshes
+                .iter_mut()
+                .flat_map(|mesh| &mut mesh.primitives);
+            for primitive in primitives {
+                if let Some(x) = primitive.attributes.get_mut(&Valid(Semantic::Normals)) {
+                    if *x == accessor {
+                        *x = normals;
+                    }
+                }
+            }
+            replaced += 1;
+        }
+        replaced
+    }
+
+    /// Returns whether every normal of a `NORMAL` accessor is of unit length,
+    /// allowing for the precision of its component type.
+    fn unit_normals(&self, accessor: Index<Accessor>, values: &[f32]) -> bool {
+        let tolerance = match self.root.get(accessor).map(|x| &x.component_type) {
+            Some(Valid(GenericComponentType(ComponentType::I8))) => 1.0 / 127.0,
+            _ => 0.0005,
+        };
+        values.chunks_exact(3).all(|v| {
+            let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+            (length - 1.0).abs() <= tolerance
+        })
+    }
+
+    /// Returns the triangles of a primitive, each with whether it is
+    /// degenerate, or `None` if they or the positions cannot be read.
+    fn classify_triangles(&self, primitive: &Primitive) -> Option<Vec<([u32; 3], bool)>> {
+        let positions = *primitive.attributes.get(&Valid(Semantic::Positions))?;
+        if self.root.get(positions)?.type_ != Valid(Type::Vec3) {
+            return None;
+        }
+        let triangles = self.triangles(primitive)?;
+        let positions = self.read_f32(positions)?;
+        let position = |i: u32| {
+            let i = i as usize * 3;
+            positions.get(i..i + 3).map(|p| [p[0], p[1], p[2]])
+        };
+        triangles
+            .into_iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle;
+                let degenerate = is_degenerate(position(a)?, position(b)?, position(c)?);
+                Some((triangle, degenerate))
+            })
+            .collect()
+    }
+}
+
+/// Returns whether a triangle has zero area, to the precision of `f32`, or a
+/// vertex that is not finite.
+fn is_degenerate(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> bool {
+    if a.iter().chain(&b).chain(&c).any(|x| !x.is_finite()) {
+        return true;
+    }
+    let length = |v: [f32; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    let u = length([b[0] - a[0], b[1] - a[1], b[2] - a[2]]);
+    let v = length([c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+    // The cross product of two edges is the product of their lengths and the
+    // sine of the angle between them.
+    length(face_normal(a, b, c)) <= f32::EPSILON * u * v
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json::accessor::{ComponentType, Type};
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::{Checked::Valid, Error};
+    use crate::json::Path;
+
+    fn paths(errors: Vec<(Path, Error)>) -> Vec<String> {
+        errors
+            .into_iter()
+            .map(|(path, error)| {
+                assert_eq!(error, Error::Invalid);
+                path.as_str().to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn degenerate_triangles() {
+        let mut model = triangle();
+        assert!(model.check_geometry().is_empty());
+
+        let positions = model.push_f32(
+            &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 2.0, 0.0, 0.0],
+            Type::Vec3,
+            None,
+        );
+        // A collinear triangle and one repeating a vertex.
+        let indices = model.push_indices(&[0, 1, 2, 0, 1, 3, 2, 2, 1], ComponentType::U8);
+        let primitive = &mut model.root.meshes[0].primitives[0];
+        primitive
+            .attributes
+            .insert(Valid(Semantic::Positions), positions);
+        primitive.indices = Some(indices);
+        assert_eq!(
+            paths(model.check_geometry()),
+            ["meshes[0].primitives[0].indices"]
+        );
+
+        assert_eq!(model.remove_degenerate_triangles(), 2);
+        let indices = model.root.meshes[0].primitives[0].indices.unwrap();
+        assert_eq!(model.read_u32(indices).unwrap(), [0, 1, 2]);
+        assert!(model.check_geometry().is_empty());
+        assert_eq!(model.remove_degenerate_triangles(), 0);
+    }
+
+    #[test]
+    fn bad_normals() {
+        let mut model = triangle();
+        let normals = model.push_f32(
+            &[0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0],
+            Type::Vec3,
+            None,
+        );
+        model.root.meshes[0].primitives[0]
+            .attributes
+            .insert(Valid(Semantic::Normals), normals);
+        assert_eq!(
+            paths(model.check_geometry()),
+            ["meshes[0].primitives[0].attributes[\"NORMAL\"]"]
+        );
+
+        let normals = model.push_f32(
+            &[0.0, 0.0, 1.0, f32::NAN, 0.0, 0.0, 0.0, 0.0, 1.0],
+            Type::Vec3,
+            None,
+        );
+        model.root.meshes[0].primitives[0]
+            .attributes
+            .insert(Valid(Semantic::Normals), normals);
+        assert_eq!(model.check_geometry().len(), 1);
+
+        assert_eq!(model.normalize_normals(), 1);
+        let primitive = &model.root.meshes[0].primitives[0];
+        let normals = primitive.attributes[&Valid(Semantic::Normals)];
+        assert_eq!(model.read_f32(normals).unwrap(), [0.0, 0.0, 1.0].repeat(3));
+        assert!(model.check_geometry().is_empty());
+        assert_eq!(model.normalize_normals(), 0);
+    }
+}
@@ -0,0 +1,194 @@
+use super::Model;
+use crate::json::accessor::{Accessor, ComponentType, GenericComponentType, Type};
+use crate::json::animation::Property;
+use crate::json::mesh::Semantic;
+use crate::json::validation::Checked::Valid;
+use crate::json::Index;
+use std::collections::BTreeMap;
+
+impl Model {
+    /// Scales the whole document by `factor`, for example `0.001` to convert
+    /// an asset modelled in millimeters to the meters used by glTF.
+    ///
+    /// `POSITION` attributes and morph target displacements, node and
+    /// animated translations, the translation of inverse bind matrices and
+    /// `EXT_mesh_gpu_instancing` translations are scaled, as are camera clip
+    /// planes and orthographic magnifications, light ranges and the
+    /// distances of `KHR_materials_volume`. Bounds are updated where present.
+    ///
+    /// Scaled data is appended to the first buffer as `f32` data, including
+    /// that of integer accessors. Buffer views no longer referenced are left in
+    /// place and may be removed afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not positive and finite.
+    pub fn rescale(&mut self, factor: f32) {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "{} is not a valid scale factor",
+            factor
+        );
+
+        for node in &mut self.root.nodes {
+            if let Some(matrix) = node.matrix.as_mut() {
+                matrix[12..15].iter_mut().for_each(|x| *x *= factor);
+            }
+            if let Some(translation) = node.translation.as_mut() {
+                translation.iter_mut().for_each(|x| *x *= factor);
+            }
+        }
+
+        for camera in &mut self.root.cameras {
+            if let Some(perspective) = camera.perspective.as_mut() {
+                perspective.znear *= factor;
+                perspective.zfar = perspective.zfar.map(|x| x * factor);
+            }
+            if let Some(orthographic) = camera.orthographic.as_mut() {
+                orthographic.xmag *= factor;
+                orthographic.ymag *= factor;
+                orthographic.znear *= factor;
+                orthographic.zfar *= factor;
+            }
+        }
+
+        #[cfg(feature = "KHR_lights_punctual")]
+        if let Some(lights) = self
+            .root
+            .extensions
+            .as_mut()
+            .and_then(|x| x.khr_lights_punctual.as_mut())
+        {
+            for light in &mut lights.lights {
+                light.range = light.range.map(|x| x * factor);
+            }
+        }
+
+        #[cfg(feature = "KHR_materials_volume")]
+        for material in &mut self.root.materials {
+            if let Some(volume) = material.extensions.as_mut().and_then(|x| x.volume.as_mut()) {
+                volume.thickness_factor.0 *= factor;
+                volume.attenuation_distance.0 *= factor;
+            }
+        }
+
+        for (accessor, type_) in self.scaled_accessors() {
+            self.scale_accessor(accessor, type_, factor);
+        }
+    }
+
+    /// Returns every accessor containing distances, with the type it is
+    /// expected to have: `VEC3` for translations and `MAT4` for transforms.
+    fn scaled_accessors(&self) -> BTreeMap<Index<Accessor>, Type> {
+        let mut accessors = BTreeMap::new();
+        for primitive in self.root.meshes.iter().flat_map(|x| &x.primitives) {
+            let positions = primitive.attributes.get(&Valid(Semantic::Positions));
+            let displacements = primitive
+                .targets
+                .iter()
+                .flatten()
+                .flat_map(|x| &x.positions);
+            for &accessor in positions.into_iter().chain(displacements) {
+                accessors.insert(accessor, Type::Vec3);
+            }
+        }
+        for skin in &self.root.skins {
+            if let Some(accessor) = skin.inverse_bind_matrices {
+                accessors.insert(accessor, Type::Mat4);
+            }
+        }
+        for animation in &self.root.animations {
+            for channel in &animation.channels {
+                if channel.target.path != Valid(Property::Translation) {
+                    continue;
+                }
+                if let Some(sampler) = animation.samplers.get(channel.sampler.value()) {
+                    accessors.insert(sampler.output, Type::Vec3);
+                }
+            }
+        }
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        for node in &self.root.nodes {
+            let instancing = node
+                .extensions
+                .as_ref()
+                .and_then(|x| x.ext_mesh_gpu_instancing.as_ref());
+            if let Some(&accessor) = instancing.and_then(|x| x.attributes.get("TRANSLATION")) {
+                accessors.insert(accessor, Type::Vec3);
+            }
+        }
+        accessors
+    }
+
+    /// Rewrites an accessor with its distances scaled, leaving accessors of an
+    /// unexpected type or with unreadable data alone.
+    fn scale_accessor(&mut self, index: Index<Accessor>, type_: Type, factor: f32) {
+        match self.root.get(index) {
+            Some(accessor) if accessor.type_ == Valid(type_) => {}
+            _ => return,
+        }
+        let mut values = match self.read_f32(index) {
+            Some(values) => values,
+            None => return,
+        };
+        for element in values.chunks_exact_mut(type_.multiplicity()) {
+            match type_ {
+                Type::Mat4 => element[12..15].iter_mut().for_each(|x| *x *= factor),
+                _ => element.iter_mut().for_each(|x| *x *= factor),
+            }
+        }
+
+        // Borrow the buffer view of a new accessor.
+        let target = self.root.accessors[index.value()]
+            .buffer_view
+            .and_then(|view| self.root.get(view))
+            .and_then(|view| match view.target {
+                Some(Valid(target)) => Some(target),
+                _ => None,
+            });
+        self.push_f32(&values, type_, target);
+        let replacement = self.root.accessors.pop().unwrap();
+        let accessor = &mut self.root.accessors[index.value()];
+        accessor.buffer_view = replacement.buffer_view;
+        accessor.byte_offset = 0;
+        accessor.component_type = Valid(GenericComponentType(ComponentType::F32));
+        accessor.normalized = false;
+        accessor.sparse = None;
+        if accessor.min.is_some() || accessor.max.is_some() {
+            let bounds = self.compute_bounds(index);
+            let accessor = &mut self.root.accessors[index.value()];
+            accessor.min = bounds.as_ref().map(|(min, _)| min.clone().into());
+            accessor.max = bounds.map(|(_, max)| max.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::tests::triangle;
+    use crate::json::{self, Index, Value};
+
+    #[test]
+    fn rescale() {
+        let mut model = triangle();
+        let nodes = r#"[{"mesh": 0, "translation": [1.0, 2.0, 3.0], "scale": [2.0, 2.0, 2.0]}]"#;
+        model.root.nodes = json::deserialize::from_str(nodes).unwrap();
+        let cameras = r#"[{"type": "perspective", "perspective": {"yfov": 1.0, "znear": 10.0}}]"#;
+        model.root.cameras = json::deserialize::from_str(cameras).unwrap();
+        model.update_bounds();
+
+        model.rescale(0.5);
+        let node = &model.root.nodes[0];
+        assert_eq!(node.translation, Some([0.5, 1.0, 1.5]));
+        assert_eq!(node.scale, Some([2.0, 2.0, 2.0]));
+        let perspective = model.root.cameras[0].perspective.as_ref().unwrap();
+        assert_eq!(perspective.znear, 5.0);
+        assert_eq!(perspective.zfar, None);
+        assert_eq!(
+            model.read_f32(Index::new(0)).unwrap(),
+            [0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        let max = model.root.accessors[0].max.clone();
+        assert_eq!(max, Some(Value::from(vec![0.5, 1.0, 0.0])));
+    }
+}
@@ -0,0 +1,257 @@
+use super::Model;
+use crate::json::accessor::{ComponentType, Type};
+use crate::json::buffer::Target;
+use crate::json::mesh::{Mode, Primitive, Semantic};
+use crate::json::validation::Checked::Valid;
+use std::collections::HashMap;
+
+/// The input and output of the MikkTSpace algorithm for a single primitive.
+struct Geometry<'a> {
+    triangles: &'a [[u32; 3]],
+    positions: &'a [f32],
+    normals: &'a [f32],
+    tex_coords: &'a [f32],
+
+    /// The generated tangent of every triangle corner.
+    tangents: Vec<[f32; 4]>,
+}
+
+impl<'a> Geometry<'a> {
+    fn vertex(&self, face: usize, vert: usize) -> usize {
+        self.triangles[face][vert] as usize
+    }
+}
+
+impl<'a> mikktspace::Geometry for Geometry<'a> {
+    fn num_faces(&self) -> usize {
+        self.triangles.len()
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        let i = 3 * self.vertex(face, vert);
+        [
+            self.positions[i],
+            self.positions[i + 1],
+            self.positions[i + 2],
+        ]
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        let i = 3 * self.vertex(face, vert);
+        [self.normals[i], self.normals[i + 1], self.normals[i + 2]]
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        let i = 2 * self.vertex(face, vert);
+        [self.tex_coords[i], self.tex_coords[i + 1]]
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        // MikkTSpace expects texture coordinates with the origin at the bottom
+        // left, whereas glTF puts it at the top left, which flips the bitangent.
+        let [x, y, z, w] = tangent;
+        self.tangents[3 * face + vert] = [x, y, z, -w];
+    }
+}
+
+impl Model {
+    /// Generates a `TANGENT` attribute using the MikkTSpace algorithm for
+    /// every triangle primitive that has normals and a normal texture with
+    /// matching texture coordinates, but no tangents. Returns the number of
+    /// primitives updated.
+    ///
+    /// Vertices whose triangles require different tangents are duplicated.
+    /// New data is appended to the first buffer, and accessors replaced in
+    /// the process are left in place and may be removed afterwards.
+    #[cfg_attr(docsrs, doc(cfg(feature = "tangents")))]
+    pub fn generate_tangents(&mut self) -> usize {
+        let mut generated = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = self.root.meshes[mesh].primitives[index].clone();
+                if primitive
+                    .attributes
+                    .contains_key(&Valid(Semantic::Tangents))
+                {
+                    continue;
+                }
+                if let Some(primitive) = self.with_tangents(primitive) {
+                    self.root.meshes[mesh].primitives[index] = primitive;
+                    generated += 1;
+                }
+            }
+        }
+        generated
+    }
+
+    /// Returns `primitive` with generated tangents, or `None` if it has no
+    /// normal texture or its data cannot be read.
+    fn with_tangents(&mut self, mut primitive: Primitive) -> Option<Primitive> {
+        let material = self.root.get(primitive.material?)?;
+        let set = material.normal_texture.as_ref()?.tex_coord;
+        let positions = *primitive.attributes.get(&Valid(Semantic::Positions))?;
+        let normals = *primitive.attributes.get(&Valid(Semantic::Normals))?;
+        let tex_coords = *primitive.attributes.get(&Valid(Semantic::TexCoords(set)))?;
+        let types = [
+            (positions, Type::Vec3),
+            (normals, Type::Vec3),
+            (tex_coords, Type::Vec2),
+        ];
+        for (accessor, type_) in types {
+            if self.root.get(accessor)?.type_ != Valid(type_) {
+                return None;
+            }
+        }
+
+        let triangles = self.triangles(&primitive)?;
+        let positions = self.read_f32(positions)?;
+        let normals = self.read_f32(normals)?;
+        let tex_coords = self.read_f32(tex_coords)?;
+        let vertex_count = positions.len() / 3;
+        if normals.len() != 3 * vertex_count
+            || tex_coords.len() != 2 * vertex_count
+            || triangles
+                .iter()
+                .flatten()
+                .any(|&i| i as usize >= vertex_count)
+        {
+            return None;
+        }
+
+        let mut geometry = Geometry {
+            triangles: &triangles,
+            positions: &positions,
+            normals: &normals,
+            tex_coords: &tex_coords,
+            tangents: vec![[1.0, 0.0, 0.0, 1.0]; 3 * triangles.len()],
+        };
+        if !mikktspace::generate_tangents(&mut geometry) {
+            return None;
+        }
+
+        // Assign the tangent of every corner to its vertex, appending a copy of
+        // the vertex whenever a corner disagrees with the tangent already
+        // assigned.
+        let mut assigned: Vec<Option<[f32; 4]>> = vec![None; vertex_count];
+        let mut tangents = vec![[1.0, 0.0, 0.0, 1.0]; vertex_count];
+        let mut sources: Vec<u32> = (0..vertex_count as u32).collect();
+        let mut copies = HashMap::new();
+        let mut indices = Vec::with_capacity(geometry.tangents.len());
+        let corners = triangles.iter().flatten().zip(&geometry.tangents);
+        for (&vertex, &tangent) in corners {
+            let index = match assigned[vertex as usize] {
+                None => {
+                    assigned[vertex as usize] = Some(tangent);
+                    tangents[vertex as usize] = tangent;
+                    vertex
+                }
+                Some(existing) if existing == tangent => vertex,
+                Some(_) => {
+                    let key = (vertex, tangent.map(f32::to_bits));
+                    *copies.entry(key).or_insert_with(|| {
+                        sources.push(vertex);
+                        tangents.push(tangent);
+                        sources.len() as u32 - 1
+                    })
+                }
+            };
+            indices.push(index);
+        }
+
+        if sources.len() > vertex_count {
+            self.remap_vertices(&mut primitive, &sources)?;
+            let component_type = if sources.len() <= u16::MAX as usize {
+                ComponentType::U16
+            } else {
+                ComponentType::U32
+            };
+            primitive.indices = Some(self.push_indices(&indices, component_type));
+            primitive.mode = Valid(Mode::Triangles);
+        }
+        let tangents: Vec<f32> = tangents.into_iter().flatten().collect();
+        let tangents = self.push_f32(&tangents, Type::Vec4, Some(Target::ArrayBuffer));
+        primitive
+            .attributes
+            .insert(Valid(Semantic::Tangents), tangents);
+        Some(primitive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::Model;
+    use crate::json::{self, mesh::Semantic, validation::Checked::Valid};
+
+    /// A unit quad facing +Z with texture coordinates following the glTF
+    /// convention, and a material with a normal texture.
+    fn quad() -> Model {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [
+                {"bufferView": 0, "count": 4, "componentType": 5126, "type": "VEC3"},
+                {"bufferView": 1, "count": 4, "componentType": 5126, "type": "VEC3"},
+                {"bufferView": 2, "count": 4, "componentType": 5126, "type": "VEC2"},
+                {"bufferView": 3, "count": 6, "componentType": 5121, "type": "SCALAR"}
+            ],
+            "buffers": [{"byteLength": 134}],
+            "bufferViews": [
+                {"buffer": 0, "byteLength": 48},
+                {"buffer": 0, "byteOffset": 48, "byteLength": 48},
+                {"buffer": 0, "byteOffset": 96, "byteLength": 32},
+                {"buffer": 0, "byteOffset": 128, "byteLength": 6}
+            ],
+            "images": [{"uri": "normal.png"}],
+            "textures": [{"source": 0}],
+            "materials": [{"normalTexture": {"index": 0}}],
+            "meshes": [{"primitives": [{
+                "attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2},
+                "indices": 3,
+                "material": 0
+            }]}]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        let positions = [
+            0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+        let normals = [0.0f32, 0.0, 1.0].repeat(4);
+        let tex_coords = [0.0f32, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0];
+        let mut data: Vec<u8> = positions
+            .iter()
+            .chain(&normals)
+            .chain(&tex_coords)
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        data.extend_from_slice(&[0, 1, 2, 0, 2, 3]);
+        Model::new(root, vec![data])
+    }
+
+    #[test]
+    fn generate_tangents() {
+        let mut model = quad();
+        assert_eq!(model.generate_tangents(), 1);
+        let primitive = &model.root.meshes[0].primitives[0];
+        let tangents = primitive.attributes[&Valid(Semantic::Tangents)];
+        let tangents = model.read_f32(tangents).unwrap();
+        assert_eq!(tangents.len(), 16);
+        for tangent in tangents.chunks(4) {
+            assert_relative_eq!(tangent[0], 1.0, epsilon = 1e-5);
+            assert_relative_eq!(tangent[1], 0.0, epsilon = 1e-5);
+            assert_relative_eq!(tangent[2], 0.0, epsilon = 1e-5);
+            assert_eq!(tangent[3], 1.0);
+        }
+
+        // Primitives that have tangents are left alone.
+        assert_eq!(model.generate_tangents(), 0);
+    }
+
+    #[test]
+    fn requires_normal_texture() {
+        let mut model = quad();
+        model.root.materials[0].normal_texture = None;
+        assert_eq!(model.generate_tangents(), 0);
+    }
+}
@@ -0,0 +1,167 @@
+use super::Model;
+use crate::json::accessor::{Accessor, ComponentType};
+use crate::json::mesh::{Primitive, Semantic};
+use crate::json::validation::Checked::Valid;
+use crate::json::Index;
+use std::collections::HashMap;
+
+impl Model {
+    /// Merges duplicate vertices within every primitive, returning the total
+    /// number of vertices removed.
+    ///
+    /// Two vertices are duplicates when all of their attributes and morph
+    /// target displacements are equal. With an `epsilon` of zero, values must
+    /// be bitwise identical. Otherwise values are snapped to a grid with a
+    /// spacing of `epsilon` and compared after snapping, so values closer than
+    /// `epsilon` usually, but not always, compare equal. Normalized integer
+    /// attributes are compared by their normalized value. The first vertex of
+    /// each set of duplicates is kept unchanged.
+    ///
+    /// Primitives that lose vertices get new, smaller accessors and a new
+    /// index accessor, appended to the first buffer, and the bounds of their
+    /// positions are recomputed. Accessors replaced in the process are left in
+    /// place and may be removed afterwards.
+    pub fn weld_vertices(&mut self, epsilon: f32) -> usize {
+        let mut removed = 0;
+        for mesh in 0..self.root.meshes.len() {
+            for index in 0..self.root.meshes[mesh].primitives.len() {
+                let primitive = self.root.meshes[mesh].primitives[index].clone();
+                if let Some((primitive, count)) = self.welded(primitive, epsilon) {
+                    self.root.meshes[mesh].primitives[index] = primitive;
+                    removed += count;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Returns `primitive` with duplicate vertices merged and the number of
+    /// vertices removed, or `None` if it has no duplicates or its data cannot
+    /// be read.
+    fn welded(&mut self, mut primitive: Primitive, epsilon: f32) -> Option<(Primitive, usize)> {
+        let positions = *primitive.attributes.get(&Valid(Semantic::Positions))?;
+        let vertex_count = self.root.get(positions)?.count as usize;
+        let mut accessors: Vec<Index<Accessor>> = primitive.attributes.values().copied().collect();
+        for target in primitive.targets.iter().flatten() {
+            accessors.extend(target.positions);
+            accessors.extend(target.normals);
+            accessors.extend(target.tangents);
+        }
+        let mut attributes = Vec::with_capacity(accessors.len());
+        for accessor in accessors {
+            if self.root.get(accessor)?.count as usize != vertex_count {
+                return None;
+            }
+            attributes.push(self.read_f32(accessor)?);
+        }
+
+        let key = |value: f32| {
+            if epsilon > 0.0 {
+                (value / epsilon).round() as i64
+            } else {
+                value.to_bits() as i64
+            }
+        };
+        let mut sources: Vec<u32> = Vec::new();
+        let mut unique = HashMap::new();
+        let mut remap = Vec::with_capacity(vertex_count);
+        for vertex in 0..vertex_count {
+            let mut values = Vec::new();
+            for data in &attributes {
+                let components = data.len() / vertex_count;
+                let start = vertex * components;
+                values.extend(data[start..start + components].iter().map(|&x| key(x)));
+            }
+            let index = *unique.entry(values).or_insert_with(|| {
+                sources.push(vertex as u32);
+                sources.len() as u32 - 1
+            });
+            remap.push(index);
+        }
+        if sources.len() == vertex_count {
+            return None;
+        }
+
+        let indices = match primitive.indices {
+            Some(index) => self.read_u32(index)?,
+            None => (0..vertex_count as u32).collect(),
+        };
+        let indices: Vec<u32> = indices
+            .into_iter()
+            .map(|i| remap.get(i as usize).copied())
+            .collect::<Option<_>>()?;
+        self.remap_vertices(&mut primitive, &sources)?;
+        // Dropping vertices that are close to others may shrink the bounds.
+        let positions = primitive.attributes.get(&Valid(Semantic::Positions));
+        let targets = primitive.targets.iter().flatten();
+        for &index in positions
+            .into_iter()
+            .chain(targets.flat_map(|x| &x.positions))
+        {
+            self.update_accessor_bounds(index);
+        }
+        let component_type = if sources.len() < u16::MAX as usize {
+            ComponentType::U16
+        } else {
+            ComponentType::U32
+        };
+        primitive.indices = Some(self.push_indices(&indices, component_type));
+        Some((primitive, vertex_count - sources.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::{tests::triangle, Model};
+    use crate::json::accessor::Type;
+    use crate::json::mesh::Semantic;
+    use crate::json::validation::Checked::Valid;
+    use crate::json::Value;
+
+    /// A unit quad made of two triangles without shared vertices, with one
+    /// copy of the first corner displaced by `offset`.
+    fn quad(offset: f32) -> Model {
+        let mut model = triangle();
+        let positions = [
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, //
+            0.0, 0.0, offset, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+        let positions = model.push_f32(&positions, Type::Vec3, None);
+        let primitive = &mut model.root.meshes[0].primitives[0];
+        primitive
+            .attributes
+            .insert(Valid(Semantic::Positions), positions);
+        primitive.indices = None;
+        model
+    }
+
+    #[test]
+    fn weld_vertices() {
+        let mut model = quad(0.0);
+        assert_eq!(model.weld_vertices(0.0), 2);
+        let primitive = &model.root.meshes[0].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        assert_eq!(model.root.accessors[positions.value()].count, 4);
+        assert_eq!(
+            model.read_u32(primitive.indices.unwrap()).unwrap(),
+            [0, 1, 2, 0, 2, 3]
+        );
+
+        // Welded primitives are left alone.
+        assert_eq!(model.weld_vertices(0.0), 0);
+    }
+
+    #[test]
+    fn weld_close_vertices() {
+        let mut model = quad(1e-6);
+        assert_eq!(model.weld_vertices(0.0), 1);
+        let mut model = quad(1e-6);
+        model.update_bounds();
+        assert_eq!(model.weld_vertices(1e-4), 2);
+        let primitive = &model.root.meshes[0].primitives[0];
+        let positions = primitive.attributes[&Valid(Semantic::Positions)];
+        let accessor = &model.root.accessors[positions.value()];
+        assert_eq!(accessor.min, Some(Value::from(vec![0.0, 0.0, 0.0])));
+        assert_eq!(accessor.max, Some(Value::from(vec![1.0, 1.0, 0.0])));
+    }
+}
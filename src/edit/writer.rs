@@ -0,0 +1,292 @@
+use crate::json::accessor::{Accessor, ComponentType, GenericComponentType, Type};
+use crate::json::validation::Checked::Valid;
+use crate::json::{self, buffer, Index, Value};
+
+/// A component type that can be written by a [`BufferWriter`].
+pub trait Component: Copy + PartialOrd + Into<Value> {
+    /// The matching accessor component type.
+    const COMPONENT_TYPE: ComponentType;
+
+    /// Appends the little endian representation of `self`.
+    fn write(self, bytes: &mut Vec<u8>);
+}
+
+/// An element type that can be written by a [`BufferWriter`], such as `u32`,
+/// `[f32; 3]` or `[[f32; 4]; 4]`.
+pub trait Element: Copy {
+    /// The type of the components of the element.
+    type Component: Component;
+
+    /// The matching accessor type.
+    const TYPE: Type;
+
+    /// Appends the components of `self`, matrices in column-major order.
+    fn components(&self, components: &mut Vec<Self::Component>);
+}
+
+macro_rules! impl_component {
+    ($($ty:ty => $component_type:ident),*) => {$(
+        impl Component for $ty {
+            const COMPONENT_TYPE: ComponentType = ComponentType::$component_type;
+
+            fn write(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl Element for $ty {
+            type Component = $ty;
+            const TYPE: Type = Type::Scalar;
+
+            fn components(&self, components: &mut Vec<$ty>) {
+                components.push(*self);
+            }
+        }
+
+        impl Element for [$ty; 2] {
+            type Component = $ty;
+            const TYPE: Type = Type::Vec2;
+
+            fn components(&self, components: &mut Vec<$ty>) {
+                components.extend_from_slice(self);
+            }
+        }
+
+        impl Element for [$ty; 3] {
+            type Component = $ty;
+            const TYPE: Type = Type::Vec3;
+
+            fn components(&self, components: &mut Vec<$ty>) {
+                components.extend_from_slice(self);
+            }
+        }
+
+        impl Element for [$ty; 4] {
+            type Component = $ty;
+            const TYPE: Type = Type::Vec4;
+
+            fn components(&self, components: &mut Vec<$ty>) {
+                components.extend_from_slice(self);
+            }
+        }
+    )*};
+}
+
+impl_component!(i8 => I8, u8 => U8, i16 => I16, u16 => U16, u32 => U32, f32 => F32);
+
+// Matrices of narrower components need column padding, so only `f32`
+// matrices are supported.
+macro_rules! impl_matrix {
+    ($($n:literal => $type_:ident),*) => {$(
+        impl Element for [[f32; $n]; $n] {
+            type Component = f32;
+            const TYPE: Type = Type::$type_;
+
+            fn components(&self, components: &mut Vec<f32>) {
+                self.iter().for_each(|column| components.extend_from_slice(column));
+            }
+        }
+    )*};
+}
+
+impl_matrix!(2 => Mat2, 3 => Mat3, 4 => Mat4);
+
+/// Appends typed data to a new buffer of a document, creating a buffer view
+/// and accessor for each slice written.
+///
+/// Every buffer view starts on a four byte boundary and every accessor is
+/// given the bounds of its data, so the results can be used as vertex
+/// attributes, indices or animation samplers as they are.
+///
+/// ```
+/// use gltf::edit::BufferWriter;
+/// use gltf::json;
+///
+/// let mut root = json::Root::new(json::Asset::new("example"));
+/// let mut writer = BufferWriter::new(&mut root);
+/// let positions = writer.write_vertices(&[[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+/// let indices = writer.write_indices(&[0u16, 1, 2]);
+/// let (buffer, data) = writer.finish();
+///
+/// assert_eq!(data.len(), 44);
+/// assert_eq!(root.buffers[buffer.value()].byte_length, 44);
+/// assert_eq!(root.accessors[indices.value()].count, 3);
+/// assert_eq!(root.accessors[positions.value()].max, Some(json::Value::from(vec![1.0, 1.0, 0.0])));
+/// ```
+#[derive(Debug)]
+pub struct BufferWriter<'a> {
+    /// The document the buffer belongs to.
+    root: &'a mut json::Root,
+
+    /// The buffer being written.
+    buffer: Index<json::Buffer>,
+
+    /// The data written so far.
+    data: Vec<u8>,
+}
+
+impl<'a> BufferWriter<'a> {
+    /// Adds a new, empty buffer to `root` to write to.
+    pub fn new(root: &'a mut json::Root) -> Self {
+        let buffer = root.push(json::Buffer {
+            byte_length: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+        });
+        Self {
+            root,
+            buffer,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the buffer being written.
+    pub fn buffer(&self) -> Index<json::Buffer> {
+        self.buffer
+    }
+
+    /// Appends `bytes` as a new buffer view.
+    pub fn write_view(
+        &mut self,
+        bytes: &[u8],
+        target: Option<buffer::Target>,
+    ) -> Index<buffer::View> {
+        self.pad();
+        let offset = self.data.len() as u32;
+        self.data.extend_from_slice(bytes);
+        self.root.buffers[self.buffer.value()].byte_length = self.data.len() as u32;
+        self.root.push(buffer::View {
+            buffer: self.buffer,
+            byte_length: bytes.len() as u32,
+            byte_offset: Some(offset),
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Valid),
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+        })
+    }
+
+    /// Appends `data` as a new accessor with its own buffer view, returning
+    /// the index of the accessor.
+    pub fn write<T: Element>(
+        &mut self,
+        data: &[T],
+        target: Option<buffer::Target>,
+    ) -> Index<Accessor> {
+        let mut components = Vec::with_capacity(data.len() * T::TYPE.multiplicity());
+        data.iter().for_each(|x| x.components(&mut components));
+        let mut bytes = Vec::with_capacity(components.len() * T::Component::COMPONENT_TYPE.size());
+        components.iter().for_each(|x| x.write(&mut bytes));
+        let (min, max) = bounds(&components, T::TYPE.multiplicity());
+
+        let view = self.write_view(&bytes, target);
+        self.root.push(Accessor {
+            buffer_view: Some(view),
+            byte_offset: 0,
+            count: data.len() as u32,
+            component_type: Valid(GenericComponentType(T::Component::COMPONENT_TYPE)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "unrecognized")]
+            unrecognized: Default::default(),
+            type_: Valid(T::TYPE),
+            min,
+            max,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        })
+    }
+
+    /// Appends vertex attribute data, such as positions or texture
+    /// coordinates.
+    pub fn write_vertices<T: Element>(&mut self, data: &[T]) -> Index<Accessor> {
+        self.write(data, Some(buffer::Target::ArrayBuffer))
+    }
+
+    /// Appends index data.
+    pub fn write_indices<T: Element>(&mut self, data: &[T]) -> Index<Accessor> {
+        self.write(data, Some(buffer::Target::ElementArrayBuffer))
+    }
+
+    /// Finishes the buffer, padding it to a multiple of four bytes, and
+    /// returns its index together with its contents.
+    pub fn finish(mut self) -> (Index<json::Buffer>, Vec<u8>) {
+        self.pad();
+        self.root.buffers[self.buffer.value()].byte_length = self.data.len() as u32;
+        (self.buffer, self.data)
+    }
+
+    /// Pads the data to a multiple of four bytes, the largest component size.
+    fn pad(&mut self) {
+        while self.data.len() % 4 != 0 {
+            self.data.push(0);
+        }
+    }
+}
+
+/// Returns the per component minimum and maximum of elements of `n`
+/// components, or `None` for no elements.
+fn bounds<C: Component>(components: &[C], n: usize) -> (Option<Value>, Option<Value>) {
+    let mut elements = components.chunks_exact(n);
+    let first = match elements.next() {
+        Some(first) => first,
+        None => return (None, None),
+    };
+    let (mut min, mut max) = (first.to_vec(), first.to_vec());
+    for element in elements {
+        for ((min, max), &x) in min.iter_mut().zip(&mut max).zip(element) {
+            if x < *min {
+                *min = x;
+            }
+            if x > *max {
+                *max = x;
+            }
+        }
+    }
+    let value = |x: Vec<C>| Value::from(x.into_iter().map(Into::into).collect::<Vec<Value>>());
+    (Some(value(min)), Some(value(max)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BufferWriter;
+    use crate::edit::Model;
+    use crate::json::{self, Index, Value};
+
+    #[test]
+    fn write() {
+        let mut root = json::Root::new(json::Asset::new("test"));
+        let mut writer = BufferWriter::new(&mut root);
+        let indices = writer.write_indices(&[2u8, 0, 1]);
+        let matrices = writer.write(&[[[1.0f32, 0.0], [0.0, 1.0]]], None);
+        let weights = writer.write_vertices(&[[255u8, 0, 0, 0], [128, 127, 0, 0]]);
+        let (buffer, data) = writer.finish();
+        assert_eq!(buffer, Index::new(0));
+        assert_eq!(data.len(), 28);
+
+        // Views after the three index bytes start on a four byte boundary.
+        assert_eq!(root.buffer_views[1].byte_offset, Some(4));
+        assert_eq!(
+            root.accessors[indices.value()].min,
+            Some(Value::from(vec![0]))
+        );
+        let weights_json = &root.accessors[weights.value()];
+        assert_eq!(weights_json.max, Some(Value::from(vec![255, 127, 0, 0])));
+        assert!(crate::Document::from_json(root.clone()).is_ok());
+
+        let model = Model::new(root, vec![data]);
+        assert_eq!(model.read_u32(indices).unwrap(), [2, 0, 1]);
+        assert_eq!(model.read_f32(matrices).unwrap(), [1.0, 0.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Accessor, Document};
+
+#[cfg(feature = "utils")]
+use crate::{accessor, animation::util::Rotations, scene::Transform, Buffer};
+
+/// Per-instance transforms of a mesh.
+#[derive(Clone, Debug)]
+pub struct MeshGpuInstancing<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+}
+
+/// Instance reader.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    pub(crate) instancing: MeshGpuInstancing<'a>,
+    pub(crate) get_buffer_data: F,
+}
+
+/// An `Iterator` that visits the transform of each instance.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct ReadTransforms<'s> {
+    translations: Option<accessor::Iter<'s, [f32; 3]>>,
+    rotations: Option<
+        crate::animation::util::rotations::CastingIter<'s, crate::animation::util::rotations::F32>,
+    >,
+    scales: Option<accessor::Iter<'s, [f32; 3]>>,
+    remaining: usize,
+}
+
+impl<'a> MeshGpuInstancing<'a> {
+    /// Constructs a `MeshGpuInstancing`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the accessor of an instance attribute, such as `TRANSLATION`
+    /// or an application specific `_ID`.
+    pub fn get(&self, name: &str) -> Option<Accessor<'a>> {
+        self.json
+            .attributes
+            .get(name)
+            .map(|index| self.document.accessors().nth(index.value()).unwrap())
+    }
+
+    /// Returns the accessor of the instance translations, if provided.
+    pub fn translation(&self) -> Option<Accessor<'a>> {
+        self.get("TRANSLATION")
+    }
+
+    /// Returns the accessor of the instance rotations, if provided.
+    pub fn rotation(&self) -> Option<Accessor<'a>> {
+        self.get("ROTATION")
+    }
+
+    /// Returns the accessor of the instance scales, if provided.
+    pub fn scale(&self) -> Option<Accessor<'a>> {
+        self.get("SCALE")
+    }
+
+    /// Constructs the instance reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            instancing: self.clone(),
+            get_buffer_data,
+        }
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the instance translations.
+    pub fn read_translations(&self) -> Option<accessor::Iter<'s, [f32; 3]>> {
+        self.instancing
+            .translation()
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+    }
+
+    /// Visits the instance rotations.
+    pub fn read_rotations(&self) -> Option<Rotations<'s>> {
+        use accessor::{DataType, Iter};
+        let accessor = self.instancing.rotation()?;
+        match accessor.data_type() {
+            DataType::I8 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::I8),
+            DataType::I16 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::I16),
+            DataType::F32 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::F32),
+            _ => None,
+        }
+    }
+
+    /// Visits the instance scales.
+    pub fn read_scales(&self) -> Option<accessor::Iter<'s, [f32; 3]>> {
+        self.instancing
+            .scale()
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+    }
+
+    /// Visits the transform of each instance.
+    ///
+    /// Instance attributes that are not provided take their default value.
+    /// Returns `None` if none of `TRANSLATION`, `ROTATION` and `SCALE` are
+    /// provided or if any of them cannot be read.
+    pub fn read_transforms(&self) -> Option<ReadTransforms<'s>> {
+        let count = [
+            self.instancing.translation(),
+            self.instancing.rotation(),
+            self.instancing.scale(),
+        ]
+        .iter()
+        .flatten()
+        .map(|accessor| accessor.count())
+        .min()?;
+        let translations = match self.instancing.translation() {
+            Some(_) => Some(self.read_translations()?),
+            None => None,
+        };
+        let rotations = match self.instancing.rotation() {
+            Some(_) => Some(self.read_rotations()?.into_f32()),
+            None => None,
+        };
+        let scales = match self.instancing.scale() {
+            Some(_) => Some(self.read_scales()?),
+            None => None,
+        };
+        Some(ReadTransforms {
+            translations,
+            rotations,
+            scales,
+            remaining: count,
+        })
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'s> ExactSizeIterator for ReadTransforms<'s> {}
+
+#[cfg(feature = "utils")]
+impl<'s> Iterator for ReadTransforms<'s> {
+    type Item = Transform;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let translation = match self.translations.as_mut() {
+            Some(iter) => iter.next()?,
+            None => [0.0, 0.0, 0.0],
+        };
+        let rotation = match self.rotations.as_mut() {
+            Some(iter) => iter.next()?,
+            None => [0.0, 0.0, 0.0, 1.0],
+        };
+        let scale = match self.scales.as_mut() {
+            Some(iter) => iter.next()?,
+            None => [1.0, 1.0, 1.0],
+        };
+        Some(Transform::Decomposed {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use crate::scene::Transform;
+
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["EXT_mesh_gpu_instancing"],
+        "buffers": [{ "byteLength": 40 }],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 24 },
+            { "buffer": 0, "byteOffset": 24, "byteLength": 16 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3" },
+            {
+                "bufferView": 1,
+                "componentType": 5122,
+                "normalized": true,
+                "count": 2,
+                "type": "VEC4"
+            }
+        ],
+        "nodes": [{
+            "extensions": {
+                "EXT_mesh_gpu_instancing": {
+                    "attributes": { "TRANSLATION": 0, "ROTATION": 1 }
+                }
+            }
+        }]
+    }"#;
+
+    #[test]
+    fn read_transforms() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let mut data = Vec::new();
+        for x in [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+        for x in [0i16, 0, 0, i16::MAX, i16::MAX, 0, 0, 0] {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+
+        let node = gltf.nodes().next().unwrap();
+        let instancing = node.mesh_gpu_instancing().unwrap();
+        assert!(instancing.scale().is_none());
+        let reader = instancing.reader(|_| Some(&data));
+        let transforms: Vec<_> = reader
+            .read_transforms()
+            .unwrap()
+            .map(Transform::decomposed)
+            .collect();
+        assert_eq!(
+            transforms,
+            [
+                ([1.0, 2.0, 3.0], [0.0, 0.0, 0.0, 1.0], [1.0, 1.0, 1.0]),
+                ([4.0, 5.0, 6.0], [1.0, 0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            ]
+        );
+    }
+}
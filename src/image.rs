@@ -39,6 +39,12 @@ pub enum Format {
 
     /// Red, green, blue, alpha (32 bits float)
     R32G32B32A32FLOAT,
+
+    /// An undecoded KTX2 file, as used by `KHR_texture_basisu`.
+    ///
+    /// The crate does not transcode Basis Universal, so the pixel data holds
+    /// the encoded file and the width and height are read from its header.
+    Ktx2,
 }
 
 /// Describes an image data source.
@@ -118,17 +124,144 @@ impl<'a> Image<'a> {
 
     /// Returns the image data source.
     pub fn source(&self) -> Source<'a> {
+        self.try_source().unwrap()
+    }
+
+    /// Returns the image data source.
+    ///
+    /// This returns `None` rather than panicking if the buffer view index is
+    /// out of range, or if the image has neither a buffer view with a MIME
+    /// type nor a URI, which can only happen for documents loaded without
+    /// validation.
+    pub fn try_source(&self) -> Option<Source<'a>> {
+        let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str());
         if let Some(index) = self.json.buffer_view.as_ref() {
-            let view = self.document.views().nth(index.value()).unwrap();
-            let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str()).unwrap();
-            Source::View { view, mime_type }
+            let view = self.document.views().nth(index.value())?;
+            Some(Source::View {
+                view,
+                mime_type: mime_type?,
+            })
         } else {
-            let uri = self.json.uri.as_ref().unwrap();
-            let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str());
-            Source::Uri { uri, mime_type }
+            let uri = self.json.uri.as_ref()?;
+            Some(Source::Uri { uri, mime_type })
         }
     }
 
+    /// Returns the encoded image data of an image stored in a buffer view,
+    /// such as an image embedded in a GLB file, given the loaded buffers of
+    /// the document.
+    ///
+    /// Returns `None` if the image is stored at a URI, or if the buffer view
+    /// lies outside of its buffer's data.
+    ///
+    /// ```
+    /// # #[cfg(feature = "import")]
+    /// # fn run() -> gltf::Result<()> {
+    /// let gltf = gltf::Gltf::from_slice(br#"{
+    ///     "asset": { "version": "2.0" },
+    ///     "buffers": [{ "byteLength": 6, "uri": "data:application/octet-stream;base64,AAECAwQF" }],
+    ///     "bufferViews": [{ "buffer": 0, "byteOffset": 2, "byteLength": 3 }],
+    ///     "images": [{ "bufferView": 0, "mimeType": "image/png" }, { "uri": "a.png" }]
+    /// }"#)?;
+    /// let buffers = gltf::import_buffers(&gltf, None, None)?;
+    /// let mut images = gltf.images();
+    /// assert_eq!(images.next().unwrap().view_data(&buffers), Some(&[2, 3, 4][..]));
+    /// assert_eq!(images.next().unwrap().view_data(&buffers), None);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(feature = "import")]
+    /// # run().unwrap();
+    /// ```
+    pub fn view_data<'b, B>(&self, buffer_data: &'b [B]) -> Option<&'b [u8]>
+    where
+        B: std::ops::Deref<Target = [u8]>,
+    {
+        match self.try_source()? {
+            Source::View { view, .. } => {
+                let data = buffer_data.get(view.buffer().index())?;
+                data.get(view.offset()..view.offset() + view.length())
+            }
+            Source::Uri { .. } => None,
+        }
+    }
+
+    /// Reads and decodes the image into pixel data.
+    ///
+    /// PNG and JPEG images are supported, plus WebP with the `EXT_texture_webp`
+    /// feature. KTX2 images are not decoded but returned as
+    /// [`Format::Ktx2`]. Images may be embedded in a buffer view or a data URI, or
+    /// stored externally. `buffer_data` holds the imported buffers of the
+    /// document and URIs are loaded with `resolver`.
+    ///
+    /// ```no_run
+    /// # fn run() -> gltf::Result<()> {
+    /// let gltf = gltf::Gltf::open("examples/Lantern.gltf")?;
+    /// let resolver = gltf::FileResolver::new("examples");
+    /// let buffers = gltf::import_buffers_with(&gltf, None, &resolver)?;
+    /// for image in gltf.images() {
+    ///     let data = image.decode(&buffers, &resolver)?;
+    ///     println!("{}x{} {:?}", data.width, data.height, data.format);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn decode<R, B>(&self, buffer_data: &[B], resolver: &R) -> Result<Data>
+    where
+        R: crate::Resolver + ?Sized,
+        B: std::ops::Deref<Target = [u8]>,
+    {
+        crate::import::decode_image_with(self.source(), buffer_data, resolver)
+    }
+
+    /// Reads the image into pixel data, passing the encoded data of KTX2
+    /// images to `transcode` and decoding all other images like
+    /// [`Image::decode`].
+    ///
+    /// The crate does not bind a Basis Universal transcoder, so `transcode`
+    /// supplies one, returning `None` for data it cannot transcode.
+    ///
+    /// ```
+    /// # fn run() -> gltf::Result<()> {
+    /// let gltf = gltf::Gltf::from_slice(br#"{
+    ///     "asset": { "version": "2.0" },
+    ///     "images": [{ "uri": "data:image/ktx2;base64,q0tUWA==" }]
+    /// }"#)?;
+    /// let image = gltf.images().next().unwrap();
+    /// let data = image.transcode(&[] as &[gltf::buffer::Data], &gltf::DataUriResolver, |ktx2| {
+    ///     assert_eq!(ktx2, b"\xabKTX");
+    ///     Some(gltf::image::Data {
+    ///         pixels: vec![255; 4],
+    ///         format: gltf::image::Format::R8G8B8A8,
+    ///         width: 1,
+    ///         height: 1,
+    ///     })
+    /// })?;
+    /// assert_eq!(data.width, 1);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    #[cfg(all(feature = "import", feature = "KHR_texture_basisu"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "import", feature = "KHR_texture_basisu")))
+    )]
+    pub fn transcode<R, B, F>(&self, buffer_data: &[B], resolver: &R, transcode: F) -> Result<Data>
+    where
+        R: crate::Resolver + ?Sized,
+        B: std::ops::Deref<Target = [u8]>,
+        F: FnOnce(&[u8]) -> Option<Data>,
+    {
+        crate::import::transcode_image_with(self.source(), buffer_data, resolver, transcode)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
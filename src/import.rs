@@ -1,11 +1,11 @@
 use crate::buffer;
 use crate::image;
 use std::borrow::Cow;
-use std::{fs, io};
+use std::{fs, io, ops};
 
 use crate::{Document, Error, Gltf, Result};
 use image_crate::ImageFormat::{Jpeg, Png};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
@@ -13,13 +13,13 @@ type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 /// Represents the set of URI schemes the importer supports.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Scheme<'a> {
-    /// `data:[<media type>];base64,<data>`.
-    Data(Option<&'a str>, &'a str),
+    /// `data:[<media type>][;base64],<data>`, holding the decoded data.
+    Data(Option<&'a str>, Vec<u8>),
 
     /// `file:[//]<absolute file path>`.
     ///
     /// Note: The file scheme does not implement authority.
-    File(&'a str),
+    File(Cow<'a, str>),
 
     /// `../foo`, etc.
     Relative(Cow<'a, str>),
@@ -29,39 +29,209 @@ enum Scheme<'a> {
 }
 
 impl<'a> Scheme<'a> {
-    fn parse(uri: &str) -> Scheme<'_> {
-        if uri.contains(':') {
+    fn parse(uri: &str) -> Result<Scheme<'_>> {
+        let decode = |path| urlencoding::decode(path).map_err(|_| invalid_uri());
+        Ok(if uri.contains(':') {
             if let Some(rest) = uri.strip_prefix("data:") {
-                let mut it = rest.split(";base64,");
-
-                match (it.next(), it.next()) {
-                    (match0_opt, Some(match1)) => Scheme::Data(match0_opt, match1),
-                    (Some(match0), _) => Scheme::Data(None, match0),
-                    _ => Scheme::Unsupported,
-                }
+                // Example: "uri" : "data:application/octet-stream;base64,wsVHPgA...."
+                let (media_type, data) = rest.split_once(',').ok_or_else(invalid_uri)?;
+                let (media_type, data) = match media_type.strip_suffix(";base64") {
+                    Some(media_type) => (media_type, base64::decode(data).map_err(Error::Base64)?),
+                    None => (
+                        media_type,
+                        urlencoding::decode_binary(data.as_bytes()).into_owned(),
+                    ),
+                };
+                Scheme::Data(Some(media_type).filter(|x| !x.is_empty()), data)
             } else if let Some(rest) = uri.strip_prefix("file://") {
-                Scheme::File(rest)
+                Scheme::File(decode(rest)?)
             } else if let Some(rest) = uri.strip_prefix("file:") {
-                Scheme::File(rest)
+                Scheme::File(decode(rest)?)
             } else {
                 Scheme::Unsupported
             }
         } else {
-            Scheme::Relative(urlencoding::decode(uri).unwrap())
-        }
+            Scheme::Relative(decode(uri)?)
+        })
     }
 
-    fn read(base: Option<&Path>, uri: &str) -> Result<Vec<u8>> {
-        match Scheme::parse(uri) {
+    fn read(self, base: Option<&Path>) -> Result<Vec<u8>> {
+        match self {
             // The path may be unused in the Scheme::Data case
-            // Example: "uri" : "data:application/octet-stream;base64,wsVHPgA...."
-            Scheme::Data(_, base64) => base64::decode(base64).map_err(Error::Base64),
-            Scheme::File(path) if base.is_some() => read_to_end(path),
+            Scheme::Data(_, data) => Ok(data),
+            Scheme::File(path) if base.is_some() => read_to_end(&*path),
             Scheme::Relative(path) if base.is_some() => read_to_end(base.unwrap().join(&*path)),
             Scheme::Unsupported => Err(Error::UnsupportedScheme),
             _ => Err(Error::ExternalReferenceInSliceImport),
         }
     }
+
+    /// Memory-maps external files and reads all other sources.
+    ///
+    /// # Safety
+    ///
+    /// See [`import_buffers_mapped`].
+    #[cfg(feature = "mmap")]
+    unsafe fn map(self, base: Option<&Path>) -> Result<buffer::MappedData> {
+        let path = match self {
+            Scheme::File(path) if base.is_some() => path.into_owned().into(),
+            Scheme::Relative(path) if base.is_some() => base.unwrap().join(&*path),
+            scheme => return Ok(buffer::MappedData::Owned(buffer::Data(scheme.read(base)?))),
+        };
+        let file = fs::File::open(path).map_err(Error::Io)?;
+        let mmap = memmap2::Mmap::map(&file).map_err(Error::Io)?;
+        Ok(buffer::MappedData::Mapped(mmap))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a> Scheme<'a> {
+    async fn read_async(self, base: Option<&Path>) -> Result<Vec<u8>> {
+        match self {
+            Scheme::File(path) if base.is_some() => {
+                tokio::fs::read(&*path).await.map_err(Error::Io)
+            }
+            Scheme::Relative(path) if base.is_some() => {
+                let path = base.unwrap().join(&*path);
+                tokio::fs::read(path).await.map_err(Error::Io)
+            }
+            scheme => scheme.read(base),
+        }
+    }
+}
+
+/// Loads the data referenced by the URIs of a glTF document.
+///
+/// Implement this trait to import assets from sources other than the file
+/// system, such as archives, databases or the network. Closures of type
+/// `Fn(&str) -> Result<Vec<u8>>` implement it too.
+pub trait Resolver {
+    /// Returns the data referenced by `uri`, exactly as it appears in the
+    /// document.
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>>;
+}
+
+impl<F> Resolver for F
+where
+    F: Fn(&str) -> Result<Vec<u8>>,
+{
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        self(uri)
+    }
+}
+
+/// Resolves data URIs, rejecting all other URIs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DataUriResolver;
+
+impl Resolver for DataUriResolver {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        Scheme::parse(uri)?.read(None)
+    }
+}
+
+/// Resolves data URIs, `file:` URIs and paths relative to a base directory.
+#[derive(Clone, Debug)]
+pub struct FileResolver {
+    base: PathBuf,
+}
+
+impl FileResolver {
+    /// Constructs a `FileResolver` resolving relative paths from `base`.
+    pub fn new<P>(base: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { base: base.into() }
+    }
+}
+
+impl Resolver for FileResolver {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        Scheme::parse(uri)?.read(Some(&self.base))
+    }
+}
+
+/// Resolves `http:` and `https:` URIs with blocking requests, passing all
+/// other URIs to an inner resolver.
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+#[derive(Clone, Debug)]
+pub struct HttpResolver<R> {
+    agent: ureq::Agent,
+    inner: R,
+    max_size: u64,
+}
+
+#[cfg(feature = "http")]
+impl<R> HttpResolver<R> {
+    /// The default limit on the size of a response body, 256 MiB.
+    pub const DEFAULT_MAX_SIZE: u64 = 256 * 1024 * 1024;
+
+    /// The default timeout of a whole request, 30 seconds.
+    pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+    /// Constructs an `HttpResolver` with the default size limit and timeout.
+    pub fn new(inner: R) -> Self {
+        Self::with_limits(inner, Self::DEFAULT_MAX_SIZE, Self::DEFAULT_TIMEOUT)
+    }
+
+    /// Constructs an `HttpResolver` that rejects response bodies larger than
+    /// `max_size` bytes and requests taking longer than `timeout`.
+    pub fn with_limits(inner: R, max_size: u64, timeout: std::time::Duration) -> Self {
+        let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        Self {
+            agent,
+            inner,
+            max_size,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl<R> Resolver for HttpResolver<R>
+where
+    R: Resolver,
+{
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        use io::Read;
+        if !(uri.starts_with("http://") || uri.starts_with("https://")) {
+            return self.inner.resolve(uri);
+        }
+        let response = self
+            .agent
+            .get(uri)
+            .call()
+            .map_err(|error| Error::Io(io::Error::new(io::ErrorKind::Other, error)))?;
+        let length = response
+            .header("Content-Length")
+            .and_then(|length| length.parse::<u64>().ok());
+        if length.map_or(false, |length| length > self.max_size) {
+            return Err(response_too_large(self.max_size));
+        }
+        let mut data = Vec::new();
+        response
+            .into_reader()
+            .take(self.max_size + 1)
+            .read_to_end(&mut data)
+            .map_err(Error::Io)?;
+        if data.len() as u64 > self.max_size {
+            return Err(response_too_large(self.max_size));
+        }
+        Ok(data)
+    }
+}
+
+/// Returns the error for a malformed URI.
+fn invalid_uri() -> Error {
+    Error::Io(io::Error::new(io::ErrorKind::InvalidData, "malformed URI"))
+}
+
+/// Returns the error for an HTTP response body larger than `max_size` bytes.
+#[cfg(feature = "http")]
+fn response_too_large(max_size: u64) -> Error {
+    let message = format!("HTTP response exceeds {} bytes", max_size);
+    Error::Io(io::Error::new(io::ErrorKind::InvalidData, message))
 }
 
 fn read_to_end<P>(path: P) -> Result<Vec<u8>>
@@ -98,19 +268,73 @@ impl buffer::Data {
         base: Option<&Path>,
         blob: &mut Option<Vec<u8>>,
     ) -> Result<Self> {
-        let mut data = match source {
-            buffer::Source::Uri(uri) => Scheme::read(base, uri),
+        let data = match source {
+            buffer::Source::Uri(uri) => Scheme::parse(uri)?.read(base),
             buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
         }?;
+        Ok(buffer::Data::padded(data))
+    }
+
+    /// Pads the data with zeros to a multiple of four bytes.
+    fn padded(mut data: Vec<u8>) -> Self {
         while data.len() % 4 != 0 {
             data.push(0);
         }
-        Ok(buffer::Data(data))
+        buffer::Data(data)
     }
 }
 
+/// Checks that the data loaded for a buffer is at least as long as the buffer.
+fn check_buffer_length(buffer: &buffer::Buffer<'_>, actual: usize) -> Result<()> {
+    if actual < buffer.length() {
+        return Err(Error::BufferLength {
+            buffer: buffer.index(),
+            expected: buffer.length(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Returns whether a buffer only exists for compressed buffer views to
+/// decompress into, which has nothing to load.
+#[cfg(feature = "EXT_meshopt_compression")]
+fn is_fallback(buffer: &buffer::Buffer<'_>) -> bool {
+    buffer.is_meshopt_fallback()
+}
+
+#[cfg(not(feature = "EXT_meshopt_compression"))]
+fn is_fallback(_buffer: &buffer::Buffer<'_>) -> bool {
+    false
+}
+
+/// Returns zeroed data for a fallback buffer.
+fn fallback_data(buffer: &buffer::Buffer<'_>) -> Option<Vec<u8>> {
+    if is_fallback(buffer) {
+        Some(vec![0; buffer.length()])
+    } else {
+        None
+    }
+}
+
+/// Decompresses the buffer views compressed with `EXT_meshopt_compression`
+/// into their fallback buffers.
+#[cfg(feature = "EXT_meshopt_compression")]
+fn decompress(document: &Document, buffers: &mut [buffer::Data]) -> Result<()> {
+    crate::meshopt::decompress(document, buffers, |data| Some(&mut data.0))
+}
+
+#[cfg(not(feature = "EXT_meshopt_compression"))]
+fn decompress(_document: &Document, _buffers: &mut [buffer::Data]) -> Result<()> {
+    Ok(())
+}
+
 /// Import buffer data referenced by a glTF document.
 ///
+/// Buffer views compressed with `EXT_meshopt_compression` are decompressed
+/// into their fallback buffers when the extension is enabled, such that
+/// accessors can be read as usual.
+///
 /// ### Note
 ///
 /// This function is intended for advanced users who wish to forego loading image data.
@@ -118,20 +342,78 @@ impl buffer::Data {
 pub fn import_buffers(
     document: &Document,
     base: Option<&Path>,
-    mut blob: Option<Vec<u8>>,
+    blob: Option<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
+    match base {
+        Some(base) => import_buffers_with(document, blob, &FileResolver::new(base)),
+        None => import_buffers_with(document, blob, &DataUriResolver),
+    }
+}
+
+/// Import buffer data referenced by a glTF document, memory-mapping external
+/// files instead of reading them into memory.
+///
+/// Data URIs and the `BIN` section of binary glTF are still read into memory.
+/// The returned buffers dereference to `&[u8]`, so they can be used with
+/// [`import_images`] and the accessor readers just like [`buffer::Data`].
+///
+/// # Safety
+///
+/// The mapped files must not be modified or truncated, by this or any other
+/// process, while the returned buffers are alive.
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+pub unsafe fn import_buffers_mapped(
+    document: &Document,
+    base: Option<&Path>,
+    mut blob: Option<Vec<u8>>,
+) -> Result<Vec<buffer::MappedData>> {
     let mut buffers = Vec::new();
     for buffer in document.buffers() {
-        let data = buffer::Data::from_source_and_blob(buffer.source(), base, &mut blob)?;
-        if data.len() < buffer.length() {
-            return Err(Error::BufferLength {
-                buffer: buffer.index(),
-                expected: buffer.length(),
-                actual: data.len(),
-            });
-        }
+        let data = match (fallback_data(&buffer), buffer.source()) {
+            (Some(data), _) => buffer::MappedData::Owned(buffer::Data::padded(data)),
+            (None, buffer::Source::Uri(uri)) => Scheme::parse(uri)?.map(base)?,
+            (None, buffer::Source::Bin) => {
+                let data = blob.take().ok_or(Error::MissingBlob)?;
+                buffer::MappedData::Owned(buffer::Data(data))
+            }
+        };
+        check_buffer_length(&buffer, data.len())?;
         buffers.push(data);
     }
+    // Fallback buffers are always read into memory.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    crate::meshopt::decompress(document, &mut buffers, |data| match data {
+        buffer::MappedData::Owned(data) => Some(&mut data.0),
+        buffer::MappedData::Mapped(_) => None,
+    })?;
+    Ok(buffers)
+}
+
+/// Import buffer data referenced by a glTF document, loading URIs with the
+/// given resolver.
+///
+/// `blob` represents the `BIN` section of a binary glTF file.
+pub fn import_buffers_with<R>(
+    document: &Document,
+    mut blob: Option<Vec<u8>>,
+    resolver: &R,
+) -> Result<Vec<buffer::Data>>
+where
+    R: Resolver + ?Sized,
+{
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        let data = match (fallback_data(&buffer), buffer.source()) {
+            (Some(data), _) => data,
+            (None, buffer::Source::Uri(uri)) => resolver.resolve(uri)?,
+            (None, buffer::Source::Bin) => blob.take().ok_or(Error::MissingBlob)?,
+        };
+        let data = buffer::Data::padded(data);
+        check_buffer_length(&buffer, data.len())?;
+        buffers.push(data);
+    }
+    decompress(document, &mut buffers)?;
     Ok(buffers)
 }
 
@@ -139,75 +421,83 @@ impl image::Data {
     /// Construct an image data object by reading the given source.
     /// If `base` is provided, then external filesystem references will
     /// be resolved from this directory.
-    pub fn from_source(
+    pub fn from_source<B>(
         source: image::Source<'_>,
         base: Option<&Path>,
-        buffer_data: &[buffer::Data],
-    ) -> Result<Self> {
-        #[cfg(feature = "guess_mime_type")]
-        let guess_format = |encoded_image: &[u8]| match image_crate::guess_format(encoded_image) {
-            Ok(image_crate::ImageFormat::Png) => Some(Png),
-            Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
-            _ => None,
-        };
-        #[cfg(not(feature = "guess_mime_type"))]
-        let guess_format = |_encoded_image: &[u8]| None;
-        let decoded_image = match source {
-            image::Source::Uri { uri, mime_type } if base.is_some() => match Scheme::parse(uri) {
-                Scheme::Data(Some(annoying_case), base64) => {
-                    let encoded_image = base64::decode(base64).map_err(Error::Base64)?;
-                    let encoded_format = match annoying_case {
-                        "image/png" => Png,
-                        "image/jpeg" => Jpeg,
-                        _ => match guess_format(&encoded_image) {
-                            Some(format) => format,
-                            None => return Err(Error::UnsupportedImageEncoding),
-                        },
-                    };
-
-                    image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?
-                }
-                Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                _ => {
-                    let encoded_image = Scheme::read(base, uri)?;
-                    let encoded_format = match mime_type {
-                        Some("image/png") => Png,
-                        Some("image/jpeg") => Jpeg,
-                        Some(_) => match guess_format(&encoded_image) {
-                            Some(format) => format,
-                            None => return Err(Error::UnsupportedImageEncoding),
-                        },
-                        None => match uri.rsplit('.').next() {
-                            Some("png") => Png,
-                            Some("jpg") | Some("jpeg") => Jpeg,
-                            _ => match guess_format(&encoded_image) {
-                                Some(format) => format,
-                                None => return Err(Error::UnsupportedImageEncoding),
-                            },
-                        },
-                    };
-                    image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?
-                }
-            },
-            image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-                let begin = view.offset();
-                let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
-                let encoded_format = match mime_type {
-                    "image/png" => Png,
-                    "image/jpeg" => Jpeg,
-                    _ => match guess_format(encoded_image) {
-                        Some(format) => format,
-                        None => return Err(Error::UnsupportedImageEncoding),
-                    },
-                };
-                image_crate::load_from_memory_with_format(encoded_image, encoded_format)?
-            }
-            _ => return Err(Error::ExternalReferenceInSliceImport),
-        };
+        buffer_data: &[B],
+    ) -> Result<Self>
+    where
+        B: ops::Deref<Target = [u8]>,
+    {
+        match base {
+            Some(base) => decode_image_with(source, buffer_data, &FileResolver::new(base)),
+            None => decode_image_with(source, buffer_data, &DataUriResolver),
+        }
+    }
+}
+
+/// Returns the bytes of a buffer view holding an encoded image.
+fn view_data<'a, B>(view: &buffer::View<'_>, buffer_data: &'a [B]) -> Cow<'a, [u8]>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let parent_buffer_data = &buffer_data[view.buffer().index()];
+    let begin = view.offset();
+    let end = begin + view.length();
+    Cow::Borrowed(&parent_buffer_data[begin..end])
+}
 
-        image::Data::new(decoded_image)
+/// Decodes an image, determining its format from the MIME type, then the
+/// extension of its URI, then its contents.
+fn decode_image(
+    encoded_image: &[u8],
+    mime_type: Option<&str>,
+    uri: Option<&str>,
+) -> Result<image::Data> {
+    #[cfg(feature = "guess_mime_type")]
+    let guess_format = |encoded_image: &[u8]| match image_crate::guess_format(encoded_image) {
+        Ok(image_crate::ImageFormat::Png) => Some(Png),
+        Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
+        #[cfg(feature = "EXT_texture_webp")]
+        Ok(image_crate::ImageFormat::WebP) => Some(image_crate::ImageFormat::WebP),
+        _ => None,
+    };
+    #[cfg(not(feature = "guess_mime_type"))]
+    let guess_format = |_encoded_image: &[u8]| None;
+    let extension = uri.and_then(|uri| uri.rsplit('.').next());
+    let encoded_format = match (mime_type, extension) {
+        (Some("image/ktx2"), _) | (None, Some("ktx2")) => return ktx2_data(encoded_image),
+        (Some("image/png"), _) => Png,
+        (Some("image/jpeg"), _) => Jpeg,
+        (None, Some("png")) => Png,
+        (None, Some("jpg")) | (None, Some("jpeg")) => Jpeg,
+        #[cfg(feature = "EXT_texture_webp")]
+        (Some("image/webp"), _) | (None, Some("webp")) => image_crate::ImageFormat::WebP,
+        _ => match guess_format(encoded_image) {
+            Some(format) => format,
+            None => return Err(Error::UnsupportedImageEncoding),
+        },
+    };
+    let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
+    image::Data::new(decoded_image)
+}
+
+/// Passes a KTX2 image through undecoded, reading its dimensions from the
+/// header.
+fn ktx2_data(encoded_image: &[u8]) -> Result<image::Data> {
+    const IDENTIFIER: &[u8] = b"\xabKTX 20\xbb\r\n\x1a\n";
+    let dimension = |offset: usize| {
+        let bytes = encoded_image.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    };
+    match (dimension(20), dimension(24)) {
+        (Some(width), Some(height)) if encoded_image.starts_with(IDENTIFIER) => Ok(image::Data {
+            pixels: encoded_image.to_vec(),
+            format: image::Format::Ktx2,
+            width,
+            height,
+        }),
+        _ => Err(Error::UnsupportedImageEncoding),
     }
 }
 
@@ -217,18 +507,195 @@ impl image::Data {
 ///
 /// This function is intended for advanced users who wish to forego loading buffer data.
 /// A typical user should call [`import`] instead.
-pub fn import_images(
+pub fn import_images<B>(
+    document: &Document,
+    base: Option<&Path>,
+    buffer_data: &[B],
+) -> Result<Vec<image::Data>>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    match base {
+        Some(base) => import_images_with(document, buffer_data, &FileResolver::new(base)),
+        None => import_images_with(document, buffer_data, &DataUriResolver),
+    }
+}
+
+/// Asynchronously import buffer data referenced by a glTF document.
+///
+/// External files are read with `tokio::fs`. See [`import_buffers`].
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub async fn import_buffers_async(
     document: &Document,
     base: Option<&Path>,
-    buffer_data: &[buffer::Data],
-) -> Result<Vec<image::Data>> {
+    blob: Option<Vec<u8>>,
+) -> Result<Vec<buffer::Data>> {
+    let mut fetched = Vec::new();
+    for buffer in document.buffers() {
+        if let (false, buffer::Source::Uri(uri)) = (is_fallback(&buffer), buffer.source()) {
+            fetched.push(Scheme::parse(uri)?.read_async(base).await?);
+        }
+    }
+    import_buffers_with(document, blob, &Prefetched::new(fetched))
+}
+
+/// Asynchronously import image data referenced by a glTF document.
+///
+/// External files are read with `tokio::fs`; decoding happens on the calling
+/// task. See [`import_images`].
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub async fn import_images_async<B>(
+    document: &Document,
+    base: Option<&Path>,
+    buffer_data: &[B],
+) -> Result<Vec<image::Data>>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let mut fetched = Vec::new();
+    for image in document.images() {
+        if let image::Source::Uri { uri, .. } = image.source() {
+            fetched.push(Scheme::parse(uri)?.read_async(base).await?);
+        }
+    }
+    import_images_with(document, buffer_data, &Prefetched::new(fetched))
+}
+
+/// Hands out data read ahead of time by the asynchronous importers, in the
+/// order the synchronous importers request it.
+#[cfg(feature = "async")]
+struct Prefetched(std::cell::RefCell<std::vec::IntoIter<Vec<u8>>>);
+
+#[cfg(feature = "async")]
+impl Prefetched {
+    fn new(data: Vec<Vec<u8>>) -> Self {
+        Prefetched(std::cell::RefCell::new(data.into_iter()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl Resolver for Prefetched {
+    fn resolve(&self, _uri: &str) -> Result<Vec<u8>> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .next()
+            .expect("data prefetched for every URI"))
+    }
+}
+
+/// Asynchronously import glTF 2.0 from the file system.
+///
+/// This is the asynchronous counterpart of [`import`], reading the document
+/// and its external resources with `tokio::fs` so that the calling executor
+/// is not blocked on file I/O.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub async fn import_async<P>(path: P) -> Result<Import>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| Path::new("./"));
+    let file = tokio::fs::File::open(path).await.map_err(Error::Io)?;
+    let Gltf { document, blob } = Gltf::from_reader_async(file).await?;
+    let buffer_data = import_buffers_async(&document, Some(base), blob).await?;
+    let image_data = import_images_async(&document, Some(base), &buffer_data).await?;
+    Ok((document, buffer_data, image_data))
+}
+
+/// Import image data referenced by a glTF document, loading URIs with the
+/// given resolver.
+pub fn import_images_with<R, B>(
+    document: &Document,
+    buffer_data: &[B],
+    resolver: &R,
+) -> Result<Vec<image::Data>>
+where
+    R: Resolver + ?Sized,
+    B: ops::Deref<Target = [u8]>,
+{
     let mut images = Vec::new();
     for image in document.images() {
-        images.push(image::Data::from_source(image.source(), base, buffer_data)?);
+        images.push(image.decode(buffer_data, resolver)?);
     }
     Ok(images)
 }
 
+/// Reads and decodes an image, loading URIs with the given resolver.
+pub(crate) fn decode_image_with<R, B>(
+    source: image::Source<'_>,
+    buffer_data: &[B],
+    resolver: &R,
+) -> Result<image::Data>
+where
+    R: Resolver + ?Sized,
+    B: ops::Deref<Target = [u8]>,
+{
+    let (encoded_image, mime_type, uri) = encoded_image_with(source, buffer_data, resolver)?;
+    decode_image(&encoded_image, mime_type, uri)
+}
+
+/// Reads an image, passing KTX2 images to `transcode` and decoding all
+/// others.
+#[cfg(feature = "KHR_texture_basisu")]
+pub(crate) fn transcode_image_with<R, B, F>(
+    source: image::Source<'_>,
+    buffer_data: &[B],
+    resolver: &R,
+    transcode: F,
+) -> Result<image::Data>
+where
+    R: Resolver + ?Sized,
+    B: ops::Deref<Target = [u8]>,
+    F: FnOnce(&[u8]) -> Option<image::Data>,
+{
+    let (encoded_image, mime_type, uri) = encoded_image_with(source, buffer_data, resolver)?;
+    let extension = uri.and_then(|uri| uri.rsplit('.').next());
+    if mime_type == Some("image/ktx2") || (mime_type.is_none() && extension == Some("ktx2")) {
+        transcode(&encoded_image).ok_or(Error::UnsupportedImageEncoding)
+    } else {
+        decode_image(&encoded_image, mime_type, uri)
+    }
+}
+
+/// Encoded image data with its MIME type and URI.
+type EncodedImage<'a, 'b> = (Cow<'b, [u8]>, Option<&'a str>, Option<&'a str>);
+
+/// Reads the encoded data of an image with its MIME type, and its URI if the
+/// format may be told by the file extension.
+fn encoded_image_with<'a, 'b, R, B>(
+    source: image::Source<'a>,
+    buffer_data: &'b [B],
+    resolver: &R,
+) -> Result<EncodedImage<'a, 'b>>
+where
+    R: Resolver + ?Sized,
+    B: ops::Deref<Target = [u8]>,
+{
+    Ok(match source {
+        image::Source::Uri { uri, mime_type } => {
+            let encoded_image = Cow::Owned(resolver.resolve(uri)?);
+            match data_uri_media_type(uri) {
+                Some(media_type) => (encoded_image, Some(media_type), None),
+                None => (encoded_image, mime_type, Some(uri)),
+            }
+        }
+        image::Source::View { view, mime_type } => {
+            (view_data(&view, buffer_data), Some(mime_type), None)
+        }
+    })
+}
+
+/// Returns the media type of a data URI, if it has one.
+fn data_uri_media_type(uri: &str) -> Option<&str> {
+    let (media_type, _) = uri.strip_prefix("data:")?.split_once(',')?;
+    let media_type = media_type.strip_suffix(";base64").unwrap_or(media_type);
+    Some(media_type).filter(|x| !x.is_empty())
+}
+
 fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
     let buffer_data = import_buffers(&document, base, blob)?;
     let image_data = import_images(&document, base, &buffer_data)?;
@@ -245,6 +712,12 @@ fn import_path(path: &Path) -> Result<Import> {
 
 /// Import glTF 2.0 from the file system.
 ///
+/// Parses standard or binary glTF and loads every buffer and image it
+/// references, whether stored in an external file relative to `path`, a data
+/// URI, or the binary chunk of a GLB file. The buffer and image data are
+/// returned in document order, and the underlying JSON is available through
+/// [`Document::into_json`].
+///
 /// ```
 /// # fn run() -> Result<(), gltf::Error> {
 /// # let path = "examples/Box.gltf";
@@ -314,3 +787,300 @@ where
 {
     import_slice_impl(slice.as_ref())
 }
+
+/// Import glTF 2.0 from a reader.
+///
+/// The whole input is read into memory first, so the reader need not
+/// support seeking. Standard and binary glTF are told apart by their
+/// contents. File paths in the document are assumed to be relative to the
+/// current working directory.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// # let file = std::fs::File::open("examples/Box.glb").map_err(gltf::Error::Io)?;
+/// # #[allow(unused)]
+/// let (document, buffers, images) = gltf::import_reader(file)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_reader<R>(mut reader: R) -> Result<Import>
+where
+    R: io::Read,
+{
+    let mut slice = Vec::new();
+    reader.read_to_end(&mut slice).map_err(Error::Io)?;
+    import_slice_impl(&slice)
+}
+
+/// Import glTF 2.0 from a slice, loading the resources it references with
+/// the given resolver.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// # let bytes = std::fs::read("examples/Box.gltf").map_err(gltf::Error::Io)?;
+/// let resolver = gltf::FileResolver::new("examples");
+/// # #[allow(unused)]
+/// let (document, buffers, images) = gltf::import_slice_with(&bytes, &resolver)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_slice_with<S, R>(slice: S, resolver: &R) -> Result<Import>
+where
+    S: AsRef<[u8]>,
+    R: Resolver + ?Sized,
+{
+    let Gltf { document, blob } = Gltf::from_slice(slice.as_ref())?;
+    let buffer_data = import_buffers_with(&document, blob, resolver)?;
+    let image_data = import_images_with(&document, &buffer_data, resolver)?;
+    Ok((document, buffer_data, image_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_reader_skips_byte_order_mark() {
+        let json = b"\xEF\xBB\xBF{\"asset\": {\"version\": \"2.0\"}, \"nodes\": [{}]}";
+        let (document, buffers, images) = import_reader(&json[..]).unwrap();
+        assert_eq!(document.nodes().len(), 1);
+        assert!(buffers.is_empty() && images.is_empty());
+    }
+
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[test]
+    fn import_decompresses_meshopt_views() {
+        // Four vertices with 16 bit x and y coordinates of 0 or 300, padded to
+        // eight bytes, followed by six indices.
+        let mut data = vec![
+            0xa0, 0x01, 0x3f, 0x00, 0x00, 0x00, 0x58, 0x57, 0x58, 0x01, 0x26, 0x00, 0x00, 0x00,
+            0x01, 0x0c, 0x00, 0x00, 0x00, 0x58, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00,
+        ];
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&[
+            0xd1, 0x00, 0x04, 0x04, 0x04, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        let json = format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "extensionsUsed": ["EXT_meshopt_compression"],
+                "extensionsRequired": ["EXT_meshopt_compression"],
+                "buffers": [
+                    {{"byteLength": {}, "uri": "data:;base64,{}"}},
+                    {{"byteLength": 44, "extensions": {{"EXT_meshopt_compression": {{"fallback": true}}}}}}
+                ],
+                "bufferViews": [
+                    {{
+                        "buffer": 1, "byteLength": 32, "byteStride": 8,
+                        "extensions": {{"EXT_meshopt_compression": {{
+                            "buffer": 0, "byteLength": 61, "byteStride": 8, "count": 4,
+                            "mode": "ATTRIBUTES"
+                        }}}}
+                    }},
+                    {{
+                        "buffer": 1, "byteOffset": 32, "byteLength": 12,
+                        "extensions": {{"EXT_meshopt_compression": {{
+                            "buffer": 0, "byteOffset": 61, "byteLength": 11, "byteStride": 2,
+                            "count": 6, "mode": "INDICES"
+                        }}}}
+                    }}
+                ],
+                "accessors": [
+                    {{"bufferView": 0, "count": 4, "componentType": 5123, "type": "VEC2"}},
+                    {{"bufferView": 1, "count": 6, "componentType": 5123, "type": "SCALAR"}}
+                ]
+            }}"#,
+            data.len(),
+            base64::encode(&data),
+        );
+        let (document, buffers, _) = import_slice(json.as_bytes()).unwrap();
+        let get_buffer_data = |buffer: crate::Buffer| Some(&buffers[buffer.index()][..]);
+        let mut accessors = document.accessors();
+        let coordinates =
+            crate::accessor::Iter::<[u16; 2]>::new(accessors.next().unwrap(), get_buffer_data);
+        let coordinates: Vec<_> = coordinates.unwrap().collect();
+        assert_eq!(coordinates, [[0, 0], [300, 0], [0, 300], [300, 300]]);
+        let indices = crate::accessor::Iter::<u16>::new(accessors.next().unwrap(), get_buffer_data);
+        let indices: Vec<_> = indices.unwrap().collect();
+        assert_eq!(indices, [0, 1, 2, 3, 2, 1]);
+
+        let malformed = json.replace("\"INDICES\"", "\"TRIANGLES\"");
+        let error = import_slice(malformed.as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "buffer view 1: malformed EXT_meshopt_compression data"
+        );
+
+        // Oversized views are rejected before decoding allocates for them,
+        // with or without validation.
+        let oversized = json.replace("\"count\": 4,", "\"count\": 4000000000,");
+        assert!(matches!(
+            import_slice(oversized.as_bytes()),
+            Err(Error::Validation(_))
+        ));
+        let Gltf { document, blob } =
+            Gltf::from_slice_without_validation(oversized.as_bytes()).unwrap();
+        let error = import_buffers(&document, None, blob).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "buffer view 0: malformed EXT_meshopt_compression data"
+        );
+    }
+
+    #[test]
+    fn parse_file_uris() {
+        assert_eq!(
+            Scheme::parse("file:///models/My%20Box.bin").unwrap(),
+            Scheme::File("/models/My Box.bin".into())
+        );
+        assert_eq!(
+            Scheme::parse("file:Box0.bin").unwrap(),
+            Scheme::File("Box0.bin".into())
+        );
+    }
+
+    #[test]
+    fn parse_data_uris() {
+        assert_eq!(
+            Scheme::parse("data:application/octet-stream;base64,AAEC").unwrap(),
+            Scheme::Data(Some("application/octet-stream"), vec![0, 1, 2])
+        );
+        assert_eq!(
+            Scheme::parse("data:;base64,AAEC").unwrap(),
+            Scheme::Data(None, vec![0, 1, 2])
+        );
+        assert_eq!(
+            Scheme::parse("data:,%00%01%02").unwrap(),
+            Scheme::Data(None, vec![0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn parse_malformed_uris() {
+        assert!(matches!(
+            Scheme::parse("data:application/octet-stream;base64"),
+            Err(Error::Io(error)) if error.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            Scheme::parse("data:application/octet-stream;base64,A%B"),
+            Err(Error::Base64(_))
+        ));
+        assert!(matches!(
+            Scheme::parse("Box%FF.bin"),
+            Err(Error::Io(error)) if error.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn import_buffers_relative_to_base() {
+        let gltf = Gltf::open("examples/Box.gltf").unwrap();
+        let buffers = import_buffers(&gltf, Some(Path::new("examples")), None).unwrap();
+        assert_eq!(buffers.len(), 1);
+        assert!(buffers[0].len() >= gltf.buffers().next().unwrap().length());
+
+        let result = import_buffers(&gltf, Some(Path::new("tests")), None);
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn import_buffers_with_resolvers() {
+        let gltf = Gltf::open("examples/Box.gltf").unwrap();
+        let resolver = FileResolver::new("examples");
+        let buffers = import_buffers_with(&gltf, None, &resolver).unwrap();
+        let expected = import_buffers(&gltf, Some(Path::new("examples")), None).unwrap();
+        assert_eq!(buffers[0].0, expected[0].0);
+
+        let result = import_buffers_with(&gltf, None, &DataUriResolver);
+        assert!(matches!(result, Err(Error::ExternalReferenceInSliceImport)));
+
+        let resolver = |uri: &str| -> Result<Vec<u8>> {
+            assert_eq!(uri, "Box0.bin");
+            Ok(vec![0; 648])
+        };
+        let buffers = import_buffers_with(&gltf, None, &resolver).unwrap();
+        assert_eq!(buffers[0].len(), 648);
+    }
+
+    #[test]
+    fn data_uri_media_types() {
+        assert_eq!(
+            data_uri_media_type("data:image/png;base64,AA"),
+            Some("image/png")
+        );
+        assert_eq!(data_uri_media_type("data:image/png,%00"), Some("image/png"));
+        assert_eq!(data_uri_media_type("data:;base64,AA"), None);
+        assert_eq!(data_uri_media_type("image.png"), None);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn http_resolver() {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).unwrap();
+                let response =
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\nglTF";
+                stream.write_all(response).unwrap();
+            }
+        });
+        let uri = format!("http://{}/Box0.bin", address);
+
+        let resolver = HttpResolver::new(DataUriResolver);
+        assert_eq!(resolver.resolve(&uri).unwrap(), b"glTF");
+        assert_eq!(resolver.resolve("data:,%01").unwrap(), [1]);
+
+        let resolver =
+            HttpResolver::with_limits(DataUriResolver, 2, std::time::Duration::from_secs(5));
+        let error = resolver.resolve(&uri).unwrap_err();
+        assert_eq!(error.to_string(), "HTTP response exceeds 2 bytes");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn decode_data_uri_image() {
+        let mut png = Vec::new();
+        let pixels = image_crate::RgbaImage::from_raw(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 128]);
+        image_crate::DynamicImage::ImageRgba8(pixels.unwrap())
+            .write_to(&mut io::Cursor::new(&mut png), Png)
+            .unwrap();
+        let json = format!(
+            r#"{{"asset": {{"version": "2.0"}}, "images": [{{"uri": "data:image/png;base64,{}"}}]}}"#,
+            base64::encode(&png)
+        );
+        let gltf = Gltf::from_slice(json.as_bytes()).unwrap();
+        let image = gltf.images().next().unwrap();
+        let data = image
+            .decode::<_, buffer::Data>(&[], &DataUriResolver)
+            .unwrap();
+        assert_eq!((data.width, data.height), (2, 1));
+        assert_eq!(data.format, image::Format::R8G8B8A8);
+        assert_eq!(data.pixels, [255, 0, 0, 255, 0, 0, 255, 128]);
+    }
+
+    #[cfg(feature = "EXT_texture_webp")]
+    #[test]
+    fn decode_webp_image() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "images": [{"uri": "data:image/webp;base64,UklGRhoAAABXRUJQVlA4TA0AAAAvAAAAEAcQERGIiP4HAA=="}]
+        }"#;
+        let gltf = Gltf::from_slice(json.as_bytes()).unwrap();
+        let image = gltf.images().next().unwrap();
+        let data = image
+            .decode::<_, buffer::Data>(&[], &DataUriResolver)
+            .unwrap();
+        assert_eq!((data.width, data.height), (1, 1));
+    }
+}
@@ -105,6 +105,16 @@ pub mod buffer;
 /// Cameras and their projections.
 pub mod camera;
 
+/// Conversion from and to other 3D model formats.
+#[cfg(any(feature = "obj", feature = "stl"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "obj", feature = "stl"))))]
+pub mod convert;
+
+/// Passes that modify a document together with its buffer data.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub mod edit;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -116,6 +126,11 @@ mod import;
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+/// Support for the `EXT_mesh_gpu_instancing` extension.
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+pub mod ext_mesh_gpu_instancing;
+
 /// Support for the `KHR_lights_punctual` extension.
 #[cfg(feature = "KHR_lights_punctual")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -132,6 +147,10 @@ pub mod material;
 /// For internal use.
 mod math;
 
+/// Decoders of `EXT_meshopt_compression` compressed buffer views.
+#[cfg(all(feature = "import", feature = "EXT_meshopt_compression"))]
+mod meshopt;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
@@ -162,12 +181,30 @@ pub use self::import::import;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_buffers;
+#[cfg(feature = "mmap")]
+#[doc(inline)]
+pub use self::import::import_buffers_mapped;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_images;
 #[cfg(feature = "import")]
 #[doc(inline)]
+pub use self::import::import_reader;
+#[cfg(feature = "import")]
+#[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "http")]
+#[doc(inline)]
+pub use self::import::HttpResolver;
+#[cfg(feature = "async")]
+#[doc(inline)]
+pub use self::import::{import_async, import_buffers_async, import_images_async};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{
+    import_buffers_with, import_images_with, import_slice_with, DataUriResolver, FileResolver,
+    Resolver,
+};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
@@ -182,7 +219,32 @@ pub use self::texture::Texture;
 use std::path::Path;
 use std::{fs, io, ops, result};
 
-pub(crate) trait Normalize<T> {
+/// Conversion between the normalized integer and floating point
+/// representations of accessor data.
+///
+/// Integers read from an accessor with the `normalized` flag set stand for
+/// values in `[0, 1]` if unsigned and `[-1, 1]` if signed, following the
+/// rules of the glTF specification: `f = max(c / 127.0, -1.0)` for `i8`,
+/// `f = c / 255.0` for `u8` and likewise for the 16-bit types. Converting an
+/// `f32` back rounds to the nearest integer, `c = round(f * 127.0)` for
+/// `i8`, clamping values outside the representable range. Conversions
+/// between integer types rescale from one range to the other.
+///
+/// ```
+/// use gltf::Normalize;
+///
+/// let color: [f32; 3] = [255u8, 0, 255].normalize();
+/// assert_eq!(color, [1.0, 0.0, 1.0]);
+/// let weight: f32 = (-128i8).normalize();
+/// assert_eq!(weight, -1.0);
+///
+/// let color: [u8; 3] = [1.0f32, 0.0, 0.2].normalize();
+/// assert_eq!(color, [255, 0, 51]);
+/// let coord: u16 = 0.5f32.normalize();
+/// assert_eq!(coord, 32768);
+/// ```
+pub trait Normalize<T> {
+    /// Converts `self` to the representation `T`.
     fn normalize(self) -> T;
 }
 
@@ -218,6 +280,12 @@ pub enum Error {
     Deserialize(json::Error),
 
     /// Standard I/O error.
+    ///
+    /// Malformed URIs, malformed `EXT_meshopt_compression` data, glTF 1.0
+    /// assets that cannot be converted and oversized HTTP responses are
+    /// reported as errors of kind `InvalidData`, and failed HTTP requests as
+    /// errors of kind `Other`. Their underlying error, if any, is available
+    /// through `std::io::Error::get_ref`.
     Io(std::io::Error),
 
     /// Image decoding error.
@@ -268,6 +336,73 @@ pub struct Gltf {
 #[derive(Clone, Debug)]
 pub struct Document(json::Root);
 
+/// Returns `slice` without a leading UTF-8 byte order mark, which the glTF
+/// specification forbids but tolerant readers may ignore.
+fn strip_bom(slice: &[u8]) -> &[u8] {
+    slice.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(slice)
+}
+
+/// Deserializes glTF JSON, converting glTF 1.0 assets to glTF 2.0.
+#[cfg(feature = "v1")]
+fn deserialize_json(slice: &[u8]) -> Result<json::Root> {
+    let slice = strip_bom(slice);
+    if json::detect_version(slice) == Some(json::Version::V1) {
+        let root = json::v1::Root::from_slice(slice)?;
+        json::convert::v1_to_v2(&root)
+            .map_err(|error| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, error)))
+    } else {
+        Ok(json::deserialize::from_slice(slice)?)
+    }
+}
+
+/// Deserializes glTF JSON.
+#[cfg(not(feature = "v1"))]
+fn deserialize_json(slice: &[u8]) -> Result<json::Root> {
+    let slice = strip_bom(slice);
+    Ok(json::deserialize::from_slice(slice)?)
+}
+
+/// Deserializes glTF JSON from a reader, converting glTF 1.0 assets to glTF 2.0.
+#[cfg(feature = "v1")]
+fn deserialize_json_from_reader<R: io::Read>(mut reader: R) -> Result<json::Root> {
+    let mut slice = Vec::new();
+    reader.read_to_end(&mut slice)?;
+    deserialize_json(&slice)
+}
+
+/// Deserializes glTF JSON from a reader.
+#[cfg(not(feature = "v1"))]
+fn deserialize_json_from_reader<R: io::Read>(reader: R) -> Result<json::Root> {
+    Ok(json::deserialize::from_reader(reader)?)
+}
+
+impl Error {
+    /// Returns the path of the value being parsed when a JSON
+    /// deserialization error occurred, such as
+    /// `meshes[14].primitives[2].attributes`, given the JSON text that was
+    /// parsed.
+    ///
+    /// The path is found by scanning `json` up to the line and column of the
+    /// error, so that nothing is tracked while parsing succeeds. For binary
+    /// glTF, `json` is the JSON chunk. Returns `None` for other errors and
+    /// errors without a position, such as unexpected ends of input.
+    ///
+    /// ```
+    /// let json = br#"{"asset": {"version": "2.0"}, "meshes": [{"primitives": 0}]}"#;
+    /// let error = gltf::Gltf::from_slice(json).unwrap_err();
+    /// let path = error.json_path(json).unwrap();
+    /// assert_eq!(path.as_str(), "meshes[0].primitives");
+    /// ```
+    pub fn json_path(&self, json: &[u8]) -> Option<json::Path> {
+        match self {
+            Error::Deserialize(error) if error.line() > 0 => {
+                Some(json::Path::locate(json, error.line(), error.column()))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Gltf {
     /// Convenience function that loads glTF from the file system.
     pub fn open<P>(path: P) -> Result<Self>
@@ -287,15 +422,16 @@ impl Gltf {
     {
         let mut magic = [0u8; 4];
         reader.read_exact(&mut magic)?;
-        reader.seek(io::SeekFrom::Start(0))?;
+        // Skip a byte order mark, as `strip_bom` does for slices.
+        let start = magic.len() - strip_bom(&magic).len();
+        reader.seek(io::SeekFrom::Start(start as u64))?;
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if magic.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_reader(reader)?;
-            // TODO: use `json::from_reader` instead of `json::from_slice`
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = deserialize_json(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_reader(reader)?;
+            json = deserialize_json_from_reader(reader)?;
             blob = None;
         };
         let document = Document::from_json_without_validation(json);
@@ -312,16 +448,31 @@ impl Gltf {
         Ok(gltf)
     }
 
+    /// Asynchronously loads glTF from a reader.
+    ///
+    /// The whole input is read into memory before it is parsed.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn from_reader_async<R>(mut reader: R) -> Result<Self>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+        let mut slice = Vec::new();
+        reader.read_to_end(&mut slice).await?;
+        Self::from_slice(&slice)
+    }
+
     /// Loads glTF from a slice of bytes without performing validation
     /// checks.
     pub fn from_slice_without_validation(slice: &[u8]) -> Result<Self> {
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if slice.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_slice(slice)?;
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = deserialize_json(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_slice(slice)?;
+            json = deserialize_json(slice)?;
             blob = None;
         };
         let document = Document::from_json_without_validation(json);
@@ -384,7 +535,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
-    pub fn accessors(&self) -> iter::Accessors {
+    pub fn accessors(&self) -> iter::Accessors<'_> {
         iter::Accessors {
             iter: self.0.accessors.iter().enumerate(),
             document: self,
@@ -392,7 +543,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the animations of the glTF asset.
-    pub fn animations(&self) -> iter::Animations {
+    pub fn animations(&self) -> iter::Animations<'_> {
         iter::Animations {
             iter: self.0.animations.iter().enumerate(),
             document: self,
@@ -400,7 +551,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the pre-loaded buffers of the glTF asset.
-    pub fn buffers(&self) -> iter::Buffers {
+    pub fn buffers(&self) -> iter::Buffers<'_> {
         iter::Buffers {
             iter: self.0.buffers.iter().enumerate(),
             document: self,
@@ -408,7 +559,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the cameras of the glTF asset.
-    pub fn cameras(&self) -> iter::Cameras {
+    pub fn cameras(&self) -> iter::Cameras<'_> {
         iter::Cameras {
             iter: self.0.cameras.iter().enumerate(),
             document: self,
@@ -416,7 +567,7 @@ impl Document {
     }
 
     /// Returns the default scene, if provided.
-    pub fn default_scene(&self) -> Option<Scene> {
+    pub fn default_scene(&self) -> Option<Scene<'_>> {
         self.0
             .scene
             .as_ref()
@@ -424,17 +575,17 @@ impl Document {
     }
 
     /// Returns the extensions referenced in this .document file.
-    pub fn extensions_used(&self) -> iter::ExtensionsUsed {
+    pub fn extensions_used(&self) -> iter::ExtensionsUsed<'_> {
         iter::ExtensionsUsed(self.0.extensions_used.iter())
     }
 
     /// Returns the extensions required to load and render this asset.
-    pub fn extensions_required(&self) -> iter::ExtensionsRequired {
+    pub fn extensions_required(&self) -> iter::ExtensionsRequired<'_> {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
-    pub fn images(&self) -> iter::Images {
+    pub fn images(&self) -> iter::Images<'_> {
         iter::Images {
             iter: self.0.images.iter().enumerate(),
             document: self,
@@ -445,7 +596,7 @@ impl Document {
     /// `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
-    pub fn lights(&self) -> Option<iter::Lights> {
+    pub fn lights(&self) -> Option<iter::Lights<'_>> {
         let iter = self
             .0
             .extensions
@@ -466,7 +617,7 @@ impl Document {
     /// `KHR_materials_variants` extension.
     #[cfg(feature = "KHR_materials_variants")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
-    pub fn variants(&self) -> Option<iter::Variants> {
+    pub fn variants(&self) -> Option<iter::Variants<'_>> {
         let iter = self
             .0
             .extensions
@@ -484,7 +635,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the materials of the glTF asset.
-    pub fn materials(&self) -> iter::Materials {
+    pub fn materials(&self) -> iter::Materials<'_> {
         iter::Materials {
             iter: self.0.materials.iter().enumerate(),
             document: self,
@@ -492,7 +643,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the meshes of the glTF asset.
-    pub fn meshes(&self) -> iter::Meshes {
+    pub fn meshes(&self) -> iter::Meshes<'_> {
         iter::Meshes {
             iter: self.0.meshes.iter().enumerate(),
             document: self,
@@ -500,7 +651,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
-    pub fn nodes(&self) -> iter::Nodes {
+    pub fn nodes(&self) -> iter::Nodes<'_> {
         iter::Nodes {
             iter: self.0.nodes.iter().enumerate(),
             document: self,
@@ -508,7 +659,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
-    pub fn samplers(&self) -> iter::Samplers {
+    pub fn samplers(&self) -> iter::Samplers<'_> {
         iter::Samplers {
             iter: self.0.samplers.iter().enumerate(),
             document: self,
@@ -516,7 +667,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the scenes of the glTF asset.
-    pub fn scenes(&self) -> iter::Scenes {
+    pub fn scenes(&self) -> iter::Scenes<'_> {
         iter::Scenes {
             iter: self.0.scenes.iter().enumerate(),
             document: self,
@@ -524,7 +675,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the skins of the glTF asset.
-    pub fn skins(&self) -> iter::Skins {
+    pub fn skins(&self) -> iter::Skins<'_> {
         iter::Skins {
             iter: self.0.skins.iter().enumerate(),
             document: self,
@@ -532,7 +683,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the textures of the glTF asset.
-    pub fn textures(&self) -> iter::Textures {
+    pub fn textures(&self) -> iter::Textures<'_> {
         iter::Textures {
             iter: self.0.textures.iter().enumerate(),
             document: self,
@@ -541,7 +692,7 @@ impl Document {
 
     /// Returns an `Iterator` that visits the pre-loaded buffer views of the glTF
     /// asset.
-    pub fn views(&self) -> iter::Views {
+    pub fn views(&self) -> iter::Views<'_> {
         iter::Views {
             iter: self.0.buffer_views.iter().enumerate(),
             document: self,
@@ -596,7 +747,20 @@ impl std::fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "import")]
+            Error::Base64(ref e) => Some(e),
+            Error::Binary(ref e) => Some(e),
+            Error::Deserialize(ref e) => Some(e),
+            Error::Io(ref e) => Some(e),
+            #[cfg(feature = "import")]
+            Error::Image(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<binary::Error> for Error {
     fn from(err: binary::Error) -> Self {
@@ -751,25 +915,25 @@ impl Normalize<f32> for u16 {
 
 impl Normalize<i8> for f32 {
     fn normalize(self) -> i8 {
-        (self * 127.0) as i8
+        (self.clamp(-1.0, 1.0) * 127.0).round() as i8
     }
 }
 
 impl Normalize<u8> for f32 {
     fn normalize(self) -> u8 {
-        (self.max(0.0) * 255.0) as u8
+        (self.clamp(0.0, 1.0) * 255.0).round() as u8
     }
 }
 
 impl Normalize<i16> for f32 {
     fn normalize(self) -> i16 {
-        (self * 32767.0) as i16
+        (self.clamp(-1.0, 1.0) * 32767.0).round() as i16
     }
 }
 
 impl Normalize<u16> for f32 {
     fn normalize(self) -> u16 {
-        (self.max(0.0) * 65535.0) as u16
+        (self.clamp(0.0, 1.0) * 65535.0).round() as u16
     }
 }
 
@@ -54,6 +54,24 @@ impl<'a> Material<'a> {
         self.json.alpha_cutoff.map(|value| value.0)
     }
 
+    /// The alpha cutoff value of the material, or the default of `0.5` if it
+    /// is not given.
+    ///
+    /// The alpha cutoff only applies in the `Mask` alpha mode.
+    ///
+    /// ```
+    /// # let gltf = gltf::Gltf::from_slice(br#"{
+    /// #     "asset": { "version": "2.0" },
+    /// #     "materials": [{ "alphaMode": "MASK" }]
+    /// # }"#).unwrap();
+    /// let material = gltf.materials().next().unwrap();
+    /// assert_eq!(material.alpha_cutoff(), None);
+    /// assert_eq!(material.alpha_cutoff_or_default(), 0.5);
+    /// ```
+    pub fn alpha_cutoff_or_default(&self) -> f32 {
+        self.json.alpha_cutoff.unwrap_or_default().0
+    }
+
     /// The alpha rendering mode of the material.  The material's alpha rendering
     /// mode enumeration specifying the interpretation of the alpha value of the main
     /// factor and texture.
@@ -126,6 +144,15 @@ impl<'a> Material<'a> {
         self.json.extensions.as_ref()?.ior.as_ref().map(|x| x.ior.0)
     }
 
+    /// The index of refraction of the material, or the default of `1.5` if it
+    /// is not given.
+    #[cfg(feature = "KHR_materials_ior")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_ior")))]
+    pub fn ior_or_default(&self) -> f32 {
+        self.ior()
+            .unwrap_or(json::extensions::material::IndexOfRefraction::default().0)
+    }
+
     /// Parameter values that define a volume for the transmission of light through the material
     #[cfg(feature = "KHR_materials_volume")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_volume")))]
@@ -138,6 +165,54 @@ impl<'a> Material<'a> {
             .map(|x| Volume::new(self.document, x))
     }
 
+    /// Parameter values that define a clear coating layer on top of the material
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+    pub fn clearcoat(&self) -> Option<Clearcoat<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .clearcoat
+            .as_ref()
+            .map(|x| Clearcoat::new(self.document, x))
+    }
+
+    /// Parameter values that define a sheen layer for cloth-like materials
+    #[cfg(feature = "KHR_materials_sheen")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+    pub fn sheen(&self) -> Option<Sheen<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .sheen
+            .as_ref()
+            .map(|x| Sheen::new(self.document, x))
+    }
+
+    /// Parameter values that define the anisotropic reflection of the material
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+    pub fn anisotropy(&self) -> Option<Anisotropy<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .anisotropy
+            .as_ref()
+            .map(|x| Anisotropy::new(self.document, x))
+    }
+
+    /// Parameter values that define a thin-film iridescence layer on top of the material
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+    pub fn iridescence(&self) -> Option<Iridescence<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .iridescence
+            .as_ref()
+            .map(|x| Iridescence::new(self.document, x))
+    }
+
     /// Parameter values that define the strength and colour of the specular reflection of the material
     #[cfg(feature = "KHR_materials_specular")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
@@ -204,6 +279,20 @@ impl<'a> Material<'a> {
         self.json.emissive_factor.0
     }
 
+    /// The strength adjustment to be multiplied with the emissive color of the
+    /// material.
+    ///
+    /// The default value is `1.0`.
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_emissive_strength")))]
+    pub fn emissive_strength(&self) -> f32 {
+        self.json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.emissive_strength.as_ref())
+            .map_or(1.0, |x| x.emissive_strength.0)
+    }
+
     /// Specifies whether the material is unlit.
     ///
     /// Returns `true` if the [`KHR_materials_unlit`] property was specified, in which
@@ -413,7 +502,7 @@ pub struct Specular<'a> {
 #[cfg(feature = "KHR_materials_specular")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
 impl<'a> Specular<'a> {
-    /// Constructs `Volume`.
+    /// Constructs `Specular`.
     pub(crate) fn new(
         document: &'a Document,
         json: &'a json::extensions::material::Specular,
@@ -422,6 +511,8 @@ impl<'a> Specular<'a> {
     }
 
     /// The strength of the specular reflection.
+    ///
+    /// The default value is `1.0`.
     pub fn specular_factor(&self) -> f32 {
         self.json.specular_factor.0
     }
@@ -437,6 +528,8 @@ impl<'a> Specular<'a> {
     }
 
     /// The F0 color of the specular reflection (linear RGB).
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
     pub fn specular_color_factor(&self) -> [f32; 3] {
         self.json.specular_color_factor.0
     }
@@ -457,6 +550,253 @@ impl<'a> Specular<'a> {
     }
 }
 
+/// A clear coating layer on top of a material.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+pub struct Clearcoat<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Clearcoat,
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+impl<'a> Clearcoat<'a> {
+    /// Constructs `Clearcoat`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Clearcoat,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The clearcoat layer intensity.
+    pub fn clearcoat_factor(&self) -> f32 {
+        self.json.clearcoat_factor.0
+    }
+
+    /// A texture that defines the clearcoat layer intensity, stored in the
+    /// `R` channel. This will be multiplied by `clearcoat_factor`.
+    pub fn clearcoat_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The clearcoat layer roughness.
+    pub fn clearcoat_roughness_factor(&self) -> f32 {
+        self.json.clearcoat_roughness_factor.0
+    }
+
+    /// A texture that defines the clearcoat layer roughness, stored in the
+    /// `G` channel. This will be multiplied by `clearcoat_roughness_factor`.
+    pub fn clearcoat_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// A tangent space normal map for the clearcoat layer.
+    pub fn clearcoat_normal_texture(&self) -> Option<NormalTexture<'a>> {
+        self.json.clearcoat_normal_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            NormalTexture::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// A sheen layer for cloth-like materials.
+#[cfg(feature = "KHR_materials_sheen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+pub struct Sheen<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Sheen,
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+impl<'a> Sheen<'a> {
+    /// Constructs `Sheen`.
+    pub(crate) fn new(document: &'a Document, json: &'a json::extensions::material::Sheen) -> Self {
+        Self { document, json }
+    }
+
+    /// The sheen color in linear space.
+    pub fn sheen_color_factor(&self) -> [f32; 3] {
+        self.json.sheen_color_factor.0
+    }
+
+    /// A texture that defines the sheen color, stored in the `RGB` channels
+    /// and encoded in sRGB. This will be multiplied by `sheen_color_factor`.
+    pub fn sheen_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The sheen roughness.
+    pub fn sheen_roughness_factor(&self) -> f32 {
+        self.json.sheen_roughness_factor.0
+    }
+
+    /// A texture that defines the sheen roughness, stored in the alpha (`A`)
+    /// channel. This will be multiplied by `sheen_roughness_factor`.
+    pub fn sheen_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Anisotropic reflection of a material.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+pub struct Anisotropy<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Anisotropy,
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+impl<'a> Anisotropy<'a> {
+    /// Constructs `Anisotropy`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Anisotropy,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The anisotropy strength.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_strength(&self) -> f32 {
+        self.json.anisotropy_strength.0
+    }
+
+    /// The rotation of the anisotropy in tangent, bitangent space, measured in
+    /// radians counter-clockwise from the tangent.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_rotation(&self) -> f32 {
+        self.json.anisotropy_rotation
+    }
+
+    /// A texture that defines the anisotropy direction in its `RG` channels and
+    /// strength in its `B` channel.
+    pub fn anisotropy_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.anisotropy_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// A thin-film iridescence layer on top of a material.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+pub struct Iridescence<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Iridescence,
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+impl<'a> Iridescence<'a> {
+    /// Constructs `Iridescence`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Iridescence,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The iridescence intensity.
+    ///
+    /// The default value is `0.0`.
+    pub fn iridescence_factor(&self) -> f32 {
+        self.json.iridescence_factor.0
+    }
+
+    /// A texture that defines the iridescence intensity, stored in the `R`
+    /// channel. This will be multiplied by `iridescence_factor`.
+    pub fn iridescence_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.iridescence_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The index of refraction of the thin-film layer.
+    ///
+    /// The default value is `1.3`.
+    pub fn iridescence_ior(&self) -> f32 {
+        self.json.iridescence_ior.0
+    }
+
+    /// The minimum thickness of the thin-film layer in nanometres.
+    ///
+    /// The default value is `100.0`.
+    pub fn iridescence_thickness_minimum(&self) -> f32 {
+        self.json.iridescence_thickness_minimum.0
+    }
+
+    /// The maximum thickness of the thin-film layer in nanometres.
+    ///
+    /// The default value is `400.0`.
+    pub fn iridescence_thickness_maximum(&self) -> f32 {
+        self.json.iridescence_thickness_maximum.0
+    }
+
+    /// A texture that defines the thickness of the thin-film layer, stored in
+    /// the `G` channel. It interpolates between the minimum and maximum
+    /// thickness.
+    pub fn iridescence_thickness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json
+            .iridescence_thickness_texture
+            .as_ref()
+            .map(|json| {
+                let texture = self.document.textures().nth(json.index.value()).unwrap();
+                texture::Info::new(texture, json)
+            })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
 /// A set of parameter values that are used to define the specular-glossiness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -564,6 +904,18 @@ impl<'a> NormalTexture<'a> {
         self.texture.clone()
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -603,6 +955,18 @@ impl<'a> OcclusionTexture<'a> {
         self.texture.clone()
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
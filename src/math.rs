@@ -163,7 +163,6 @@ impl Vector4 {
         [self.x, self.y, self.z, self.w]
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [f32; 4]) -> Self {
         Self { x, y, z, w }
     }
@@ -250,7 +249,17 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
+    /// Create the identity matrix.
+    #[rustfmt::skip]
+    pub fn identity() -> Matrix4 {
+        Matrix4::new(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
     pub fn from_array([x, y, z, w]: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4 {
             x: Vector4::from_array(x),
@@ -308,6 +317,19 @@ impl Matrix4 {
         }
     }
 
+    /// Transform a point, including the translation component of the matrix.
+    pub fn transform_point(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let v = self.x * x + self.y * y + self.z * z + self.w;
+        [v.x, v.y, v.z]
+    }
+
+    /// Transform a direction, ignoring the translation component of the matrix.
+    #[cfg(feature = "utils")]
+    pub fn transform_vector(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let v = self.x * x + self.y * y + self.z * z;
+        [v.x, v.y, v.z]
+    }
+
     pub fn as_array(&self) -> [[f32; 4]; 4] {
         [
             self.x.as_array(),
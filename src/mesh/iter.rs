@@ -71,10 +71,10 @@ impl<'a> Iterator for Primitives<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let mesh = self.mesh;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Primitive::new(mesh, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -134,6 +134,31 @@ impl<'a> Iterator for MorphTargets<'a> {
     }
 }
 
+/// An `Iterator` that visits the attributes of a Draco compressed `Primitive`.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[derive(Clone, Debug)]
+pub struct DracoAttributes<'a> {
+    /// The internal attribute iterator.
+    pub(crate) iter:
+        collections::btree_map::Iter<'a, json::validation::Checked<json::mesh::Semantic>, u32>,
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> ExactSizeIterator for DracoAttributes<'a> {}
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> Iterator for DracoAttributes<'a> {
+    type Item = (super::Semantic, u32);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(key, id)| (key.as_ref().unwrap().clone(), *id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 /// An `Iterator` that visits the variant mappings of a `Mesh`.
 #[cfg(feature = "KHR_materials_variants")]
 #[derive(Clone, Debug)]
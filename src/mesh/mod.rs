@@ -56,6 +56,7 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
+use crate::math::Matrix4;
 use crate::{Accessor, Buffer, Document, Material};
 
 #[cfg(feature = "utils")]
@@ -80,6 +81,49 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+impl BoundingBox {
+    /// Returns the smallest bounding box containing both `self` and `other`.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        let mut result = self.clone();
+        for i in 0..3 {
+            result.min[i] = result.min[i].min(other.min[i]);
+            result.max[i] = result.max[i].max(other.max[i]);
+        }
+        result
+    }
+
+    /// Returns the bounding box of the corners of `self` transformed by a
+    /// column-major matrix, such as a node's world transform.
+    pub fn transform(&self, matrix: [[f32; 4]; 4]) -> BoundingBox {
+        let matrix = Matrix4::from_array(matrix);
+        let mut result: Option<BoundingBox> = None;
+        for corner in 0..8 {
+            let x = if corner & 1 == 0 {
+                self.min[0]
+            } else {
+                self.max[0]
+            };
+            let y = if corner & 2 == 0 {
+                self.min[1]
+            } else {
+                self.max[1]
+            };
+            let z = if corner & 4 == 0 {
+                self.min[2]
+            } else {
+                self.max[2]
+            };
+            let p = matrix.transform_point([x, y, z]);
+            let point = Bounds { min: p, max: p };
+            result = Some(match result {
+                Some(result) => result.union(&point),
+                None => point,
+            });
+        }
+        result.unwrap()
+    }
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a> {
@@ -158,6 +202,73 @@ impl<'a> Mesh<'a> {
         self.json.name.as_deref()
     }
 
+    /// Measures the surface area, volume and centroid of the triangles of
+    /// every primitive together; see [`Reader::measure`].
+    ///
+    /// Primitives of other modes are skipped. Returns `None` if the positions
+    /// or indices of a triangle primitive cannot be read.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let mesh = document.meshes().next().unwrap();
+    /// let box_ = mesh
+    ///     .measure(|buffer| Some(&buffers[buffer.index()]), None)
+    ///     .unwrap();
+    /// assert!((box_.area() - 6.0).abs() < 1e-5);
+    /// assert!((box_.volume() - 1.0).abs() < 1e-5);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn measure<'s, F>(
+        &self,
+        get_buffer_data: F,
+        transform: Option<[[f32; 4]; 4]>,
+    ) -> Option<util::Measurements>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let mut result = util::Measurements::default();
+        for primitive in self.primitives() {
+            let mode = primitive.mode();
+            if !matches!(
+                mode,
+                Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan
+            ) {
+                continue;
+            }
+            // Primitives are read directly, since a `Reader` must borrow its
+            // primitive for the lifetime of the document.
+            let accessor = primitive.get(&Semantic::Positions)?;
+            let positions: Vec<[f32; 3]> =
+                util::DequantizingIter::new(accessor, get_buffer_data.clone())?.collect();
+            let indices = util::topology::VertexIndices::new(&primitive, get_buffer_data.clone())?;
+            let triangles = util::topology::Triangles::new(indices, mode);
+            result += util::Measurements::new(&positions, triangles, transform)?;
+        }
+        Some(result)
+    }
+
+    /// Returns the union of the `POSITION` bounds of every primitive.
+    ///
+    /// Returns `None` if any primitive lacks `POSITION` bounds.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut result: Option<BoundingBox> = None;
+        for primitive in self.primitives() {
+            let bounds = primitive.get(&Semantic::Positions)?.bounds_vec3()?;
+            result = Some(match result {
+                Some(result) => result.union(&bounds),
+                None => bounds,
+            });
+        }
+        result
+    }
+
     /// Defines the geometry to be renderered with a material.
     pub fn primitives(&self) -> iter::Primitives<'a> {
         iter::Primitives {
@@ -234,11 +345,18 @@ impl<'a> Primitive<'a> {
 
     /// Returns the material to apply to this primitive when rendering
     pub fn material(&self) -> Material<'a> {
-        self.json
-            .material
-            .as_ref()
-            .map(|index| self.mesh.document.materials().nth(index.value()).unwrap())
-            .unwrap_or_else(|| Material::default(self.mesh.document))
+        self.try_material().unwrap()
+    }
+
+    /// Returns the material to apply to this primitive when rendering.
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_material(&self) -> Option<Material<'a>> {
+        match self.json.material.as_ref() {
+            Some(index) => self.mesh.document.materials().nth(index.value()),
+            None => Some(Material::default(self.mesh.document)),
+        }
     }
 
     /// The type of primitives to render.
@@ -271,7 +389,7 @@ impl<'a> Primitive<'a> {
             .as_ref()
             .and_then(|extensions| extensions.khr_materials_variants.as_ref())
             .map(|variants| variants.mappings.iter())
-            .unwrap_or_else(|| (&[]).iter());
+            .unwrap_or_else(|| [].iter());
 
         iter::Mappings {
             document: self.mesh.document,
@@ -279,6 +397,32 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the Draco compressed geometry of this primitive, if provided.
+    ///
+    /// The compressed data must be decoded by a Draco decoder before use, for
+    /// example with `Reader::read_draco`.
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn draco_mesh_compression(&self) -> Option<DracoMeshCompression<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .khr_draco_mesh_compression
+            .as_ref()
+            .map(|json| DracoMeshCompression::new(self.mesh.document, json))
+    }
+
+    /// Returns the material used by this primitive when the given variant is
+    /// active, falling back to `material()` when the variant is not mapped.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn material_for_variant(&self, variant: usize) -> Material<'a> {
+        self.json
+            .material_for_variant(json::Index::new(variant as u32))
+            .map(|index| self.mesh.document.materials().nth(index.value()).unwrap())
+            .unwrap_or_else(|| Material::default(self.mesh.document))
+    }
+
     /// Constructs the primitive reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
@@ -293,6 +437,53 @@ impl<'a> Primitive<'a> {
     }
 }
 
+/// Draco compressed geometry of a primitive.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+#[derive(Clone, Debug)]
+pub struct DracoMeshCompression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::KhrDracoMeshCompression,
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> DracoMeshCompression<'a> {
+    /// Constructs a `DracoMeshCompression`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::mesh::KhrDracoMeshCompression,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the buffer view containing the compressed data.
+    pub fn view(&self) -> crate::buffer::View<'a> {
+        self.document
+            .views()
+            .nth(self.json.buffer_view.value())
+            .unwrap()
+    }
+
+    /// Returns the unique id of an attribute in the compressed data.
+    pub fn get(&self, semantic: &Semantic) -> Option<u32> {
+        self.json
+            .attributes
+            .get(&Checked::Valid(semantic.clone()))
+            .copied()
+    }
+
+    /// Returns an `Iterator` that visits the compressed attributes and their
+    /// unique ids.
+    pub fn attributes(&self) -> iter::DracoAttributes<'a> {
+        iter::DracoAttributes {
+            iter: self.json.attributes.iter(),
+        }
+    }
+}
+
 #[cfg(feature = "utils")]
 impl<'a, 's, F> Reader<'a, 's, F>
 where
@@ -305,6 +496,23 @@ where
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
+    /// Visits the vertices of attributes interleaved in one buffer view, such
+    /// as `[Semantic::Positions, Semantic::Normals]`.
+    ///
+    /// Returns `None` if an attribute is missing or the attributes are not
+    /// stored together; see [`accessor::Interleaved::new`].
+    pub fn read_interleaved<const N: usize>(
+        &self,
+        semantics: [Semantic; N],
+    ) -> Option<accessor::Interleaved<'s, N>> {
+        let mut accessors = Vec::with_capacity(N);
+        for semantic in &semantics {
+            accessors.push(self.primitive.get(semantic)?);
+        }
+        let accessors = accessors.try_into().ok()?;
+        accessor::Interleaved::new(accessors, self.get_buffer_data.clone())
+    }
+
     /// Visits the vertex normals of a primitive.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
         self.primitive
@@ -319,6 +527,44 @@ where
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
+    /// Visits the vertex positions of a primitive as `f32`, dequantizing
+    /// the integer component types permitted by `KHR_mesh_quantization`.
+    ///
+    /// Integer positions are usually dequantized by the transform of the
+    /// node instancing the mesh; see [`util::DequantizingIter::fold_node`].
+    pub fn read_positions_dequantized(&self) -> Option<util::DequantizingIter<'s, 3>> {
+        self.read_dequantized(&Semantic::Positions)
+    }
+
+    /// Visits the vertex normals of a primitive as `f32`, dequantizing the
+    /// normalized integer component types permitted by
+    /// `KHR_mesh_quantization`.
+    pub fn read_normals_dequantized(&self) -> Option<util::DequantizingIter<'s, 3>> {
+        self.read_dequantized(&Semantic::Normals)
+    }
+
+    /// Visits the vertex tangents of a primitive as `f32`, dequantizing the
+    /// normalized integer component types permitted by
+    /// `KHR_mesh_quantization`.
+    pub fn read_tangents_dequantized(&self) -> Option<util::DequantizingIter<'s, 4>> {
+        self.read_dequantized(&Semantic::Tangents)
+    }
+
+    /// Visits the texture co-ordinates of a primitive as `f32`, dequantizing
+    /// the integer component types permitted by `KHR_mesh_quantization`.
+    pub fn read_tex_coords_dequantized(&self, set: u32) -> Option<util::DequantizingIter<'s, 2>> {
+        self.read_dequantized(&Semantic::TexCoords(set))
+    }
+
+    fn read_dequantized<const N: usize>(
+        &self,
+        semantic: &Semantic,
+    ) -> Option<util::DequantizingIter<'s, N>> {
+        self.primitive.get(semantic).and_then(|accessor| {
+            util::DequantizingIter::new(accessor, self.get_buffer_data.clone())
+        })
+    }
+
     /// Visits the vertex colors of a primitive.
     pub fn read_colors(&self, set: u32) -> Option<util::ReadColors<'s>> {
         use self::util::ReadColors;
@@ -340,27 +586,90 @@ where
                         .map(ReadColors::RgbaU16),
                     (F32, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
                         .map(ReadColors::RgbaF32),
-                    _ => unreachable!(),
+                    _ => None,
                 },
             )
     }
 
+    /// Visits the vertex colors of a primitive as linear RGBA `f32`, whether
+    /// they are stored as RGB or RGBA, as floats or as normalized integers.
+    ///
+    /// Alpha defaults to `1.0` for RGB colors. This is a shorthand for
+    /// [`read_colors`](Self::read_colors) followed by
+    /// [`into_rgba_f32`](util::ReadColors::into_rgba_f32).
+    pub fn read_colors_rgba_f32(
+        &self,
+        set: u32,
+    ) -> Option<util::colors::CastingIter<'s, util::colors::RgbaF32>> {
+        self.read_colors(set).map(util::ReadColors::into_rgba_f32)
+    }
+
     /// Visits the vertex draw sequence of a primitive.
     pub fn read_indices(&self) -> Option<util::ReadIndices<'s>> {
-        use self::util::ReadIndices;
-        use accessor::DataType;
         self.primitive
             .indices()
-            .and_then(|accessor| match accessor.data_type() {
-                DataType::U8 => {
-                    accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U8)
-                }
-                DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadIndices::U16),
-                DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadIndices::U32),
-                _ => unreachable!(),
-            })
+            .and_then(|accessor| util::ReadIndices::new(accessor, self.get_buffer_data.clone()))
+    }
+
+    /// Visits the triangles of a triangle, triangle strip or triangle fan
+    /// primitive, as vertex indices.
+    ///
+    /// Non-indexed primitives are treated as if indexed by `0..count`, where
+    /// `count` is the number of vertices. Returns `None` for other modes.
+    pub fn read_triangles(&self) -> Option<util::ReadTriangles<'s>> {
+        match self.primitive.mode() {
+            Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan => {
+                let indices = self.read_vertex_indices()?;
+                let triangles = util::topology::Triangles::new(indices, self.primitive.mode());
+                Some(util::ReadTriangles(triangles))
+            }
+            _ => None,
+        }
+    }
+
+    /// Measures the surface area, volume and centroid of the triangles of a
+    /// primitive, with positions dequantized as by
+    /// [`read_positions_dequantized`](Self::read_positions_dequantized) and
+    /// transformed by `transform` if given, such as the world transform of a
+    /// node instancing the mesh.
+    ///
+    /// Returns `None` if the primitive does not consist of triangles or its
+    /// positions or indices cannot be read.
+    pub fn measure(&self, transform: Option<[[f32; 4]; 4]>) -> Option<util::Measurements> {
+        let positions: Vec<[f32; 3]> = self.read_positions_dequantized()?.collect();
+        util::Measurements::new(&positions, self.read_triangles()?, transform)
+    }
+
+    /// Visits the line segments of a line, line strip or line loop primitive,
+    /// as vertex indices.
+    ///
+    /// Non-indexed primitives are treated as if indexed by `0..count`, where
+    /// `count` is the number of vertices. Returns `None` for other modes.
+    pub fn read_lines(&self) -> Option<util::ReadLines<'s>> {
+        match self.primitive.mode() {
+            Mode::Lines | Mode::LineStrip | Mode::LineLoop => {
+                let indices = self.read_vertex_indices()?;
+                let lines = util::topology::Lines::new(indices, self.primitive.mode());
+                Some(util::ReadLines(lines))
+            }
+            _ => None,
+        }
+    }
+
+    /// Visits the points of a point primitive, as vertex indices.
+    ///
+    /// Non-indexed primitives are treated as if indexed by `0..count`, where
+    /// `count` is the number of vertices. Returns `None` for other modes.
+    pub fn read_points(&self) -> Option<util::ReadPoints<'s>> {
+        match self.primitive.mode() {
+            Mode::Points => self.read_vertex_indices(),
+            _ => None,
+        }
+    }
+
+    /// Visits the indices of a primitive, or generates them if it has none.
+    fn read_vertex_indices(&self) -> Option<util::topology::VertexIndices<'s>> {
+        util::topology::VertexIndices::new(self.primitive, self.get_buffer_data.clone())
     }
 
     /// Visits the joint indices of the primitive.
@@ -393,7 +702,7 @@ where
                     .map(ReadTexCoords::U16),
                 DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
                     .map(ReadTexCoords::F32),
-                _ => unreachable!(),
+                _ => None,
             })
     }
 
@@ -415,6 +724,27 @@ where
             })
     }
 
+    /// Visits the joint influences of every vertex, gathered from all
+    /// `JOINTS_n` and `WEIGHTS_n` attribute sets, for skins with more than
+    /// four influences per vertex.
+    ///
+    /// Sets are read from `0` until the first set missing either attribute.
+    /// Returns `None` if the primitive has no `JOINTS_0` and `WEIGHTS_0`.
+    pub fn read_influences(&self) -> Option<util::ReadInfluences<'s>> {
+        let mut sets = Vec::new();
+        for set in 0.. {
+            match (self.read_joints(set), self.read_weights(set)) {
+                (Some(joints), Some(weights)) => sets.push((joints.into_u16(), weights.into_f32())),
+                _ => break,
+            }
+        }
+        if sets.is_empty() {
+            None
+        } else {
+            Some(util::ReadInfluences::new(sets))
+        }
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -422,6 +752,34 @@ where
             reader: self.clone(),
         }
     }
+
+    /// Decodes the `KHR_draco_mesh_compression` geometry of the primitive.
+    ///
+    /// This library does not implement Draco itself; `decode` is called with
+    /// the compressed data and the semantic and unique id of every compressed
+    /// attribute, and returns the decoded geometry with its attributes in the
+    /// same order. Returns `None` if the primitive is not compressed, the
+    /// compressed data cannot be read, `decode` fails, or the decoded
+    /// geometry does not match the accessors of the primitive in semantic or
+    /// number of values.
+    ///
+    /// The accessors of a compressed primitive have no data of their own, so
+    /// the other `read_*` functions return `None` for them. To rewrite a
+    /// document so that its accessors read the decoded data instead, see
+    /// [`Model::decompress_draco`](crate::edit::Model::decompress_draco).
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn read_draco<D>(&self, decode: D) -> Option<util::DracoGeometry>
+    where
+        D: FnOnce(&[u8], &[(Semantic, u32)]) -> Option<util::DracoGeometry>,
+    {
+        let draco = self.primitive.draco_mesh_compression()?;
+        let view = draco.view();
+        let data = (self.get_buffer_data)(view.buffer())?
+            .get(view.offset()..view.offset() + view.length())?;
+        let ids: Vec<_> = draco.attributes().collect();
+        decode(data, &ids).filter(|geometry| geometry.matches(self.primitive, &ids))
+    }
 }
 
 impl<'a> MorphTarget<'a> {
@@ -38,13 +38,13 @@ trait ColorChannel {
 
 impl ColorChannel for u8 {
     fn max_color() -> Self {
-        u8::max_value()
+        u8::MAX
     }
 }
 
 impl ColorChannel for u16 {
     fn max_color() -> Self {
-        u16::max_value()
+        u16::MAX
     }
 }
 
@@ -335,3 +335,66 @@ impl Cast for RgbaF32 {
         x.normalize().into_rgba()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 43 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 43 }],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5121, "normalized": true, "count": 1, "type": "VEC3" },
+            {
+                "bufferView": 0, "byteOffset": 4, "componentType": 5123, "normalized": true,
+                "count": 1, "type": "VEC4"
+            },
+            { "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 1, "type": "VEC3" },
+            { "bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 1, "type": "VEC4" },
+            { "bufferView": 0, "byteOffset": 40, "componentType": 5120, "count": 1, "type": "VEC3" }
+        ],
+        "meshes": [{
+            "primitives": [
+                { "attributes": { "COLOR_0": 0 } },
+                { "attributes": { "COLOR_0": 1 } },
+                { "attributes": { "COLOR_0": 2 } },
+                { "attributes": { "COLOR_0": 3 } },
+                { "attributes": { "COLOR_0": 4 } }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn read_colors_rgba_f32() {
+        let mut data = vec![255, 0, 51, 0];
+        for channel in [u16::MAX, 0, 0, 0] {
+            data.extend(channel.to_le_bytes());
+        }
+        for channel in [0.5f32, 0.25, 1.0, 0.5, 0.25, 1.0, 0.5] {
+            data.extend(channel.to_le_bytes());
+        }
+        data.extend([127, 0, 0]);
+
+        // Positions are left out for brevity, and signed colors are invalid.
+        let gltf = crate::Gltf::from_slice_without_validation(JSON.as_bytes()).unwrap();
+        let mesh = gltf.meshes().next().unwrap();
+        let colors: Vec<_> = mesh
+            .primitives()
+            .map(|primitive| {
+                let reader = primitive.reader(|_| Some(&data[..]));
+                reader
+                    .read_colors_rgba_f32(0)
+                    .map(|colors| colors.collect::<Vec<_>>())
+            })
+            .collect();
+        assert_eq!(
+            colors,
+            [
+                Some(vec![[1.0, 0.0, 51.0 * 255.0_f32.recip(), 1.0]]),
+                Some(vec![[1.0, 0.0, 0.0, 0.0]]),
+                Some(vec![[0.5, 0.25, 1.0, 1.0]]),
+                Some(vec![[0.5, 0.25, 1.0, 0.5]]),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::accessor::{Accessor, DataType, Iter};
+use crate::{Buffer, Node, Normalize};
+
+/// The components of a quantized attribute, by component type.
+#[derive(Clone, Debug)]
+enum Components<'a, const N: usize> {
+    I8(Iter<'a, [i8; N]>),
+    U8(Iter<'a, [u8; N]>),
+    I16(Iter<'a, [i16; N]>),
+    U16(Iter<'a, [u16; N]>),
+    F32(Iter<'a, [f32; N]>),
+}
+
+/// Dequantizing iterator for vertex attributes of any component type
+/// permitted by `KHR_mesh_quantization`, yielding `[f32; N]`.
+///
+/// Normalized integers are mapped to `[0, 1]` or `[-1, 1]` and other integers
+/// are converted as they are, before the scale and offset are applied.
+#[derive(Clone, Debug)]
+pub struct DequantizingIter<'a, const N: usize> {
+    components: Components<'a, N>,
+    normalized: bool,
+    scale: [f32; N],
+    offset: [f32; N],
+}
+
+impl<'a, const N: usize> DequantizingIter<'a, N> {
+    /// Constructs an iterator over the data of `accessor`, or returns `None`
+    /// if it does not have `N` components of a permitted type.
+    pub(crate) fn new<'b, F>(accessor: Accessor<'b>, get_buffer_data: F) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        if accessor.dimensions().multiplicity() != N {
+            return None;
+        }
+        let normalized = accessor.normalized();
+        let components = match accessor.data_type() {
+            DataType::I8 => Components::I8(Iter::new(accessor, get_buffer_data)?),
+            DataType::U8 => Components::U8(Iter::new(accessor, get_buffer_data)?),
+            DataType::I16 => Components::I16(Iter::new(accessor, get_buffer_data)?),
+            DataType::U16 => Components::U16(Iter::new(accessor, get_buffer_data)?),
+            DataType::F32 => Components::F32(Iter::new(accessor, get_buffer_data)?),
+            DataType::U32 => return None,
+        };
+        Some(DequantizingIter {
+            components,
+            normalized,
+            scale: [1.0; N],
+            offset: [0.0; N],
+        })
+    }
+
+    /// Multiplies every component by `scale` and then adds `offset`, as
+    /// when dequantizing with a known range.
+    pub fn with_scale_offset(self, scale: [f32; N], offset: [f32; N]) -> Self {
+        DequantizingIter {
+            scale,
+            offset,
+            ..self
+        }
+    }
+}
+
+/// Converts the components of one item and applies `scale` and `offset`.
+fn dequantize<T, const N: usize>(
+    item: [T; N],
+    normalized: bool,
+    scale: &[f32; N],
+    offset: &[f32; N],
+) -> [f32; N]
+where
+    T: Copy + Normalize<f32> + Into<f64>,
+{
+    let mut output = [0.0; N];
+    for (i, x) in item.iter().enumerate() {
+        let x = if normalized {
+            x.normalize()
+        } else {
+            Into::<f64>::into(*x) as f32
+        };
+        output[i] = x * scale[i] + offset[i];
+    }
+    output
+}
+
+impl<'a> DequantizingIter<'a, 3> {
+    /// Folds the translation and scale of `node` into the positions, so that
+    /// they no longer need to be applied by the node.
+    ///
+    /// Tools emitting `KHR_mesh_quantization` data commonly store the
+    /// dequantization transform of integer positions this way. Returns
+    /// `None` if the node also rotates, since a rotation cannot be folded
+    /// into a scale and offset per component.
+    pub fn fold_node(self, node: &Node) -> Option<Self> {
+        let (translation, rotation, scale) = node.transform().decomposed();
+        if rotation != [0.0, 0.0, 0.0, 1.0] {
+            return None;
+        }
+        let offset = [
+            self.offset[0] * scale[0] + translation[0],
+            self.offset[1] * scale[1] + translation[1],
+            self.offset[2] * scale[2] + translation[2],
+        ];
+        let scale = [
+            self.scale[0] * scale[0],
+            self.scale[1] * scale[1],
+            self.scale[2] * scale[2],
+        ];
+        Some(self.with_scale_offset(scale, offset))
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for DequantizingIter<'a, N> {}
+impl<'a, const N: usize> Iterator for DequantizingIter<'a, N> {
+    type Item = [f32; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (normalized, scale, offset) = (self.normalized, &self.scale, &self.offset);
+        match self.components {
+            Components::I8(ref mut i) => i.next().map(|x| dequantize(x, normalized, scale, offset)),
+            Components::U8(ref mut i) => i.next().map(|x| dequantize(x, normalized, scale, offset)),
+            Components::I16(ref mut i) => {
+                i.next().map(|x| dequantize(x, normalized, scale, offset))
+            }
+            Components::U16(ref mut i) => {
+                i.next().map(|x| dequantize(x, normalized, scale, offset))
+            }
+            Components::F32(ref mut i) => {
+                i.next().map(|x| dequantize(x, normalized, scale, offset))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.components {
+            Components::I8(ref i) => i.size_hint(),
+            Components::U8(ref i) => i.size_hint(),
+            Components::I16(ref i) => i.size_hint(),
+            Components::U16(ref i) => i.size_hint(),
+            Components::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["KHR_mesh_quantization"],
+        "extensionsRequired": ["KHR_mesh_quantization"],
+        "buffers": [{ "byteLength": 32 }],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 16, "byteStride": 8 },
+            { "buffer": 0, "byteOffset": 16, "byteLength": 8, "byteStride": 4 },
+            { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0, "componentType": 5122, "count": 2, "type": "VEC3",
+                "min": [-4, 2, 3], "max": [1, 5, 6]
+            },
+            { "bufferView": 1, "componentType": 5120, "normalized": true, "count": 2, "type": "VEC3" },
+            { "bufferView": 2, "componentType": 5123, "normalized": true, "count": 2, "type": "VEC2" }
+        ],
+        "meshes": [{
+            "primitives": [{ "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 } }]
+        }],
+        "nodes": [
+            { "mesh": 0, "translation": [1, 0, 0], "scale": [0.5, 0.5, 0.5] },
+            { "mesh": 0, "rotation": [0, 0, 1, 0] }
+        ]
+    }"#;
+
+    #[test]
+    fn dequantize_attributes() {
+        let mut data = Vec::new();
+        for position in [[1i16, 2, 3, 0], [-4, 5, 6, 0]] {
+            position.iter().for_each(|x| data.extend(x.to_le_bytes()));
+        }
+        data.extend([127, 0, 129, 0, 0, 127, 0, 0]);
+        for uv in [[u16::MAX, 0], [0, u16::MAX]] {
+            uv.iter().for_each(|x| data.extend(x.to_le_bytes()));
+        }
+
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&data[..]));
+
+        // The `f32` readers do not accept quantized data.
+        assert!(reader.read_positions().is_none());
+        assert!(reader.read_normals().is_none());
+
+        let positions = reader.read_positions_dequantized().unwrap();
+        assert_eq!(positions.len(), 2);
+        let folded = positions.clone().fold_node(&gltf.nodes().next().unwrap());
+        assert_eq!(
+            positions.collect::<Vec<_>>(),
+            [[1.0, 2.0, 3.0], [-4.0, 5.0, 6.0]]
+        );
+        assert_eq!(
+            folded.unwrap().collect::<Vec<_>>(),
+            [[1.5, 1.0, 1.5], [-1.0, 2.5, 3.0]]
+        );
+        let positions = reader.read_positions_dequantized().unwrap();
+        assert!(positions.fold_node(&gltf.nodes().nth(1).unwrap()).is_none());
+
+        let normals = reader.read_normals_dequantized().unwrap();
+        assert_eq!(
+            normals.collect::<Vec<_>>(),
+            [[1.0, 0.0, -1.0], [0.0, 1.0, 0.0]]
+        );
+        let uvs = reader.read_tex_coords_dequantized(0).unwrap();
+        assert_eq!(uvs.collect::<Vec<_>>(), [[1.0, 0.0], [0.0, 1.0]]);
+        let uvs = reader.read_tex_coords_dequantized(0).unwrap();
+        let uvs = uvs.with_scale_offset([2.0, 2.0], [-1.0, 0.0]);
+        assert_eq!(uvs.collect::<Vec<_>>(), [[1.0, 0.0], [-1.0, 2.0]]);
+
+        // Missing attributes are not dequantized.
+        assert!(reader.read_tangents_dequantized().is_none());
+    }
+}
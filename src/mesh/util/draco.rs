@@ -0,0 +1,63 @@
+use crate::json::accessor::ComponentType;
+use crate::mesh::{Primitive, Semantic};
+
+/// The uncompressed geometry of a primitive, as given to a Draco encoder or
+/// returned by a decoder.
+#[derive(Clone, Debug)]
+pub struct DracoGeometry {
+    /// The vertex indices of every triangle.
+    pub triangles: Vec<[u32; 3]>,
+
+    /// The vertex attributes, in the order of the primitive's attributes.
+    pub attributes: Vec<DracoAttribute>,
+}
+
+/// A vertex attribute of a primitive, as given to a Draco encoder or returned
+/// by a decoder.
+#[derive(Clone, Debug)]
+pub struct DracoAttribute {
+    /// The semantic of the attribute.
+    pub semantic: Semantic,
+
+    /// The component type that the attribute must be decoded to.
+    pub component_type: ComponentType,
+
+    /// Whether the components are normalized integers.
+    pub normalized: bool,
+
+    /// The number of components of every vertex.
+    pub components: usize,
+
+    /// The components of every vertex, with normalized integers given in
+    /// the range `[0, 1]` or `[-1, 1]`.
+    pub values: Vec<f32>,
+}
+
+impl DracoGeometry {
+    /// Returns `true` if the geometry matches the accessors of `primitive`:
+    /// the attributes are those of `ids` in the same order, every attribute
+    /// holds as many values as its accessor, and every triangle refers to
+    /// existing vertices.
+    pub(crate) fn matches(&self, primitive: &Primitive, ids: &[(Semantic, u32)]) -> bool {
+        if self.attributes.len() != ids.len() {
+            return false;
+        }
+        let mut vertices = None;
+        for ((semantic, _), attribute) in ids.iter().zip(&self.attributes) {
+            let accessor = match primitive.get(semantic) {
+                Some(accessor) => accessor,
+                None => return false,
+            };
+            let count = accessor.count() * accessor.dimensions().multiplicity();
+            if attribute.semantic != *semantic || attribute.values.len() != count {
+                return false;
+            }
+            vertices.get_or_insert(accessor.count());
+        }
+        let vertices = vertices.unwrap_or(0);
+        self.triangles
+            .iter()
+            .flatten()
+            .all(|&index| (index as usize) < vertices)
+    }
+}
@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+
+use super::{joints, weights};
+
+/// One `JOINTS_n` and `WEIGHTS_n` attribute set.
+type Set<'a> = (
+    joints::CastingIter<'a, joints::U16>,
+    weights::CastingIter<'a, weights::F32>,
+);
+
+/// Iterator gathering the joint influences of every vertex from all of its
+/// `JOINTS_n` and `WEIGHTS_n` attribute sets.
+///
+/// Every item lists the joints with a non-zero weight and their weights,
+/// sorted by decreasing weight, with joints of equal weight in set order.
+#[derive(Clone, Debug)]
+pub struct ReadInfluences<'a> {
+    sets: Vec<Set<'a>>,
+    limit: Option<usize>,
+    normalize: bool,
+}
+
+impl<'a> ReadInfluences<'a> {
+    pub(crate) fn new(sets: Vec<Set<'a>>) -> Self {
+        ReadInfluences {
+            sets,
+            limit: None,
+            normalize: false,
+        }
+    }
+
+    /// Keeps only the `limit` influences of greatest weight of every vertex,
+    /// such as for renderers supporting four influences per vertex.
+    pub fn limit(self, limit: usize) -> Self {
+        ReadInfluences {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Scales the weights of every vertex to sum to one, after applying any
+    /// [`limit`](Self::limit).
+    pub fn normalized(self) -> Self {
+        ReadInfluences {
+            normalize: true,
+            ..self
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadInfluences<'a> {}
+impl<'a> Iterator for ReadInfluences<'a> {
+    type Item = Vec<(u16, f32)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut influences = Vec::with_capacity(self.sets.len() * 4);
+        for (joints, weights) in &mut self.sets {
+            let (joints, weights) = (joints.next()?, weights.next()?);
+            influences.extend(
+                joints
+                    .into_iter()
+                    .zip(weights)
+                    .filter(|&(_, weight)| weight > 0.0),
+            );
+        }
+        // A stable sort keeps joints of equal weight in set order.
+        influences.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        if let Some(limit) = self.limit {
+            influences.truncate(limit);
+        }
+        if self.normalize {
+            let sum: f32 = influences.iter().map(|&(_, weight)| weight).sum();
+            if sum > 0.0 {
+                influences.iter_mut().for_each(|(_, weight)| *weight /= sum);
+            }
+        }
+        Some(influences)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sets
+            .iter()
+            .map(|(joints, _)| joints.len())
+            .min()
+            .map_or((0, Some(0)), |len| (len, Some(len)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 64 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 64 }],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5121, "count": 2, "type": "VEC4" },
+            { "bufferView": 0, "byteOffset": 8, "componentType": 5126, "count": 2, "type": "VEC4" },
+            { "bufferView": 0, "byteOffset": 40, "componentType": 5123, "count": 2, "type": "VEC4" },
+            {
+                "bufferView": 0, "byteOffset": 56, "componentType": 5121, "normalized": true,
+                "count": 2, "type": "VEC4"
+            }
+        ],
+        "meshes": [{
+            "primitives": [
+                { "attributes": { "JOINTS_0": 0, "WEIGHTS_0": 1, "JOINTS_1": 2, "WEIGHTS_1": 3 } },
+                { "attributes": { "JOINTS_0": 0 } }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn read_influences() {
+        let mut data = vec![0, 1, 2, 3, 4, 5, 0, 0];
+        for weight in [0.4f32, 0.25, 0.1, 0.1, 0.5, 0.5, 0.0, 0.0] {
+            data.extend(weight.to_le_bytes());
+        }
+        for joint in [4u16, 5, 6, 7, 0, 0, 0, 0] {
+            data.extend(joint.to_le_bytes());
+        }
+        data.extend([51, 0, 0, 0, 0, 0, 0, 0]);
+
+        // Positions are left out for brevity.
+        let gltf = crate::Gltf::from_slice_without_validation(JSON.as_bytes()).unwrap();
+        let mesh = gltf.meshes().next().unwrap();
+        let mut primitives = mesh.primitives();
+        let primitive = primitives.next().unwrap();
+        let reader = primitive.reader(|_| Some(&data[..]));
+        let joints = |influences: &[(u16, f32)]| -> Vec<u16> {
+            influences.iter().map(|&(joint, _)| joint).collect()
+        };
+
+        let influences: Vec<_> = reader.read_influences().unwrap().collect();
+        assert_eq!(influences.len(), 2);
+        assert_eq!(joints(&influences[0]), [0, 1, 4, 2, 3]);
+        assert_eq!(influences[1], [(4, 0.5), (5, 0.5)]);
+
+        let influences: Vec<_> = reader
+            .read_influences()
+            .unwrap()
+            .limit(2)
+            .normalized()
+            .collect();
+        assert_eq!(joints(&influences[0]), [0, 1]);
+        let weights: Vec<f32> = influences[0].iter().map(|&(_, x)| x).collect();
+        assert!((weights[0] - 0.4 / 0.65).abs() < 1e-6);
+        assert!((weights[1] - 0.25 / 0.65).abs() < 1e-6);
+
+        // Joints without weights are not influences.
+        let primitive = primitives.next().unwrap();
+        let reader = primitive.reader(|_| Some(&data[..]));
+        assert!(reader.read_influences().is_none());
+    }
+}
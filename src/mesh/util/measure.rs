@@ -0,0 +1,214 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+use crate::edit::determinant;
+use crate::math::Matrix4;
+
+/// The surface area, enclosed volume and centroids of the triangles of a
+/// primitive or mesh.
+///
+/// The volume is the sum of the signed volumes of the tetrahedra formed by
+/// every triangle and the origin, which is the enclosed volume for closed
+/// surfaces with counter-clockwise, outward facing triangles and negative if
+/// they face inwards. For open surfaces it depends on the origin. Values are
+/// accumulated in `f64`, and measurements of several primitives may be
+/// combined with `+` or [`Iterator::sum`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Measurements {
+    area: f64,
+    volume: f64,
+    /// The sum of the centroid of every triangle weighted by its area.
+    area_moment: [f64; 3],
+    /// The sum of the centroid of every tetrahedron weighted by its volume.
+    volume_moment: [f64; 3],
+}
+
+impl Measurements {
+    /// Measures triangles given as indices into `positions`, transformed by
+    /// `transform` if given, or returns `None` if an index is out of range.
+    ///
+    /// Triangles are rewound for transforms that mirror the geometry, as by
+    /// renderers, such that mirroring keeps the sign of the volume.
+    pub(crate) fn new<I>(
+        positions: &[[f32; 3]],
+        triangles: I,
+        transform: Option<[[f32; 4]; 4]>,
+    ) -> Option<Self>
+    where
+        I: Iterator<Item = [u32; 3]>,
+    {
+        let matrix = transform.map(Matrix4::from_array);
+        let positions: Vec<[f64; 3]> = positions
+            .iter()
+            .map(|&p| {
+                let p = matrix.as_ref().map_or(p, |m| m.transform_point(p));
+                p.map(f64::from)
+            })
+            .collect();
+        let mirrored = matrix.map_or(false, |m| determinant(&m) < 0.0);
+
+        let mut result = Self::default();
+        for [a, b, c] in triangles {
+            let (a, b, c) = (
+                *positions.get(a as usize)?,
+                *positions.get(b as usize)?,
+                *positions.get(c as usize)?,
+            );
+            let (b, c) = if mirrored { (c, b) } else { (b, c) };
+            let normal = cross(sub(b, a), sub(c, a));
+            let area = dot(normal, normal).sqrt() / 2.0;
+            let volume = dot(a, cross(b, c)) / 6.0;
+            result.area += area;
+            result.volume += volume;
+            for i in 0..3 {
+                let sum = a[i] + b[i] + c[i];
+                result.area_moment[i] += area * sum / 3.0;
+                // The fourth vertex of the tetrahedron is the origin.
+                result.volume_moment[i] += volume * sum / 4.0;
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns the total area of the triangles.
+    pub fn area(&self) -> f32 {
+        self.area as f32
+    }
+
+    /// Returns the signed volume enclosed by the triangles.
+    pub fn volume(&self) -> f32 {
+        self.volume as f32
+    }
+
+    /// Returns the centroid of the enclosed volume, or the surface centroid if
+    /// the volume is zero, such as for a flat surface.
+    ///
+    /// Returns `None` if the triangles have zero area.
+    pub fn centroid(&self) -> Option<[f32; 3]> {
+        if self.volume != 0.0 {
+            Some(self.volume_moment.map(|x| (x / self.volume) as f32))
+        } else {
+            self.surface_centroid()
+        }
+    }
+
+    /// Returns the centroid of the surface of the triangles, or `None` if they
+    /// have zero area.
+    pub fn surface_centroid(&self) -> Option<[f32; 3]> {
+        if self.area > 0.0 {
+            Some(self.area_moment.map(|x| (x / self.area) as f32))
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Measurements {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for Measurements {
+    fn add_assign(&mut self, other: Self) {
+        self.area += other.area;
+        self.volume += other.volume;
+        for i in 0..3 {
+            self.area_moment[i] += other.area_moment[i];
+            self.volume_moment[i] += other.volume_moment[i];
+        }
+    }
+}
+
+impl Sum for Measurements {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Measurements;
+
+    /// The unit cube with outward facing triangles.
+    fn cube() -> (Vec<[f32; 3]>, Vec<[u32; 3]>) {
+        let positions = (0..8)
+            .map(|i| [i & 1, i >> 1 & 1, i >> 2 & 1].map(|x| x as f32))
+            .collect();
+        let quads = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+        ];
+        let triangles = quads
+            .iter()
+            .flat_map(|&[a, b, c, d]| [[a, b, c], [a, c, d]])
+            .collect();
+        (positions, triangles)
+    }
+
+    fn assert_near(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6),
+            "{:?}",
+            a
+        );
+    }
+
+    #[test]
+    fn measure_cube() {
+        let (positions, triangles) = cube();
+        let unit = Measurements::new(&positions, triangles.iter().copied(), None).unwrap();
+        assert_eq!((unit.area(), unit.volume()), (6.0, 1.0));
+        assert_near(unit.centroid().unwrap(), [0.5; 3]);
+        assert_near(unit.surface_centroid().unwrap(), [0.5; 3]);
+
+        // Scaled by two, mirrored and translated.
+        let transform = [
+            [-2.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.0],
+            [1.0, 0.0, 0.0, 1.0],
+        ];
+        let world =
+            Measurements::new(&positions, triangles.iter().copied(), Some(transform)).unwrap();
+        assert_eq!((world.area(), world.volume()), (24.0, 8.0));
+        assert_near(world.centroid().unwrap(), [0.0, 1.0, 1.0]);
+
+        // Two cubes, and the bottom face alone.
+        let both = unit + world;
+        assert_eq!(both.volume(), 9.0);
+        assert_near(
+            both.centroid().unwrap(),
+            [1.0 / 18.0, 17.0 / 18.0, 17.0 / 18.0],
+        );
+        let face = Measurements::new(&positions, triangles[..2].iter().copied(), None).unwrap();
+        assert_eq!((face.area(), face.volume()), (1.0, 0.0));
+        assert_near(face.centroid().unwrap(), [0.5, 0.5, 0.0]);
+
+        assert!(Measurements::new(&positions, [[0, 1, 8]].into_iter(), None).is_none());
+        assert!(Measurements::default().centroid().is_none());
+    }
+}
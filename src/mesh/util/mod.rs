@@ -1,15 +1,32 @@
 /// Casting iterator adapters for colors.
 pub mod colors;
 
+/// Dequantizing iterator adapters for quantized vertex attributes.
+pub mod dequantize;
+
+/// Geometry decoded from `KHR_draco_mesh_compression` data.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+pub mod draco;
+
 /// Casting iterator adapters for vertex indices.
 pub mod indices;
 
+/// Iterator gathering the joint influences of every vertex.
+pub mod influences;
+
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
+/// Surface area, volume and centroid of triangles.
+pub mod measure;
+
 /// Casting iterator adapters for texture co-ordinates.
 pub mod tex_coords;
 
+/// Iterators over the triangles, line segments and points of a primitive.
+pub mod topology;
+
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
@@ -18,6 +35,13 @@ use crate::mesh;
 use crate::accessor::Iter;
 use crate::Buffer;
 
+pub use self::dequantize::DequantizingIter;
+#[cfg(feature = "KHR_draco_mesh_compression")]
+pub use self::draco::{DracoAttribute, DracoGeometry};
+pub use self::influences::ReadInfluences;
+pub use self::measure::Measurements;
+pub use self::topology::{ReadLines, ReadPoints, ReadTriangles};
+
 /// XYZ vertex positions of type `[f32; 3]`.
 pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
 
@@ -188,6 +212,20 @@ impl<'a> ReadColors<'a> {
 }
 
 impl<'a> ReadIndices<'a> {
+    /// Reads an index accessor of any valid component type.
+    pub(crate) fn new<'b, F>(accessor: crate::Accessor<'b>, get_buffer_data: F) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        use crate::accessor::DataType;
+        match accessor.data_type() {
+            DataType::U8 => Iter::new(accessor, get_buffer_data).map(ReadIndices::U8),
+            DataType::U16 => Iter::new(accessor, get_buffer_data).map(ReadIndices::U16),
+            DataType::U32 => Iter::new(accessor, get_buffer_data).map(ReadIndices::U32),
+            _ => unreachable!(),
+        }
+    }
+
     /// Reinterpret indices as u32, which can fit any possible index.
     pub fn into_u32(self) -> self::indices::CastingIter<'a, self::indices::U32> {
         self::indices::CastingIter::new(self)
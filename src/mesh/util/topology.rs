@@ -0,0 +1,263 @@
+use std::ops;
+
+use super::{indices, ReadIndices};
+use crate::mesh::{Mode, Primitive, Semantic};
+use crate::Buffer;
+
+/// Vertex indices of a primitive, read from its index accessor or generated
+/// for non-indexed primitives.
+#[derive(Clone, Debug)]
+pub enum VertexIndices<'a> {
+    /// Indices read from the primitive's index accessor.
+    Indexed(indices::CastingIter<'a, indices::U32>),
+    /// The indices `0..count` of a non-indexed primitive.
+    Sequential(ops::Range<u32>),
+}
+
+impl<'a> VertexIndices<'a> {
+    /// Reads the indices of a primitive, or generates them if it has none.
+    pub(crate) fn new<'b, F>(primitive: &Primitive<'b>, get_buffer_data: F) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        match primitive.indices() {
+            Some(accessor) => {
+                let indices = ReadIndices::new(accessor, get_buffer_data)?;
+                Some(VertexIndices::Indexed(indices.into_u32()))
+            }
+            None => {
+                let count = primitive.get(&Semantic::Positions)?.count();
+                Some(VertexIndices::Sequential(0..count as u32))
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for VertexIndices<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            VertexIndices::Indexed(iter) => iter.next(),
+            VertexIndices::Sequential(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            VertexIndices::Indexed(iter) => iter.size_hint(),
+            VertexIndices::Sequential(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// Expands a sequence of vertex indices into triangles according to a
+/// primitive mode, which must be one of the triangle modes.
+#[derive(Clone, Debug)]
+pub(crate) struct Triangles<I> {
+    iter: I,
+    mode: Mode,
+    /// The first vertex of a fan and the most recent vertex of a strip.
+    a: Option<u32>,
+    /// The most recent vertex of a fan or strip.
+    b: Option<u32>,
+    /// The number of triangles produced so far.
+    n: usize,
+}
+
+impl<I: Iterator<Item = u32>> Triangles<I> {
+    pub(crate) fn new(iter: I, mode: Mode) -> Self {
+        debug_assert!(matches!(
+            mode,
+            Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan
+        ));
+        Self {
+            iter,
+            mode,
+            a: None,
+            b: None,
+            n: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Triangles<I> {
+    type Item = [u32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let triangle = match self.mode {
+            Mode::TriangleStrip => {
+                let a = match self.a {
+                    Some(a) => a,
+                    None => self.iter.next()?,
+                };
+                let b = match self.b {
+                    Some(b) => b,
+                    None => self.iter.next()?,
+                };
+                let c = self.iter.next()?;
+                self.a = Some(b);
+                self.b = Some(c);
+                // Every other triangle is flipped to keep a consistent winding.
+                if self.n % 2 == 0 {
+                    [a, b, c]
+                } else {
+                    [b, a, c]
+                }
+            }
+            Mode::TriangleFan => {
+                let a = match self.a {
+                    Some(a) => a,
+                    None => self.iter.next()?,
+                };
+                let b = match self.b {
+                    Some(b) => b,
+                    None => self.iter.next()?,
+                };
+                let c = self.iter.next()?;
+                self.a = Some(a);
+                self.b = Some(c);
+                [b, c, a]
+            }
+            _ => [self.iter.next()?, self.iter.next()?, self.iter.next()?],
+        };
+        self.n += 1;
+        Some(triangle)
+    }
+}
+
+/// Expands a sequence of vertex indices into line segments according to a
+/// primitive mode, which must be one of the line modes.
+#[derive(Clone, Debug)]
+pub(crate) struct Lines<I> {
+    iter: I,
+    mode: Mode,
+    /// The first vertex of a loop.
+    first: Option<u32>,
+    /// The most recent vertex of a strip or loop.
+    previous: Option<u32>,
+    done: bool,
+}
+
+impl<I: Iterator<Item = u32>> Lines<I> {
+    pub(crate) fn new(iter: I, mode: Mode) -> Self {
+        debug_assert!(matches!(
+            mode,
+            Mode::Lines | Mode::LineStrip | Mode::LineLoop
+        ));
+        Self {
+            iter,
+            mode,
+            first: None,
+            previous: None,
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Lines<I> {
+    type Item = [u32; 2];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.mode {
+            Mode::LineStrip | Mode::LineLoop => {
+                let a = match self.previous {
+                    Some(a) => a,
+                    None => {
+                        let a = self.iter.next()?;
+                        self.first = Some(a);
+                        a
+                    }
+                };
+                match self.iter.next() {
+                    Some(b) => {
+                        self.previous = Some(b);
+                        Some([a, b])
+                    }
+                    None => {
+                        self.done = true;
+                        // Only close loops that have at least one segment.
+                        match (self.mode, self.first) {
+                            (Mode::LineLoop, Some(first)) if self.previous.is_some() => {
+                                Some([a, first])
+                            }
+                            _ => None,
+                        }
+                    }
+                }
+            }
+            _ => Some([self.iter.next()?, self.iter.next()?]),
+        }
+    }
+}
+
+/// Triangles of a primitive, as vertex indices.
+///
+/// Triangle strips and fans are expanded into separate triangles with the
+/// winding order of the first triangle.
+#[derive(Clone, Debug)]
+pub struct ReadTriangles<'a>(pub(crate) Triangles<VertexIndices<'a>>);
+
+impl<'a> Iterator for ReadTriangles<'a> {
+    type Item = [u32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Line segments of a primitive, as vertex indices.
+///
+/// Line strips and loops are expanded into separate segments.
+#[derive(Clone, Debug)]
+pub struct ReadLines<'a>(pub(crate) Lines<VertexIndices<'a>>);
+
+impl<'a> Iterator for ReadLines<'a> {
+    type Item = [u32; 2];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Points of a primitive, as vertex indices.
+pub type ReadPoints<'a> = VertexIndices<'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangles(mode: Mode, n: u32) -> Vec<[u32; 3]> {
+        Triangles::new(0..n, mode).collect()
+    }
+
+    fn lines(mode: Mode, n: u32) -> Vec<[u32; 2]> {
+        Lines::new(0..n, mode).collect()
+    }
+
+    #[test]
+    fn triangle_modes() {
+        assert_eq!(triangles(Mode::Triangles, 7), [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(
+            triangles(Mode::TriangleStrip, 5),
+            [[0, 1, 2], [2, 1, 3], [2, 3, 4]]
+        );
+        assert_eq!(
+            triangles(Mode::TriangleFan, 5),
+            [[1, 2, 0], [2, 3, 0], [3, 4, 0]]
+        );
+        assert!(triangles(Mode::TriangleStrip, 2).is_empty());
+        assert!(triangles(Mode::TriangleFan, 0).is_empty());
+    }
+
+    #[test]
+    fn line_modes() {
+        assert_eq!(lines(Mode::Lines, 5), [[0, 1], [2, 3]]);
+        assert_eq!(lines(Mode::LineStrip, 3), [[0, 1], [1, 2]]);
+        assert_eq!(lines(Mode::LineLoop, 3), [[0, 1], [1, 2], [2, 0]]);
+        assert!(lines(Mode::LineLoop, 1).is_empty());
+    }
+}
@@ -0,0 +1,591 @@
+//! Decoders for the `EXT_meshopt_compression` buffer view codecs and filters,
+//! following the bitstream specification of the extension.
+
+use crate::json::extensions::buffer::ext_meshopt_compression::{Filter, Mode};
+use crate::{Document, Error, Result};
+
+/// Decompresses every compressed buffer view into its range of its buffer,
+/// if that buffer is a fallback buffer. `target` returns the data of a
+/// buffer that can be written to.
+pub(crate) fn decompress<B>(
+    document: &Document,
+    buffers: &mut [B],
+    target: fn(&mut B) -> Option<&mut [u8]>,
+) -> Result<()>
+where
+    B: std::ops::Deref<Target = [u8]>,
+{
+    for view in document.views() {
+        let compression = match view.meshopt_compression() {
+            Some(compression) if view.buffer().is_meshopt_fallback() => compression,
+            _ => continue,
+        };
+        let error = || {
+            let message = format!(
+                "buffer view {}: malformed EXT_meshopt_compression data",
+                view.index()
+            );
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                message,
+            ))
+        };
+        let (mode, filter) = (compression.mode(), compression.filter());
+        // The decoded size is checked before anything is allocated for it.
+        let size = compression.count().checked_mul(compression.stride());
+        if size.map_or(true, |size| size > view.length()) {
+            return Err(error());
+        }
+        let source = buffers
+            .get(compression.buffer().index())
+            .and_then(|data| {
+                data.get(compression.offset()..)?
+                    .get(..compression.length())
+            })
+            .ok_or_else(error)?;
+        let decoded = decode(
+            source,
+            compression.count(),
+            compression.stride(),
+            mode,
+            filter,
+        )
+        .ok_or_else(error)?;
+        let data = target(&mut buffers[view.buffer().index()]).ok_or_else(error)?;
+        data.get_mut(view.offset()..view.offset() + decoded.len())
+            .ok_or_else(error)?
+            .copy_from_slice(&decoded);
+    }
+    Ok(())
+}
+
+/// Decodes the compressed data of a buffer view, returning `count * stride`
+/// bytes, or `None` if the data is malformed.
+pub(crate) fn decode(
+    data: &[u8],
+    count: usize,
+    stride: usize,
+    mode: Mode,
+    filter: Filter,
+) -> Option<Vec<u8>> {
+    let mut output = match mode {
+        Mode::Attributes => decode_vertex_buffer(data, count, stride)?,
+        Mode::Triangles => write_indices(&decode_index_buffer(data, count)?, stride)?,
+        Mode::Indices => write_indices(&decode_index_sequence(data, count)?, stride)?,
+    };
+    match (mode, filter) {
+        (_, Filter::None) => {}
+        (Mode::Attributes, Filter::Octahedral) if stride == 4 => decode_octahedral_i8(&mut output),
+        (Mode::Attributes, Filter::Octahedral) if stride == 8 => decode_octahedral_i16(&mut output),
+        (Mode::Attributes, Filter::Quaternion) if stride == 8 => decode_quaternion(&mut output),
+        (Mode::Attributes, Filter::Exponential) if stride % 4 == 0 => {
+            decode_exponential(&mut output)
+        }
+        _ => return None,
+    }
+    Some(output)
+}
+
+/// Writes indices as little endian integers of `stride` bytes.
+fn write_indices(indices: &[u32], stride: usize) -> Option<Vec<u8>> {
+    match stride {
+        2 => Some(
+            indices
+                .iter()
+                .flat_map(|&x| (x as u16).to_le_bytes())
+                .collect(),
+        ),
+        4 => Some(indices.iter().flat_map(|&x| x.to_le_bytes()).collect()),
+        _ => None,
+    }
+}
+
+/// Reads bytes from the front of a slice, failing instead of panicking when
+/// the slice is exhausted.
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn byte(&mut self) -> Option<u8> {
+        let (&first, rest) = self.data.split_first()?;
+        self.data = rest;
+        Some(first)
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.data.len() < n {
+            return None;
+        }
+        let (first, rest) = self.data.split_at(n);
+        self.data = rest;
+        Some(first)
+    }
+
+    /// Reads an unsigned LEB128 integer of at most five bytes.
+    fn vbyte(&mut self) -> Option<u32> {
+        let lead = self.byte()?;
+        if lead < 128 {
+            return Some(lead as u32);
+        }
+        let mut result = (lead & 127) as u32;
+        let mut shift = 7;
+        for _ in 0..4 {
+            let group = self.byte()?;
+            result |= ((group & 127) as u32) << shift;
+            shift += 7;
+            if group < 128 {
+                break;
+            }
+        }
+        Some(result)
+    }
+}
+
+/// Converts a zigzag encoded integer to a two's complement delta.
+fn unzigzag(v: u32) -> u32 {
+    (v >> 1) ^ (v & 1).wrapping_neg()
+}
+
+const VERTEX_HEADER: u8 = 0xa0;
+const VERTEX_BLOCK_SIZE_BYTES: usize = 8192;
+const VERTEX_BLOCK_MAX_SIZE: usize = 256;
+const BYTE_GROUP_SIZE: usize = 16;
+const BYTE_GROUP_DECODE_LIMIT: usize = 24;
+const TAIL_MAX_SIZE: usize = 32;
+
+/// Decodes `count` elements of `stride` bytes compressed with the vertex
+/// codec.
+fn decode_vertex_buffer(data: &[u8], count: usize, stride: usize) -> Option<Vec<u8>> {
+    if stride == 0 || stride > 256 || stride % 4 != 0 || data.len() < 1 + stride {
+        return None;
+    }
+    if data[0] & 0xf0 != VERTEX_HEADER || data[0] & 0x0f > 0 {
+        return None;
+    }
+    let mut last = data[data.len() - stride..].to_vec();
+    let block_size =
+        ((VERTEX_BLOCK_SIZE_BYTES / stride) & !(BYTE_GROUP_SIZE - 1)).min(VERTEX_BLOCK_MAX_SIZE);
+    let mut output = vec![0; count * stride];
+    let mut cursor = Cursor { data: &data[1..] };
+    for chunk in output.chunks_mut(block_size * stride) {
+        decode_vertex_block(&mut cursor, chunk, stride, &mut last)?;
+    }
+    let tail = TAIL_MAX_SIZE.max(stride);
+    if cursor.data.len() != tail {
+        return None;
+    }
+    Some(output)
+}
+
+/// Decodes one block of vertices into `output`, whose elements are stored
+/// as deltas from the previous element, starting with `last`.
+fn decode_vertex_block(
+    cursor: &mut Cursor,
+    output: &mut [u8],
+    stride: usize,
+    last: &mut [u8],
+) -> Option<()> {
+    let count = output.len() / stride;
+    let aligned = (count + BYTE_GROUP_SIZE - 1) & !(BYTE_GROUP_SIZE - 1);
+    let mut buffer = [0; VERTEX_BLOCK_MAX_SIZE];
+    for k in 0..stride {
+        decode_bytes(cursor, &mut buffer[..aligned])?;
+        let mut p = last[k];
+        for i in 0..count {
+            let v = buffer[i];
+            let v = ((v & 1).wrapping_neg() ^ (v >> 1)).wrapping_add(p);
+            output[i * stride + k] = v;
+            p = v;
+        }
+    }
+    last.copy_from_slice(&output[(count - 1) * stride..]);
+    Some(())
+}
+
+/// Decodes a byte stream made of groups of 16 values, each packed into 0, 2,
+/// 4 or 8 bits as given by a two bit header.
+fn decode_bytes(cursor: &mut Cursor, buffer: &mut [u8]) -> Option<()> {
+    let header_size = (buffer.len() / BYTE_GROUP_SIZE + 3) / 4;
+    let header = cursor.take(header_size)?;
+    for (i, group) in buffer.chunks_mut(BYTE_GROUP_SIZE).enumerate() {
+        if cursor.data.len() < BYTE_GROUP_DECODE_LIMIT {
+            return None;
+        }
+        let bitslog2 = (header[i / 4] >> ((i % 4) * 2)) & 3;
+        decode_bytes_group(cursor, group, bitslog2)?;
+    }
+    Some(())
+}
+
+fn decode_bytes_group(cursor: &mut Cursor, group: &mut [u8], bitslog2: u8) -> Option<()> {
+    match bitslog2 {
+        0 => group.iter_mut().for_each(|x| *x = 0),
+        3 => group.copy_from_slice(cursor.take(BYTE_GROUP_SIZE)?),
+        _ => {
+            let bits = 1 << bitslog2;
+            let packed = cursor.take(BYTE_GROUP_SIZE * bits / 8)?;
+            let sentinel = (1 << bits) - 1;
+            for (i, value) in group.iter_mut().enumerate() {
+                let byte = packed[i * bits / 8];
+                let shift = 8 - bits - (i * bits) % 8;
+                let encoded = (byte >> shift) & sentinel;
+                *value = if encoded == sentinel {
+                    cursor.byte()?
+                } else {
+                    encoded
+                };
+            }
+        }
+    }
+    Some(())
+}
+
+const INDEX_HEADER: u8 = 0xe0;
+const SEQUENCE_HEADER: u8 = 0xd0;
+
+/// Decodes `count` triangle list indices compressed with the index codec.
+fn decode_index_buffer(data: &[u8], count: usize) -> Option<Vec<u32>> {
+    if count % 3 != 0 || data.len() < 1 + count / 3 + 16 {
+        return None;
+    }
+    if data[0] & 0xf0 != INDEX_HEADER {
+        return None;
+    }
+    let version = data[0] & 0x0f;
+    if version > 1 {
+        return None;
+    }
+
+    let mut edge_fifo = [(u32::MAX, u32::MAX); 16];
+    let mut vertex_fifo = [u32::MAX; 16];
+    let (mut edge_offset, mut vertex_offset) = (0usize, 0usize);
+    let (mut next, mut last) = (0u32, 0u32);
+    let fec_max = if version >= 1 { 13 } else { 15 };
+
+    let codes = &data[1..1 + count / 3];
+    let aux_table = &data[data.len() - 16..];
+    let mut cursor = Cursor {
+        data: &data[1 + count / 3..data.len() - 16],
+    };
+    let mut indices = Vec::with_capacity(count);
+
+    macro_rules! push_vertex {
+        ($v:expr) => {
+            push_vertex!($v, true)
+        };
+        ($v:expr, $cond:expr) => {
+            vertex_fifo[vertex_offset] = $v;
+            vertex_offset = (vertex_offset + $cond as usize) & 15;
+        };
+    }
+    macro_rules! push_edge {
+        ($a:expr, $b:expr) => {
+            edge_fifo[edge_offset] = ($a, $b);
+            edge_offset = (edge_offset + 1) & 15;
+        };
+    }
+    let decode_index = |cursor: &mut Cursor, last: u32| -> Option<u32> {
+        Some(last.wrapping_add(unzigzag(cursor.vbyte()?)))
+    };
+
+    for &code in codes {
+        if code < 0xf0 {
+            let fe = (code >> 4) as usize;
+            let (a, b) = edge_fifo[edge_offset.wrapping_sub(1 + fe) & 15];
+            let fec = (code & 15) as usize;
+            if fec < fec_max {
+                let c = if fec == 0 {
+                    next
+                } else {
+                    vertex_fifo[vertex_offset.wrapping_sub(1 + fec) & 15]
+                };
+                let fec0 = fec == 0;
+                next += fec0 as u32;
+                indices.extend_from_slice(&[a, b, c]);
+                push_vertex!(c, fec0);
+                push_edge!(c, b);
+                push_edge!(a, c);
+            } else {
+                let c = if fec != 15 {
+                    // 13 and 14 encode deltas of -1 and 1.
+                    last.wrapping_add((fec as u32).wrapping_sub(fec as u32 ^ 3))
+                } else {
+                    decode_index(&mut cursor, last)?
+                };
+                last = c;
+                indices.extend_from_slice(&[a, b, c]);
+                push_vertex!(c);
+                push_edge!(c, b);
+                push_edge!(a, c);
+            }
+        } else if code < 0xfe {
+            let aux = aux_table[(code & 15) as usize];
+            let feb = (aux >> 4) as usize;
+            let fec = (aux & 15) as usize;
+            let a = next;
+            next += 1;
+            let b = if feb == 0 {
+                next
+            } else {
+                vertex_fifo[vertex_offset.wrapping_sub(feb) & 15]
+            };
+            let feb0 = feb == 0;
+            next += feb0 as u32;
+            let c = if fec == 0 {
+                next
+            } else {
+                vertex_fifo[vertex_offset.wrapping_sub(fec) & 15]
+            };
+            let fec0 = fec == 0;
+            next += fec0 as u32;
+            indices.extend_from_slice(&[a, b, c]);
+            push_vertex!(a);
+            push_vertex!(b, feb0);
+            push_vertex!(c, fec0);
+            push_edge!(b, a);
+            push_edge!(c, b);
+            push_edge!(a, c);
+        } else {
+            let aux = cursor.byte()?;
+            let fea = if code == 0xfe { 0 } else { 15 };
+            let feb = (aux >> 4) as usize;
+            let fec = (aux & 15) as usize;
+            let mut a = 0;
+            if fea == 0 {
+                a = next;
+                next += 1;
+            }
+            let mut b = if feb == 0 {
+                next += 1;
+                next - 1
+            } else {
+                vertex_fifo[vertex_offset.wrapping_sub(feb) & 15]
+            };
+            let mut c = if fec == 0 {
+                next += 1;
+                next - 1
+            } else {
+                vertex_fifo[vertex_offset.wrapping_sub(fec) & 15]
+            };
+            if fea == 15 {
+                a = decode_index(&mut cursor, last)?;
+                last = a;
+            }
+            if feb == 15 {
+                b = decode_index(&mut cursor, last)?;
+                last = b;
+            }
+            if fec == 15 {
+                c = decode_index(&mut cursor, last)?;
+                last = c;
+            }
+            indices.extend_from_slice(&[a, b, c]);
+            push_vertex!(a);
+            push_vertex!(b, feb == 0 || feb == 15);
+            push_vertex!(c, fec == 0 || fec == 15);
+            push_edge!(b, a);
+            push_edge!(c, b);
+            push_edge!(a, c);
+        }
+    }
+    if !cursor.data.is_empty() {
+        return None;
+    }
+    Some(indices)
+}
+
+/// Decodes `count` indices compressed with the index sequence codec.
+fn decode_index_sequence(data: &[u8], count: usize) -> Option<Vec<u32>> {
+    if data.len() < 1 + count + 4 {
+        return None;
+    }
+    if data[0] & 0xf0 != SEQUENCE_HEADER || data[0] & 0x0f > 1 {
+        return None;
+    }
+    let mut cursor = Cursor {
+        data: &data[1..data.len() - 4],
+    };
+    let mut last = [0u32; 2];
+    let mut indices = Vec::with_capacity(count);
+    for _ in 0..count {
+        let v = cursor.vbyte()?;
+        // The lowest bit selects which of the two previous indices the delta
+        // applies to.
+        let baseline = (v & 1) as usize;
+        let index = last[baseline].wrapping_add(unzigzag(v >> 1));
+        last[baseline] = index;
+        indices.push(index);
+    }
+    if !cursor.data.is_empty() {
+        return None;
+    }
+    Some(indices)
+}
+
+/// Rounds to the nearest integer, away from zero at halfway.
+fn round(x: f32) -> i32 {
+    (x + if x >= 0.0 { 0.5 } else { -0.5 }) as i32
+}
+
+/// Reconstructs a unit vector from octahedral coordinates, scaled by `max`.
+fn octahedral(x: f32, y: f32, z: f32, max: f32) -> [i32; 3] {
+    let z = z - x.abs() - y.abs();
+    let t = z.min(0.0);
+    let x = x + if x >= 0.0 { t } else { -t };
+    let y = y + if y >= 0.0 { t } else { -t };
+    let s = max / (x * x + y * y + z * z).sqrt();
+    [round(x * s), round(y * s), round(z * s)]
+}
+
+fn decode_octahedral_i8(data: &mut [u8]) {
+    for element in data.chunks_exact_mut(4) {
+        let [x, y, z] = [0, 1, 2].map(|i| element[i] as i8 as f32);
+        let v = octahedral(x, y, z, 127.0);
+        for i in 0..3 {
+            element[i] = v[i] as i8 as u8;
+        }
+    }
+}
+
+fn decode_octahedral_i16(data: &mut [u8]) {
+    for element in data.chunks_exact_mut(8) {
+        let [x, y, z] = [0, 2, 4].map(|i| i16::from_le_bytes([element[i], element[i + 1]]) as f32);
+        let v = octahedral(x, y, z, 32767.0);
+        for i in 0..3 {
+            element[2 * i..2 * i + 2].copy_from_slice(&(v[i] as i16).to_le_bytes());
+        }
+    }
+}
+
+fn decode_quaternion(data: &mut [u8]) {
+    let scale = std::f32::consts::FRAC_1_SQRT_2;
+    for element in data.chunks_exact_mut(8) {
+        let q = [0, 2, 4, 6].map(|i| i16::from_le_bytes([element[i], element[i + 1]]));
+        // The last component holds the scale in its upper bits and the index
+        // of the omitted component in its lowest two bits.
+        let s = scale / (q[3] | 3) as f32;
+        let [x, y, z] = [q[0], q[1], q[2]].map(|v| v as f32 * s);
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+        let omitted = (q[3] & 3) as usize;
+        let values = [(1, x), (2, y), (3, z), (0, w)];
+        for (offset, value) in values {
+            let i = (omitted + offset) & 3;
+            let value = round(value * 32767.0) as i16;
+            element[2 * i..2 * i + 2].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+fn decode_exponential(data: &mut [u8]) {
+    for element in data.chunks_exact_mut(4) {
+        let v = u32::from_le_bytes([element[0], element[1], element[2], element[3]]);
+        // A 24 bit signed mantissa and an 8 bit signed exponent.
+        let m = ((v << 8) as i32) >> 8;
+        let e = (v as i32) >> 24;
+        let value = f32::from_bits(((e + 127) as u32) << 23) * m as f32;
+        element.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u16s(data: &[u8]) -> Vec<u16> {
+        data.chunks_exact(2)
+            .map(|x| u16::from_le_bytes([x[0], x[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn vertex_buffer() {
+        // Four vertices of 12 bytes, with 16 bit x and y coordinates of 0 or
+        // 300 followed by eight zero bytes.
+        let mut data = vec![
+            0xa0, 0x01, 0x3f, 0x00, 0x00, 0x00, 0x58, 0x57, 0x58, 0x01, 0x26, 0x00, 0x00, 0x00,
+            0x01, 0x0c, 0x00, 0x00, 0x00, 0x58, 0x01, 0x08, 0x00, 0x00, 0x00,
+        ];
+        // The headers of the eight zero columns, then the tail, which holds
+        // the initial value of every byte and is at least 32 bytes long.
+        data.extend_from_slice(&[0; 8 + 32]);
+        let decoded = decode_vertex_buffer(&data, 4, 12).unwrap();
+        let vertices: Vec<&[u8]> = decoded.chunks_exact(12).collect();
+        assert_eq!(u16s(&vertices[0][..4]), [0, 0]);
+        assert_eq!(u16s(&vertices[1][..4]), [300, 0]);
+        assert_eq!(u16s(&vertices[2][..4]), [0, 300]);
+        assert_eq!(u16s(&vertices[3][..4]), [300, 300]);
+        assert!(vertices.iter().all(|x| x[4..] == [0; 8]));
+
+        assert_eq!(decode_vertex_buffer(&data[..data.len() - 1], 4, 12), None);
+        data[0] = 0xa1;
+        assert_eq!(decode_vertex_buffer(&data, 4, 12), None);
+    }
+
+    #[test]
+    fn index_buffer() {
+        let expected = [0, 1, 2, 2, 1, 3, 4, 6, 5, 7, 8, 9];
+        let v0 = [
+            0xe0, 0xf0, 0x10, 0xfe, 0xff, 0xf0, 0x0c, 0xff, 0x02, 0x02, 0x02, 0x00, 0x76, 0x87,
+            0x56, 0x67, 0x78, 0xa9, 0x86, 0x65, 0x89, 0x68, 0x98, 0x01, 0x69, 0x00, 0x00,
+        ];
+        assert_eq!(decode_index_buffer(&v0, 12).unwrap(), expected);
+        assert_eq!(decode_index_buffer(&v0[..v0.len() - 1], 12), None);
+        assert_eq!(decode_index_buffer(&v0, 9), None);
+
+        // Version 1 encodes a vertex one before or after the last explicitly
+        // encoded one with codes 13 and 14, which version 0 reads from the
+        // vertex FIFO.
+        let mut v1 = vec![0xe1, 0xf0, 0x10, 0x0e, 0x0d];
+        v1.extend_from_slice(&[0; 16]);
+        let expected = [0, 1, 2, 2, 1, 3, 2, 3, 1, 2, 1, 0];
+        assert_eq!(decode_index_buffer(&v1, 12).unwrap(), expected);
+    }
+
+    #[test]
+    fn index_sequence() {
+        let data = [
+            0xd1, 0x00, 0x04, 0xcd, 0x01, 0x04, 0x07, 0x98, 0x1f, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let decoded = decode(&data, 6, 2, Mode::Indices, Filter::None).unwrap();
+        assert_eq!(u16s(&decoded), [0, 1, 51, 2, 49, 1000]);
+        assert_eq!(decode_index_sequence(&data[..12], 6), None);
+    }
+
+    #[test]
+    fn octahedral_filter() {
+        // +Z, +X and -Z, the last folded over the octahedron's lower half.
+        let mut data = vec![0, 0, 127, 0, 127, 0, 127, 0, 127, 127, 127, 0];
+        decode_octahedral_i8(&mut data);
+        let data: Vec<i8> = data.into_iter().map(|x| x as i8).collect();
+        assert_eq!(data, [0, 0, 127, 0, 127, 0, 0, 0, 0, 0, -127, 0]);
+    }
+
+    #[test]
+    fn quaternion_filter() {
+        // The identity, omitting w, and a rotation of 180 degrees about x,
+        // omitting x, whose other components are stored rotated.
+        let scale = (0x1fff << 2) as i16;
+        let mut data: Vec<u8> = [0, 0, 0, scale | 3, 0, 0, 0, scale]
+            .iter()
+            .flat_map(|x: &i16| x.to_le_bytes())
+            .collect();
+        decode_quaternion(&mut data);
+        let data: Vec<i16> = u16s(&data).into_iter().map(|x| x as i16).collect();
+        assert_eq!(data, [0, 0, 0, 32767, 32767, 0, 0, 0]);
+    }
+
+    #[test]
+    fn exponential_filter() {
+        let mut data: Vec<u8> = [0xfe00_0006u32, 0x00ff_fffd]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        decode_exponential(&mut data);
+        let values: Vec<f32> = data
+            .chunks_exact(4)
+            .map(|x| f32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+            .collect();
+        assert_eq!(values, [1.5, -3.0]);
+    }
+}
@@ -1,5 +1,6 @@
 use std::slice;
 
+use crate::math::Matrix4;
 use crate::{Document, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
@@ -22,6 +23,18 @@ pub struct Children<'a> {
     pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
 }
 
+/// An `Iterator` that visits every node in a scene hierarchy, depth first, along
+/// with its world transform.
+#[derive(Clone, Debug)]
+pub struct WorldTransforms<'a> {
+    /// Nodes yet to be visited, paired with the world transform of their parent
+    /// and their depth.
+    pub(crate) stack: Vec<(Node<'a>, [[f32; 4]; 4], usize)>,
+
+    /// Whether each node of the document has been visited.
+    pub(crate) visited: Vec<bool>,
+}
+
 impl<'a> ExactSizeIterator for Nodes<'a> {}
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
@@ -62,3 +75,30 @@ impl<'a> Iterator for Children<'a> {
             .map(|index| self.document.nodes().nth(index.value()).unwrap())
     }
 }
+
+impl<'a> WorldTransforms<'a> {
+    /// Visits the next node along with its world transform and its depth,
+    /// skipping nodes that have been visited already.
+    pub(crate) fn next_with_depth(&mut self) -> Option<(Node<'a>, [[f32; 4]; 4], usize)> {
+        loop {
+            let (node, parent, depth) = self.stack.pop()?;
+            if std::mem::replace(&mut self.visited[node.index()], true) {
+                continue;
+            }
+            let local = Matrix4::from_array(node.transform().matrix());
+            let world = (Matrix4::from_array(parent) * local).as_array();
+            let first_child = self.stack.len();
+            self.stack
+                .extend(node.children().map(|child| (child, world, depth + 1)));
+            self.stack[first_child..].reverse();
+            return Some((node, world, depth));
+        }
+    }
+}
+
+impl<'a> Iterator for WorldTransforms<'a> {
+    type Item = (Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_depth().map(|(node, world, _)| (node, world))
+    }
+}
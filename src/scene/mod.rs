@@ -79,6 +79,93 @@ impl Transform {
             } => (translation, rotation, scale),
         }
     }
+
+    /// Returns the matrix representation of this transform as a `mint` type.
+    ///
+    /// See [`Transform::matrix`].
+    #[cfg(feature = "mint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+    pub fn mint_matrix(self) -> mint::ColumnMatrix4<f32> {
+        self.matrix().into()
+    }
+
+    /// Returns a decomposed representation of this transform as `mint`
+    /// types, in the order translation, rotation and scale.
+    ///
+    /// See [`Transform::decomposed`].
+    ///
+    /// ```
+    /// let transform = gltf::scene::Transform::Decomposed {
+    ///     translation: [1.0, 2.0, 3.0],
+    ///     rotation: [0.0, 0.0, 0.0, 1.0],
+    ///     scale: [1.0, 1.0, 1.0],
+    /// };
+    /// let (translation, rotation, _) = transform.mint_decomposed();
+    /// assert_eq!(translation, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    /// assert_eq!(rotation.s, 1.0);
+    /// ```
+    #[cfg(feature = "mint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+    pub fn mint_decomposed(
+        self,
+    ) -> (
+        mint::Vector3<f32>,
+        mint::Quaternion<f32>,
+        mint::Vector3<f32>,
+    ) {
+        let (translation, rotation, scale) = self.decomposed();
+        (translation.into(), rotation.into(), scale.into())
+    }
+}
+
+#[cfg(feature = "cgmath")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cgmath")))]
+impl From<Transform> for cgmath::Matrix4<f32> {
+    /// See [`Transform::matrix`].
+    fn from(transform: Transform) -> Self {
+        transform.mint_matrix().into()
+    }
+}
+
+#[cfg(feature = "cgmath")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cgmath")))]
+impl From<Transform>
+    for (
+        cgmath::Vector3<f32>,
+        cgmath::Quaternion<f32>,
+        cgmath::Vector3<f32>,
+    )
+{
+    /// See [`Transform::decomposed`].
+    fn from(transform: Transform) -> Self {
+        let (translation, rotation, scale) = transform.mint_decomposed();
+        (translation.into(), rotation.into(), scale.into())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+impl From<Transform> for nalgebra::Matrix4<f32> {
+    /// See [`Transform::matrix`].
+    fn from(transform: Transform) -> Self {
+        transform.mint_matrix().into()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+impl From<Transform>
+    for (
+        nalgebra::Vector3<f32>,
+        nalgebra::Quaternion<f32>,
+        nalgebra::Vector3<f32>,
+    )
+{
+    /// See [`Transform::decomposed`].
+    fn from(transform: Transform) -> Self {
+        let (translation, rotation, scale) = transform.mint_decomposed();
+        (translation.into(), rotation.into(), scale.into())
+    }
 }
 
 /// A node in the node hierarchy.
@@ -147,6 +234,21 @@ impl<'a> Node<'a> {
         &self.json.extras
     }
 
+    /// Returns the per-instance transforms of the mesh at this node as defined by
+    /// the `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    pub fn mesh_gpu_instancing(
+        &self,
+    ) -> Option<crate::ext_mesh_gpu_instancing::MeshGpuInstancing<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .ext_mesh_gpu_instancing
+            .as_ref()
+            .map(|json| crate::ext_mesh_gpu_instancing::MeshGpuInstancing::new(self.document, json))
+    }
+
     /// Returns the light at this node as defined by the `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -244,6 +346,73 @@ impl<'a> Scene<'a> {
             iter: self.json.nodes.iter(),
         }
     }
+
+    /// Returns the world space bounding box of every mesh in the scene.
+    ///
+    /// Returns `None` if the scene contains no meshes or any mesh lacks
+    /// `POSITION` bounds.
+    pub fn bounding_box(&self) -> Option<crate::mesh::BoundingBox> {
+        let mut result: Option<crate::mesh::BoundingBox> = None;
+        for (node, matrix) in self.world_transforms() {
+            if let Some(mesh) = node.mesh() {
+                let bounds = mesh.bounding_box()?.transform(matrix);
+                result = Some(match result {
+                    Some(result) => result.union(&bounds),
+                    None => bounds,
+                });
+            }
+        }
+        result
+    }
+
+    /// Returns an `Iterator` that visits every node in the scene hierarchy, depth
+    /// first, along with its world transform matrix in column-major order.
+    ///
+    /// The world transform of a node is the product of the local transforms of
+    /// its ancestors and itself, starting from the root node.
+    ///
+    /// Each node is visited at most once, so invalid documents in which a node
+    /// is its own ancestor, or is reached along several paths, cannot cause
+    /// infinite or repeated traversal.
+    pub fn world_transforms(&self) -> iter::WorldTransforms<'a> {
+        let identity = Matrix4::identity().as_array();
+        let mut stack: Vec<_> = self.nodes().map(|node| (node, identity, 0)).collect();
+        stack.reverse();
+        iter::WorldTransforms {
+            stack,
+            visited: vec![false; self.document.nodes().len()],
+        }
+    }
+
+    /// Visits every node in the scene hierarchy depth first, calling `f` with
+    /// the node, its world transform matrix in column-major order and its
+    /// depth, which is zero for the root nodes of the scene.
+    ///
+    /// Nodes are visited in the order of [`Scene::world_transforms`], and
+    /// likewise at most once.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// scene.walk(|node, world, depth| {
+    ///     println!("{}{} at {:?}", "  ".repeat(depth), node.index(), world[3]);
+    /// });
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn walk<F>(&self, mut f: F)
+    where
+        F: FnMut(Node<'a>, [[f32; 4]; 4], usize),
+    {
+        let mut iter = self.world_transforms();
+        while let Some((node, world, depth)) = iter.next_with_depth() {
+            f(node, world, depth);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -296,6 +465,57 @@ mod tests {
         test_decompose(translation, rotation, scale);
     }
 
+    #[test]
+    fn world_transforms() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0, 2] }],
+            "nodes": [
+                { "translation": [1.0, 0.0, 0.0], "children": [1] },
+                { "scale": [2.0, 2.0, 2.0] },
+                { "translation": [0.0, 3.0, 0.0] }
+            ]
+        }"#;
+        let gltf = crate::Gltf::from_slice(json.as_bytes()).unwrap();
+        let scene = gltf.scenes().next().unwrap();
+        let transforms: Vec<_> = scene
+            .world_transforms()
+            .map(|(node, matrix)| (node.index(), matrix))
+            .collect();
+        assert_eq!(
+            transforms,
+            [
+                (
+                    0,
+                    [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [1.0, 0.0, 0.0, 1.0],
+                    ]
+                ),
+                (
+                    1,
+                    [
+                        [2.0, 0.0, 0.0, 0.0],
+                        [0.0, 2.0, 0.0, 0.0],
+                        [0.0, 0.0, 2.0, 0.0],
+                        [1.0, 0.0, 0.0, 1.0],
+                    ]
+                ),
+                (
+                    2,
+                    [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [0.0, 3.0, 0.0, 1.0],
+                    ]
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn decompose_identity() {
         let translation = [0.0, 0.0, 0.0];
@@ -591,4 +811,75 @@ mod tests {
         let scale = [10.0, 0.1, -0.1];
         test_decompose_scale(scale);
     }
+
+    #[test]
+    fn walk() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0, 3]}],
+            "nodes": [
+                {"children": [1, 2], "translation": [1, 0, 0]},
+                {"children": [0], "translation": [0, 2, 0]},
+                {"scale": [2, 2, 2]},
+                {"children": [2]}
+            ]
+        }"#;
+        let gltf = crate::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+        let scene = gltf.scenes().next().unwrap();
+        let mut visits = Vec::new();
+        scene.walk(|node, world, depth| {
+            visits.push((node.index(), world[3][..3].to_vec(), depth));
+        });
+        assert_eq!(
+            visits,
+            [
+                (0, vec![1.0, 0.0, 0.0], 0),
+                (1, vec![1.0, 2.0, 0.0], 1),
+                (2, vec![1.0, 0.0, 0.0], 1),
+                (3, vec![0.0, 0.0, 0.0], 0),
+            ]
+        );
+        let nodes: Vec<usize> = scene
+            .world_transforms()
+            .map(|(node, _)| node.index())
+            .collect();
+        assert_eq!(nodes, [0, 1, 2, 3]);
+    }
+
+    #[cfg(feature = "cgmath")]
+    #[test]
+    fn transform_into_cgmath() {
+        let transform = Transform::Decomposed {
+            translation: [1.0, 2.0, 3.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [2.0, 2.0, 2.0],
+        };
+        let matrix = cgmath::Matrix4::from(transform.clone());
+        assert_eq!(matrix.w, cgmath::Vector4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(matrix.x, cgmath::Vector4::new(2.0, 0.0, 0.0, 0.0));
+        let (translation, rotation, scale): (cgmath::Vector3<f32>, cgmath::Quaternion<f32>, _) =
+            transform.into();
+        assert_eq!(translation, cgmath::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(rotation, cgmath::Quaternion::new(1.0, 0.0, 0.0, 0.0));
+        assert_eq!(scale, cgmath::Vector3::new(2.0, 2.0, 2.0));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn transform_into_nalgebra() {
+        let transform = Transform::Decomposed {
+            translation: [1.0, 2.0, 3.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [2.0, 2.0, 2.0],
+        };
+        let matrix = nalgebra::Matrix4::from(transform.clone());
+        assert_eq!(matrix[(0, 3)], 1.0);
+        assert_eq!(matrix[(1, 3)], 2.0);
+        assert_eq!(matrix[(0, 0)], 2.0);
+        let (translation, rotation, scale): (nalgebra::Vector3<f32>, nalgebra::Quaternion<f32>, _) =
+            transform.into();
+        assert_eq!(translation, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(rotation, nalgebra::Quaternion::new(1.0, 0.0, 0.0, 0.0));
+        assert_eq!(scale, nalgebra::Vector3::new(2.0, 2.0, 2.0));
+    }
 }
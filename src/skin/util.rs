@@ -1,6 +1,7 @@
 use crate::accessor;
+use crate::math::Matrix4;
 
-use crate::{Buffer, Skin};
+use crate::{Buffer, Node, Skin};
 
 /// Inverse Bind Matrices of type `[[f32; 4]; 4]`.
 pub type ReadInverseBindMatrices<'a> = accessor::Iter<'a, [[f32; 4]; 4]>;
@@ -26,4 +27,108 @@ where
             .inverse_bind_matrices()
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
+
+    /// Computes the joint matrices of the skin for use in vertex skinning.
+    ///
+    /// Each joint matrix is the product of the world transform of the joint, as
+    /// returned by `world_transform`, and its inverse bind matrix. The identity
+    /// matrix is used when the skin has no inverse bind matrices.
+    ///
+    /// Returns `None` if the inverse bind matrices cannot be read, or if there
+    /// are fewer inverse bind matrices than joints.
+    pub fn joint_matrices<W>(&self, world_transform: W) -> Option<Vec<[[f32; 4]; 4]>>
+    where
+        W: Fn(Node<'a>) -> [[f32; 4]; 4],
+    {
+        let mut inverse_bind_matrices = match self.skin.inverse_bind_matrices() {
+            Some(_) => Some(self.read_inverse_bind_matrices()?),
+            None => None,
+        };
+        self.skin
+            .joints()
+            .map(|joint| {
+                let inverse_bind_matrix = match inverse_bind_matrices.as_mut() {
+                    Some(iter) => Matrix4::from_array(iter.next()?),
+                    None => Matrix4::identity(),
+                };
+                let world_transform = Matrix4::from_array(world_transform(joint));
+                Some((world_transform * inverse_bind_matrix).as_array())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    const JSON: &str = r#"{
+        "asset": { "version": "2.0" },
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [
+            { "translation": [1.0, 0.0, 0.0], "children": [1] },
+            { "translation": [0.0, 2.0, 0.0] }
+        ],
+        "buffers": [{ "byteLength": 128 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 128 }],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4" },
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "MAT4" }
+        ],
+        "skins": [
+            { "joints": [0, 1], "inverseBindMatrices": 0 },
+            { "joints": [1] },
+            { "joints": [0, 1], "inverseBindMatrices": 1 }
+        ]
+    }"#;
+
+    #[rustfmt::skip]
+    fn translation(x: f32, y: f32, z: f32) -> [[f32; 4]; 4] {
+        [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [x, y, z, 1.0],
+        ]
+    }
+
+    #[test]
+    fn joint_matrices() {
+        let gltf = crate::Gltf::from_slice(JSON.as_bytes()).unwrap();
+        // Inverse bind matrices of the joints in their bind pose.
+        let data: Vec<u8> = [translation(-1.0, 0.0, 0.0), translation(-1.0, -2.0, 0.0)]
+            .iter()
+            .flatten()
+            .flatten()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let world_transforms: HashMap<_, _> = gltf
+            .scenes()
+            .next()
+            .unwrap()
+            .world_transforms()
+            .map(|(node, matrix)| (node.index(), matrix))
+            .collect();
+        let world_transform = |node: crate::Node| world_transforms[&node.index()];
+
+        let mut skins = gltf.skins();
+        let skin = skins.next().unwrap();
+        let reader = skin.reader(|_| Some(&data));
+        assert_eq!(
+            reader.joint_matrices(world_transform).unwrap(),
+            [translation(0.0, 0.0, 0.0), translation(0.0, 0.0, 0.0)]
+        );
+
+        let skin = skins.next().unwrap();
+        let reader = skin.reader(|_| Some(&data));
+        assert_eq!(
+            reader.joint_matrices(world_transform).unwrap(),
+            [translation(1.0, 2.0, 0.0)]
+        );
+
+        // Too few inverse bind matrices for the joints.
+        let skin = skins.next().unwrap();
+        let reader = skin.reader(|_| Some(&data));
+        assert!(reader.joint_matrices(world_transform).is_none());
+    }
 }
@@ -132,19 +132,56 @@ impl<'a> Texture<'a> {
 
     /// Returns the sampler used by this texture.
     pub fn sampler(&self) -> Sampler<'a> {
-        self.json
-            .sampler
-            .as_ref()
-            .map(|index| self.document.samplers().nth(index.value()).unwrap())
-            .unwrap_or_else(|| Sampler::default(self.document))
+        self.try_sampler().unwrap()
+    }
+
+    /// Returns the sampler used by this texture.
+    ///
+    /// This returns `None` rather than panicking if the index is out of range,
+    /// which can only happen for documents loaded without validation.
+    pub fn try_sampler(&self) -> Option<Sampler<'a>> {
+        match self.json.sampler.as_ref() {
+            Some(index) => self.document.samplers().nth(index.value()),
+            None => Some(Sampler::default(self.document)),
+        }
     }
 
     /// Returns the image used by this texture.
+    ///
+    /// This is the texture's `source`, or, for textures without one, the image
+    /// provided by the `KHR_texture_basisu` or `EXT_texture_webp` extension.
     pub fn source(&self) -> image::Image<'a> {
-        self.document
-            .images()
-            .nth(self.json.source.value())
-            .unwrap()
+        self.try_source().unwrap()
+    }
+
+    /// Returns the image used by this texture, like [`Texture::source`].
+    ///
+    /// This returns `None` rather than panicking if the index is out of range
+    /// or no image is provided, which can only happen for documents loaded
+    /// without validation.
+    pub fn try_source(&self) -> Option<image::Image<'a>> {
+        let index = self.json.source.or_else(|| self.json.extension_source())?;
+        self.document.images().nth(index.value())
+    }
+
+    /// Returns the KTX2 image provided by the `KHR_texture_basisu` extension,
+    /// which should be preferred over [`Texture::source`] by clients able to
+    /// transcode Basis Universal textures.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    pub fn basisu_source(&self) -> Option<image::Image<'a>> {
+        let basisu = self.json.extensions.as_ref()?.texture_basisu.as_ref()?;
+        self.document.images().nth(basisu.source.value())
+    }
+
+    /// Returns the WebP image provided by the `EXT_texture_webp` extension,
+    /// which should be preferred over [`Texture::source`] by clients able to
+    /// decode WebP.
+    #[cfg(feature = "EXT_texture_webp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_texture_webp")))]
+    pub fn webp_source(&self) -> Option<image::Image<'a>> {
+        let webp = self.json.extensions.as_ref()?.texture_webp.as_ref()?;
+        self.document.images().nth(webp.source.value())
     }
 
     /// Optional application specific data.
@@ -202,6 +239,7 @@ impl<'a> AsRef<Texture<'a>> for Info<'a> {
 /// To support this use case, this extension adds `offset`, `rotation`, and `scale` properties to textureInfo structures.
 /// These properties would typically be implemented as an affine transform on the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug)]
 pub struct TextureTransform<'a> {
     /// The corresponding JSON struct.
     json: &'a json::extensions::texture::TextureTransform,
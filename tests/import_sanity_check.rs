@@ -83,3 +83,55 @@ fn sparse_accessor_without_buffer_view_test() -> Result<(), Box<dyn StdError>> {
 fn import_sanity_check() {
     assert!(run().is_ok());
 }
+
+#[cfg(feature = "utils")]
+#[test]
+fn read_triangles_matches_indices() {
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+            let triangles: Vec<[u32; 3]> = reader.read_triangles().unwrap().collect();
+            assert_eq!(triangles.concat(), indices);
+            assert!(reader.read_lines().is_none());
+            assert!(reader.read_points().is_none());
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn import_buffers_mapped_matches_import_buffers() {
+    let path = path::Path::new("tests/box_sparse.gltf");
+    let gltf = gltf::Gltf::open(path).unwrap();
+    let base = path.parent();
+    let buffers = gltf::import_buffers(&gltf.document, base, None).unwrap();
+    let mapped = unsafe { gltf::import_buffers_mapped(&gltf.document, base, None) }.unwrap();
+    assert_eq!(buffers.len(), mapped.len());
+    for (data, mapped) in buffers.iter().zip(&mapped) {
+        assert!(matches!(mapped, gltf::buffer::MappedData::Mapped(_)));
+        assert_eq!(&data[..mapped.len()], &mapped[..]);
+    }
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn import_async_matches_import() {
+    let path = path::Path::new("tests/box_sparse.gltf");
+    let (document, buffers, images) = gltf::import(path).unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let (async_document, async_buffers, async_images) =
+        runtime.block_on(gltf::import_async(path)).unwrap();
+    assert_eq!(
+        document.into_json().to_string().unwrap(),
+        async_document.into_json().to_string().unwrap()
+    );
+    assert_eq!(buffers.len(), async_buffers.len());
+    for (data, async_data) in buffers.iter().zip(&async_buffers) {
+        assert_eq!(data.0, async_data.0);
+    }
+    assert_eq!(images.len(), async_images.len());
+}
@@ -22,3 +22,539 @@ fn test_accessor_bounds() {
         }
     );
 }
+
+#[test]
+fn test_scene_bounding_box() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [{
+            "bufferView": 0, "count": 3, "componentType": 5126, "type": "VEC3",
+            "min": [-1.0, -1.0, -1.0], "max": [1.0, 1.0, 1.0]
+        }],
+        "buffers": [{ "byteLength": 36 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+        "nodes": [
+            { "translation": [10.0, 0.0, 0.0], "children": [1] },
+            { "mesh": 0 },
+            { "mesh": 0, "scale": [2.0, 2.0, 2.0] }
+        ],
+        "scenes": [{ "nodes": [0, 2] }]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mesh = gltf.meshes().next().unwrap();
+    assert_eq!(
+        mesh.bounding_box(),
+        Some(Bounds {
+            min: [-1.0, -1.0, -1.0],
+            max: [1.0, 1.0, 1.0]
+        })
+    );
+    let scene = gltf.scenes().next().unwrap();
+    assert_eq!(
+        scene.bounding_box(),
+        Some(Bounds {
+            min: [-2.0, -2.0, -2.0],
+            max: [11.0, 2.0, 2.0]
+        })
+    );
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_texture_transform() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [{ "uri": "image.png" }],
+        "textures": [{ "source": 0 }],
+        "materials": [{
+            "normalTexture": {
+                "index": 0,
+                "extensions": {
+                    "KHR_texture_transform": { "offset": [0.5, 0.25], "texCoord": 1 }
+                }
+            },
+            "occlusionTexture": {
+                "index": 0,
+                "extensions": {
+                    "KHR_texture_transform": { "rotation": 1.5, "scale": [2.0, 3.0] }
+                }
+            }
+        }]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let material = gltf.materials().next().unwrap();
+
+    let transform = material
+        .normal_texture()
+        .unwrap()
+        .texture_transform()
+        .unwrap();
+    assert_eq!(transform.offset(), [0.5, 0.25]);
+    assert_eq!(transform.rotation(), 0.0);
+    assert_eq!(transform.scale(), [1.0, 1.0]);
+    assert_eq!(transform.tex_coord(), Some(1));
+
+    let transform = material
+        .occlusion_texture()
+        .unwrap()
+        .texture_transform()
+        .unwrap();
+    assert_eq!(transform.offset(), [0.0, 0.0]);
+    assert_eq!(transform.rotation(), 1.5);
+    assert_eq!(transform.scale(), [2.0, 3.0]);
+    assert_eq!(transform.tex_coord(), None);
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[test]
+fn test_draco_mesh_compression() {
+    use gltf::json::accessor::ComponentType;
+    use gltf::mesh::util::{DracoAttribute, DracoGeometry};
+    use gltf::mesh::Semantic;
+
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 16 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 16 }],
+        "accessors": [
+            {
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [0.0, 0.0, 0.0],
+                "max": [1.0, 1.0, 1.0]
+            },
+            { "componentType": 5126, "count": 3, "type": "VEC2" }
+        ],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": 0, "TEXCOORD_0": 1 },
+                "extensions": {
+                    "KHR_draco_mesh_compression": {
+                        "bufferView": 0,
+                        "attributes": { "POSITION": 0, "TEXCOORD_0": 1 }
+                    }
+                }
+            }]
+        }]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let draco = primitive.draco_mesh_compression().unwrap();
+    assert_eq!(draco.view().index(), 0);
+    assert_eq!(draco.get(&Semantic::Positions), Some(0));
+    assert_eq!(draco.get(&Semantic::Normals), None);
+    assert_eq!(
+        draco.attributes().collect::<Vec<_>>(),
+        [(Semantic::Positions, 0), (Semantic::TexCoords(0), 1)]
+    );
+
+    // Accessors of compressed primitives have no data until decoded.
+    let data = (0..16).collect::<Vec<u8>>();
+    let reader = primitive.reader(|_| Some(&data));
+    assert!(reader.read_positions().is_none());
+
+    let attribute = |semantic, components, count| DracoAttribute {
+        semantic,
+        component_type: ComponentType::F32,
+        normalized: false,
+        components,
+        values: vec![0.5; components * count],
+    };
+    let decode = |count| {
+        move |data: &[u8], ids: &[(Semantic, u32)]| {
+            assert_eq!(data, &(0..16).collect::<Vec<u8>>()[..]);
+            assert_eq!(ids, [(Semantic::Positions, 0), (Semantic::TexCoords(0), 1)]);
+            Some(DracoGeometry {
+                triangles: vec![[0, 1, 2]],
+                attributes: vec![
+                    attribute(Semantic::Positions, 3, count),
+                    attribute(Semantic::TexCoords(0), 2, count),
+                ],
+            })
+        }
+    };
+    let geometry = reader.read_draco(decode(3)).unwrap();
+    assert_eq!(geometry.triangles, [[0, 1, 2]]);
+    assert_eq!(geometry.attributes[0].values.len(), 9);
+
+    // Geometry that does not match the accessors is rejected.
+    assert!(reader.read_draco(decode(2)).is_none());
+    assert!(reader.read_draco(|_, _| None).is_none());
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_material_for_variant() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["KHR_materials_variants"],
+        "extensions": {
+            "KHR_materials_variants": {
+                "variants": [{ "name": "red" }, { "name": "green" }, { "name": "blue" }]
+            }
+        },
+        "buffers": [{ "byteLength": 12 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 12 }],
+        "accessors": [{
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 1,
+            "type": "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [0.0, 0.0, 0.0]
+        }],
+        "materials": [{}, {}],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": 0 },
+                "material": 0,
+                "extensions": {
+                    "KHR_materials_variants": {
+                        "mappings": [{ "material": 1, "variants": [0, 2] }]
+                    }
+                }
+            }]
+        }]
+    }"#;
+    let root = gltf::json::Root::from_str(json).unwrap();
+    let primitive = &root.meshes[0].primitives[0];
+    let material = |variant| {
+        primitive
+            .material_for_variant(gltf::json::Index::new(variant))
+            .map(|index| index.value())
+    };
+    assert_eq!(material(0), Some(1));
+    assert_eq!(material(1), Some(0));
+    assert_eq!(material(2), Some(1));
+
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.material_for_variant(1).index(), Some(0));
+    assert_eq!(primitive.material_for_variant(2).index(), Some(1));
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[test]
+fn test_emissive_strength() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_emissive_strength": { "emissiveStrength": 5.0 } } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let strengths: Vec<_> = gltf.materials().map(|x| x.emissive_strength()).collect();
+    assert_eq!(strengths, [5.0, 1.0]);
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[test]
+fn test_clearcoat() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [{ "uri": "image.png" }],
+        "textures": [{ "source": 0 }],
+        "materials": [{
+            "extensions": {
+                "KHR_materials_clearcoat": {
+                    "clearcoatFactor": 1.0,
+                    "clearcoatRoughnessTexture": { "index": 0 },
+                    "clearcoatNormalTexture": { "index": 0, "scale": 0.5 }
+                }
+            }
+        }]
+    }"#;
+    let root = gltf::json::Root::from_str(json).unwrap();
+    let gltf = gltf::Gltf::from_slice(&root.to_vec().unwrap()).unwrap();
+    let material = gltf.materials().next().unwrap();
+    let clearcoat = material.clearcoat().unwrap();
+    assert_eq!(clearcoat.clearcoat_factor(), 1.0);
+    assert!(clearcoat.clearcoat_texture().is_none());
+    assert_eq!(clearcoat.clearcoat_roughness_factor(), 0.0);
+    assert!(clearcoat.clearcoat_roughness_texture().is_some());
+    assert_eq!(clearcoat.clearcoat_normal_texture().unwrap().scale(), 0.5);
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[test]
+fn test_sheen() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [{ "uri": "image.png" }],
+        "textures": [{ "source": 0 }],
+        "materials": [{
+            "extensions": {
+                "KHR_materials_sheen": {
+                    "sheenColorFactor": [0.5, 0.25, 1.0],
+                    "sheenColorTexture": { "index": 0, "texCoord": 1 },
+                    "sheenRoughnessFactor": 0.75
+                }
+            }
+        }]
+    }"#;
+    let root = gltf::json::Root::from_str(json).unwrap();
+    let gltf = gltf::Gltf::from_slice(&root.to_vec().unwrap()).unwrap();
+    let material = gltf.materials().next().unwrap();
+    let sheen = material.sheen().unwrap();
+    assert_eq!(sheen.sheen_color_factor(), [0.5, 0.25, 1.0]);
+    assert_eq!(sheen.sheen_color_texture().unwrap().tex_coord(), 1);
+    assert_eq!(sheen.sheen_roughness_factor(), 0.75);
+    assert!(sheen.sheen_roughness_texture().is_none());
+}
+
+#[cfg(all(
+    feature = "KHR_materials_anisotropy",
+    feature = "KHR_materials_iridescence"
+))]
+#[test]
+fn test_anisotropy_and_iridescence() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [{ "uri": "image.png" }],
+        "textures": [{ "source": 0 }],
+        "materials": [{
+            "extensions": {
+                "KHR_materials_anisotropy": {
+                    "anisotropyStrength": 0.5,
+                    "anisotropyRotation": 1.0,
+                    "anisotropyTexture": { "index": 0 }
+                },
+                "KHR_materials_iridescence": {
+                    "iridescenceFactor": 1.0,
+                    "iridescenceThicknessMaximum": 800.0,
+                    "iridescenceThicknessTexture": { "index": 0 }
+                }
+            }
+        }]
+    }"#;
+    let root = gltf::json::Root::from_str(json).unwrap();
+    let gltf = gltf::Gltf::from_slice(&root.to_vec().unwrap()).unwrap();
+    let material = gltf.materials().next().unwrap();
+
+    let anisotropy = material.anisotropy().unwrap();
+    assert_eq!(anisotropy.anisotropy_strength(), 0.5);
+    assert_eq!(anisotropy.anisotropy_rotation(), 1.0);
+    assert!(anisotropy.anisotropy_texture().is_some());
+
+    let iridescence = material.iridescence().unwrap();
+    assert_eq!(iridescence.iridescence_factor(), 1.0);
+    assert!(iridescence.iridescence_texture().is_none());
+    assert_eq!(iridescence.iridescence_ior(), 1.3);
+    assert_eq!(iridescence.iridescence_thickness_minimum(), 100.0);
+    assert_eq!(iridescence.iridescence_thickness_maximum(), 800.0);
+    assert!(iridescence.iridescence_thickness_texture().is_some());
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_load_v1() {
+    let json = r#"{
+        "asset": { "version": "1.0" },
+        "scene": "scene",
+        "scenes": { "scene": { "nodes": ["node"] } },
+        "nodes": { "node": { "meshes": ["mesh"] } },
+        "meshes": {
+            "mesh": {
+                "primitives": [{
+                    "attributes": { "POSITION": "positions" },
+                    "material": "material"
+                }]
+            }
+        },
+        "accessors": {
+            "positions": {
+                "bufferView": "view",
+                "byteOffset": 0,
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [0, 0, 0],
+                "max": [1, 1, 0]
+            }
+        },
+        "bufferViews": { "view": { "buffer": "buffer", "byteOffset": 0, "byteLength": 36 } },
+        "buffers": { "buffer": { "uri": "triangle.bin", "byteLength": 36 } },
+        "materials": { "material": { "values": { "diffuse": [0, 1, 0, 1] } } }
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let node = scene.nodes().next().unwrap();
+    let primitive = node.mesh().unwrap().primitives().next().unwrap();
+    assert!(primitive.get(&gltf::Semantic::Positions).is_some());
+
+    // Conversion failures are reported as invalid data.
+    let unresolved = json.replace(r#""meshes": ["mesh"]"#, r#""meshes": ["missing"]"#);
+    match gltf::Gltf::from_slice(unresolved.as_bytes()) {
+        Err(gltf::Error::Io(error)) => {
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(error.to_string(), r#"unresolved mesh id "missing""#);
+        }
+        _ => panic!("expected a conversion error"),
+    }
+    assert_eq!(
+        primitive
+            .material()
+            .pbr_metallic_roughness()
+            .base_color_factor(),
+        [0.0, 1.0, 0.0, 1.0]
+    );
+
+    // The JSON chunk of binary glTF is converted too.
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: json.as_bytes().into(),
+        bin: None,
+        chunks: Vec::new(),
+    };
+    let mut slice = Vec::new();
+    glb.to_writer(&mut slice).unwrap();
+    let gltf = gltf::Gltf::from_slice(&slice).unwrap();
+    assert_eq!(gltf.meshes().len(), 1);
+    let gltf = gltf::Gltf::from_reader(std::io::Cursor::new(&slice)).unwrap();
+    assert_eq!(gltf.meshes().len(), 1);
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[test]
+fn test_texture_basisu() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [
+            { "uri": "fallback.png" },
+            { "uri": "texture.ktx2", "mimeType": "image/ktx2" }
+        ],
+        "textures": [
+            { "source": 0, "extensions": { "KHR_texture_basisu": { "source": 1 } } },
+            { "source": 0 },
+            { "extensions": { "KHR_texture_basisu": { "source": 1 } } }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mut textures = gltf.textures();
+    let texture = textures.next().unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert_eq!(texture.basisu_source().unwrap().index(), 1);
+    assert!(textures.next().unwrap().basisu_source().is_none());
+
+    // Without a fallback, the source is the KTX2 image.
+    let texture = textures.next().unwrap();
+    assert_eq!(texture.source().index(), 1);
+    assert_eq!(texture.basisu_source().unwrap().index(), 1);
+}
+
+#[cfg(all(feature = "KHR_texture_basisu", feature = "import"))]
+#[test]
+fn test_import_texture_basisu() {
+    // The header of a 2x3 KTX2 image, without any level data.
+    let mut ktx2 = b"\xabKTX 20\xbb\r\n\x1a\n".to_vec();
+    ktx2.extend([0; 8]);
+    ktx2.extend(2u32.to_le_bytes());
+    ktx2.extend(3u32.to_le_bytes());
+    let json = format!(
+        r#"{{
+            "asset": {{ "version": "2.0" }},
+            "extensionsUsed": ["KHR_texture_basisu"],
+            "extensionsRequired": ["KHR_texture_basisu"],
+            "images": [{{ "uri": "data:image/ktx2;base64,{}" }}],
+            "textures": [{{ "extensions": {{ "KHR_texture_basisu": {{ "source": 0 }} }} }}]
+        }}"#,
+        base64::encode(&ktx2)
+    );
+    let (document, _, images) = gltf::import_slice(json.as_bytes()).unwrap();
+    assert_eq!(document.textures().next().unwrap().source().index(), 0);
+    assert_eq!(images[0].format, gltf::image::Format::Ktx2);
+    assert_eq!((images[0].width, images[0].height), (2, 3));
+    assert_eq!(images[0].pixels, ktx2);
+}
+
+#[cfg(feature = "EXT_texture_webp")]
+#[test]
+fn test_texture_webp() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "images": [
+            { "uri": "fallback.png" },
+            { "uri": "texture.webp", "mimeType": "image/webp" }
+        ],
+        "textures": [
+            { "source": 0, "extensions": { "EXT_texture_webp": { "source": 1 } } },
+            { "extensions": { "EXT_texture_webp": { "source": 1 } } }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mut textures = gltf.textures();
+    let texture = textures.next().unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert_eq!(texture.webp_source().unwrap().index(), 1);
+
+    // Without a fallback, the source is the WebP image.
+    let texture = textures.next().unwrap();
+    assert_eq!(texture.source().index(), 1);
+    assert_eq!(texture.webp_source().unwrap().index(), 1);
+}
+
+#[test]
+fn test_deserialize_error_location() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [
+            { "primitives": [] },
+            { "primitives": [{ "attributes": { "POSITION": "zero" } }] }
+        ]
+    }"#;
+    let error = gltf::Gltf::from_slice(json.as_bytes()).unwrap_err();
+    let path = error.json_path(json.as_bytes()).unwrap();
+    assert_eq!(path.as_str(), "meshes[1].primitives[0].attributes.POSITION");
+    match error {
+        gltf::Error::Deserialize(error) => assert_eq!(error.line(), 5),
+        _ => panic!("unexpected error: {}", error),
+    }
+
+    // Streamed input reports the same position.
+    let reader = std::io::Cursor::new(json.as_bytes());
+    let error = gltf::Gltf::from_reader(reader).unwrap_err();
+    let path = error.json_path(json.as_bytes()).unwrap();
+    assert_eq!(path.as_str(), "meshes[1].primitives[0].attributes.POSITION");
+
+    // A byte order mark is skipped when streaming too.
+    let json = b"\xEF\xBB\xBF{\"asset\": {\"version\": \"2.0\"}}";
+    assert!(gltf::Gltf::from_reader(std::io::Cursor::new(&json[..])).is_ok());
+}
+
+#[test]
+fn test_try_getters_on_unvalidated_documents() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [{ "count": 1, "componentType": 5126, "type": "SCALAR" }],
+        "animations": [{
+            "samplers": [{ "input": 0, "output": 7 }],
+            "channels": [{ "sampler": 3, "target": { "node": 9, "path": "scale" } }]
+        }],
+        "images": [{ "bufferView": 5, "mimeType": "image/png" }],
+        "meshes": [{ "primitives": [{ "attributes": {}, "material": 4 }] }],
+        "textures": [{ "source": 6, "sampler": 8 }]
+    }"#;
+    let root = gltf::json::deserialize::from_str(json).unwrap();
+    let document = gltf::Document::from_json_without_validation(root);
+
+    let animation = document.animations().next().unwrap();
+    let channel = animation.channels().next().unwrap();
+    assert!(channel.try_sampler().is_none());
+    assert!(channel.target().try_node().is_none());
+    let sampler = animation.samplers().next().unwrap();
+    assert!(sampler.try_input().is_some());
+    assert!(sampler.try_output().is_none());
+    assert!(document.images().next().unwrap().try_source().is_none());
+    let mesh = document.meshes().next().unwrap();
+    assert!(mesh.primitives().next().unwrap().try_material().is_none());
+    let texture = document.textures().next().unwrap();
+    assert!(texture.try_source().is_none());
+    assert!(texture.try_sampler().is_none());
+}